@@ -8,24 +8,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     ];
 
     // Actually invoke code generation on our search paths.
-    let (source, dependent_paths) =
-        roslibrust::codegen::find_and_generate_ros_messages_without_ros_package_path(p)?;
-    // This returns two things:
-    // 1) A TokenStream which is the rust code we want to generate
-    // 2) A list of paths that if modified would require the code to be regenerated. We use this to inform Cargo
+    let packages = roslibrust::codegen::find_and_generate_ros_messages_per_package(
+        p,
+        &roslibrust::codegen::CodegenOptions::default(),
+    )?;
+    // This returns one entry per ROS package found, each containing:
+    // 1) The name of the package
+    // 2) A TokenStream which is the rust code we want to generate for that package
+    // 3) A list of paths that if modified would require the code to be regenerated. We use this to inform Cargo
     //    of when to re-run our build script.
 
     // It is important for build scripts to only output files to OUT_DIR which is an environment variable set by Cargo.
     let out_dir = std::env::var_os("OUT_DIR").unwrap();
-    // Name of the file in out_dir we want to write our generated code to
-    let dest_path = std::path::Path::new(&out_dir).join("messages.rs");
-    // Write the generated code to disk
-    std::fs::write(dest_path, source.to_string())?;
+    // Write the generated code to disk, one file per package plus a mod.rs which includes all of them.
+    // Files whose contents didn't change are left untouched, so cargo doesn't needlessly recompile our crate.
+    roslibrust::codegen::write_generated_packages(&packages, std::path::Path::new(&out_dir))?;
 
     // If we stopped at this point, our code would still work, but Cargo would not know to rebuild
     // our package when a message file changed.
     // Cargo recognizes certain command line strings that build scripts print out:
-    for path in &dependent_paths {
+    for path in packages.iter().flat_map(|package| &package.dependent_paths) {
         // Tell cargo to re-run our build script if any of these files change
         println!("cargo:rerun-if-changed={}", path.display());
     }
@@ -3,7 +3,7 @@
 
 // ANCHOR: getting_started_1
 // Bring generated messages into scope:
-include!(concat!(env!("OUT_DIR"), "/messages.rs"));
+include!(concat!(env!("OUT_DIR"), "/mod.rs"));
 
 // Bring in traits we need from roslibrust
 use roslibrust::traits::{Publish, Ros};
@@ -1,9 +1,9 @@
 //! This file shows how to correctly import files generated by build.rs:
 
-// This macro trick correctly "imports" messages.rs into our crate
+// This macro trick correctly "imports" the generated mod.rs (which includes one file per package) into our crate
 // This should only be invoked once in the crate and other locations can access the
 // messages via `use`
-include!(concat!(env!("OUT_DIR"), "/messages.rs"));
+include!(concat!(env!("OUT_DIR"), "/mod.rs"));
 
 // Important to bring traits we need into scope from roslibrust
 // In this case we need to the Publish trait in scope so we can access the .publish() function on our Publisher
@@ -17,6 +17,17 @@ mod ros2 {
     );
 }
 
+/// Generates `From` impls between the ros1 and ros2 versions of each message, e.g. `std_msgs::Header`
+mod conversions {
+    roslibrust_codegen_macro::generate_ros1_ros2_conversions!(
+        crate::ros1 = ["assets/ros1_common_interfaces/std_msgs"],
+        crate::ros2 = [
+            "assets/ros2_common_interfaces/std_msgs",
+            "assets/ros2_required_msgs/rcl_interfaces/builtin_interfaces"
+        ],
+    );
+}
+
 /// The goal of this example is to create a "baby bridge" that will listen to a ros1 message,
 /// and re-publish it to ros2.
 ///
@@ -82,15 +93,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 info!("Got a message from ros1 {msg:?}");
 
                 // We got a new message!
-                // Covert it to the ros2 format
-                let converted_msg = ros2::std_msgs::Header {
-                    // TODO want to try to unify the time types again, but need to sort out ROS2 hashing first
-                    stamp: ros2::builtin_interfaces::Time {
-                        sec: msg.stamp.secs,
-                        nanosec: msg.stamp.nsecs as u32,
-                    },
-                    frame_id: msg.frame_id,
-                };
+                // Covert it to the ros2 format using the generated conversion
+                let converted_msg: ros2::std_msgs::Header = msg.into();
 
                 // and re-publish it!
                 publisher.publish(&converted_msg).await?;
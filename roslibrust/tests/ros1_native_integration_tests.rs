@@ -273,6 +273,119 @@ mod tests {
         }
     }
 
+    #[test_log::test(tokio::test)]
+    async fn persistent_client_reconnects_to_restarted_server() {
+        let nh = NodeHandle::new(
+            "http://localhost:11311",
+            "/persistent_client_reconnects_to_restarted_server",
+        )
+        .await
+        .unwrap();
+        let service_name = "/persistent_client_reconnects_to_restarted_server/add_two";
+
+        let server_fn = |request: test_msgs::AddTwoIntsRequest| {
+            Ok(test_msgs::AddTwoIntsResponse {
+                sum: request.a + request.b,
+            })
+        };
+
+        let handle = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>(service_name, server_fn)
+            .await
+            .unwrap();
+        let client = nh
+            .service_client::<test_msgs::AddTwoInts>(service_name)
+            .await
+            .unwrap();
+        client
+            .call(&test_msgs::AddTwoIntsRequest { a: 1, b: 2 })
+            .await
+            .unwrap();
+
+        // Restart the server, the existing client should find it again
+        std::mem::drop(handle);
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        let _handle = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>(service_name, server_fn)
+            .await
+            .unwrap();
+
+        let call = client
+            .call(&test_msgs::AddTwoIntsRequest { a: 2, b: 2 })
+            .await
+            .unwrap();
+        assert_eq!(call.sum, 4);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn service_call_timeout() {
+        let nh = NodeHandle::new("http://localhost:11311", "/service_call_timeout")
+            .await
+            .unwrap();
+
+        let server_fn = |request: test_msgs::AddTwoIntsRequest| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            Ok(test_msgs::AddTwoIntsResponse {
+                sum: request.a + request.b,
+            })
+        };
+        let _handle = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>("/service_call_timeout/slow", server_fn)
+            .await
+            .unwrap();
+
+        let client = nh
+            .service_client::<test_msgs::AddTwoInts>("/service_call_timeout/slow")
+            .await
+            .unwrap()
+            .with_timeout(std::time::Duration::from_millis(50));
+        let call = client
+            .call(&test_msgs::AddTwoIntsRequest { a: 1, b: 2 })
+            .await;
+        assert!(matches!(call, Err(roslibrust::Error::Timeout(_))));
+
+        // A longer per-call timeout overrides the client's and succeeds
+        let call = client
+            .call_with_timeout(
+                &test_msgs::AddTwoIntsRequest { a: 1, b: 2 },
+                std::time::Duration::from_secs(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(call.sum, 3);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn wait_for_service() {
+        let nh = NodeHandle::new("http://localhost:11311", "/wait_for_service")
+            .await
+            .unwrap();
+
+        let res = nh
+            .wait_for_service(
+                "/wait_for_service/add_two",
+                Some(std::time::Duration::from_millis(250)),
+            )
+            .await;
+        assert!(matches!(res, Err(NodeError::Timeout(_))));
+
+        let server_fn = |request: test_msgs::AddTwoIntsRequest| {
+            Ok(test_msgs::AddTwoIntsResponse {
+                sum: request.a + request.b,
+            })
+        };
+        let _handle = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>("/wait_for_service/add_two", server_fn)
+            .await
+            .unwrap();
+        nh.wait_for_service(
+            "/wait_for_service/add_two",
+            Some(std::time::Duration::from_secs(1)),
+        )
+        .await
+        .unwrap();
+    }
+
     #[test_log::test(tokio::test)]
     async fn basic_service_server() {
         const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
@@ -427,6 +540,50 @@ mod tests {
         assert!(call.is_err());
     }
 
+    #[test_log::test(tokio::test)]
+    async fn intra_process_messages_are_shared() {
+        let nh = NodeHandle::new(
+            "http://localhost:11311",
+            "/intra_process_messages_are_shared",
+        )
+        .await
+        .unwrap();
+        let topic = "/intra_process_messages_are_shared";
+
+        let mut subscriber = nh.subscribe::<std_msgs::String>(topic, 1).await.unwrap();
+        let publisher = nh
+            .advertise::<std_msgs::String>(topic, 1, false)
+            .await
+            .unwrap();
+        // Publishers are connected to local subscriptions immediately, no need to wait on the master
+        let msg = std::sync::Arc::new(std_msgs::String {
+            data: "shared".to_string(),
+        });
+        publisher.publish_shared(msg.clone()).await.unwrap();
+
+        let received = timeout(
+            std::time::Duration::from_millis(250),
+            subscriber.next_shared(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+        assert!(std::sync::Arc::ptr_eq(&msg, &received));
+
+        // The connection is still visible to the rest of the ROS graph
+        let master_client = roslibrust_ros1::MasterClient::new(
+            "http://localhost:11311",
+            "NAN",
+            "/intra_process_messages_are_shared_mc",
+        )
+        .await
+        .unwrap();
+        let state = master_client.get_system_state().await.unwrap();
+        assert!(state.is_publishing(topic, "/intra_process_messages_are_shared"));
+        assert!(state.is_subscribed(topic, "/intra_process_messages_are_shared"));
+    }
+
     #[test_log::test(tokio::test)]
     async fn test_dropping_publisher_unadvertises() {
         let nh = NodeHandle::new("http://localhost:11311", "/test_dropping_publisher")
@@ -0,0 +1,260 @@
+//! Wrapper types for ROS2 bounded sequences (`int32[<=5]`) and bounded strings (`string<=10`)
+//!
+//! These serialize exactly like the `Vec` / `String` they wrap, but can't be constructed or deserialized
+//! holding more elements than their bound allows. DDS peers reject messages exceeding a bound, so
+//! it is much better to find out when building the message than after it has been sent.
+
+use simple_error::{bail, SimpleError};
+
+use crate::WString;
+
+/// A `Vec<T>` holding at most `N` elements, generated for ROS2 bounded sequences e.g. `int32[<=5]`
+/// Derefs to a slice so elements can be read and modified in place, operations changing the length go
+/// through methods which check the bound.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BoundedVec<T, const N: usize>(Vec<T>);
+
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// The maximum number of elements this type can hold
+    pub const BOUND: usize = N;
+
+    /// Creates an empty BoundedVec
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Appends an element, fails if the vector is already full
+    pub fn push(&mut self, value: T) -> Result<(), SimpleError> {
+        if self.0.len() >= N {
+            bail!("Unable to push element, BoundedVec is already at its bound of {N}");
+        }
+        self.0.push(value);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
+
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> std::ops::Deref for BoundedVec<T, N> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> std::ops::DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for BoundedVec<T, N> {
+    type Error = SimpleError;
+    fn try_from(val: Vec<T>) -> Result<Self, Self::Error> {
+        if val.len() > N {
+            bail!(
+                "Vec of length {} exceeds the bound of {N} for this BoundedVec",
+                val.len()
+            );
+        }
+        Ok(Self(val))
+    }
+}
+
+impl<T, const N: usize> From<BoundedVec<T, N>> for Vec<T> {
+    fn from(val: BoundedVec<T, N>) -> Self {
+        val.0
+    }
+}
+
+impl<T, const N: usize> IntoIterator for BoundedVec<T, N> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a BoundedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: serde::Serialize, const N: usize> serde::Serialize for BoundedVec<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for BoundedVec<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner = Vec::<T>::deserialize(deserializer)?;
+        Self::try_from(inner).map_err(serde::de::Error::custom)
+    }
+}
+
+// BoundedString and BoundedWString only differ in what they wrap and how length is measured
+macro_rules! bounded_string {
+    ($(#[$doc:meta])* $name:ident, $inner:ty, $len:expr, $unit:literal) => {
+        $(#[$doc])*
+        #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name<const N: usize>($inner);
+
+        impl<const N: usize> $name<N> {
+            /// The maximum length of this type, in
+            #[doc = $unit]
+            pub const BOUND: usize = N;
+
+            /// Appends to the string, fails if the result would exceed the bound
+            pub fn push_str(&mut self, s: &str) -> Result<(), SimpleError> {
+                let mut value = String::from(self.0.clone());
+                value.push_str(s);
+                *self = Self::try_from(<$inner>::from(value))?;
+                Ok(())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl<const N: usize> std::ops::Deref for $name<N> {
+            type Target = str;
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl<const N: usize> std::fmt::Display for $name<N> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl<const N: usize> TryFrom<$inner> for $name<N> {
+            type Error = SimpleError;
+            fn try_from(val: $inner) -> Result<Self, Self::Error> {
+                let len = $len(&val);
+                if len > N {
+                    bail!(
+                        "String {val:?} of length {len} exceeds the bound of {N} {} for this {}",
+                        $unit,
+                        stringify!($name)
+                    );
+                }
+                Ok(Self(val))
+            }
+        }
+
+        impl<const N: usize> TryFrom<&str> for $name<N> {
+            type Error = SimpleError;
+            fn try_from(val: &str) -> Result<Self, Self::Error> {
+                Self::try_from(<$inner>::from(val))
+            }
+        }
+
+        impl<const N: usize> From<$name<N>> for $inner {
+            fn from(val: $name<N>) -> Self {
+                val.0
+            }
+        }
+
+        impl<const N: usize> serde::Serialize for $name<N> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de, const N: usize> serde::Deserialize<'de> for $name<N> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = <$inner>::deserialize(deserializer)?;
+                Self::try_from(inner).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+bounded_string!(
+    /// A `String` at most `N` bytes long, generated for ROS2 bounded strings e.g. `string<=10`
+    BoundedString,
+    String,
+    String::len,
+    "bytes"
+);
+
+bounded_string!(
+    /// A [WString] at most `N` UTF-16 code units long, generated for ROS2 bounded wstrings e.g. `wstring<=10`
+    BoundedWString,
+    WString,
+    WString::len_utf16,
+    "UTF-16 code units"
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{BoundedString, BoundedVec, BoundedWString};
+
+    #[test]
+    fn bounded_vec_enforces_bound() {
+        let mut vec = BoundedVec::<i32, 2>::try_from(vec![1]).unwrap();
+        vec.push(2).unwrap();
+        assert!(vec.push(3).is_err());
+        assert_eq!(&vec[..], &[1, 2]);
+        assert!(BoundedVec::<i32, 2>::try_from(vec![1, 2, 3]).is_err());
+
+        // Serializes like the Vec it wraps, and checks the bound when deserializing
+        let serialized = serde_json::to_string(&vec).unwrap();
+        assert_eq!(serialized, "[1,2]");
+        let roundtrip: BoundedVec<i32, 2> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(roundtrip, vec);
+        assert!(serde_json::from_str::<BoundedVec<i32, 2>>("[1,2,3]").is_err());
+    }
+
+    #[test]
+    fn bounded_string_enforces_bound() {
+        let mut string = BoundedString::<5>::try_from("abc").unwrap();
+        string.push_str("de").unwrap();
+        assert!(string.push_str("f").is_err());
+        assert_eq!(string.as_str(), "abcde");
+        // Bound is in bytes
+        assert!(BoundedString::<5>::try_from("ééé").is_err());
+
+        assert_eq!(serde_json::to_string(&string).unwrap(), "\"abcde\"");
+        assert!(serde_json::from_str::<BoundedString<5>>("\"abcdef\"").is_err());
+
+        // Bound is in UTF-16 code units
+        assert!(BoundedWString::<3>::try_from("ééé").is_ok());
+        assert!(BoundedWString::<3>::try_from("éé😀").is_err());
+    }
+}
@@ -0,0 +1,554 @@
+//! Generation of conversions between the ROS1 and ROS2 versions of messages
+//!
+//! Given the ROS1 and ROS2 messages generated into two different modules, this pairs up messages with the
+//! same package and name and generates `From` / `TryFrom` impls between them when their fields line up.
+//! Converting into ROS2 is `TryFrom` when bounded sequences or strings are involved, converting into ROS1 is always `From`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::parse::convert_ros_type_to_rust_type;
+use crate::utils::RosVersion;
+use crate::{bail, find_and_parse_ros_messages, resolve_dependency_graph};
+use crate::{ArrayType, Error, FieldInfo, MessageFile};
+
+/// Field names which differ between ROS1 and ROS2 but mean the same thing, as (ROS1, ROS2)
+const FIELD_RENAMES: &[(&str, &str)] = &[("secs", "sec"), ("nsecs", "nanosec")];
+
+/// ROS1 types which are builtin messages in ROS2, as (ROS1, ROS2)
+const TYPE_RENAMES: &[(&str, &str)] = &[
+    ("time", "builtin_interfaces/Time"),
+    ("duration", "builtin_interfaces/Duration"),
+];
+
+/// ROS1 fields which were removed in ROS2, as (ROS1 message, field)
+/// These are dropped when converting to ROS2 and defaulted when converting to ROS1
+const DROPPED_ROS1_FIELDS: &[(&str, &str)] = &[("std_msgs/Header", "seq")];
+
+/// A message present in both ROS1 and ROS2 for which no conversion could be generated
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionMismatch {
+    /// Full name of the message e.g. std_msgs/Header
+    pub message: String,
+    /// Why the two versions of the message couldn't be matched up
+    pub reason: String,
+}
+
+impl std::fmt::Display for ConversionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.message, self.reason)
+    }
+}
+
+/// How a single element of a field is converted
+#[derive(Clone, Debug, PartialEq)]
+enum ElementConversion {
+    /// Same rust type on both sides, moved as is
+    Same,
+    /// ROS1 string to ROS2 bounded string
+    BoundedString,
+    /// ROS1 time / duration to builtin_interfaces
+    Time,
+    /// A nested message, by full name
+    Message(String),
+}
+
+/// How the shape (array-ness) of a field is converted
+#[derive(Clone, Debug, PartialEq)]
+enum ShapeConversion {
+    Single,
+    Fixed,
+    Vec,
+    /// ROS1 unbounded sequence to ROS2 bounded sequence
+    BoundedVec,
+}
+
+#[derive(Clone, Debug)]
+enum FieldConversion {
+    Matched {
+        ros1_name: String,
+        ros2_name: String,
+        element: ElementConversion,
+        shape: ShapeConversion,
+    },
+    /// A field only present in ROS1, see [DROPPED_ROS1_FIELDS]
+    Dropped { ros1_name: String },
+}
+
+/// Generates `From` / `TryFrom` impls between every pair of structurally matching messages in `ros1_messages` and `ros2_messages`.
+/// Returns the generated impls and the messages present in both which could not be converted.
+///
+/// * `ros1_module` - Path to the module the ROS1 messages were generated into, e.g. `crate::ros1`
+/// * `ros2_module` - Path to the module the ROS2 messages were generated into, e.g. `crate::ros2`
+/// * `time_conversions` - Whether to generate the conversions between ROS1 `time` / `duration` and `builtin_interfaces`.
+///   These only depend on `ros2_module`, so they must be generated once per ROS2 module.
+pub fn generate_ros1_ros2_conversions(
+    ros1_messages: &[MessageFile],
+    ros2_messages: &[MessageFile],
+    ros1_module: &str,
+    ros2_module: &str,
+    time_conversions: bool,
+) -> Result<(TokenStream, Vec<ConversionMismatch>), Error> {
+    let (Ok(ros1_module), Ok(ros2_module)) = (
+        TokenStream::from_str(ros1_module),
+        TokenStream::from_str(ros2_module),
+    ) else {
+        bail!("Module paths {ros1_module:?} and {ros2_module:?} must be valid rust paths");
+    };
+    // ROS2 builtin interfaces are generated alongside ROS1 messages too, they have nothing to convert to
+    let ros1 = ros1_messages
+        .iter()
+        .filter(|msg| msg.parsed.version != Some(RosVersion::ROS2))
+        .map(|msg| (msg.get_full_name(), msg))
+        .collect::<BTreeMap<_, _>>();
+    let ros2 = ros2_messages
+        .iter()
+        .map(|msg| (msg.get_full_name(), msg))
+        .collect::<BTreeMap<_, _>>();
+
+    // The ROS2 messages ROS1 time / duration can be converted into, conversions for them are generated by hand
+    let time_types = TYPE_RENAMES
+        .iter()
+        .map(|(_, ros2_type)| ros2_type.to_string())
+        .filter(|ros2_type| ros2.contains_key(ros2_type))
+        .collect::<BTreeSet<_>>();
+    let mut mismatches = vec![];
+    let mut candidates = ros1
+        .keys()
+        .filter(|name| ros2.contains_key(*name))
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    // Removing a candidate can invalidate the messages which contain it, so iterate until nothing changes
+    let (plans, fallible) = loop {
+        let mut plans = BTreeMap::new();
+        for name in &candidates {
+            match plan_conversion(ros1[name], ros2[name], &candidates, &time_types) {
+                Ok(plan) => {
+                    plans.insert(name.clone(), plan);
+                }
+                Err(reason) => mismatches.push(ConversionMismatch {
+                    message: name.clone(),
+                    reason,
+                }),
+            }
+        }
+        if plans.len() != candidates.len() {
+            candidates = plans.into_keys().collect();
+            continue;
+        }
+
+        let fallible = find_fallible(&plans);
+        let unsupported = plans
+            .iter()
+            .filter_map(|(name, plan)| {
+                plan.iter().find_map(|field| match field {
+                    FieldConversion::Matched {
+                        ros1_name,
+                        element,
+                        shape: ShapeConversion::Fixed,
+                        ..
+                    } if is_fallible(element, &fallible) => Some(ConversionMismatch {
+                        message: name.clone(),
+                        reason: format!(
+                            "fixed size array {ros1_name} has elements which can fail to convert"
+                        ),
+                    }),
+                    _ => None,
+                })
+            })
+            .collect::<Vec<_>>();
+        if unsupported.is_empty() {
+            break (plans, fallible);
+        }
+        for mismatch in unsupported {
+            candidates.remove(&mismatch.message);
+            mismatches.push(mismatch);
+        }
+    };
+
+    let mut impls = vec![];
+    if time_conversions && time_types.contains("builtin_interfaces/Time") {
+        impls.push(generate_time_conversions(
+            &ros2_module,
+            "Time",
+            "secs",
+            "nsecs",
+        ));
+    }
+    if time_conversions && time_types.contains("builtin_interfaces/Duration") {
+        impls.push(generate_time_conversions(
+            &ros2_module,
+            "Duration",
+            "sec",
+            "nsec",
+        ));
+    }
+    for (name, plan) in &plans {
+        let msg = &ros1[name].parsed;
+        let package = format_ident!("{}", msg.package);
+        let struct_name = format_ident!("{}", msg.name);
+        let ros1_type = quote! { #ros1_module::#package::#struct_name };
+        let ros2_type = quote! { #ros2_module::#package::#struct_name };
+        impls.push(generate_conversion(
+            &ros1_type,
+            &ros2_type,
+            plan,
+            fallible.contains(name),
+            &fallible,
+        ));
+    }
+    mismatches.sort_by(|a, b| a.message.cmp(&b.message));
+    Ok((quote! { #(#impls)* }, mismatches))
+}
+
+/// Searches the given paths for ROS1 and ROS2 messages respectively and generates conversions between them,
+/// see [generate_ros1_ros2_conversions]. Also returns the file system paths that if modified would trigger re-generation.
+///
+/// The messages themselves are expected to be generated into `ros1_module` and `ros2_module` from the same paths
+/// e.g. with [crate::find_and_generate_ros_messages_without_ros_package_path].
+pub fn find_and_generate_ros1_ros2_conversions(
+    ros1_search_paths: Vec<PathBuf>,
+    ros2_search_paths: Vec<PathBuf>,
+    ros1_module: &str,
+    ros2_module: &str,
+    time_conversions: bool,
+) -> Result<(TokenStream, Vec<PathBuf>, Vec<ConversionMismatch>), Error> {
+    let (ros1_messages, mut dependent_paths) = find_and_resolve_messages(&ros1_search_paths)?;
+    let (ros2_messages, ros2_paths) = find_and_resolve_messages(&ros2_search_paths)?;
+    dependent_paths.extend(ros2_paths);
+    let (source, mismatches) = generate_ros1_ros2_conversions(
+        &ros1_messages,
+        &ros2_messages,
+        ros1_module,
+        ros2_module,
+        time_conversions,
+    )?;
+    Ok((source, dependent_paths, mismatches))
+}
+
+/// Messages and service request / responses found in the given paths
+fn find_and_resolve_messages(
+    search_paths: &[PathBuf],
+) -> Result<(Vec<MessageFile>, Vec<PathBuf>), Error> {
+    let (messages, services, _actions) = find_and_parse_ros_messages(search_paths)?;
+    let (mut messages, services) = resolve_dependency_graph(messages, services)?;
+    let dependent_paths = messages
+        .iter()
+        .map(|m| m.parsed.path.clone())
+        .chain(services.iter().map(|s| s.parsed.path.clone()))
+        .filter(|p| !p.starts_with("/tmp/roslibrust_builtin/"))
+        .collect();
+    messages.extend(
+        services
+            .into_iter()
+            .flat_map(|srv| [srv.request, srv.response]),
+    );
+    Ok((messages, dependent_paths))
+}
+
+/// Matches up the fields of the two versions of a message, nested messages must be in `convertible`
+fn plan_conversion(
+    ros1: &MessageFile,
+    ros2: &MessageFile,
+    convertible: &BTreeSet<String>,
+    time_types: &BTreeSet<String>,
+) -> Result<Vec<FieldConversion>, String> {
+    let name = ros1.get_full_name();
+    let mut ros2_fields = ros2.parsed.fields.iter().collect::<Vec<_>>();
+    let mut plan = vec![];
+    for ros1_field in &ros1.parsed.fields {
+        let counterpart = ros2_fields.iter().position(|ros2_field| {
+            ros2_field.field_name == ros1_field.field_name
+                || FIELD_RENAMES.contains(&(&ros1_field.field_name, &ros2_field.field_name))
+        });
+        let Some(index) = counterpart else {
+            if DROPPED_ROS1_FIELDS.contains(&(&name, &ros1_field.field_name)) {
+                plan.push(FieldConversion::Dropped {
+                    ros1_name: ros1_field.field_name.clone(),
+                });
+                continue;
+            }
+            return Err(format!(
+                "field {} has no counterpart in ROS2",
+                ros1_field.field_name
+            ));
+        };
+        let ros2_field = ros2_fields.remove(index);
+        let (element, shape) = plan_field(ros1_field, ros2_field, convertible, time_types)
+            .map_err(|e| {
+                format!(
+                    "field {} ({}) doesn't match {} ({}): {e}",
+                    ros1_field.field_name,
+                    ros1_field.field_type,
+                    ros2_field.field_name,
+                    ros2_field.field_type
+                )
+            })?;
+        plan.push(FieldConversion::Matched {
+            ros1_name: ros1_field.field_name.clone(),
+            ros2_name: ros2_field.field_name.clone(),
+            element,
+            shape,
+        });
+    }
+    if let Some(extra) = ros2_fields.first() {
+        return Err(format!(
+            "ROS2 field {} has no counterpart in ROS1",
+            extra.field_name
+        ));
+    }
+    Ok(plan)
+}
+
+fn plan_field(
+    ros1: &FieldInfo,
+    ros2: &FieldInfo,
+    convertible: &BTreeSet<String>,
+    time_types: &BTreeSet<String>,
+) -> Result<(ElementConversion, ShapeConversion), String> {
+    let shape = match (&ros1.field_type.array_info, &ros2.field_type.array_info) {
+        (ArrayType::NotArray, ArrayType::NotArray) => ShapeConversion::Single,
+        (ArrayType::FixedLength(a), ArrayType::FixedLength(b)) if a == b => ShapeConversion::Fixed,
+        (ArrayType::Unbounded, ArrayType::Unbounded) => ShapeConversion::Vec,
+        (ArrayType::Unbounded, ArrayType::Bounded(_)) => ShapeConversion::BoundedVec,
+        _ => return Err("array sizes differ".to_owned()),
+    };
+
+    let ros1_type = ros1.field_type.field_type.as_str();
+    let ros2_full_name = ros2.get_full_type_name();
+    let element = match (&ros1.field_type.package_name, &ros2.field_type.package_name) {
+        (None, Some(_)) if TYPE_RENAMES.contains(&(ros1_type, ros2_full_name.as_str())) => {
+            if !time_types.contains(&ros2_full_name) {
+                return Err(format!("{ros2_full_name} is not available"));
+            }
+            ElementConversion::Time
+        }
+        (None, None) => {
+            let ros1_rust = convert_ros_type_to_rust_type(RosVersion::ROS1, ros1_type);
+            let ros2_rust =
+                convert_ros_type_to_rust_type(RosVersion::ROS2, &ros2.field_type.field_type);
+            if ros1_rust.is_none()
+                || ros1_rust != ros2_rust
+                || matches!(ros1_type, "time" | "duration")
+            {
+                return Err("types differ".to_owned());
+            }
+            if ros2.field_type.string_capacity.is_some() {
+                ElementConversion::BoundedString
+            } else {
+                ElementConversion::Same
+            }
+        }
+        (Some(_), Some(_)) => {
+            let ros1_full_name = ros1.get_full_type_name();
+            if ros1_full_name != ros2_full_name {
+                return Err("types differ".to_owned());
+            }
+            if !convertible.contains(&ros1_full_name) {
+                return Err(format!("{ros1_full_name} can't be converted"));
+            }
+            ElementConversion::Message(ros1_full_name)
+        }
+        _ => return Err("types differ".to_owned()),
+    };
+    Ok((element, shape))
+}
+
+fn is_fallible(element: &ElementConversion, fallible: &BTreeSet<String>) -> bool {
+    match element {
+        ElementConversion::BoundedString => true,
+        ElementConversion::Message(name) => fallible.contains(name),
+        ElementConversion::Same | ElementConversion::Time => false,
+    }
+}
+
+/// Names of the messages whose conversion into ROS2 can fail
+fn find_fallible(plans: &BTreeMap<String, Vec<FieldConversion>>) -> BTreeSet<String> {
+    let mut fallible = BTreeSet::new();
+    loop {
+        let newly_fallible = plans
+            .iter()
+            .filter(|(name, _)| !fallible.contains(*name))
+            .filter(|(_, plan)| {
+                plan.iter().any(|field| match field {
+                    FieldConversion::Matched { element, shape, .. } => {
+                        *shape == ShapeConversion::BoundedVec || is_fallible(element, &fallible)
+                    }
+                    FieldConversion::Dropped { .. } => false,
+                })
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        if newly_fallible.is_empty() {
+            return fallible;
+        }
+        fallible.extend(newly_fallible);
+    }
+}
+
+/// Expression converting `value` of a field in the given direction
+fn convert_field(
+    value: TokenStream,
+    element: &ElementConversion,
+    shape: &ShapeConversion,
+    to_ros2: bool,
+    fallible: bool,
+) -> TokenStream {
+    let convert = if element == &ElementConversion::Same {
+        None
+    } else if fallible && to_ros2 {
+        Some(quote! { ::std::convert::TryInto::try_into })
+    } else {
+        Some(quote! { ::std::convert::Into::into })
+    };
+    match (shape, convert) {
+        (ShapeConversion::Single, None) => value,
+        (ShapeConversion::Single, Some(convert)) if fallible && to_ros2 => {
+            quote! { #convert(#value)? }
+        }
+        (ShapeConversion::Single, Some(convert)) => quote! { #convert(#value) },
+        // Fixed size arrays of fallible elements are reported as a mismatch
+        (ShapeConversion::Fixed, None) => value,
+        (ShapeConversion::Fixed, Some(convert)) => quote! { #value.map(#convert) },
+        (ShapeConversion::Vec, None) => value,
+        (ShapeConversion::BoundedVec, None) => {
+            quote! { ::std::convert::TryInto::try_into(#value)? }
+        }
+        (ShapeConversion::Vec | ShapeConversion::BoundedVec, Some(convert)) => {
+            let collected = if fallible && to_ros2 {
+                quote! {
+                    #value
+                        .into_iter()
+                        .map(#convert)
+                        .collect::<::std::result::Result<::std::vec::Vec<_>, _>>()?
+                }
+            } else {
+                quote! { #value.into_iter().map(#convert).collect::<::std::vec::Vec<_>>() }
+            };
+            if *shape == ShapeConversion::BoundedVec {
+                if to_ros2 {
+                    quote! { ::std::convert::TryInto::try_into(#collected)? }
+                } else {
+                    collected
+                }
+            } else {
+                collected
+            }
+        }
+    }
+}
+
+/// Generates the pair of impls for a single message
+fn generate_conversion(
+    ros1_type: &TokenStream,
+    ros2_type: &TokenStream,
+    plan: &[FieldConversion],
+    fallible: bool,
+    fallible_messages: &BTreeSet<String>,
+) -> TokenStream {
+    let value = if plan.is_empty() {
+        format_ident!("_value")
+    } else {
+        format_ident!("value")
+    };
+    let mut to_ros2 = vec![];
+    let mut to_ros1 = vec![];
+    for field in plan {
+        match field {
+            FieldConversion::Matched {
+                ros1_name,
+                ros2_name,
+                element,
+                shape,
+            } => {
+                let ros1_name = format_ident!("r#{}", ros1_name);
+                let ros2_name = format_ident!("r#{}", ros2_name);
+                let element_fallible = is_fallible(element, fallible_messages);
+                let into_ros2 = convert_field(
+                    quote! { #value.#ros1_name },
+                    element,
+                    shape,
+                    true,
+                    element_fallible,
+                );
+                let into_ros1 = match (element, shape) {
+                    (ElementConversion::Same, ShapeConversion::BoundedVec) => {
+                        quote! { ::std::convert::Into::into(#value.#ros2_name) }
+                    }
+                    _ => convert_field(quote! { #value.#ros2_name }, element, shape, false, false),
+                };
+                to_ros2.push(quote! { #ros2_name: #into_ros2, });
+                to_ros1.push(quote! { #ros1_name: #into_ros1, });
+            }
+            FieldConversion::Dropped { ros1_name } => {
+                let ros1_name = format_ident!("r#{}", ros1_name);
+                to_ros1.push(quote! { #ros1_name: ::std::default::Default::default(), });
+            }
+        }
+    }
+    let into_ros2 = if fallible {
+        quote! {
+            impl ::std::convert::TryFrom<#ros1_type> for #ros2_type {
+                type Error = ::roslibrust::codegen::SimpleError;
+                fn try_from(#value: #ros1_type) -> ::std::result::Result<Self, Self::Error> {
+                    ::std::result::Result::Ok(Self { #(#to_ros2)* })
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::std::convert::From<#ros1_type> for #ros2_type {
+                fn from(#value: #ros1_type) -> Self {
+                    Self { #(#to_ros2)* }
+                }
+            }
+        }
+    };
+    quote! {
+        #into_ros2
+        impl ::std::convert::From<#ros2_type> for #ros1_type {
+            fn from(#value: #ros2_type) -> Self {
+                Self { #(#to_ros1)* }
+            }
+        }
+    }
+}
+
+/// Conversions between the ROS1 integral time types and the builtin_interfaces messages of ROS2
+/// ROS2 nanoseconds are unsigned so negative ROS1 nanoseconds are normalized by borrowing from seconds
+fn generate_time_conversions(
+    ros2_module: &TokenStream,
+    name: &str,
+    ros1_sec: &str,
+    ros1_nsec: &str,
+) -> TokenStream {
+    let name = format_ident!("{name}");
+    let ros1_sec = format_ident!("{ros1_sec}");
+    let ros1_nsec = format_ident!("{ros1_nsec}");
+    let ros1_type = quote! { ::roslibrust::codegen::integral_types::#name };
+    let ros2_type = quote! { #ros2_module::builtin_interfaces::#name };
+    quote! {
+        impl ::std::convert::From<#ros1_type> for #ros2_type {
+            fn from(value: #ros1_type) -> Self {
+                let nanos = value.#ros1_sec as i64 * 1_000_000_000 + value.#ros1_nsec as i64;
+                Self {
+                    sec: nanos.div_euclid(1_000_000_000) as i32,
+                    nanosec: nanos.rem_euclid(1_000_000_000) as u32,
+                }
+            }
+        }
+        impl ::std::convert::From<#ros2_type> for #ros1_type {
+            fn from(value: #ros2_type) -> Self {
+                let nanos = value.sec as i64 * 1_000_000_000 + value.nanosec as i64;
+                Self {
+                    #ros1_sec: nanos.div_euclid(1_000_000_000) as i32,
+                    #ros1_nsec: nanos.rem_euclid(1_000_000_000) as i32,
+                }
+            }
+        }
+    }
+}
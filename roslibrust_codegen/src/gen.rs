@@ -1,6 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use serde::de::DeserializeOwned;
+use std::collections::BTreeSet;
 use std::str::FromStr;
 use syn::parse_quote;
 
@@ -17,6 +18,22 @@ pub struct CodegenOptions {
     pub generate_definition: bool,
     /// Whether to use roslibrust's re-exported serde (default: true)
     pub roslibrust_serde: bool,
+    /// Whether to generate enums for groups of constants belonging to a field (default: false)
+    /// A field `uint8 status` with constants `STATUS_OK=0` and `STATUS_WARN=1` of the same type gets a
+    /// `#[repr(u8)]` enum `{Message}Status { Ok, Warn }` and `status_enum()` / `set_status_enum()` accessors.
+    /// The field itself stays a `u8`, so messages are serialized the same either way.
+    pub generate_constant_enums: bool,
+    /// Whether to generate serde-free ROS1 and CDR encoders for messages (default: false)
+    /// Messages made of primitives, strings, and arrays of those (including nested messages which are)
+    /// implement `roslibrust::codec::WireCodec` and set `RosMessageType::WIRE_CODEC`, which backends
+    /// use in place of serde. Large primitive arrays are copied directly instead of element by element.
+    pub generate_wire_codecs: bool,
+    /// Whether to generate borrowed variants of messages (default: false)
+    /// Every message with a wire codec gets a `{Message}Ref<'a>` whose strings and byte sequences borrow
+    /// from the buffer the message was received in, for use with the backends' borrowed subscribers.
+    /// Messages without strings or byte sequences are their own borrowed variant, `{Message}Ref<'a>` is
+    /// an alias for them. Enabling this also enables `generate_wire_codecs`.
+    pub generate_borrowed_types: bool,
 }
 
 impl Default for CodegenOptions {
@@ -24,6 +41,9 @@ impl Default for CodegenOptions {
         Self {
             generate_definition: true,
             roslibrust_serde: true,
+            generate_constant_enums: false,
+            generate_wire_codecs: false,
+            generate_borrowed_types: false,
         }
     }
 }
@@ -60,12 +80,23 @@ fn derive_attrs(options: &CodegenOptions, _has_large_array: bool) -> Vec<syn::At
     attrs
 }
 
+/// What codegen needs to know about the other types generated into the same package module
+#[derive(Debug, Default)]
+pub struct PackageTypes {
+    /// Names of every message and service type in the package, and of their borrowed variants
+    pub names: BTreeSet<String>,
+    /// Constant enum names which more than one group of constants in the package would get, see [constant_enum_names].
+    /// None of those enums are generated.
+    pub colliding_enums: BTreeSet<String>,
+}
+
 /// Generates the service for a given service file
 /// The service definition defines a struct representing the service an an implementation
 /// of the RosServiceType trait for that struct
 pub fn generate_service(
     service: ServiceFile,
     options: Option<&CodegenOptions>,
+    package_types: &PackageTypes,
 ) -> Result<TokenStream, Error> {
     let default_options = CodegenOptions::default();
     let options = options.unwrap_or(&default_options);
@@ -78,8 +109,8 @@ pub fn generate_service(
     let request_name = format_ident!("{}", service.parsed.request_type.name);
     let response_name = format_ident!("{}", service.parsed.response_type.name);
 
-    let request_msg = generate_struct(service.request, Some(options))?;
-    let response_msg = generate_struct(service.response, Some(options))?;
+    let request_msg = generate_struct(service.request, Some(options), package_types)?;
+    let response_msg = generate_struct(service.response, Some(options), package_types)?;
     Ok(quote! {
 
         #request_msg
@@ -106,9 +137,11 @@ pub fn generate_raw_string_literal(value: &str) -> TokenStream {
     TokenStream::from_str(&wrapped).unwrap()
 }
 
+/// Generates the struct for a given message file, along with any helper types enabled in `options`
 pub fn generate_struct(
     msg: MessageFile,
     options: Option<&CodegenOptions>,
+    package_types: &PackageTypes,
 ) -> Result<TokenStream, Error> {
     let default_options = CodegenOptions::default();
     let options = options.unwrap_or(&default_options);
@@ -121,6 +154,26 @@ pub fn generate_struct(
     );
 
     let attrs = derive_attrs(options, has_large_array);
+    let constant_enums = if options.generate_constant_enums {
+        generate_constant_enums(&msg, package_types)?
+    } else {
+        quote! {}
+    };
+    let use_wire_codec = (options.generate_wire_codecs || options.generate_borrowed_types)
+        && msg.supports_wire_codec;
+    let mut wire_codec = if use_wire_codec {
+        generate_wire_codec(&msg)?
+    } else {
+        quote! {}
+    };
+    if options.generate_borrowed_types && msg.supports_wire_codec {
+        wire_codec.extend(generate_borrowed_type(&msg)?);
+    }
+    let header_impl = if msg.parsed.first_field_is_header() {
+        generate_header_impl(&msg)?
+    } else {
+        quote! {}
+    };
     let fields = msg
         .parsed
         .fields
@@ -152,6 +205,15 @@ pub fn generate_struct(
     let definition = msg.definition;
     let ros2_hash = msg.ros2_hash;
 
+    let wire_codec_const = if use_wire_codec {
+        quote! {
+            const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+                ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate the trait impl conditionally based on options
     let trait_impl = if options.generate_definition {
         // Include DEFINITION field
@@ -163,6 +225,7 @@ pub fn generate_struct(
                 const DEFINITION: &'static str = #raw_message_definition;
                 const ROS2_HASH: &'static [u8; 32] = &#ros2_hash;
                 const ROS2_TYPE_NAME: &'static str = #ros2_type_name;
+                #wire_codec_const
             }
         }
     } else {
@@ -174,6 +237,7 @@ pub fn generate_struct(
                 const DEFINITION: &'static str = "";
                 const ROS2_HASH: &'static [u8; 32] = &#ros2_hash;
                 const ROS2_TYPE_NAME: &'static str = #ros2_type_name;
+                #wire_codec_const
             }
         }
     };
@@ -187,6 +251,7 @@ pub fn generate_struct(
         }
 
         #trait_impl
+        #header_impl
     };
 
     // Only if we have constants append the impl
@@ -198,9 +263,355 @@ pub fn generate_struct(
             }
         });
     }
+    base.extend(constant_enums);
+    base.extend(wire_codec);
     Ok(base)
 }
 
+/// Generates the [roslibrust_common::HasHeader] impl for a message whose first field is a std_msgs/Header
+/// ROS1 and ROS2 headers differ in field names and ROS2 has no seq, so the impl depends on the message's version
+fn generate_header_impl(msg: &MessageFile) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let field = &msg.parsed.fields[0];
+    let name = format_ident!("r#{}", field.field_name);
+    let header_type = TokenStream::from_str(&message_type_path(
+        "std_msgs",
+        &msg.parsed.package,
+        "Header",
+    ))
+    .expect("Header type paths are always valid rust syntax");
+    let version_specific = match msg.parsed.version.unwrap_or(RosVersion::ROS1) {
+        RosVersion::ROS1 => quote! {
+            fn stamp(&self) -> (i32, u32) {
+                (self.#name.stamp.secs, self.#name.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.#name.stamp.secs = sec;
+                self.#name.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.#name.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.#name.seq = seq;
+            }
+        },
+        RosVersion::ROS2 => quote! {
+            fn stamp(&self) -> (i32, u32) {
+                (self.#name.stamp.sec, self.#name.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.#name.stamp.sec = sec;
+                self.#name.stamp.nanosec = nanosec;
+            }
+        },
+    };
+    Ok(quote! {
+        impl ::roslibrust::HasHeader for #struct_name {
+            type Header = #header_type;
+            fn header(&self) -> &Self::Header {
+                &self.#name
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.#name
+            }
+            fn frame_id(&self) -> &str {
+                &self.#name.frame_id
+            }
+            #version_specific
+        }
+    })
+}
+
+/// Generates the [roslibrust_common::codec::WireCodec] impl for a message
+/// Only valid for messages where [MessageFile::supports_wire_codec] is true
+fn generate_wire_codec(msg: &MessageFile) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let mut encode = vec![];
+    let mut decode = vec![];
+    for field in &msg.parsed.fields {
+        let name = format_ident!("r#{}", field.field_name);
+        let ros_type = field.field_type.field_type.as_str();
+        let is_message =
+            field.field_type.package_name.is_some() || matches!(ros_type, "time" | "duration");
+        let (encode_field, decode_field) = match (&field.field_type.array_info, ros_type) {
+            (_, "wstring") | (ArrayType::Bounded(_), _) => bail!(
+                "Field {} of {} is not supported by wire codecs",
+                field.field_name,
+                msg.get_full_name()
+            ),
+            (ArrayType::NotArray, "string") => (
+                quote! { writer.put_str(&self.#name); },
+                quote! { reader.get_string()? },
+            ),
+            (ArrayType::FixedLength(_), "string") => (
+                quote! { for item in &self.#name { writer.put_str(item); } },
+                quote! { reader.get_array_with(|reader| reader.get_string())? },
+            ),
+            (ArrayType::Unbounded, "string") => (
+                quote! { writer.put_seq_with(&self.#name, |writer, item| writer.put_str(item)); },
+                quote! { reader.get_seq_with(|reader| reader.get_string())? },
+            ),
+            (ArrayType::NotArray, _) if is_message => (
+                quote! { ::roslibrust::codec::WireCodec::encode(&self.#name, writer); },
+                quote! { ::roslibrust::codec::WireCodec::decode(reader)? },
+            ),
+            (ArrayType::FixedLength(_), _) if is_message => (
+                quote! {
+                    for item in &self.#name {
+                        ::roslibrust::codec::WireCodec::encode(item, writer);
+                    }
+                },
+                quote! { reader.get_array_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))? },
+            ),
+            (ArrayType::Unbounded, _) if is_message => (
+                quote! {
+                    writer.put_seq_with(&self.#name, |writer, item| {
+                        ::roslibrust::codec::WireCodec::encode(item, writer)
+                    });
+                },
+                quote! { reader.get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))? },
+            ),
+            (ArrayType::NotArray, _) => {
+                (quote! { writer.put(self.#name); }, quote! { reader.get()? })
+            }
+            (ArrayType::FixedLength(_), _) => (
+                quote! { writer.put_slice(&self.#name); },
+                quote! { reader.get_array()? },
+            ),
+            (ArrayType::Unbounded, _) => (
+                quote! { writer.put_seq(&self.#name); },
+                quote! { reader.get_seq()? },
+            ),
+        };
+        encode.push(encode_field);
+        decode.push(quote! { #name: #decode_field, });
+    }
+    Ok(quote! {
+        #[allow(unused_variables)]
+        impl ::roslibrust::codec::WireCodec for #struct_name {
+            fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+                #(#encode )*
+            }
+
+            fn decode(
+                reader: &mut ::roslibrust::codec::WireReader<'_>,
+            ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+                ::std::result::Result::Ok(Self {
+                    #(#decode )*
+                })
+            }
+        }
+    })
+}
+
+/// Generates an enum for each field which has a group of constants prefixed with the field's name
+/// and matching its integer type, along with typed accessors for the field.
+/// Groups which can't be represented as an enum (duplicate values, non integer literals, names which aren't
+/// valid identifiers) or whose enum name is one of `package_types.colliding_enums` are skipped.
+fn generate_constant_enums(
+    msg: &MessageFile,
+    package_types: &PackageTypes,
+) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let version = msg.parsed.version.unwrap_or(RosVersion::ROS1);
+    let mut enums = quote! {};
+    for (field, variants) in constant_enum_groups(msg) {
+        let variants = match variants {
+            Ok(variants) => variants,
+            Err(reason) => {
+                log::warn!(
+                    "Not generating a constant enum for {}.{}: {reason}",
+                    msg.get_full_name(),
+                    field.field_name
+                );
+                continue;
+            }
+        };
+        let enum_name = constant_enum_name(msg, field, &package_types.names);
+        if package_types.colliding_enums.contains(&enum_name) {
+            // Already warned about when the collision was found
+            continue;
+        }
+        let ros_type = field.field_type.field_type.as_str();
+        let rust_type = convert_ros_type_to_rust_type(version, ros_type)
+            .ok_or(Error::new(format!("No Rust type for {}", field.field_type)))?;
+        let repr = format_ident!("{rust_type}");
+        let enum_name = format_ident!("{enum_name}");
+        let field_name = format_ident!("r#{}", field.field_name);
+        let getter = format_ident!("{}_enum", field.field_name);
+        let setter = format_ident!("set_{}_enum", field.field_name);
+        let variant_names = variants.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let values = variants
+            .iter()
+            .map(|(_, value)| proc_macro2::Literal::i128_unsuffixed(*value))
+            .collect::<Vec<_>>();
+        let getter_doc = format!(
+            "Returns `{}` as a [{enum_name}], or the raw value if it doesn't match one of the constants",
+            field.field_name
+        );
+        enums.extend(quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #[repr(#repr)]
+            pub enum #enum_name {
+                #(#variant_names = #values, )*
+            }
+
+            impl ::std::convert::TryFrom<#repr> for #enum_name {
+                type Error = #repr;
+                fn try_from(value: #repr) -> ::std::result::Result<Self, #repr> {
+                    match value {
+                        #(#values => ::std::result::Result::Ok(Self::#variant_names), )*
+                        other => ::std::result::Result::Err(other),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<#enum_name> for #repr {
+                fn from(value: #enum_name) -> #repr {
+                    value as #repr
+                }
+            }
+
+            #[allow(unused)]
+            impl #struct_name {
+                #[doc = #getter_doc]
+                pub fn #getter(&self) -> ::std::result::Result<#enum_name, #repr> {
+                    ::std::convert::TryFrom::try_from(self.#field_name)
+                }
+
+                pub fn #setter(&mut self, value: #enum_name) {
+                    self.#field_name = value.into();
+                }
+            }
+        });
+    }
+    Ok(enums)
+}
+
+/// Names the enum generated for `field`'s constants `{Msg}{Field}`, or `{Msg}{Field}Enum` if that is one of `names`
+fn constant_enum_name(msg: &MessageFile, field: &FieldInfo, names: &BTreeSet<String>) -> String {
+    let enum_name = format!("{}{}", msg.parsed.name, to_camel_case(&field.field_name));
+    if names.contains(&enum_name) {
+        format!("{enum_name}Enum")
+    } else {
+        enum_name
+    }
+}
+
+/// Returns the names of the constant enums [generate_constant_enums] would generate for `msg`,
+/// each with the name of the field it is generated for
+pub(crate) fn constant_enum_names(
+    msg: &MessageFile,
+    names: &BTreeSet<String>,
+) -> Vec<(String, String)> {
+    constant_enum_groups(msg)
+        .into_iter()
+        .filter(|(_, variants)| variants.is_ok())
+        .map(|(field, _)| {
+            (
+                constant_enum_name(msg, field, names),
+                field.field_name.clone(),
+            )
+        })
+        .collect()
+}
+
+/// The name and value of each variant of a constant enum
+type EnumVariants = Vec<(syn::Ident, i128)>;
+
+/// Finds the integer fields of `msg` with a group of constants, along with the group's variants
+/// or why they can't be represented as an enum
+fn constant_enum_groups(msg: &MessageFile) -> Vec<(&FieldInfo, Result<EnumVariants, String>)> {
+    msg.parsed
+        .fields
+        .iter()
+        .filter(|field| field.field_type.array_info == ArrayType::NotArray)
+        .filter(|field| {
+            matches!(
+                field.field_type.field_type.as_str(),
+                "int8"
+                    | "uint8"
+                    | "byte"
+                    | "char"
+                    | "int16"
+                    | "uint16"
+                    | "int32"
+                    | "uint32"
+                    | "int64"
+                    | "uint64"
+            )
+        })
+        .filter_map(|field| {
+            constant_enum_variants(field, &msg.parsed.constants)
+                .transpose()
+                .map(|variants| (field, variants))
+        })
+        .collect()
+}
+
+/// Finds the constants belonging to `field`, those named `{FIELD_NAME}_{VARIANT}` with the field's type
+/// Returns the variant names and values, None if there aren't at least two such constants,
+/// or why they can't be represented as an enum
+fn constant_enum_variants(
+    field: &FieldInfo,
+    constants: &[ConstantInfo],
+) -> Result<Option<EnumVariants>, String> {
+    let prefix = format!("{}_", field.field_name.to_uppercase());
+    let group = constants
+        .iter()
+        .filter(|constant| constant.constant_type == field.field_type.field_type)
+        .filter(|constant| constant.constant_name.starts_with(&prefix))
+        .collect::<Vec<_>>();
+    if group.len() < 2 {
+        return Ok(None);
+    }
+    let mut variants: EnumVariants = vec![];
+    for constant in &group {
+        let name = &constant.constant_name;
+        let variant = to_camel_case(&name[prefix.len()..]);
+        let value = constant.constant_value.inner.trim();
+        let value = value
+            .parse::<i128>()
+            .map_err(|_| format!("{name} has the value {value}, which isn't an integer"))?;
+        let ident = syn::parse_str::<syn::Ident>(&variant)
+            .map_err(|_| format!("{name} would be named {variant}, which isn't an identifier"))?;
+        // Aliased values can't be expressed as enum variants
+        if let Some(other) = variants
+            .iter()
+            .position(|(other, v)| *other == ident || *v == value)
+        {
+            let other = &group[other].constant_name;
+            return Err(format!("{name} has the same name or value as {other}"));
+        }
+        variants.push((ident, value));
+    }
+    Ok(Some(variants))
+}
+
+/// Converts SCREAMING_SNAKE_CASE or snake_case to CamelCase
+fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first)
+                .chain(chars.map(|c| c.to_ascii_lowercase()))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Path to a generated message type from within the module of `msg_pkg`
+fn message_type_path(pkg: &str, msg_pkg: &str, name: &str) -> String {
+    if pkg == msg_pkg {
+        format!("self::{name}")
+    } else {
+        format!("{pkg}::{name}")
+    }
+}
+
 fn generate_field_definition(
     field: FieldInfo,
     msg_pkg: &str,
@@ -208,17 +619,19 @@ fn generate_field_definition(
     options: &CodegenOptions,
 ) -> Result<TokenStream, Error> {
     let rust_field_type = match field.field_type.package_name {
-        Some(ref pkg) => {
-            if pkg.as_str() == msg_pkg {
-                format!("self::{}", field.field_type.field_type)
-            } else {
-                format!("{}::{}", pkg, field.field_type.field_type)
-            }
-        }
+        Some(ref pkg) => message_type_path(pkg, msg_pkg, &field.field_type.field_type),
         None => convert_ros_type_to_rust_type(version, &field.field_type.field_type)
             .ok_or(Error::new(format!("No Rust type for {}", field.field_type)))?
             .to_owned(),
     };
+    // Bounded strings get a wrapper type which enforces their capacity
+    let rust_field_type = match field.field_type.string_capacity {
+        Some(capacity) if field.field_type.field_type == "wstring" => {
+            format!("::roslibrust::codegen::BoundedWString<{capacity}>")
+        }
+        Some(capacity) => format!("::roslibrust::codegen::BoundedString<{capacity}>"),
+        None => rust_field_type,
+    };
     // Wrap type in appropriate Vec or array wrapper based on array information
     let rust_field_type = match field.field_type.array_info {
         ArrayType::Unbounded => {
@@ -226,8 +639,8 @@ fn generate_field_definition(
         }
         ArrayType::FixedLength(fixed_length) => format!("[{rust_field_type}; {fixed_length}]"),
         ArrayType::NotArray => rust_field_type,
-        ArrayType::Bounded(_) => {
-            format!("::std::vec::Vec<{rust_field_type}>")
+        ArrayType::Bounded(bound) => {
+            format!("::roslibrust::codegen::BoundedVec<{rust_field_type}, {bound}>")
         }
     };
     let rust_field_type = TokenStream::from_str(rust_field_type.as_str()).expect(
@@ -245,7 +658,14 @@ fn generate_field_definition(
             &field.field_type.array_info,
             version,
         )?;
-        if matches!(
+        if field.field_type.string_capacity.is_some()
+            || matches!(field.field_type.array_info, ArrayType::Bounded(_))
+        {
+            let default_code = bounded_default_code(&field, default_val)?;
+            quote! {
+                #[default(_code = #default_code)]
+            }
+        } else if matches!(
             field.field_type.array_info,
             ArrayType::Unbounded | ArrayType::Bounded(_) | ArrayType::FixedLength(_)
         ) {
@@ -283,8 +703,14 @@ fn generate_field_definition(
     let is_uint8_field = matches!(field.field_type.field_type.as_str(), "uint8" | "byte");
 
     let serde_line = match &field.field_type.array_info {
+        // Bounded byte arrays have their own version of serde_rosmsg_bytes to check the bound
+        ArrayType::Bounded(_) if is_uint8_field && options.roslibrust_serde => {
+            quote! { #[serde(with = "::roslibrust::codegen::serde_rosmsg_bytes::bounded")] }
+        }
+        // BoundedVec doesn't work with serde_bytes, it serializes like a Vec<u8> without it
+        ArrayType::Bounded(_) => quote! {},
         // Dynamic-length arrays (Vec<T>)
-        ArrayType::Unbounded | ArrayType::Bounded(_) => {
+        ArrayType::Unbounded => {
             if is_uint8_field {
                 if options.roslibrust_serde {
                     // Use roslibrust's custom module that handles both base64 (rosbridge) and binary
@@ -320,6 +746,230 @@ fn generate_field_definition(
     })
 }
 
+/// Builds the SmartDefault code for the default value of a bounded field
+/// The bound is checked here so that a default which doesn't fit fails codegen instead of panicking in Default
+/// `default_val` -- The default as generated by [ros_literal_to_rust_literal]
+fn bounded_default_code(field: &FieldInfo, default_val: TokenStream) -> Result<String, Error> {
+    let default_val = syn::parse2::<syn::LitStr>(default_val)
+        .map_err(|e| {
+            Error::new(format!(
+                "Unsupported default value for bounded field {}: {e}",
+                field.field_name
+            ))
+        })?
+        .value();
+    match field.field_type.array_info {
+        ArrayType::NotArray => {
+            let capacity = field.field_type.string_capacity.unwrap_or(usize::MAX);
+            let len = if field.field_type.field_type == "wstring" {
+                default_val.encode_utf16().count()
+            } else {
+                default_val.len()
+            };
+            if len > capacity {
+                bail!(
+                    "Default value {default_val:?} for field {} exceeds its bound of {capacity}",
+                    field.field_name
+                );
+            }
+            Ok(format!(
+                "::std::convert::TryInto::try_into({default_val:?}).unwrap()"
+            ))
+        }
+        ArrayType::Bounded(bound) if field.field_type.string_capacity.is_none() => {
+            let len = field
+                .default
+                .as_ref()
+                .and_then(|d| serde_json::from_str::<Vec<serde_json::Value>>(&d.inner).ok())
+                .map(|items| items.len())
+                .unwrap_or_default();
+            if len > bound {
+                bail!(
+                    "Default value for field {} has {len} elements which exceeds its bound of {bound}",
+                    field.field_name
+                );
+            }
+            Ok(format!(
+                "{{ let default: ::std::vec::Vec<_> = {default_val}; ::std::convert::TryInto::try_into(default).unwrap() }}"
+            ))
+        }
+        _ => bail!(
+            "Default values are not supported for arrays of bounded strings, found on field {}",
+            field.field_name
+        ),
+    }
+}
+
+/// Generates the borrowed variant of a message, `{Message}Ref<'a>`, and its trait impls
+/// Only valid for messages where [MessageFile::supports_wire_codec] is true
+fn generate_borrowed_type(msg: &MessageFile) -> Result<TokenStream, Error> {
+    let struct_name = format_ident!("{}", msg.parsed.name);
+    let ref_name = format_ident!("{}Ref", msg.parsed.name);
+    let version = msg.parsed.version.unwrap_or(RosVersion::ROS1);
+    if !msg.has_borrowed_fields {
+        // Nothing to borrow, the message is its own borrowed variant
+        return Ok(quote! {
+            pub type #ref_name<'a> = #struct_name;
+
+            impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for #struct_name {
+                type Owned = Self;
+
+                fn decode_borrowed(
+                    reader: &mut ::roslibrust::codec::WireReader<'a>,
+                ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+                    ::roslibrust::codec::WireCodec::decode(reader)
+                }
+
+                fn to_owned_message(&self) -> Self {
+                    self.clone()
+                }
+            }
+
+            impl ::roslibrust::codec::HasBorrowed for #struct_name {
+                type Borrowed<'a> = #ref_name<'a>;
+                fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+                    msg
+                }
+            }
+        });
+    }
+
+    let mut fields = vec![];
+    let mut decode = vec![];
+    let mut to_owned = vec![];
+    for field in &msg.parsed.fields {
+        let name = format_ident!("r#{}", field.field_name);
+        let ros_type = field.field_type.field_type.as_str();
+        let base_type = match field.field_type.package_name {
+            Some(ref pkg) => format!(
+                "{}Ref<'a>",
+                message_type_path(pkg, &msg.parsed.package, ros_type)
+            ),
+            None if ros_type == "string" => "&'a str".to_owned(),
+            None => convert_ros_type_to_rust_type(version, ros_type)
+                .ok_or(Error::new(format!("No Rust type for {}", field.field_type)))?
+                .to_owned(),
+        };
+        let is_bytes = matches!(ros_type, "uint8" | "byte" | "char");
+        let rust_type = match field.field_type.array_info {
+            ArrayType::NotArray => base_type,
+            ArrayType::FixedLength(len) => format!("[{base_type}; {len}]"),
+            ArrayType::Unbounded if is_bytes => "&'a [u8]".to_owned(),
+            ArrayType::Unbounded => format!("::std::vec::Vec<{base_type}>"),
+            ArrayType::Bounded(_) => bail!(
+                "Field {} of {} is not supported by borrowed types",
+                field.field_name,
+                msg.get_full_name()
+            ),
+        };
+        let rust_type = TokenStream::from_str(&rust_type)
+            .map_err(|e| Error::with("Generated an invalid borrowed field type", e))?;
+        fields.push(quote! { pub #name: #rust_type, });
+
+        let (decode_field, to_owned_field) = if field.field_type.package_name.is_some() {
+            let decode_item =
+                quote! { ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader) };
+            let to_owned_item =
+                quote! { ::roslibrust::codec::WireDecodeBorrowed::to_owned_message };
+            match field.field_type.array_info {
+                ArrayType::FixedLength(_) => (
+                    quote! { reader.get_array_with(|reader| #decode_item)? },
+                    quote! { self.#name.each_ref().map(#to_owned_item) },
+                ),
+                ArrayType::Unbounded => (
+                    quote! { reader.get_seq_with(|reader| #decode_item)? },
+                    quote! { self.#name.iter().map(#to_owned_item).collect() },
+                ),
+                _ => (
+                    quote! { #decode_item? },
+                    quote! { ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.#name) },
+                ),
+            }
+        } else if ros_type == "string" {
+            match field.field_type.array_info {
+                ArrayType::FixedLength(_) => (
+                    quote! { reader.get_array_with(|reader| reader.get_str())? },
+                    quote! { self.#name.map(|item| item.to_owned()) },
+                ),
+                ArrayType::Unbounded => (
+                    quote! { reader.get_seq_with(|reader| reader.get_str())? },
+                    quote! { self.#name.iter().map(|item| (*item).to_owned()).collect() },
+                ),
+                _ => (
+                    quote! { reader.get_str()? },
+                    quote! { self.#name.to_owned() },
+                ),
+            }
+        } else if matches!(ros_type, "time" | "duration") {
+            let decode_item = quote! { ::roslibrust::codec::WireCodec::decode(reader) };
+            match field.field_type.array_info {
+                ArrayType::FixedLength(_) => (
+                    quote! { reader.get_array_with(|reader| #decode_item)? },
+                    quote! { self.#name.clone() },
+                ),
+                ArrayType::Unbounded => (
+                    quote! { reader.get_seq_with(|reader| #decode_item)? },
+                    quote! { self.#name.clone() },
+                ),
+                _ => (quote! { #decode_item? }, quote! { self.#name.clone() }),
+            }
+        } else {
+            match field.field_type.array_info {
+                ArrayType::FixedLength(_) => {
+                    (quote! { reader.get_array()? }, quote! { self.#name })
+                }
+                ArrayType::Unbounded if is_bytes => (
+                    quote! { reader.get_byte_seq()? },
+                    quote! { self.#name.to_vec() },
+                ),
+                ArrayType::Unbounded => {
+                    (quote! { reader.get_seq()? }, quote! { self.#name.clone() })
+                }
+                _ => (quote! { reader.get()? }, quote! { self.#name }),
+            }
+        };
+        decode.push(quote! { #name: #decode_field, });
+        to_owned.push(quote! { #name: #to_owned_field, });
+    }
+
+    let doc = format!(
+        "Borrowed variant of [{struct_name}], strings and byte sequences point into the buffer it was decoded from"
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(non_snake_case)]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct #ref_name<'a> {
+            #(#fields )*
+        }
+
+        impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for #ref_name<'a> {
+            type Owned = #struct_name;
+
+            fn decode_borrowed(
+                reader: &mut ::roslibrust::codec::WireReader<'a>,
+            ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+                ::std::result::Result::Ok(Self {
+                    #(#decode )*
+                })
+            }
+
+            fn to_owned_message(&self) -> #struct_name {
+                #struct_name {
+                    #(#to_owned )*
+                }
+            }
+        }
+
+        impl ::roslibrust::codec::HasBorrowed for #struct_name {
+            type Borrowed<'a> = #ref_name<'a>;
+            fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+                msg
+            }
+        }
+    })
+}
+
 fn generate_constant_field_definition(
     constant: ConstantInfo,
     version: RosVersion,
@@ -329,7 +979,9 @@ fn generate_constant_field_definition(
         .ok_or(Error::new(format!(
             "A constant was detected {constant:?} for which no valid rust type was found."
         )))?;
-    let constant_rust_type = if constant_rust_type == "::std::string::String" {
+    let constant_rust_type = if constant_rust_type == "::std::string::String"
+        || constant_rust_type == "::roslibrust::codegen::integral_types::WString"
+    {
         String::from("&'static str")
     } else {
         // Oof it's ugly in here
@@ -438,14 +1090,18 @@ fn parse_ros_value(
         "int32" => generic_parse_value::<i32>(value, is_list),
         "uint64" => generic_parse_value::<u64>(value, is_list),
         "int64" => generic_parse_value::<i64>(value, is_list),
-        "string" => {
+        "string" | "wstring" => {
             // String is a special case because of quotes and to_string()
             if is_list {
                 // TODO there is a bug here, no idea how I should be attempting to convert / escape single quotes here...
                 let parsed: Vec<String> = serde_json::from_str(value).map_err(|e|
                     Error::with(format!("Failed to parse a literal value in a message file to the corresponding rust type: {value} to Vec<String>").as_str(), e)
                 )?;
-                let vec_str = format!("{parsed:?}.iter().map(|x| x.to_string()).collect()");
+                let vec_str = if ros_type == "wstring" {
+                    format!("{parsed:?}.iter().map(|x| x.to_string().into()).collect()")
+                } else {
+                    format!("{parsed:?}.iter().map(|x| x.to_string()).collect()")
+                };
                 Ok(quote! { #vec_str })
             } else {
                 match version {
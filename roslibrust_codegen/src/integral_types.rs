@@ -1,5 +1,6 @@
 use simple_error::{bail, SimpleError};
 
+use roslibrust_common::codec::{WireCodec, WireError, WireReader, WireWriter};
 use roslibrust_common::RosMessageType;
 
 /// Matches the integral ros1 type time, with extensions for ease of use
@@ -78,6 +79,20 @@ impl RosMessageType for Time {
     const DEFINITION: &'static str = "";
 }
 
+impl WireCodec for Time {
+    fn encode(&self, writer: &mut WireWriter<'_>) {
+        writer.put(self.secs);
+        writer.put(self.nsecs);
+    }
+
+    fn decode(reader: &mut WireReader<'_>) -> Result<Self, WireError> {
+        Ok(Self {
+            secs: reader.get()?,
+            nsecs: reader.get()?,
+        })
+    }
+}
+
 /// Matches the integral ros1 duration type, with extensions for ease of use
 /// NOTE: Is not a message in and of itself use std_msgs/Duration for that
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Debug, Default, Clone, PartialEq)]
@@ -131,6 +146,109 @@ impl TryFrom<Duration> for std::time::Duration {
     }
 }
 
+impl WireCodec for Duration {
+    fn encode(&self, writer: &mut WireWriter<'_>) {
+        writer.put(self.sec);
+        writer.put(self.nsec);
+    }
+
+    fn decode(reader: &mut WireReader<'_>) -> Result<Self, WireError> {
+        Ok(Self {
+            sec: reader.get()?,
+            nsec: reader.get()?,
+        })
+    }
+}
+
+/// Matches the ros2 type wstring, a string of UTF-16 code units
+/// ROS2 serializes wstrings the way rosidl_typesupport_fastrtps does: a u32 count of UTF-16 code units,
+/// followed by each code unit widened to a u32. WString always uses that layout, ROS1 has no wstring type
+/// so ROS1 messages containing one serialize identically. Human readable formats such as rosbridge's JSON carry
+/// it as a plain string instead.
+/// The length of a bounded wstring (`wstring<=10`) is counted in UTF-16 code units, see [WString::len_utf16].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WString(pub String);
+
+impl WString {
+    /// Number of UTF-16 code units in the string, which is what ROS2 bounds and serializes
+    pub fn len_utf16(&self) -> usize {
+        self.0.encode_utf16().count()
+    }
+}
+
+impl std::ops::Deref for WString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for WString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for WString {
+    fn from(val: String) -> Self {
+        WString(val)
+    }
+}
+
+impl From<&str> for WString {
+    fn from(val: &str) -> Self {
+        WString(val.to_string())
+    }
+}
+
+impl From<WString> for String {
+    fn from(val: WString) -> Self {
+        val.0
+    }
+}
+
+impl WString {
+    /// Builds a WString from the widened UTF-16 code units of the binary layout
+    fn from_utf16_units(units: Vec<u32>) -> Result<Self, String> {
+        let units = units
+            .into_iter()
+            .map(|unit| {
+                u16::try_from(unit)
+                    .map_err(|_| format!("wstring contained invalid UTF-16 code unit {unit}"))
+            })
+            .collect::<Result<Vec<u16>, _>>()?;
+        String::from_utf16(&units)
+            .map(WString)
+            .map_err(|e| e.to_string())
+    }
+}
+
+// Human readable formats like rosbridge's JSON get a plain string, binary formats the code unit layout
+impl serde::Serialize for WString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+        if serializer.is_human_readable() {
+            return serializer.serialize_str(&self.0);
+        }
+        let mut seq = serializer.serialize_seq(Some(self.len_utf16()))?;
+        for unit in self.0.encode_utf16() {
+            seq.serialize_element(&u32::from(unit))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for WString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        if deserializer.is_human_readable() {
+            return String::deserialize(deserializer).map(WString);
+        }
+        let units = Vec::<u32>::deserialize(deserializer)?;
+        WString::from_utf16_units(units).map_err(D::Error::custom)
+    }
+}
+
 /// Conversion from chrono::DateTime<chrono::Utc> to our internal Time type
 #[cfg(feature = "chrono")]
 impl TryFrom<chrono::DateTime<chrono::Utc>> for Time {
@@ -260,6 +378,28 @@ mod test {
         assert!(std_time.is_err());
     }
 
+    #[test]
+    fn test_wstring_serialization() {
+        // Characters outside the basic multilingual plane take two UTF-16 code units
+        let wstring = crate::WString::from("hé😀");
+        assert_eq!(wstring.len_utf16(), 4);
+
+        // JSON carries wstrings as plain strings
+        let serialized = serde_json::to_string(&wstring).unwrap();
+        assert_eq!(serialized, "\"hé😀\"");
+        let roundtrip: crate::WString = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(roundtrip, wstring);
+
+        // Binary formats carry widened UTF-16 code units
+        assert_eq!(
+            crate::WString::from_utf16_units(vec![104, 233, 55357, 56832]),
+            Ok(wstring)
+        );
+        // Unpaired surrogates and values too large for UTF-16 are rejected
+        assert!(crate::WString::from_utf16_units(vec![55357]).is_err());
+        assert!(crate::WString::from_utf16_units(vec![65536]).is_err());
+    }
+
     #[test]
     fn test_duration_conversions() {
         // Basic test
@@ -29,11 +29,18 @@ pub mod utils;
 use utils::RosVersion;
 mod ros2_hashing;
 use ros2_hashing::*;
+mod conversions;
 mod ros2_builtin_interfaces;
+pub use conversions::{
+    find_and_generate_ros1_ros2_conversions, generate_ros1_ros2_conversions, ConversionMismatch,
+};
 
 pub mod integral_types;
 pub use integral_types::*;
 
+pub mod bounded_types;
+pub use bounded_types::*;
+
 // Custom serde module for Vec<u8> that handles both base64 (rosbridge) and arrays (other formats)
 pub mod serde_rosmsg_bytes;
 
@@ -45,6 +52,7 @@ pub use ::serde;
 pub use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use serde_big_array::BigArray; // Used in generated code for large fixed sized arrays
 pub use serde_bytes;
+pub use simple_error::SimpleError; // Error type of generated ROS1 to ROS2 TryFrom conversions
 pub use smart_default::SmartDefault; // Used in generated code for default values // Used in generated code for faster Vec<u8> serialization
 
 /// A unique hash per message type calculated via the RIHS01 Ros2 methodology
@@ -90,6 +98,10 @@ pub struct MessageFile {
     pub definition: String,
     // If true this message has no dynamic sized members and fits in a fixed size in memory
     pub is_fixed_encoding_length: bool,
+    // If true every field (recursively) can be encoded by a generated [roslibrust_common::codec::WireCodec]
+    pub supports_wire_codec: bool,
+    // If true some field (recursively) is a string or byte sequence, which the borrowed variant of the message borrows
+    pub has_borrowed_fields: bool,
 }
 
 impl MessageFile {
@@ -107,12 +119,16 @@ impl MessageFile {
             log::error!("Failed to determine if message is fixed length: {parsed:#?}");
             None
         })?;
+        let supports_wire_codec = Self::determine_if_wire_codec_supported(&parsed, graph)?;
+        let has_borrowed_fields = Self::determine_if_has_borrowed_fields(&parsed, graph)?;
         Some(MessageFile {
             parsed,
             md5sum,
             ros2_hash,
             definition,
             is_fixed_encoding_length: is_fixed_length,
+            supports_wire_codec,
+            has_borrowed_fields,
         })
     }
 
@@ -268,6 +284,51 @@ impl MessageFile {
         }
         Some(true)
     }
+
+    /// Reports if every field (recursively) referenced by the message is supported by generated wire codecs
+    /// Bounded types and wstrings aren't, they are left to serde
+    fn determine_if_wire_codec_supported(
+        parsed: &ParsedMessageFile,
+        graph: &BTreeMap<String, MessageFile>,
+    ) -> Option<bool> {
+        for field in &parsed.fields {
+            if field.field_type.string_capacity.is_some()
+                || matches!(field.field_type.array_info, ArrayType::Bounded(_))
+                || field.field_type.field_type == "wstring"
+            {
+                return Some(false);
+            }
+            if field.field_type.package_name.is_some() {
+                let field_msg = graph.get(field.get_full_type_name().as_str())?;
+                if !Self::determine_if_wire_codec_supported(&field_msg.parsed, graph)? {
+                    return Some(false);
+                }
+            }
+        }
+        Some(true)
+    }
+
+    /// Reports if any field (recursively) referenced by the message is a string or byte sequence
+    fn determine_if_has_borrowed_fields(
+        parsed: &ParsedMessageFile,
+        graph: &BTreeMap<String, MessageFile>,
+    ) -> Option<bool> {
+        for field in &parsed.fields {
+            let field_type = field.field_type.field_type.as_str();
+            if field.field_type.package_name.is_some() {
+                let field_msg = graph.get(field.get_full_type_name().as_str())?;
+                if Self::determine_if_has_borrowed_fields(&field_msg.parsed, graph)? {
+                    return Some(true);
+                }
+            } else if field_type == "string"
+                || (field.field_type.array_info == ArrayType::Unbounded
+                    && matches!(field_type, "uint8" | "byte" | "char"))
+            {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -546,6 +607,17 @@ pub fn find_and_generate_ros_messages(
 /// * `search_paths` - A list of paths to search for ROS packages.
 pub fn find_and_generate_ros_messages_without_ros_package_path(
     search_paths: Vec<PathBuf>,
+) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+    find_and_generate_ros_messages_with_options(search_paths, &CodegenOptions::default())
+}
+
+/// Same as [find_and_generate_ros_messages_without_ros_package_path], but allows configuring the generated code.
+///
+/// * `search_paths` - A list of paths to search for ROS packages.
+/// * `options` - Code generation options.
+pub fn find_and_generate_ros_messages_with_options(
+    search_paths: Vec<PathBuf>,
+    options: &CodegenOptions,
 ) -> Result<(TokenStream, Vec<PathBuf>), Error> {
     let (messages, services, actions) = find_and_parse_ros_messages(&search_paths)?;
     if messages.is_empty() && services.is_empty() {
@@ -553,7 +625,125 @@ pub fn find_and_generate_ros_messages_without_ros_package_path(
         // As it stands there is not good way for us to manually produce a warning, so I'd rather fail loud
         bail!("Failed to find any services or messages while generating ROS message definitions, paths searched: {search_paths:?}");
     }
-    tokenize_messages_and_services(messages, services, actions)
+    tokenize_messages_and_services(messages, services, actions, options)
+}
+
+/// Same as [find_and_generate_ros_messages_with_options], but only generates the given root types and the types they depend on.
+/// See [select_root_types] for how root types are named.
+///
+/// * `search_paths` - A list of paths to search for ROS packages.
+/// * `root_types` - Messages, services or actions to generate, e.g. `["sensor_msgs/Image", "my_pkg/DoThing"]`
+/// * `options` - Code generation options.
+pub fn find_and_generate_ros_messages_for_types(
+    search_paths: Vec<PathBuf>,
+    root_types: &[String],
+    options: &CodegenOptions,
+) -> Result<(TokenStream, Vec<PathBuf>), Error> {
+    let (messages, services, actions) = find_and_parse_ros_messages(&search_paths)?;
+    let (messages, services, actions) = select_root_types(messages, services, actions, root_types)?;
+    tokenize_messages_and_services(messages, services, actions, options)
+}
+
+/// Narrows parsed files down to the `root_types` and everything they transitively depend on, so that
+/// [resolve_dependency_graph] only resolves (and codegen only generates) what is needed.
+/// Dependencies are kept whole, so md5sums and ROS2 hashes come out the same as when generating everything.
+///
+/// Root types may be messages, services or actions and are named `package/Name`, the ROS2 style
+/// `package/msg/Name`, `package/srv/Name` and `package/action/Name` are also accepted.
+/// Fails if a root type isn't found in the parsed files.
+#[allow(clippy::type_complexity)]
+pub fn select_root_types(
+    messages: Vec<ParsedMessageFile>,
+    services: Vec<ParsedServiceFile>,
+    actions: Vec<ParsedActionFile>,
+    root_types: &[String],
+) -> Result<
+    (
+        Vec<ParsedMessageFile>,
+        Vec<ParsedServiceFile>,
+        Vec<ParsedActionFile>,
+    ),
+    Error,
+> {
+    let messages_by_name = messages
+        .iter()
+        .map(|msg| (msg.get_full_name(), msg))
+        .collect::<BTreeMap<_, _>>();
+    let mut selected_services = BTreeSet::new();
+    let mut selected_actions = BTreeSet::new();
+    // Messages whose fields still need to be followed
+    let mut pending = vec![];
+    for root in root_types {
+        let name = match root.split('/').collect::<Vec<_>>()[..] {
+            [package, "msg" | "srv" | "action", name] => format!("{package}/{name}"),
+            _ => root.clone(),
+        };
+        if messages_by_name.contains_key(&name) {
+            pending.push(name);
+        } else if let Some(srv) = services.iter().find(|srv| srv.get_full_name() == name) {
+            pending.extend(
+                [&srv.request_type, &srv.response_type]
+                    .into_iter()
+                    .flat_map(|msg| &msg.fields)
+                    .filter(|field| !field.field_type.is_primitive())
+                    .map(|field| field.get_full_type_name()),
+            );
+            selected_services.insert(name);
+        } else if let Some(action) = actions
+            .iter()
+            .find(|action| format!("{}/{}", action.package, action.name) == name)
+        {
+            pending.extend(
+                [
+                    &action.action_type,
+                    &action.action_goal_type,
+                    &action.goal_type,
+                    &action.action_result_type,
+                    &action.result_type,
+                    &action.action_feedback_type,
+                    &action.feedback_type,
+                ]
+                .into_iter()
+                .map(|msg| msg.get_full_name()),
+            );
+            selected_actions.insert(name);
+        } else {
+            bail!("Requested root type {root} was not found in any package searched");
+        }
+    }
+
+    let mut selected_messages = BTreeSet::new();
+    while let Some(name) = pending.pop() {
+        // Dependencies which aren't found are left for resolve_dependency_graph to report, or are builtin
+        let Some(msg) = messages_by_name.get(&name) else {
+            continue;
+        };
+        if selected_messages.insert(name) {
+            pending.extend(
+                msg.fields
+                    .iter()
+                    .filter(|field| !field.field_type.is_primitive())
+                    .map(|field| field.get_full_type_name()),
+            );
+        }
+    }
+
+    Ok((
+        messages
+            .into_iter()
+            .filter(|msg| selected_messages.contains(&msg.get_full_name()))
+            .collect(),
+        services
+            .into_iter()
+            .filter(|srv| selected_services.contains(&srv.get_full_name()))
+            .collect(),
+        actions
+            .into_iter()
+            .filter(|action| {
+                selected_actions.contains(&format!("{}/{}", action.package, action.name))
+            })
+            .collect(),
+    ))
 }
 
 /// Generates source code and list of depnendent file system paths
@@ -561,6 +751,7 @@ fn tokenize_messages_and_services(
     messages: Vec<ParsedMessageFile>,
     services: Vec<ParsedServiceFile>,
     actions: Vec<ParsedActionFile>,
+    options: &CodegenOptions,
 ) -> Result<(TokenStream, Vec<PathBuf>), Error> {
     let (messages, services) = resolve_dependency_graph(messages, services)?;
     let msg_iter = messages.iter().map(|m| m.parsed.path.clone());
@@ -571,11 +762,154 @@ fn tokenize_messages_and_services(
         .chain(action_iter)
         .filter(|p| !p.starts_with("/tmp/roslibrust_builtin/"))
         .collect();
-    let source =
-        generate_rust_ros_message_definitions(messages, services, &CodegenOptions::default())?;
+    let source = generate_rust_ros_message_definitions(messages, services, options)?;
     Ok((source, dependent_paths))
 }
 
+/// The generated module for a single ROS package, see [find_and_generate_ros_messages_per_package]
+#[derive(Clone, Debug)]
+pub struct GeneratedPackage {
+    /// Name of the package, which is also the name of its module
+    pub name: String,
+    /// The `pub mod {name} { ... }` definition for the package
+    pub source: TokenStream,
+    /// Files that if modified would require this package to be re-generated.
+    /// Includes the files of messages in other packages this package depends on, as their md5sums and hashes are embedded.
+    pub dependent_paths: Vec<PathBuf>,
+}
+
+/// Same as [find_and_generate_ros_messages_with_options], but returns the generated code split up by package.
+/// Use [write_generated_packages] to write them out as one file per package.
+///
+/// * `search_paths` - A list of paths to search for ROS packages.
+/// * `options` - Code generation options.
+pub fn find_and_generate_ros_messages_per_package(
+    search_paths: Vec<PathBuf>,
+    options: &CodegenOptions,
+) -> Result<Vec<GeneratedPackage>, Error> {
+    let (messages, services, actions) = find_and_parse_ros_messages(&search_paths)?;
+    if messages.is_empty() && services.is_empty() {
+        bail!("Failed to find any services or messages while generating ROS message definitions, paths searched: {search_paths:?}");
+    }
+    let (messages, services) = resolve_dependency_graph(messages, services)?;
+
+    // Each package depends on its own files, plus the files of every message its files reference
+    let message_paths = messages
+        .iter()
+        .map(|msg| (msg.get_full_name(), msg))
+        .collect::<BTreeMap<_, _>>();
+    let mut dependent_paths: BTreeMap<String, BTreeSet<PathBuf>> = BTreeMap::new();
+    let files = messages
+        .iter()
+        .map(|msg| (&msg.parsed.package, &msg.parsed.path, vec![&msg.parsed]))
+        .chain(services.iter().map(|srv| {
+            (
+                &srv.parsed.package,
+                &srv.parsed.path,
+                vec![&srv.parsed.request_type, &srv.parsed.response_type],
+            )
+        }));
+    for (package, path, parsed) in files {
+        let paths = dependent_paths.entry(package.clone()).or_default();
+        paths.insert(path.clone());
+        let mut pending = parsed
+            .into_iter()
+            .flat_map(|msg| &msg.fields)
+            .map(|field| field.get_full_type_name())
+            .collect::<Vec<_>>();
+        while let Some(name) = pending.pop() {
+            if let Some(msg) = message_paths.get(&name) {
+                if paths.insert(msg.parsed.path.clone()) {
+                    pending.extend(msg.parsed.fields.iter().map(|f| f.get_full_type_name()));
+                }
+            }
+        }
+    }
+    for action in &actions {
+        dependent_paths
+            .entry(action.package.clone())
+            .or_default()
+            .insert(action.path.clone());
+    }
+
+    let modules = generate_rust_ros_message_modules(messages, services, options)?;
+    Ok(modules
+        .into_iter()
+        .map(|(name, source)| {
+            let dependent_paths = dependent_paths
+                .remove(&name)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|p| !p.starts_with("/tmp/roslibrust_builtin/"))
+                .collect();
+            GeneratedPackage {
+                name,
+                source,
+                dependent_paths,
+            }
+        })
+        .collect())
+}
+
+/// Writes each package to `{out_dir}/{package}.rs` along with a `{out_dir}/mod.rs` which includes all of them,
+/// so the generated code can be used with `include!(concat!(env!("OUT_DIR"), "/mod.rs"));`
+///
+/// Files whose content is unchanged are not re-written, so their modification time is preserved
+/// and cargo / rustc can tell nothing changed. Files included by a previous `mod.rs` for packages which are no
+/// longer generated are removed, other files in `out_dir` are left alone.
+pub fn write_generated_packages(
+    packages: &[GeneratedPackage],
+    out_dir: &Path,
+) -> Result<(), Error> {
+    let write_if_changed = |name: &str, contents: String| -> Result<(), Error> {
+        let path = out_dir.join(name);
+        if std::fs::read(&path).is_ok_and(|existing| existing == contents.as_bytes()) {
+            return Ok(());
+        }
+        std::fs::write(&path, contents).map_err(|e| {
+            Error::with(
+                format!("Failed to write generated file {path:?}:").as_str(),
+                e,
+            )
+        })
+    };
+    let previous_files = std::fs::read_to_string(out_dir.join("mod.rs"))
+        .map(|mod_rs| {
+            mod_rs
+                .lines()
+                .filter_map(|line| line.strip_prefix("include!(\""))
+                .filter_map(|line| line.strip_suffix("\");"))
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let mut mod_rs = String::new();
+    let mut file_names = BTreeSet::new();
+    for package in packages {
+        let file_name = format!("{}.rs", package.name);
+        write_if_changed(&file_name, package.source.to_string())?;
+        mod_rs.push_str(&format!("include!(\"{file_name}\");\n"));
+        file_names.insert(file_name);
+    }
+    write_if_changed("mod.rs", mod_rs)?;
+    for stale in previous_files
+        .iter()
+        .filter(|file_name| !file_names.contains(*file_name))
+    {
+        let path = out_dir.join(stale);
+        match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(Error::with(
+                    format!("Failed to remove stale generated file {path:?}:").as_str(),
+                    e,
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 /// Generates struct definitions and implementations for message and service files
 /// in the given packages.
 pub fn generate_ros_messages_for_packages(
@@ -596,7 +930,7 @@ pub fn generate_ros_messages_for_packages(
     if messages.is_empty() && services.is_empty() {
         bail!("Failed to find any services or messages while generating ROS message definitions, packages searched: {packages:?}")
     }
-    tokenize_messages_and_services(messages, services, actions)
+    tokenize_messages_and_services(messages, services, actions, &CodegenOptions::default())
 }
 
 /// Searches a list of paths for ROS packages to find their associated message
@@ -685,12 +1019,88 @@ pub fn generate_rust_ros_message_definitions(
     services: Vec<ServiceFile>,
     options: &CodegenOptions,
 ) -> Result<TokenStream, Error> {
+    let module_definitions = generate_rust_ros_message_modules(messages, services, options)?
+        .into_values()
+        .collect::<Vec<_>>();
+    Ok(quote! {
+        #(#module_definitions)*
+
+    })
+}
+
+/// Records the constant enum names which more than one group of constants in a package would get,
+/// e.g. `Foo.bar_baz` and `FooBar.baz` both make a `FooBarBaz`, and warns that those enums are skipped
+fn find_colliding_constant_enums(
+    messages: &[MessageFile],
+    services: &[ServiceFile],
+    package_types: &mut BTreeMap<String, PackageTypes>,
+) {
+    let all_messages = messages.iter().chain(
+        services
+            .iter()
+            .flat_map(|srv| [&srv.request, &srv.response]),
+    );
+    // Package and enum name to the fields whose constants would make that enum
+    let mut enums: BTreeMap<(&str, String), Vec<String>> = BTreeMap::new();
+    for msg in all_messages {
+        let pkg_name = msg.parsed.package.as_str();
+        for (enum_name, field) in gen::constant_enum_names(msg, &package_types[pkg_name].names) {
+            enums
+                .entry((pkg_name, enum_name))
+                .or_default()
+                .push(format!("{}.{field}", msg.parsed.name));
+        }
+    }
+    for ((pkg_name, enum_name), fields) in enums {
+        if fields.len() < 2 {
+            continue;
+        }
+        log::warn!(
+            "Not generating constant enum {pkg_name}::{enum_name}, the enums for {} would all have that name",
+            fields.join(", ")
+        );
+        if let Some(types) = package_types.get_mut(pkg_name) {
+            types.colliding_enums.insert(enum_name);
+        }
+    }
+}
+
+/// Same as [generate_rust_ros_message_definitions], but returns the module generated for each package by package name
+fn generate_rust_ros_message_modules(
+    messages: Vec<MessageFile>,
+    services: Vec<ServiceFile>,
+    options: &CodegenOptions,
+) -> Result<BTreeMap<String, TokenStream>, Error> {
     let mut modules_to_struct_definitions: BTreeMap<String, Vec<TokenStream>> = BTreeMap::new();
 
+    // Collect the names of every type that will be generated into each package module,
+    // so generated helper types can be named around them
+    let mut package_types: BTreeMap<String, PackageTypes> = BTreeMap::new();
+    let message_names = messages
+        .iter()
+        .map(|msg| (&msg.parsed.package, &msg.parsed.name))
+        .chain(services.iter().flat_map(|srv| {
+            [
+                (&srv.parsed.package, &srv.parsed.name),
+                (&srv.request.parsed.package, &srv.request.parsed.name),
+                (&srv.response.parsed.package, &srv.response.parsed.name),
+            ]
+        }));
+    for (pkg_name, name) in message_names {
+        let names = &mut package_types.entry(pkg_name.clone()).or_default().names;
+        names.insert(name.clone());
+        if options.generate_borrowed_types {
+            names.insert(format!("{name}Ref"));
+        }
+    }
+    if options.generate_constant_enums {
+        find_colliding_constant_enums(&messages, &services, &mut package_types);
+    }
+
     // Convert messages files into rust token streams and insert them into BTree organized by package
     messages.into_iter().try_for_each(|message| {
         let pkg_name = message.parsed.package.clone();
-        let definition = generate_struct(message, Some(options))?;
+        let definition = generate_struct(message, Some(options), &package_types[&pkg_name])?;
         if let Some(entry) = modules_to_struct_definitions.get_mut(&pkg_name) {
             entry.push(definition);
         } else {
@@ -701,7 +1111,7 @@ pub fn generate_rust_ros_message_definitions(
     // Do the same for services
     services.into_iter().try_for_each(|service| {
         let pkg_name = service.parsed.package.clone();
-        let definition = generate_service(service, Some(options))?;
+        let definition = generate_service(service, Some(options), &package_types[&pkg_name])?;
         if let Some(entry) = modules_to_struct_definitions.get_mut(&pkg_name) {
             entry.push(definition);
         } else {
@@ -714,15 +1124,13 @@ pub fn generate_rust_ros_message_definitions(
         .keys()
         .cloned()
         .collect::<Vec<String>>();
-    let module_definitions = modules_to_struct_definitions
+    Ok(modules_to_struct_definitions
         .into_iter()
-        .map(|(pkg, struct_defs)| generate_mod(pkg, struct_defs, &all_pkgs[..]))
-        .collect::<Vec<TokenStream>>();
-
-    Ok(quote! {
-        #(#module_definitions)*
-
-    })
+        .map(|(pkg, struct_defs)| {
+            let module = generate_mod(pkg.clone(), struct_defs, &all_pkgs[..]);
+            (pkg, module)
+        })
+        .collect())
 }
 
 struct MessageMetadata {
@@ -832,7 +1240,7 @@ pub fn resolve_dependency_graph(
 }
 
 /// Parses all ROS file types and returns a final expanded set
-/// Currently supports service files, message files, and action files
+/// Currently supports service files, message files, action files, and ROS2 .idl files
 /// The returned collection will contain all messages files including those buried with the
 /// service or action files, and will have fully expanded and resolved referenced types in other packages.
 /// * `msg_paths` -- List of tuple (Package, Path to File) for each file to parse
@@ -876,6 +1284,10 @@ pub(crate) fn parse_ros_files(
                 let msg = parse_ros_message_file(&contents, name, &pkg, &path)?;
                 parsed_messages.push(msg);
             }
+            "idl" => match parse_ros_idl_file(&contents, name, &pkg, &path)? {
+                ParsedIdlFile::Message(msg) => parsed_messages.push(msg),
+                ParsedIdlFile::Service(srv) => parsed_services.push(*srv),
+            },
             "action" => {
                 let action = parse_ros_action_file(&contents, name, &pkg, &path)?;
                 parsed_actions.push(action.clone());
@@ -918,6 +1330,9 @@ pub fn resolve_action_hashes(parsed_actions: Vec<ParsedActionFile>) -> Vec<Actio
 #[cfg(test)]
 mod test {
     use crate::find_and_generate_ros_messages;
+    use crate::utils::{Package, RosVersion};
+    use crate::{CodegenOptions, MessageFile};
+    use std::collections::BTreeMap;
 
     /// Confirms we don't panic on ros1 parsing
     #[test_log::test]
@@ -986,4 +1401,410 @@ mod test {
         assert!(!source.is_empty());
         assert!(!paths.is_empty());
     }
+
+    /// Confirms constant groups become enums only when asked for, and only when they can be represented
+    #[test_log::test]
+    fn generate_constant_enums() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let source = r#"
+uint8 STATUS_OK=0
+uint8 STATUS_RUN_ERROR=1
+uint8 status
+int32 LEVEL_LOW=-1
+int32 LEVEL_HIGH=1
+int32 level
+# Aliased values can't be an enum
+uint8 MODE_A=0
+uint8 MODE_B=0
+uint8 mode
+# Neither can variants which aren't identifiers
+uint8 DIM_2D=2
+uint8 DIM_3D=3
+uint8 dim
+"#;
+        let parsed = crate::parse_ros_message_file(
+            source,
+            "Node",
+            &pkg,
+            std::path::Path::new("test_pkg/msg/Node.msg"),
+        )
+        .unwrap();
+        let msg = MessageFile::resolve(parsed, &Default::default()).unwrap();
+
+        let plain = crate::gen::generate_struct(msg.clone(), None, &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(!plain.contains("pub enum"));
+
+        let options = CodegenOptions {
+            generate_constant_enums: true,
+            ..Default::default()
+        };
+        let generated = crate::gen::generate_struct(msg, Some(&options), &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(generated.contains("pub enum NodeStatus { Ok = 0 , RunError = 1 , }"));
+        assert!(generated.contains("pub enum NodeLevel { Low = - 1 , High = 1 , }"));
+        assert!(generated.contains("pub fn status_enum"));
+        assert!(!generated.contains("NodeMode"));
+        assert!(!generated.contains("NodeDim"));
+    }
+
+    /// Confirms a constant enum whose name is already taken by a message in the same package gets a suffix,
+    /// and that enums two groups of constants would both be named aren't generated
+    #[test_log::test]
+    fn generate_constant_enums_avoids_collisions() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let resolve = |source: &str, name: &str| {
+            let parsed = crate::parse_ros_message_file(
+                source,
+                name,
+                &pkg,
+                std::path::Path::new(&format!("test_pkg/msg/{name}.msg")),
+            )
+            .unwrap();
+            MessageFile::resolve(parsed, &Default::default()).unwrap()
+        };
+        let goal = resolve("uint8 STATUS_A=0\nuint8 STATUS_B=1\nuint8 status\n", "Goal");
+        let goal_status = resolve("uint8 code\n", "GoalStatus");
+
+        let options = CodegenOptions {
+            generate_constant_enums: true,
+            ..Default::default()
+        };
+        let generated =
+            crate::generate_rust_ros_message_definitions(vec![goal, goal_status], vec![], &options)
+                .unwrap()
+                .to_string();
+        assert!(generated.contains("pub enum GoalStatusEnum { A = 0 , B = 1 , }"));
+        assert!(generated.contains("pub struct GoalStatus"));
+        assert!(!generated.contains("pub enum GoalStatus {"));
+
+        // Foo.bar_baz and FooBar.baz would both be FooBarBaz, so neither is generated
+        let foo = resolve(
+            "uint8 BAR_BAZ_A=0\nuint8 BAR_BAZ_B=1\nuint8 bar_baz\nuint8 KIND_X=0\nuint8 KIND_Y=1\nuint8 kind\n",
+            "Foo",
+        );
+        let foo_bar = resolve("uint8 BAZ_A=0\nuint8 BAZ_B=1\nuint8 baz\n", "FooBar");
+        let generated =
+            crate::generate_rust_ros_message_definitions(vec![foo, foo_bar], vec![], &options)
+                .unwrap()
+                .to_string();
+        assert!(!generated.contains("FooBarBaz"));
+        assert!(!generated.contains("fn bar_baz_enum"));
+        assert!(!generated.contains("fn baz_enum"));
+        assert!(generated.contains("pub enum FooKind { X = 0 , Y = 1 , }"));
+    }
+
+    /// Confirms wire codecs are generated only when asked for, and not for messages with bounded fields
+    #[test_log::test]
+    fn generate_wire_codecs() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        };
+        let parse = |source: &str, name: &str| {
+            let parsed = crate::parse_ros_message_file(
+                source,
+                name,
+                &pkg,
+                std::path::Path::new("test_pkg/msg/Test.msg"),
+            )
+            .unwrap();
+            MessageFile::resolve(parsed, &Default::default()).unwrap()
+        };
+        let simple = parse("uint8[] data\nstring[3] names\nfloat64 value\n", "Simple");
+        let bounded = parse("int32[<=5] data\n", "Bounded");
+        assert!(simple.supports_wire_codec);
+        assert!(!bounded.supports_wire_codec);
+
+        let plain = crate::gen::generate_struct(simple.clone(), None, &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(!plain.contains("WireCodec"));
+
+        let options = CodegenOptions {
+            generate_wire_codecs: true,
+            ..Default::default()
+        };
+        let generated =
+            crate::gen::generate_struct(simple.clone(), Some(&options), &Default::default())
+                .unwrap()
+                .to_string();
+        assert!(generated.contains("impl :: roslibrust :: codec :: WireCodec for Simple"));
+        assert!(generated.contains("const WIRE_CODEC"));
+        assert!(generated.contains("writer . put_seq (& self . r#data)"));
+        assert!(!generated.contains("SimpleRef"));
+
+        let options = CodegenOptions {
+            generate_borrowed_types: true,
+            ..Default::default()
+        };
+        let generated = crate::gen::generate_struct(simple, Some(&options), &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(generated.contains("const WIRE_CODEC"));
+        assert!(generated.contains("pub struct SimpleRef < 'a >"));
+        assert!(generated.contains("pub r#data : &'a [u8]"));
+        assert!(generated.contains("pub r#names : [&'a str ; 3]"));
+        assert!(generated.contains("pub r#value : f64"));
+        let generated = crate::gen::generate_struct(bounded, Some(&options), &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(!generated.contains("WireCodec"));
+    }
+
+    /// Confirms HasHeader is implemented only for messages which lead with a std_msgs/Header
+    #[test_log::test]
+    fn generate_has_header() {
+        let parse = |source: &str, name: &str, pkg_name: &str, graph: &BTreeMap<_, _>| {
+            let pkg = Package {
+                name: pkg_name.to_string(),
+                path: "./not_a_path".into(),
+                version: Some(RosVersion::ROS1),
+            };
+            let parsed = crate::parse_ros_message_file(
+                source,
+                name,
+                &pkg,
+                std::path::Path::new("not_a_path/msg/Test.msg"),
+            )
+            .unwrap();
+            MessageFile::resolve(parsed, graph).unwrap()
+        };
+        let mut graph = BTreeMap::new();
+        let header = parse(
+            "uint32 seq\ntime stamp\nstring frame_id\n",
+            "Header",
+            "std_msgs",
+            &graph,
+        );
+        graph.insert("std_msgs/Header".to_string(), header);
+        let stamped = parse(
+            "Header header\nfloat64 range\n",
+            "Range",
+            "test_pkg",
+            &graph,
+        );
+        let trailing = parse("float64 range\nHeader header\n", "Late", "test_pkg", &graph);
+        assert!(stamped.parsed.first_field_is_header());
+        assert!(!trailing.parsed.first_field_is_header());
+
+        let generated = crate::gen::generate_struct(stamped, None, &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(generated.contains("impl :: roslibrust :: HasHeader for Range"));
+        assert!(generated.contains("type Header = std_msgs :: Header"));
+        assert!(generated.contains("self . r#header . stamp . secs = sec"));
+        assert!(generated.contains("Some (self . r#header . seq)"));
+        let generated = crate::gen::generate_struct(trailing, None, &Default::default())
+            .unwrap()
+            .to_string();
+        assert!(!generated.contains("HasHeader"));
+    }
+
+    /// Confirms conversions are generated between matching ROS1 and ROS2 messages, and mismatches are reported
+    #[test_log::test]
+    fn generate_ros1_ros2_conversions() {
+        let parse = |source: &str, name: &str, version| {
+            let pkg = Package {
+                name: "test_pkg".to_string(),
+                path: "./not_a_path".into(),
+                version: Some(version),
+            };
+            let parsed = crate::parse_ros_message_file(
+                source,
+                name,
+                &pkg,
+                std::path::Path::new("test_pkg/msg/Test.msg"),
+            )
+            .unwrap();
+            MessageFile::resolve(parsed, &Default::default()).unwrap()
+        };
+        let ros1 = [
+            parse("float64 x\nfloat64 y\n", "Point", RosVersion::ROS1),
+            parse("int32[] data\n", "Limits", RosVersion::ROS1),
+            parse("int32 a\nint32 b\n", "Odd", RosVersion::ROS1),
+        ];
+        let ros2 = [
+            parse("float64 x\nfloat64 y\n", "Point", RosVersion::ROS2),
+            parse("int32[<=3] data\n", "Limits", RosVersion::ROS2),
+            parse("int32 a\n", "Odd", RosVersion::ROS2),
+        ];
+        let (source, mismatches) =
+            crate::generate_ros1_ros2_conversions(&ros1, &ros2, "crate::ros1", "crate::ros2", true)
+                .unwrap();
+        let source = source.to_string();
+        assert!(source.contains(
+            "impl :: std :: convert :: From < crate :: ros1 :: test_pkg :: Point > for crate :: ros2 :: test_pkg :: Point"
+        ));
+        assert!(source.contains(
+            "impl :: std :: convert :: TryFrom < crate :: ros1 :: test_pkg :: Limits > for crate :: ros2 :: test_pkg :: Limits"
+        ));
+        assert!(source.contains(
+            "impl :: std :: convert :: From < crate :: ros2 :: test_pkg :: Limits > for crate :: ros1 :: test_pkg :: Limits"
+        ));
+        assert!(!source.contains("Odd"));
+        assert_eq!(
+            mismatches,
+            vec![crate::ConversionMismatch {
+                message: "test_pkg/Odd".to_string(),
+                reason: "field b has no counterpart in ROS2".to_string(),
+            }]
+        );
+    }
+
+    /// Confirms the time conversions are only generated when asked for, so they can be generated once per ROS2 module
+    #[test_log::test]
+    fn generate_ros1_ros2_time_conversions() {
+        let pkg = Package {
+            name: "builtin_interfaces".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        };
+        let parsed = crate::parse_ros_message_file(
+            "int32 sec\nuint32 nanosec\n",
+            "Time",
+            &pkg,
+            std::path::Path::new("builtin_interfaces/msg/Time.msg"),
+        )
+        .unwrap();
+        let ros2 = [MessageFile::resolve(parsed, &Default::default()).unwrap()];
+        let generate = |time_conversions| {
+            crate::generate_ros1_ros2_conversions(
+                &[],
+                &ros2,
+                "crate::ros1",
+                "crate::ros2",
+                time_conversions,
+            )
+            .unwrap()
+            .0
+            .to_string()
+        };
+        assert!(generate(true).contains(
+            "impl :: std :: convert :: From < :: roslibrust :: codegen :: integral_types :: Time > for crate :: ros2 :: builtin_interfaces :: Time"
+        ));
+        assert!(generate(false).is_empty());
+    }
+
+    /// Confirms only root types and their transitive dependencies are selected
+    #[test_log::test]
+    fn select_root_types() {
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS1),
+        };
+        let path = std::path::Path::new("test_pkg/msg/Test.msg");
+        let messages = [
+            ("Leaf", "int32 a\n"),
+            ("Middle", "Leaf leaf\n"),
+            ("Root", "Middle[] middles\ntime stamp\n"),
+            ("Unrelated", "int32 b\n"),
+            ("ServiceArg", "Leaf leaf\n"),
+        ]
+        .into_iter()
+        .map(|(name, source)| crate::parse_ros_message_file(source, name, &pkg, path).unwrap())
+        .collect::<Vec<_>>();
+        let services = vec![crate::parse_ros_service_file(
+            "ServiceArg arg\n---\nbool ok\n",
+            "DoThing",
+            &pkg,
+            std::path::Path::new("test_pkg/srv/DoThing.srv"),
+        )
+        .unwrap()];
+
+        let (selected, srvs, _) = crate::select_root_types(
+            messages.clone(),
+            services.clone(),
+            vec![],
+            &["test_pkg/msg/Root".to_string()],
+        )
+        .unwrap();
+        let names = selected.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Leaf", "Middle", "Root"]);
+        assert!(srvs.is_empty());
+
+        let (selected, srvs, _) = crate::select_root_types(
+            messages.clone(),
+            services.clone(),
+            vec![],
+            &["test_pkg/DoThing".to_string()],
+        )
+        .unwrap();
+        let names = selected.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Leaf", "ServiceArg"]);
+        assert_eq!(srvs.len(), 1);
+
+        assert!(crate::select_root_types(
+            messages,
+            services,
+            vec![],
+            &["test_pkg/Missing".to_string()]
+        )
+        .is_err());
+    }
+
+    /// Confirms per-package files are written along with a mod.rs, unchanged files are left alone and stale ones removed
+    #[test_log::test]
+    fn write_generated_packages_skips_unchanged() {
+        let dir = std::env::temp_dir().join(format!(
+            "roslibrust_codegen_write_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let package = |name: &str, source| crate::GeneratedPackage {
+            name: name.to_string(),
+            source,
+            dependent_paths: vec![],
+        };
+        let packages = vec![
+            package("pkg_a", quote::quote! { pub mod pkg_a {} }),
+            package("pkg_b", quote::quote! { pub mod pkg_b {} }),
+        ];
+        crate::write_generated_packages(&packages, &dir).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("mod.rs")).unwrap(),
+            "include!(\"pkg_a.rs\");\ninclude!(\"pkg_b.rs\");\n"
+        );
+        let modified = |file: &str| {
+            std::fs::metadata(dir.join(file))
+                .unwrap()
+                .modified()
+                .unwrap()
+        };
+        let a_modified = modified("pkg_a.rs");
+
+        let packages = vec![
+            packages[0].clone(),
+            package("pkg_b", quote::quote! { pub mod pkg_b { pub struct B; } }),
+        ];
+        crate::write_generated_packages(&packages, &dir).unwrap();
+        assert_eq!(modified("pkg_a.rs"), a_modified);
+        assert!(std::fs::read_to_string(dir.join("pkg_b.rs"))
+            .unwrap()
+            .contains("struct B"));
+
+        // Files of packages which are no longer generated are removed, other files are left alone
+        std::fs::write(dir.join("unrelated.rs"), "").unwrap();
+        crate::write_generated_packages(&packages[1..], &dir).unwrap();
+        assert!(!dir.join("pkg_a.rs").exists());
+        assert!(dir.join("pkg_b.rs").exists());
+        assert!(dir.join("unrelated.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("mod.rs")).unwrap(),
+            "include!(\"pkg_b.rs\");\n"
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }
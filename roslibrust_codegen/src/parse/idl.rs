@@ -0,0 +1,734 @@
+//! Parsing of ROS2 OMG IDL interface files.
+//!
+//! Supports the subset of IDL that rosidl itself generates and understands: nested modules, structs,
+//! typedefs, constants declared in `<Type>_Constants` modules, sequences, bounded strings and fixed size
+//! arrays. The `@default` annotation is used for field defaults, all other annotations (e.g. `@verbatim`)
+//! are accepted and ignored.
+//!
+//! The result is the same [ParsedMessageFile] / [ParsedServiceFile] that .msg and .srv files produce, so
+//! code generation and hashing don't need to know which format a type was defined in.
+
+use crate::parse::{ParsedMessageFile, ParsedServiceFile};
+use crate::utils::{Package, RosVersion};
+use crate::{bail, ArrayType, ConstantInfo, Error, FieldInfo, FieldType};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Result of parsing an .idl file, which can define either a message or a service
+#[derive(Clone, Debug)]
+pub enum ParsedIdlFile {
+    Message(ParsedMessageFile),
+    Service(Box<ParsedServiceFile>),
+}
+
+/// Parses the contents of an .idl file
+/// * `data` -- Raw contents of the file as loaded from disk
+/// * `name` -- Name of the file excluding the extension, e.g. `Header` or `AddTwoInts`
+/// * `package` -- Package the file was found in, required for relative type paths
+/// * `path` -- Path to the idl file
+///
+/// A file containing structs named `{name}_Request` and `{name}_Response` is treated as a service,
+/// otherwise the file must contain a struct named `{name}`.
+pub fn parse_ros_idl_file(
+    data: &str,
+    name: &str,
+    package: &Package,
+    path: &Path,
+) -> Result<ParsedIdlFile, Error> {
+    let tokens = tokenize(data).map_err(|e| {
+        Error::new(format!(
+            "Failed to tokenize idl file {}: {e}",
+            path.display()
+        ))
+    })?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        package,
+        typedefs: HashMap::new(),
+        structs: HashMap::new(),
+        constants: HashMap::new(),
+    };
+    parser
+        .parse_definitions(false, None)
+        .map_err(|e| Error::new(format!("Failed to parse idl file {}: {e}", path.display())))?;
+
+    let request_name = format!("{name}_Request");
+    let response_name = format!("{name}_Response");
+    if parser.structs.contains_key(&request_name) || parser.structs.contains_key(&response_name) {
+        let request_type =
+            parser.take_message(&request_name, &format!("{name}Request"), data, path)?;
+        let response_type =
+            parser.take_message(&response_name, &format!("{name}Response"), data, path)?;
+        return Ok(ParsedIdlFile::Service(Box::new(ParsedServiceFile {
+            name: name.to_owned(),
+            package: package.name.clone(),
+            request_type,
+            response_type,
+            source: data.to_owned(),
+            path: path.to_owned(),
+        })));
+    }
+    if parser.structs.contains_key(&format!("{name}_Goal")) {
+        bail!(
+            "Action definitions in idl format are not supported, found in {}",
+            path.display()
+        );
+    }
+    Ok(ParsedIdlFile::Message(
+        parser.take_message(name, name, data, path)?,
+    ))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    /// String or character literal, including its quotes
+    Literal(String),
+    Punct(char),
+    /// The `::` scope separator
+    Scope,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(s) | Token::Number(s) | Token::Literal(s) => f.write_str(s),
+            Token::Punct(c) => write!(f, "{c}"),
+            Token::Scope => f.write_str("::"),
+        }
+    }
+}
+
+/// Splits idl source into tokens, dropping comments and preprocessor directives
+fn tokenize(data: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = data.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' {
+            // Preprocessor directives (#include, #ifndef, ...) are irrelevant for us
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            if i >= chars.len() {
+                return Err("unterminated block comment".to_string());
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            if i >= chars.len() {
+                return Err("unterminated string literal".to_string());
+            }
+            i += 1;
+            let literal: String = chars[start..i].iter().collect();
+            // Adjacent string literals are concatenated, rosidl splits long @verbatim text this way
+            match tokens.last_mut() {
+                Some(Token::Literal(prev)) if c == '"' && prev.starts_with('"') => {
+                    prev.pop();
+                    prev.push_str(&literal[1..]);
+                }
+                _ => tokens.push(Token::Literal(literal)),
+            }
+        } else if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+        {
+            let start = i;
+            while i < chars.len() {
+                let n = chars[i];
+                let exponent_sign = (n == '+' || n == '-') && matches!(chars[i - 1], 'e' | 'E');
+                if n.is_ascii_alphanumeric() || n == '.' || exponent_sign {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c == ':' && chars.get(i + 1) == Some(&':') {
+            tokens.push(Token::Scope);
+            i += 2;
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    package: &'a Package,
+    /// Typedefs by their (unscoped) name, rosidl uses these for arrays e.g. `typedef double double__9[9];`
+    typedefs: HashMap<String, FieldType>,
+    structs: HashMap<String, Vec<FieldInfo>>,
+    /// Constants keyed by the name of the struct they belong to
+    constants: HashMap<String, Vec<ConstantInfo>>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, Error> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(Error::new("unexpected end of file"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn peek_is(&self, expected: &Token) -> bool {
+        self.peek() == Some(expected)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), Error> {
+        let token = self.next()?;
+        if token != expected {
+            bail!("expected `{expected}` but found `{token}`");
+        }
+        Ok(())
+    }
+
+    fn ident(&mut self) -> Result<String, Error> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            other => bail!("expected an identifier but found `{other}`"),
+        }
+    }
+
+    fn number(&mut self) -> Result<usize, Error> {
+        match self.next()? {
+            Token::Number(n) => n
+                .parse()
+                .map_err(|e| Error::new(format!("invalid size {n}: {e}"))),
+            other => bail!("expected a number but found `{other}`"),
+        }
+    }
+
+    /// Parses definitions until the end of the file, or the closing brace of the current module
+    /// `constants_owner` is the struct constants belong to when inside a `<Type>_Constants` module
+    fn parse_definitions(
+        &mut self,
+        in_module: bool,
+        constants_owner: Option<&str>,
+    ) -> Result<(), Error> {
+        loop {
+            // Annotations on anything but struct members carry nothing we use
+            self.parse_annotations()?;
+            let Some(token) = self.peek().cloned() else {
+                if in_module {
+                    bail!("unexpected end of file inside module");
+                }
+                return Ok(());
+            };
+            match token {
+                Token::Punct('}') if in_module => {
+                    self.pos += 1;
+                    self.expect(Token::Punct(';'))?;
+                    return Ok(());
+                }
+                Token::Punct(';') => self.pos += 1,
+                Token::Ident(keyword) => {
+                    self.pos += 1;
+                    match keyword.as_str() {
+                        "module" => {
+                            let name = self.ident()?;
+                            self.expect(Token::Punct('{'))?;
+                            let owner = name.strip_suffix("_Constants").map(str::to_owned);
+                            self.parse_definitions(true, owner.as_deref())?;
+                        }
+                        "struct" => self.parse_struct()?,
+                        "typedef" => self.parse_typedef()?,
+                        "const" => {
+                            let owner = constants_owner.ok_or(Error::new(
+                                "constants must be declared in a `<Type>_Constants` module",
+                            ))?;
+                            let constant = self.parse_const()?;
+                            self.constants
+                                .entry(owner.to_owned())
+                                .or_default()
+                                .push(constant);
+                        }
+                        other => bail!("unsupported idl construct `{other}`"),
+                    }
+                }
+                other => bail!("unexpected `{other}`"),
+            }
+        }
+    }
+
+    /// Parses any number of `@name` or `@name(...)` annotations, returning the value of `@default` if present
+    fn parse_annotations(&mut self) -> Result<Option<Vec<Token>>, Error> {
+        let mut default = None;
+        while self.peek_is(&Token::Punct('@')) {
+            self.pos += 1;
+            let mut name = self.ident()?;
+            while self.peek_is(&Token::Scope) {
+                self.pos += 1;
+                name = self.ident()?;
+            }
+            if !self.peek_is(&Token::Punct('(')) {
+                continue;
+            }
+            self.pos += 1;
+            let mut depth = 1;
+            let mut params = vec![];
+            loop {
+                let token = self.next()?;
+                match token {
+                    Token::Punct('(') => depth += 1,
+                    Token::Punct(')') => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+                params.push(token);
+            }
+            if name == "default" {
+                // Expected form is `value=<literal>`
+                match params.as_slice() {
+                    [Token::Ident(key), Token::Punct('='), value @ ..] if key == "value" => {
+                        default = Some(value.to_vec())
+                    }
+                    _ => bail!("malformed @default annotation"),
+                }
+            }
+        }
+        Ok(default)
+    }
+
+    fn parse_struct(&mut self) -> Result<(), Error> {
+        let name = self.ident()?;
+        if self.peek_is(&Token::Punct(';')) {
+            // Forward declaration
+            self.pos += 1;
+            return Ok(());
+        }
+        self.expect(Token::Punct('{'))?;
+        let mut fields = vec![];
+        loop {
+            let default = self.parse_annotations()?;
+            if self.peek_is(&Token::Punct('}')) {
+                self.pos += 1;
+                self.expect(Token::Punct(';'))?;
+                break;
+            }
+            let mut field_type = self.parse_type()?;
+            let field_name = self.ident()?;
+            self.parse_array_suffix(&mut field_type)?;
+            self.expect(Token::Punct(';'))?;
+            let default = default
+                .map(|value| convert_literal(&value, &field_type))
+                .transpose()?;
+            fields.push(FieldInfo {
+                field_type,
+                field_name,
+                default,
+            });
+        }
+        // rosidl gives empty messages a placeholder member, .msg parsing represents them with no fields
+        if let [only] = fields.as_slice() {
+            if only.field_name == "structure_needs_at_least_one_member" {
+                fields.clear();
+            }
+        }
+        self.structs.insert(name, fields);
+        Ok(())
+    }
+
+    fn parse_typedef(&mut self) -> Result<(), Error> {
+        let mut field_type = self.parse_type()?;
+        let name = self.ident()?;
+        self.parse_array_suffix(&mut field_type)?;
+        self.expect(Token::Punct(';'))?;
+        self.typedefs.insert(name, field_type);
+        Ok(())
+    }
+
+    fn parse_const(&mut self) -> Result<ConstantInfo, Error> {
+        let constant_type = self.parse_type()?;
+        if !constant_type.is_primitive() || constant_type.array_info != ArrayType::NotArray {
+            bail!("constants must be of a primitive type, found {constant_type}");
+        }
+        let constant_name = self.ident()?;
+        self.expect(Token::Punct('='))?;
+        let mut value = vec![];
+        while !self.peek_is(&Token::Punct(';')) {
+            value.push(self.next()?);
+        }
+        self.pos += 1;
+        Ok(ConstantInfo {
+            constant_value: convert_literal(&value, &constant_type)?,
+            constant_type: constant_type.field_type,
+            constant_name,
+        })
+    }
+
+    /// Handles a trailing `[N]` after a declarator
+    fn parse_array_suffix(&mut self, field_type: &mut FieldType) -> Result<(), Error> {
+        if !self.peek_is(&Token::Punct('[')) {
+            return Ok(());
+        }
+        self.pos += 1;
+        let size = self.number()?;
+        self.expect(Token::Punct(']'))?;
+        if field_type.array_info != ArrayType::NotArray || self.peek_is(&Token::Punct('[')) {
+            bail!("nested arrays and sequences are not supported by ROS");
+        }
+        field_type.array_info = ArrayType::FixedLength(size);
+        Ok(())
+    }
+
+    fn parse_type(&mut self) -> Result<FieldType, Error> {
+        let first = self.ident()?;
+        let primitive = match first.as_str() {
+            "sequence" => {
+                self.expect(Token::Punct('<'))?;
+                let mut inner = self.parse_type()?;
+                if inner.array_info != ArrayType::NotArray {
+                    bail!("nested arrays and sequences are not supported by ROS");
+                }
+                inner.array_info = if self.peek_is(&Token::Punct(',')) {
+                    self.pos += 1;
+                    ArrayType::Bounded(self.number()?)
+                } else {
+                    ArrayType::Unbounded
+                };
+                self.expect(Token::Punct('>'))?;
+                return Ok(inner);
+            }
+            "string" | "wstring" => {
+                let string_capacity = if self.peek_is(&Token::Punct('<')) {
+                    self.pos += 1;
+                    let capacity = self.number()?;
+                    self.expect(Token::Punct('>'))?;
+                    Some(capacity)
+                } else {
+                    None
+                };
+                return Ok(FieldType {
+                    string_capacity,
+                    ..self.primitive(&first)
+                });
+            }
+            "boolean" => "bool",
+            "octet" => "byte",
+            "char" => "char",
+            "float" => "float32",
+            "double" => "float64",
+            "short" => "int16",
+            "long" => {
+                if self.peek_is(&Token::Ident("long".to_string())) {
+                    self.pos += 1;
+                    "int64"
+                } else if self.peek_is(&Token::Ident("double".to_string())) {
+                    bail!("long double is not supported by ROS");
+                } else {
+                    "int32"
+                }
+            }
+            "unsigned" => match self.ident()?.as_str() {
+                "short" => "uint16",
+                "long" => {
+                    if self.peek_is(&Token::Ident("long".to_string())) {
+                        self.pos += 1;
+                        "uint64"
+                    } else {
+                        "uint32"
+                    }
+                }
+                other => bail!("unsupported type unsigned {other}"),
+            },
+            "int8" | "uint8" | "int16" | "uint16" | "int32" | "uint32" | "int64" | "uint64" => {
+                first.as_str()
+            }
+            "wchar" => bail!("wchar is not supported"),
+            _ => return self.parse_named_type(first),
+        };
+        Ok(self.primitive(primitive))
+    }
+
+    fn primitive(&self, ros_type: &str) -> FieldType {
+        FieldType {
+            package_name: None,
+            source_package: self.package.name.clone(),
+            field_type: ros_type.to_string(),
+            array_info: ArrayType::NotArray,
+            string_capacity: None,
+        }
+    }
+
+    /// Resolves a typedef or a (possibly scoped) reference to another message, e.g. `geometry_msgs::msg::Point`
+    fn parse_named_type(&mut self, first: String) -> Result<FieldType, Error> {
+        let mut scope = vec![first];
+        while self.peek_is(&Token::Scope) {
+            self.pos += 1;
+            scope.push(self.ident()?);
+        }
+        let field_type = scope.pop().unwrap();
+        if scope.is_empty() {
+            if let Some(typedef) = self.typedefs.get(&field_type) {
+                return Ok(typedef.clone());
+            }
+        }
+        Ok(FieldType {
+            // Scoped names are `package::msg::Type`, anything unscoped is relative to this package
+            package_name: Some(
+                scope
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| self.package.name.clone()),
+            ),
+            source_package: self.package.name.clone(),
+            field_type,
+            array_info: ArrayType::NotArray,
+            string_capacity: None,
+        })
+    }
+
+    /// Removes the struct `struct_name` and builds a message named `name` from it
+    fn take_message(
+        &mut self,
+        struct_name: &str,
+        name: &str,
+        data: &str,
+        path: &Path,
+    ) -> Result<ParsedMessageFile, Error> {
+        let fields = self.structs.remove(struct_name).ok_or(Error::new(format!(
+            "Did not find struct {struct_name} in idl file {}",
+            path.display()
+        )))?;
+        Ok(ParsedMessageFile {
+            name: name.to_owned(),
+            package: self.package.name.clone(),
+            fields,
+            constants: self.constants.remove(struct_name).unwrap_or_default(),
+            // idl files are only used by ROS2
+            version: Some(RosVersion::ROS2),
+            source: data.to_owned(),
+            path: path.to_owned(),
+        })
+    }
+}
+
+/// Converts an idl literal into the form it would be written in a ROS2 .msg file
+fn convert_literal(tokens: &[Token], field_type: &FieldType) -> Result<crate::RosLiteral, Error> {
+    let value: String = match tokens {
+        [Token::Ident(b)] if b == "TRUE" => "true".to_string(),
+        [Token::Ident(b)] if b == "FALSE" => "false".to_string(),
+        // Array defaults are given as a string holding a tuple e.g. "(0.0, 1.0)"
+        [Token::Literal(s)] if field_type.array_info != ArrayType::NotArray => {
+            let inner = s[1..s.len() - 1].trim();
+            match inner.strip_prefix('(').and_then(|i| i.strip_suffix(')')) {
+                Some(items) => format!("[{items}]"),
+                None => inner.to_string(),
+            }
+        }
+        [] => bail!("missing value for {field_type}"),
+        tokens => tokens.iter().map(ToString::to_string).collect(),
+    };
+    Ok(value.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::{parse_ros_message_file, parse_ros_service_file};
+    use std::path::PathBuf;
+
+    fn package(name: &str) -> Package {
+        Package {
+            name: name.to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        }
+    }
+
+    // Trimmed down version of what rosidl generates for a message file
+    const IDL_MSG: &str = r#"
+// generated from rosidl_adapter/resource/msg.idl.em
+// with input from test_msgs/msg/Example.msg
+
+#include "builtin_interfaces/msg/Time.idl"
+#include "geometry_msgs/msg/Point.idl"
+
+module test_msgs {
+  module msg {
+    typedef double double__9[9];
+    module Example_Constants {
+      const uint8 MODE_A = 1;
+      @verbatim (language="comment", text=
+        "A constant with a comment" "split over lines")
+      const string NAME = "example";
+    };
+    @verbatim (language="comment", text=
+      "An example message")
+    struct Example {
+      builtin_interfaces::msg::Time stamp;
+
+      @default (value=1.5)
+      double gain;
+
+      @default (value=TRUE)
+      boolean enabled;
+
+      string<10> label;
+
+      sequence<geometry_msgs::msg::Point> points;
+
+      sequence<int32, 5> bounded;
+
+      /* fixed size array through a typedef */
+      double__9 covariance;
+
+      octet data[4];
+
+      @default (value="(1, 2, 3)")
+      sequence<int16> numbers;
+    };
+  };
+};
+"#;
+
+    const EQUIVALENT_MSG: &str = r#"
+uint8 MODE_A=1
+string NAME="example"
+builtin_interfaces/Time stamp
+float64 gain 1.5
+bool enabled true
+string<=10 label
+geometry_msgs/Point[] points
+int32[<=5] bounded
+float64[9] covariance
+byte[4] data
+int16[] numbers [1, 2, 3]
+"#;
+
+    #[test_log::test]
+    fn idl_message_matches_msg() {
+        let pkg = package("test_msgs");
+        let path = PathBuf::from("test_msgs/msg/Example.idl");
+        let ParsedIdlFile::Message(idl) =
+            parse_ros_idl_file(IDL_MSG, "Example", &pkg, &path).unwrap()
+        else {
+            panic!("Expected a message");
+        };
+        let msg = parse_ros_message_file(EQUIVALENT_MSG, "Example", &pkg, &path).unwrap();
+
+        assert_eq!(idl.fields, msg.fields);
+        assert_eq!(idl.constants, msg.constants);
+        for (a, b) in idl.fields.iter().zip(msg.fields.iter()) {
+            assert_eq!(
+                a.default.as_ref().map(ToString::to_string),
+                b.default.as_ref().map(ToString::to_string)
+            );
+        }
+        for (a, b) in idl.constants.iter().zip(msg.constants.iter()) {
+            assert_eq!(a.constant_value.to_string(), b.constant_value.to_string());
+        }
+    }
+
+    #[test_log::test]
+    fn idl_service_matches_srv() {
+        let idl = r#"
+module example_interfaces {
+  module srv {
+    struct AddTwoInts_Request {
+      int64 a;
+      int64 b;
+    };
+    struct AddTwoInts_Response {
+      int64 sum;
+    };
+  };
+};
+"#;
+        let pkg = package("example_interfaces");
+        let path = PathBuf::from("example_interfaces/srv/AddTwoInts.idl");
+        let ParsedIdlFile::Service(idl) =
+            parse_ros_idl_file(idl, "AddTwoInts", &pkg, &path).unwrap()
+        else {
+            panic!("Expected a service");
+        };
+        let srv = parse_ros_service_file(
+            "int64 a\nint64 b\n---\nint64 sum\n",
+            "AddTwoInts",
+            &pkg,
+            &path,
+        )
+        .unwrap();
+        assert_eq!(idl.request_type.name, srv.request_type.name);
+        assert_eq!(idl.request_type.fields, srv.request_type.fields);
+        assert_eq!(idl.response_type.name, srv.response_type.name);
+        assert_eq!(idl.response_type.fields, srv.response_type.fields);
+    }
+
+    #[test_log::test]
+    fn idl_message_hash_matches_rosidl() {
+        let idl = r#"
+module std_msgs {
+  module msg {
+    struct String {
+      string data;
+    };
+  };
+};
+"#;
+        let pkg = package("std_msgs");
+        let path = PathBuf::from("std_msgs/msg/String.idl");
+        let ParsedIdlFile::Message(msg) = parse_ros_idl_file(idl, "String", &pkg, &path).unwrap()
+        else {
+            panic!("Expected a message");
+        };
+        let hash = crate::ros2_hashing::calculate_ros2_hash(&msg, &Default::default());
+        assert_eq!(
+            hash.to_hash_string(),
+            "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18"
+        );
+    }
+
+    #[test_log::test]
+    fn idl_empty_struct_has_no_fields() {
+        let idl = "module std_msgs { module msg { struct Empty { uint8 structure_needs_at_least_one_member; }; }; };";
+        let pkg = package("std_msgs");
+        let path = PathBuf::from("std_msgs/msg/Empty.idl");
+        let ParsedIdlFile::Message(msg) = parse_ros_idl_file(idl, "Empty", &pkg, &path).unwrap()
+        else {
+            panic!("Expected a message");
+        };
+        assert!(msg.fields.is_empty());
+    }
+}
@@ -5,6 +5,8 @@ use std::collections::HashMap;
 
 mod action;
 pub use action::{parse_ros_action_file, ParsedActionFile};
+mod idl;
+pub use idl::{parse_ros_idl_file, ParsedIdlFile};
 mod msg;
 pub use msg::{parse_ros_message_file, ParsedMessageFile};
 mod srv;
@@ -33,6 +35,7 @@ lazy_static::lazy_static! {
         ("float32", "f32"),
         ("float64", "f64"),
         ("string", "::std::string::String"),
+        ("wstring", "::roslibrust::codegen::integral_types::WString"),
         ("time", "::roslibrust::codegen::integral_types::Time"),
         ("duration", "::roslibrust::codegen::integral_types::Duration"),
     ].into_iter().collect();
@@ -54,7 +57,7 @@ lazy_static::lazy_static! {
         ("string", "::std::string::String"),
         ("builtin_interfaces/Time", "::roslibrust::codegen::integral_types::Time"),
         ("builtin_interfaces/Duration", "::roslibrust::codegen::integral_types::Duration"),
-        // ("wstring", TODO),
+        ("wstring", "::roslibrust::codegen::integral_types::WString"),
     ].into_iter().collect();
 }
 
@@ -190,20 +193,22 @@ fn parse_field_type(
     }
 }
 
-/// Specifically handles bounded string types, e.g. "string<=10"
+/// Specifically handles bounded string types, e.g. "string<=10" or "wstring<=10"
 /// Returns the field_type and the string_capacity if it is a bounded string
 /// Otherwise returns the original type and None for the capacity
 fn parse_bounded_string(type_str: &str) -> Result<(String, Option<usize>), Error> {
-    if let Some(stripped) = type_str.strip_prefix("string<=") {
-        let capacity = stripped.parse::<usize>().map_err(|err| {
-            Error::new(format!(
-                "Unable to parse capacity of bounded string: {type_str}: {err}"
-            ))
-        })?;
-        Ok(("string".to_string(), Some(capacity)))
-    } else {
-        Ok((type_str.to_string(), None))
+    let Some((string_type, stripped)) = type_str.split_once("<=") else {
+        return Ok((type_str.to_string(), None));
+    };
+    if string_type != "string" && string_type != "wstring" {
+        return Ok((type_str.to_string(), None));
     }
+    let capacity = stripped.parse::<usize>().map_err(|err| {
+        Error::new(format!(
+            "Unable to parse capacity of bounded string: {type_str}: {err}"
+        ))
+    })?;
+    Ok((string_type.to_string(), Some(capacity)))
 }
 
 /// Determines the type of a field
@@ -288,6 +293,21 @@ mod test {
         assert_eq!(parsed.array_info, ArrayType::Bounded(9));
     }
 
+    #[test_log::test]
+    fn parse_type_handles_bounded_wstring() {
+        let line = "wstring<=5[3]";
+        let pkg = Package {
+            name: "test_pkg".to_string(),
+            path: "./not_a_path".into(),
+            version: Some(RosVersion::ROS2),
+        };
+        let parsed = parse_type(line, &pkg).unwrap();
+        assert_eq!(parsed.field_type, "wstring");
+        assert_eq!(parsed.string_capacity, Some(5));
+        assert_eq!(parsed.array_info, ArrayType::FixedLength(3));
+        assert_eq!(parsed.package_name, None);
+    }
+
     #[test_log::test]
     fn parse_type_handles_unbounded_size_correctly() {
         let line = "int32[]";
@@ -1,6 +1,6 @@
 use crate::parse::{parse_constant_field, parse_field, strip_comments};
 use crate::Error;
-use crate::{ConstantInfo, FieldInfo, Package, RosVersion};
+use crate::{ArrayType, ConstantInfo, FieldInfo, Package, RosVersion};
 use std::path::{Path, PathBuf};
 
 /// Describes all information for a single message file available in the file without other context
@@ -28,6 +28,16 @@ impl ParsedMessageFile {
         })
     }
 
+    /// True iff the first field of this message is a std_msgs/Header, the convention for "stamped" messages
+    pub fn first_field_is_header(&self) -> bool {
+        self.fields.first().is_some_and(|field| {
+            field.field_type.field_type.as_str() == "Header"
+                && field.field_type.array_info == ArrayType::NotArray
+                && (field.field_type.package_name.is_none()
+                    || field.field_type.package_name == Some(String::from("std_msgs")))
+        })
+    }
+
     /// Returns full ros1 name of the message e.g. std_msgs/String
     pub fn get_full_name(&self) -> String {
         format!("{}/{}", self.package, self.name)
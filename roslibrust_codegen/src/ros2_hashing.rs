@@ -15,7 +15,7 @@ use crate::{
     ArrayType, FieldInfo, MessageFile, Ros2Hash,
 };
 
-/// The following structs define the format of the JSON file used for hashing in ROS2
+/// The structs of roslibrust_common::type_description define the format of the JSON file used for hashing in ROS2
 /// A quick description of the process to calculate a ROS2 hash:
 ///  - ROS2 parses the .msg / .srv files
 ///  - Covert them to the below struct formats
@@ -23,59 +23,7 @@ use crate::{
 ///  - Calculate the sha256 hash of the JSON string (utf-8)
 ///  - Generate a string of the format RIHS01_<hex hash>
 ///
-#[allow(unused)]
-#[derive(serde::Deserialize, serde::Serialize)]
-pub struct TypeDescriptionFile {
-    type_description_msg: TypeDescriptionMsg,
-    type_hashes: Vec<TypeHash>,
-}
-
-/// Sub-component of the ROS2 JSON file format for hashing
-/// Should not be used for other purposes
-#[derive(serde::Deserialize, serde::Serialize)]
-pub struct TypeDescriptionMsg {
-    type_description: TypeDescription,
-    referenced_type_descriptions: Vec<TypeDescription>,
-}
-
-/// Sub-component of the ROS2 JSON file format for hasing
-/// Should not be used for other purposes
-#[derive(serde::Deserialize, serde::Serialize)]
-pub struct TypeDescription {
-    type_name: String,
-    fields: Vec<Field>,
-}
-
-/// Sub-component of the ROS2 JSON file format for hashing
-/// Should not be used for other purposes
-#[derive(serde::Deserialize, serde::Serialize)]
-pub struct Field {
-    name: String,
-    #[serde(rename = "type")]
-    field_type: FieldType,
-    // Value is not used in hashing, included for completeness
-    #[serde(skip)]
-    _default_value: String,
-}
-
-/// Sub-component of the ROS2 JSON file format for hashing
-/// Should not be used for other purposes
-#[derive(serde::Deserialize, serde::Serialize)]
-pub struct FieldType {
-    type_id: u8,
-    capacity: u32,
-    string_capacity: u32,
-    nested_type_name: String,
-}
-
-/// Sub-component of the ROS2 JSON file format for hashing
-/// Should not be used for other purposes
-#[allow(unused)]
-#[derive(serde::Deserialize, serde::Serialize)]
-pub struct TypeHash {
-    type_name: String,
-    hash_string: String,
-}
+use roslibrust_common::type_description::{Field, FieldType, TypeDescription, TypeDescriptionMsg};
 
 /// Calculates the ROS2 hash of a message definition, if possible
 /// Returns None if the message definition is not hashable (e.g. contains nested types not present in the graph)
@@ -218,6 +166,8 @@ pub fn calculate_ros2_srv_hash(
             md5sum: "".to_string(),
             definition: "".to_string(),
             is_fixed_encoding_length: true,
+            supports_wire_codec: true,
+            has_borrowed_fields: true,
         },
     );
     graph_copy.insert(
@@ -229,6 +179,8 @@ pub fn calculate_ros2_srv_hash(
             md5sum: "".to_string(),
             definition: "".to_string(),
             is_fixed_encoding_length: true,
+            supports_wire_codec: true,
+            has_borrowed_fields: true,
         },
     );
     graph_copy.insert(
@@ -240,6 +192,8 @@ pub fn calculate_ros2_srv_hash(
             md5sum: "".to_string(),
             definition: "".to_string(),
             is_fixed_encoding_length: true,
+            supports_wire_codec: true,
+            has_borrowed_fields: true,
         },
     );
 
@@ -280,7 +234,7 @@ fn convert_to_type_description(
                 string_capacity: 0,
                 nested_type_name: "".to_string(),
             },
-            _default_value: "".to_string(),
+            default_value: "".to_string(),
         });
     }
     for field in &parsed.fields {
@@ -320,7 +274,7 @@ fn convert_to_type_description(
                 nested_type_name,
             },
             // Default value is not used in hashing, but included for completeness
-            _default_value: "".to_string(),
+            default_value: "".to_string(),
         });
 
         if !field.field_type.is_primitive() {
@@ -554,8 +508,8 @@ lazy_static::lazy_static! {
         map.insert("bool", "BOOLEAN");
         map.insert("octet", "BYTE");
         map.insert("string", "STRING");
-        // TODO following likely don't work yet
         map.insert("wstring", "WSTRING");
+        // TODO following likely don't work yet
         map.insert("bounded_string", "BOUNDED_STRING");
         map.insert("bounded_wstring", "BOUNDED_WSTRING");
         map
@@ -636,8 +590,9 @@ mod tests {
             ),
         ];
         for (test_file, expected_hash) in test_data {
-            let parsed: super::TypeDescriptionFile = serde_json::from_str(test_file)
-                .unwrap_or_else(|_| panic!("Failed to parse test file {test_file}"));
+            let parsed: roslibrust_common::type_description::TypeDescriptionFile =
+                serde_json::from_str(test_file)
+                    .unwrap_or_else(|_| panic!("Failed to parse test file {test_file}"));
             let hash = parsed.type_hashes[0].hash_string.clone();
 
             assert_eq!(hash, expected_hash,);
@@ -679,6 +634,15 @@ mod tests {
         field.field_type = "bool".to_string();
         field.array_info = ArrayType::NotArray;
         assert_eq!(super::get_field_type_id(&field).unwrap(), 15);
+
+        field.field_type = "wstring".to_string();
+        assert_eq!(super::get_field_type_id(&field).unwrap(), 18);
+
+        field.string_capacity = Some(10);
+        assert_eq!(super::get_field_type_id(&field).unwrap(), 22);
+
+        field.array_info = ArrayType::Unbounded;
+        assert_eq!(super::get_field_type_id(&field).unwrap(), 166);
     }
 
     /// End-To-End test from parse -> hash
@@ -716,8 +680,9 @@ mod tests {
         )];
 
         for (test_file, expected_hash) in test_data {
-            let parsed: super::TypeDescriptionFile = serde_json::from_str(test_file)
-                .unwrap_or_else(|_| panic!("Failed to parse test file {test_file}"));
+            let parsed: roslibrust_common::type_description::TypeDescriptionFile =
+                serde_json::from_str(test_file)
+                    .unwrap_or_else(|_| panic!("Failed to parse test file {test_file}"));
             let hash = parsed.type_hashes[0].hash_string.clone();
 
             assert_eq!(hash, expected_hash,);
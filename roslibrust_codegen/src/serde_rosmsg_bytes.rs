@@ -52,3 +52,29 @@ where
         serde_bytes::deserialize(deserializer)
     }
 }
+
+/// The same encoding as the parent module, for `BoundedVec<u8, N>` fields generated from ROS2 bounded byte sequences
+pub mod bounded {
+    use crate::BoundedVec;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S, const N: usize>(
+        bytes: &BoundedVec<u8, N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize(bytes.as_vec(), serializer)
+    }
+
+    pub fn deserialize<'de, D, const N: usize>(
+        deserializer: D,
+    ) -> Result<BoundedVec<u8, N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = super::deserialize(deserializer)?;
+        BoundedVec::try_from(bytes).map_err(serde::de::Error::custom)
+    }
+}
@@ -129,11 +129,22 @@ pub fn packages_from_path(mut path: PathBuf, depth: u16) -> io::Result<Vec<Packa
 }
 
 pub fn get_message_files(pkg: &Package) -> io::Result<Vec<PathBuf>> {
-    Ok(message_files_from_path(pkg.path.as_path(), "msg")?
+    let files: Vec<PathBuf> = message_files_from_path(pkg.path.as_path(), "msg")?
         .into_iter()
         .chain(message_files_from_path(pkg.path.as_path(), "srv")?)
         .chain(message_files_from_path(pkg.path.as_path(), "action")?)
-        .collect())
+        .collect();
+    // Installed ROS2 packages ship an .idl file generated from every .msg/.srv/.action file,
+    // .idl files are only used for interfaces that aren't also defined in one of the other formats
+    let idl_files = message_files_from_path(pkg.path.as_path(), "idl")?
+        .into_iter()
+        .filter(|idl| {
+            !files
+                .iter()
+                .any(|file| file.with_extension("") == idl.with_extension(""))
+        })
+        .collect::<Vec<_>>();
+    Ok(files.into_iter().chain(idl_files).collect())
 }
 
 fn message_files_from_path(path: &Path, ext: &str) -> io::Result<Vec<PathBuf>> {
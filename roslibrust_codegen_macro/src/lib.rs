@@ -4,9 +4,12 @@ use syn::{parse_macro_input, Token};
 
 struct RosLibRustMessagePaths {
     paths: Vec<std::path::PathBuf>,
+    // Root types to generate, everything is generated when empty
+    types: Vec<String>,
 }
 
 /// Parses a comma-separated list of str literals specifying paths.
+/// Optionally followed by `types = ["pkg/Type", ...]` to only generate those types and their dependencies.
 impl Parse for RosLibRustMessagePaths {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut paths = vec![];
@@ -18,7 +21,98 @@ impl Parse for RosLibRustMessagePaths {
                 break;
             }
         }
-        Ok(Self { paths })
+        let mut types = vec![];
+        if input.peek(syn::Ident) {
+            let ident = input.parse::<syn::Ident>()?;
+            if ident != "types" {
+                return Err(syn::Error::new(ident.span(), "expected `types = [...]`"));
+            }
+            input.parse::<Token![=]>()?;
+            let list;
+            syn::bracketed!(list in input);
+            types = list
+                .parse_terminated::<syn::LitStr, Token![,]>(|input| input.parse())?
+                .into_iter()
+                .map(|ty| ty.value())
+                .collect();
+            let _ = input.parse::<Token![,]>();
+        }
+        Ok(Self { paths, types })
+    }
+}
+
+/// Generates messages from `paths`, restricted to `types` when any are given
+fn generate(
+    paths: Vec<std::path::PathBuf>,
+    types: &[String],
+) -> Result<proc_macro2::TokenStream, roslibrust_codegen::SimpleError> {
+    // Note: there is not currently a way for proc_macros to indicate that they need to be re-generated
+    // We discard the "dependent_paths" part of the response here...
+    let (source, _dependent_paths) = if types.is_empty() {
+        roslibrust_codegen::find_and_generate_ros_messages_without_ros_package_path(paths)?
+    } else {
+        roslibrust_codegen::find_and_generate_ros_messages_for_types(
+            paths,
+            types,
+            &roslibrust_codegen::CodegenOptions::default(),
+        )?
+    };
+    Ok(source)
+}
+
+struct RosLibRustConversionPaths {
+    ros1_module: syn::Path,
+    ros1_paths: RosLibRustMessagePaths,
+    ros2_module: syn::Path,
+    ros2_paths: RosLibRustMessagePaths,
+    // Whether to generate the conversions for ROS1 time / duration
+    time_conversions: bool,
+    // Whether messages which can't be converted are a compile error
+    strict: bool,
+}
+
+/// Parses `ros1_module = ["paths", ...], ros2_module = ["paths", ...]`
+/// Optionally followed by `time_conversions = false` and / or `strict = true`.
+impl Parse for RosLibRustConversionPaths {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let parse_module =
+            |input: ParseStream| -> syn::Result<(syn::Path, RosLibRustMessagePaths)> {
+                let module = input.parse::<syn::Path>()?;
+                input.parse::<Token![=]>()?;
+                let paths;
+                syn::bracketed!(paths in input);
+                Ok((module, paths.parse()?))
+            };
+        let (ros1_module, ros1_paths) = parse_module(input)?;
+        input.parse::<Token![,]>()?;
+        let (ros2_module, ros2_paths) = parse_module(input)?;
+        let _ = input.parse::<Token![,]>();
+        let mut time_conversions = true;
+        let mut strict = false;
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            input.parse::<Token![=]>()?;
+            let value = input.parse::<syn::LitBool>()?.value;
+            if ident == "time_conversions" {
+                time_conversions = value;
+            } else if ident == "strict" {
+                strict = value;
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `time_conversions = <bool>` or `strict = <bool>`",
+                ));
+            }
+            let _ = input.parse::<Token![,]>();
+        }
+        Ok(Self {
+            ros1_module,
+            ros1_paths,
+            ros2_module,
+            ros2_paths,
+            time_conversions,
+            strict,
+        })
     }
 }
 
@@ -28,14 +122,24 @@ impl Parse for RosLibRustMessagePaths {
 ///
 /// In addition to provided paths, this will search paths found in the environment
 /// variable ROS_PACKAGE_PATH.
+///
+/// By default every message found is generated, to only generate some types and what they depend on
+/// list them after the paths:
+///
+/// ```ignore
+/// roslibrust_codegen_macro::find_and_generate_ros_messages!(
+///     "assets/ros1_common_interfaces",
+///     types = ["sensor_msgs/Image", "std_srvs/SetBool"]
+/// );
+/// ```
 #[proc_macro]
 pub fn find_and_generate_ros_messages(input_stream: TokenStream) -> TokenStream {
-    // Note: there is not currently a way for proc_macros to indicate that they need to be re-generated
-    // We discard the "dependent_paths" part of the response here...
-    let RosLibRustMessagePaths { paths } =
+    let RosLibRustMessagePaths { paths, types } =
         parse_macro_input!(input_stream as RosLibRustMessagePaths);
-    match roslibrust_codegen::find_and_generate_ros_messages(paths) {
-        Ok((source, _dependent_paths)) => source.into(),
+    let mut search_paths = roslibrust_codegen::utils::get_search_paths();
+    search_paths.extend(paths);
+    match generate(search_paths, &types) {
+        Ok(source) => source.into(),
         Err(e) => {
             let error_msg = e.to_string();
             quote::quote!(compile_error!(#error_msg);).into()
@@ -45,16 +149,91 @@ pub fn find_and_generate_ros_messages(input_stream: TokenStream) -> TokenStream
 
 /// Similar to `find_and_generate_ros_messages`, but does not search the
 /// `ROS_PACKAGE_PATH` environment variable paths (useful in some situations).
+/// Also accepts `types = [...]` after the paths.
 #[proc_macro]
 pub fn find_and_generate_ros_messages_without_ros_package_path(
     input_stream: TokenStream,
 ) -> TokenStream {
-    let RosLibRustMessagePaths { paths } =
+    let RosLibRustMessagePaths { paths, types } =
         parse_macro_input!(input_stream as RosLibRustMessagePaths);
-    match roslibrust_codegen::find_and_generate_ros_messages_without_ros_package_path(paths) {
-        // Note: there is not currently a way for proc_macros to indicate that they need to be re-generated
-        // We discard the "dependent_paths" part of the response here...
-        Ok((source, _dependent_paths)) => source.into(),
+    match generate(paths, &types) {
+        Ok(source) => source.into(),
+        Err(e) => {
+            let error_msg = e.to_string();
+            quote::quote!( compile_error!(#error_msg); ).into()
+        }
+    }
+}
+
+/// Generates `From` / `TryFrom` impls between the ROS1 and ROS2 versions of messages found in the given paths.
+/// Each set of paths is preceded by the module its messages were generated into:
+///
+/// ```ignore
+/// roslibrust_codegen_macro::generate_ros1_ros2_conversions!(
+///     crate::ros1 = ["assets/ros1_common_interfaces/std_msgs"],
+///     crate::ros2 = ["assets/ros2_common_interfaces/std_msgs", "assets/ros2_required_msgs/rcl_interfaces/builtin_interfaces"],
+/// );
+/// ```
+///
+/// Messages which don't line up between ROS1 and ROS2 are skipped and listed with the reason in a
+/// `deprecated` warning, which `#[allow(deprecated)]` on the surrounding module silences.
+/// Pass `strict = true` after the paths to make them a compile error instead.
+///
+/// The conversions between ROS1 `time` / `duration` and `builtin_interfaces` are generated by every invocation,
+/// when invoking this more than once for the same ROS2 module pass `time_conversions = false` to all but one.
+/// `ROS_PACKAGE_PATH` is not searched.
+#[proc_macro]
+pub fn generate_ros1_ros2_conversions(input_stream: TokenStream) -> TokenStream {
+    let RosLibRustConversionPaths {
+        ros1_module,
+        ros1_paths,
+        ros2_module,
+        ros2_paths,
+        time_conversions,
+        strict,
+    } = parse_macro_input!(input_stream as RosLibRustConversionPaths);
+    let ros1_module = quote::ToTokens::to_token_stream(&ros1_module).to_string();
+    let ros2_module = quote::ToTokens::to_token_stream(&ros2_module).to_string();
+    match roslibrust_codegen::find_and_generate_ros1_ros2_conversions(
+        ros1_paths.paths,
+        ros2_paths.paths,
+        &ros1_module,
+        &ros2_module,
+        time_conversions,
+    ) {
+        // As with the other macros the dependent paths are discarded
+        Ok((source, _dependent_paths, mismatches)) if mismatches.is_empty() => source.into(),
+        Ok((_source, _dependent_paths, mismatches)) if strict => {
+            let error_msg = format!(
+                "No ROS1 / ROS2 conversion could be generated for:\n{}",
+                mismatches
+                    .iter()
+                    .map(|mismatch| mismatch.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+            quote::quote!( compile_error!(#error_msg); ).into()
+        }
+        Ok((source, _dependent_paths, mismatches)) => {
+            // Proc macros can't emit warnings on stable, using a deprecated item is the closest we get
+            let warning = format!(
+                "no ROS1 / ROS2 conversion was generated for:\n{}",
+                mismatches
+                    .iter()
+                    .map(|mismatch| format!(" - {}", mismatch))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+            quote::quote! {
+                #source
+                const _: () = {
+                    #[deprecated(note = #warning)]
+                    struct SkippedConversions;
+                    let _ = SkippedConversions;
+                };
+            }
+            .into()
+        }
         Err(e) => {
             let error_msg = e.to_string();
             quote::quote!( compile_error!(#error_msg); ).into()
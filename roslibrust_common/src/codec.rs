@@ -0,0 +1,668 @@
+//! Serde-free encoding of messages in the ROS1 and CDR (ROS2) wire formats.
+//!
+//! Going through serde costs a function call per element of a sequence, which dominates the time spent
+//! (de)serializing large messages like images and point clouds. Codegen can instead implement [WireCodec]
+//! for simple messages, in which case the type's [crate::RosMessageType::WIRE_CODEC] is set and backends use it
+//! in place of serde.
+//!
+//! Both formats are little endian and lay fields out in order, so a single [WireCodec] implementation
+//! handles both. The differences (CDR aligns primitives to their size and null terminates strings) are
+//! handled by [WireWriter] and [WireReader].
+
+pub use bytes::{BufMut, Bytes, BytesMut};
+
+/// The encapsulation header at the front of every CDR payload, marking it as little endian plain CDR
+pub const CDR_LE_HEADER: [u8; 4] = [0x00, 0x01, 0x00, 0x00];
+
+/// Errors which can occur while decoding a message with a [WireCodec]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum WireError {
+    #[error("unexpected end of message, needed {needed} bytes but only {remaining} remain")]
+    UnexpectedEof { needed: usize, remaining: usize },
+    #[error("string field is not valid utf-8: {0}")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+    #[error(
+        "unsupported CDR encapsulation header {0:?}, only little endian plain CDR is supported"
+    )]
+    UnsupportedEncapsulation([u8; 4]),
+}
+
+/// Which wire format a [WireWriter] or [WireReader] is working with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireFormat {
+    /// The format used by TCPROS, and by roslibrust's zenoh backend
+    Ros1,
+    /// Little endian plain CDR, as used by ROS2
+    Cdr,
+}
+
+/// Primitive types which are written to the wire as their little endian bytes
+pub trait WirePrimitive: Copy + Default {
+    /// Size on the wire in bytes, which is also the CDR alignment of the type
+    const SIZE: usize;
+
+    fn write(self, buf: &mut BytesMut);
+
+    /// `data` is guaranteed to be exactly [WirePrimitive::SIZE] bytes long
+    fn read(data: &[u8]) -> Self;
+
+    /// Writes all elements of a slice, overridden for u8 to copy the slice as is
+    fn write_slice(values: &[Self], buf: &mut BytesMut) {
+        buf.reserve(values.len() * Self::SIZE);
+        for value in values {
+            value.write(buf);
+        }
+    }
+
+    /// Fills `out` from `data`, which is guaranteed to be `out.len() * SIZE` bytes long
+    fn read_slice(data: &[u8], out: &mut [Self]) {
+        for (value, bytes) in out.iter_mut().zip(data.chunks_exact(Self::SIZE)) {
+            *value = Self::read(bytes);
+        }
+    }
+}
+
+macro_rules! wire_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl WirePrimitive for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn write(self, buf: &mut BytesMut) {
+                    buf.put_slice(&self.to_le_bytes());
+                }
+
+                fn read(data: &[u8]) -> Self {
+                    <$t>::from_le_bytes(data.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+wire_primitive!(i8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+impl WirePrimitive for u8 {
+    const SIZE: usize = 1;
+
+    fn write(self, buf: &mut BytesMut) {
+        buf.put_u8(self);
+    }
+
+    fn read(data: &[u8]) -> Self {
+        data[0]
+    }
+
+    fn write_slice(values: &[Self], buf: &mut BytesMut) {
+        buf.put_slice(values);
+    }
+
+    fn read_slice(data: &[u8], out: &mut [Self]) {
+        out.copy_from_slice(data);
+    }
+}
+
+impl WirePrimitive for bool {
+    const SIZE: usize = 1;
+
+    fn write(self, buf: &mut BytesMut) {
+        buf.put_u8(self as u8);
+    }
+
+    fn read(data: &[u8]) -> Self {
+        data[0] != 0
+    }
+}
+
+/// Appends a message to a buffer in either wire format
+pub struct WireWriter<'a> {
+    buf: &'a mut BytesMut,
+    format: WireFormat,
+    // Position in buf which CDR alignment is relative to
+    origin: usize,
+}
+
+impl<'a> WireWriter<'a> {
+    /// Creates a writer appending to the end of `buf`
+    pub fn new(buf: &'a mut BytesMut, format: WireFormat) -> Self {
+        let origin = buf.len();
+        Self {
+            buf,
+            format,
+            origin,
+        }
+    }
+
+    pub fn format(&self) -> WireFormat {
+        self.format
+    }
+
+    fn align(&mut self, size: usize) {
+        if self.format == WireFormat::Cdr && size > 1 {
+            let padding = (size - (self.buf.len() - self.origin) % size) % size;
+            self.buf.put_bytes(0, padding);
+        }
+    }
+
+    pub fn put<T: WirePrimitive>(&mut self, value: T) {
+        self.align(T::SIZE);
+        value.write(self.buf);
+    }
+
+    /// Writes the length of a sequence
+    pub fn put_len(&mut self, len: usize) {
+        self.put(len as u32);
+    }
+
+    pub fn put_str(&mut self, value: &str) {
+        match self.format {
+            WireFormat::Ros1 => {
+                self.put_len(value.len());
+                self.buf.put_slice(value.as_bytes());
+            }
+            WireFormat::Cdr => {
+                // Length includes the null terminator
+                self.put_len(value.len() + 1);
+                self.buf.put_slice(value.as_bytes());
+                self.buf.put_u8(0);
+            }
+        }
+    }
+
+    /// Writes the elements of a fixed size array, or of a sequence after its length
+    pub fn put_slice<T: WirePrimitive>(&mut self, values: &[T]) {
+        if !values.is_empty() {
+            self.align(T::SIZE);
+            T::write_slice(values, self.buf);
+        }
+    }
+
+    /// Writes a sequence, its length followed by its elements
+    pub fn put_seq<T: WirePrimitive>(&mut self, values: &[T]) {
+        self.put_len(values.len());
+        self.put_slice(values);
+    }
+
+    /// Writes a sequence of non-primitive elements, e.g. strings or messages
+    pub fn put_seq_with<T>(&mut self, values: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        self.put_len(values.len());
+        for value in values {
+            f(self, value);
+        }
+    }
+}
+
+/// Reads a message in either wire format
+pub struct WireReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    format: WireFormat,
+}
+
+impl<'a> WireReader<'a> {
+    /// Creates a reader starting at the beginning of `data`, which CDR alignment is relative to
+    pub fn new(data: &'a [u8], format: WireFormat) -> Self {
+        Self {
+            data,
+            pos: 0,
+            format,
+        }
+    }
+
+    pub fn format(&self) -> WireFormat {
+        self.format
+    }
+
+    /// Number of bytes which haven't been read yet
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], WireError> {
+        if len > self.remaining() {
+            return Err(WireError::UnexpectedEof {
+                needed: len,
+                remaining: self.remaining(),
+            });
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn align(&mut self, size: usize) -> Result<(), WireError> {
+        if self.format == WireFormat::Cdr && size > 1 {
+            self.take((size - self.pos % size) % size)?;
+        }
+        Ok(())
+    }
+
+    pub fn get<T: WirePrimitive>(&mut self) -> Result<T, WireError> {
+        self.align(T::SIZE)?;
+        Ok(T::read(self.take(T::SIZE)?))
+    }
+
+    /// Reads the length of a sequence
+    pub fn get_len(&mut self) -> Result<usize, WireError> {
+        Ok(self.get::<u32>()? as usize)
+    }
+
+    pub fn get_string(&mut self) -> Result<String, WireError> {
+        Ok(self.get_str()?.to_owned())
+    }
+
+    /// Reads a string without copying it out of the message
+    pub fn get_str(&mut self) -> Result<&'a str, WireError> {
+        let len = self.get_len()?;
+        let mut bytes = self.take(len)?;
+        if self.format == WireFormat::Cdr {
+            if let [rest @ .., 0] = bytes {
+                bytes = rest;
+            }
+        }
+        Ok(std::str::from_utf8(bytes)?)
+    }
+
+    /// Reads a sequence of bytes without copying it out of the message
+    pub fn get_byte_seq(&mut self) -> Result<&'a [u8], WireError> {
+        let len = self.get_len()?;
+        self.take(len)
+    }
+
+    fn get_slice<T: WirePrimitive>(&mut self, out: &mut [T]) -> Result<(), WireError> {
+        if !out.is_empty() {
+            self.align(T::SIZE)?;
+            let bytes = self.take(out.len() * T::SIZE)?;
+            T::read_slice(bytes, out);
+        }
+        Ok(())
+    }
+
+    /// Reads a fixed size array of primitives
+    pub fn get_array<T: WirePrimitive, const N: usize>(&mut self) -> Result<[T; N], WireError> {
+        let mut out = [T::default(); N];
+        self.get_slice(&mut out)?;
+        Ok(out)
+    }
+
+    /// Reads a fixed size array of non-primitive elements, e.g. strings or messages
+    pub fn get_array_with<T, const N: usize>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> Result<T, WireError>,
+    ) -> Result<[T; N], WireError> {
+        let mut items = Vec::with_capacity(N);
+        for _ in 0..N {
+            items.push(f(self)?);
+        }
+        // We pushed exactly N items so this can't fail
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("array has exactly N elements")))
+    }
+
+    /// Reads a sequence of primitives, its length followed by its elements
+    pub fn get_seq<T: WirePrimitive>(&mut self) -> Result<Vec<T>, WireError> {
+        let len = self.get_len()?;
+        // Check the length before allocating, so a corrupt length can't trigger a huge allocation
+        let needed = len.saturating_mul(T::SIZE);
+        if needed > self.remaining() {
+            return Err(WireError::UnexpectedEof {
+                needed,
+                remaining: self.remaining(),
+            });
+        }
+        let mut out = vec![T::default(); len];
+        self.get_slice(&mut out)?;
+        Ok(out)
+    }
+
+    /// Reads a sequence of non-primitive elements, e.g. strings or messages
+    pub fn get_seq_with<T>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> Result<T, WireError>,
+    ) -> Result<Vec<T>, WireError> {
+        let len = self.get_len()?;
+        // Every element takes at least one byte, which bounds the allocation for corrupt lengths
+        let mut items = Vec::with_capacity(len.min(self.remaining()));
+        for _ in 0..len {
+            items.push(f(self)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Implemented by codegen for messages which can be encoded without serde
+///
+/// Implementations only describe the fields in order, framing of whole messages (the CDR
+/// encapsulation header) is handled by [WireCodecFns].
+pub trait WireCodec: Sized {
+    fn encode(&self, writer: &mut WireWriter<'_>);
+    fn decode(reader: &mut WireReader<'_>) -> Result<Self, WireError>;
+}
+
+/// Type erased handle to a [WireCodec] implementation, see [crate::RosMessageType::WIRE_CODEC]
+///
+/// Backends are generic over [crate::RosMessageType] and can't require [WireCodec], so they check for this instead.
+pub struct WireCodecFns<T> {
+    encode: fn(&T, &mut WireWriter<'_>),
+    decode: fn(&mut WireReader<'_>) -> Result<T, WireError>,
+}
+
+impl<T> Clone for WireCodecFns<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WireCodecFns<T> {}
+
+impl<T: WireCodec> WireCodecFns<T> {
+    pub const fn of() -> Self {
+        Self {
+            encode: T::encode,
+            decode: T::decode,
+        }
+    }
+}
+
+impl<T> WireCodecFns<T> {
+    /// Appends the ROS1 serialization of `msg` to `buf`
+    ///
+    /// Note: unlike `roslibrust_serde_rosmsg::to_vec` this doesn't write the 4 byte length prefix TCPROS uses
+    pub fn encode_ros1_into(&self, msg: &T, buf: &mut BytesMut) {
+        (self.encode)(msg, &mut WireWriter::new(buf, WireFormat::Ros1));
+    }
+
+    /// Decodes a ROS1 serialized message, `data` must not include the TCPROS length prefix
+    pub fn decode_ros1(&self, data: &[u8]) -> Result<T, WireError> {
+        (self.decode)(&mut WireReader::new(data, WireFormat::Ros1))
+    }
+
+    /// Appends the CDR serialization of `msg`, including the encapsulation header, to `buf`
+    pub fn encode_cdr_into(&self, msg: &T, buf: &mut BytesMut) {
+        buf.put_slice(&CDR_LE_HEADER);
+        (self.encode)(msg, &mut WireWriter::new(buf, WireFormat::Cdr));
+    }
+
+    /// Decodes a CDR serialized message starting with its encapsulation header
+    pub fn decode_cdr(&self, data: &[u8]) -> Result<T, WireError> {
+        let header = data.get(..4).ok_or(WireError::UnexpectedEof {
+            needed: 4,
+            remaining: data.len(),
+        })?;
+        if header != CDR_LE_HEADER {
+            return Err(WireError::UnsupportedEncapsulation(
+                header.try_into().unwrap(),
+            ));
+        }
+        (self.decode)(&mut WireReader::new(&data[4..], WireFormat::Cdr))
+    }
+}
+
+/// Implemented by codegen for the borrowed variant of a message, e.g. `ImageRef<'a>` for `Image`
+///
+/// Strings and byte sequences of the borrowed variant point into the buffer the message was decoded from.
+/// Messages without any such fields are their own borrowed variant.
+pub trait WireDecodeBorrowed<'a>: Sized {
+    /// The message type this is a borrowed variant of
+    type Owned;
+
+    fn decode_borrowed(reader: &mut WireReader<'a>) -> Result<Self, WireError>;
+
+    /// Copies the message out of the buffer it borrows from
+    fn to_owned_message(&self) -> Self::Owned;
+}
+
+/// Implemented by codegen for messages with a borrowed variant, see [WireDecodeBorrowed]
+pub trait HasBorrowed: crate::RosMessageType {
+    type Borrowed<'a>: WireDecodeBorrowed<'a, Owned = Self>;
+
+    /// Shortens the lifetime of a borrowed variant, codegen implements this as the identity function
+    ///
+    /// This is how [BorrowedMessage] hands out the message it decoded without decoding it again,
+    /// it only compiles for borrowed variants which are covariant over their lifetime.
+    fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b>;
+}
+
+/// A received message decoded into its borrowed variant, which points into the buffer the message arrived in
+///
+/// The message is decoded once when this is created, holding on to this keeps the buffer alive but
+/// nothing is copied out of it.
+pub struct BorrowedMessage<T: HasBorrowed> {
+    inner: std::sync::Arc<BorrowedMessageInner<T>>,
+    format: WireFormat,
+}
+
+#[ouroboros::self_referencing]
+struct BorrowedMessageInner<T: HasBorrowed + 'static> {
+    data: Bytes,
+    #[borrows(data)]
+    #[not_covariant]
+    message: T::Borrowed<'this>,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T: HasBorrowed> Clone for BorrowedMessage<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            format: self.format,
+        }
+    }
+}
+
+impl<T: HasBorrowed> std::fmt::Debug for BorrowedMessage<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BorrowedMessage")
+            .field("len", &self.as_bytes().len())
+            .field("format", &self.format)
+            .finish()
+    }
+}
+
+impl<T: HasBorrowed> BorrowedMessage<T> {
+    /// Decodes a ROS1 serialized message, which must not include the TCPROS length prefix
+    pub fn from_ros1(data: Bytes) -> Result<Self, WireError> {
+        Self::new(data, WireFormat::Ros1)
+    }
+
+    /// Decodes a CDR serialized message starting with its encapsulation header
+    pub fn from_cdr(data: Bytes) -> Result<Self, WireError> {
+        let header = data.get(..4).ok_or(WireError::UnexpectedEof {
+            needed: 4,
+            remaining: data.len(),
+        })?;
+        if header != CDR_LE_HEADER {
+            return Err(WireError::UnsupportedEncapsulation(
+                header.try_into().unwrap(),
+            ));
+        }
+        Self::new(data.slice(4..), WireFormat::Cdr)
+    }
+
+    fn new(data: Bytes, format: WireFormat) -> Result<Self, WireError> {
+        let inner = BorrowedMessageInner::try_new(
+            data,
+            |data| T::Borrowed::decode_borrowed(&mut WireReader::new(data, format)),
+            std::marker::PhantomData,
+        )?;
+        Ok(Self {
+            inner: std::sync::Arc::new(inner),
+            format,
+        })
+    }
+
+    /// The borrowed variant of the message, its strings and byte sequences point into the received buffer
+    pub fn get(&self) -> &T::Borrowed<'_> {
+        self.inner.with_message(T::shorten_borrowed)
+    }
+
+    pub fn to_owned_message(&self) -> T {
+        self.get().to_owned_message()
+    }
+
+    /// The serialized message body, without a TCPROS length prefix or CDR encapsulation header
+    pub fn as_bytes(&self) -> &Bytes {
+        self.inner.borrow_data()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Sample {
+        flag: bool,
+        value: f64,
+        name: String,
+        data: Vec<u8>,
+        points: [i16; 3],
+        names: Vec<String>,
+    }
+
+    impl WireCodec for Sample {
+        fn encode(&self, w: &mut WireWriter<'_>) {
+            w.put(self.flag);
+            w.put(self.value);
+            w.put_str(&self.name);
+            w.put_seq(&self.data);
+            w.put_slice(&self.points);
+            w.put_seq_with(&self.names, |w, x| w.put_str(x));
+        }
+
+        fn decode(r: &mut WireReader<'_>) -> Result<Self, WireError> {
+            Ok(Self {
+                flag: r.get()?,
+                value: r.get()?,
+                name: r.get_string()?,
+                data: r.get_seq()?,
+                points: r.get_array()?,
+                names: r.get_seq_with(|r| r.get_string())?,
+            })
+        }
+    }
+
+    fn sample() -> Sample {
+        Sample {
+            flag: true,
+            value: 1.5,
+            name: "ab".to_string(),
+            data: vec![1, 2, 3],
+            points: [-1, 2, 3],
+            names: vec!["c".to_string()],
+        }
+    }
+
+    #[test]
+    fn ros1_encoding_is_packed() {
+        let codec = WireCodecFns::<Sample>::of();
+        let mut buf = BytesMut::new();
+        codec.encode_ros1_into(&sample(), &mut buf);
+        #[rustfmt::skip]
+        let expected: Vec<u8> = vec![
+            1,
+            0, 0, 0, 0, 0, 0, 0xf8, 0x3f,
+            2, 0, 0, 0, b'a', b'b',
+            3, 0, 0, 0, 1, 2, 3,
+            0xff, 0xff, 2, 0, 3, 0,
+            1, 0, 0, 0, 1, 0, 0, 0, b'c',
+        ];
+        assert_eq!(&buf[..], &expected[..]);
+        assert_eq!(codec.decode_ros1(&buf).unwrap(), sample());
+        assert!(matches!(
+            codec.decode_ros1(&buf[..buf.len() - 1]),
+            Err(WireError::UnexpectedEof { .. })
+        ));
+    }
+
+    #[test]
+    fn cdr_encoding_is_aligned() {
+        let codec = WireCodecFns::<Sample>::of();
+        let mut buf = BytesMut::new();
+        codec.encode_cdr_into(&sample(), &mut buf);
+        #[rustfmt::skip]
+        let expected: Vec<u8> = vec![
+            0, 1, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0xf8, 0x3f,
+            3, 0, 0, 0, b'a', b'b', 0, 0,
+            3, 0, 0, 0, 1, 2, 3, 0,
+            0xff, 0xff, 2, 0, 3, 0, 0, 0,
+            1, 0, 0, 0, 2, 0, 0, 0, b'c', 0,
+        ];
+        assert_eq!(&buf[..], &expected[..]);
+        assert_eq!(codec.decode_cdr(&buf).unwrap(), sample());
+        assert!(matches!(
+            codec.decode_cdr(&[0, 0, 0, 0]),
+            Err(WireError::UnsupportedEncapsulation(_))
+        ));
+    }
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Named {
+        name: String,
+        data: Vec<u8>,
+    }
+
+    impl crate::RosMessageType for Named {
+        const ROS_TYPE_NAME: &'static str = "test_msgs/Named";
+    }
+
+    // Mirrors what codegen generates for a message with borrowed fields
+    #[derive(Debug, Clone, PartialEq)]
+    struct NamedRef<'a> {
+        name: &'a str,
+        data: &'a [u8],
+    }
+
+    impl<'a> WireDecodeBorrowed<'a> for NamedRef<'a> {
+        type Owned = Named;
+
+        fn decode_borrowed(r: &mut WireReader<'a>) -> Result<Self, WireError> {
+            Ok(Self {
+                name: r.get_str()?,
+                data: r.get_byte_seq()?,
+            })
+        }
+
+        fn to_owned_message(&self) -> Named {
+            Named {
+                name: self.name.to_owned(),
+                data: self.data.to_vec(),
+            }
+        }
+    }
+
+    impl HasBorrowed for Named {
+        type Borrowed<'a> = NamedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
+
+    #[test]
+    fn borrowed_message_points_into_its_buffer() {
+        let data = Bytes::from_static(&[2, 0, 0, 0, b'a', b'b', 3, 0, 0, 0, 1, 2, 3]);
+        let msg = BorrowedMessage::<Named>::from_ros1(data.clone()).unwrap();
+        assert_eq!(msg.get().name, "ab");
+        assert_eq!(msg.get().data, &[1, 2, 3]);
+        assert!(std::ptr::eq(msg.get().name.as_ptr(), &data[4]));
+
+        // Clones share the decoded message instead of decoding it again
+        let cloned = msg.clone();
+        assert!(std::ptr::eq(cloned.get(), msg.get()));
+        assert_eq!(
+            cloned.to_owned_message(),
+            Named {
+                name: "ab".to_string(),
+                data: vec![1, 2, 3],
+            }
+        );
+
+        assert!(matches!(
+            BorrowedMessage::<Named>::from_ros1(data.slice(..data.len() - 1)),
+            Err(WireError::UnexpectedEof { .. })
+        ));
+    }
+}
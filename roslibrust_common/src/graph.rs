@@ -0,0 +1,322 @@
+use crate::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+
+/// A snapshot of the topics, services and nodes known to a ROS backend
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    /// Names of all nodes in the graph
+    pub nodes: BTreeSet<String>,
+    /// Topics keyed by name
+    pub topics: BTreeMap<String, TopicInfo>,
+    /// Services keyed by name
+    pub services: BTreeMap<String, ServiceInfo>,
+}
+
+/// Everything known about a topic in a [Graph]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicInfo {
+    /// The type of the topic e.g. `std_msgs/String`, empty if the backend doesn't know it
+    pub type_name: String,
+    /// Names of the nodes publishing the topic
+    pub publishers: BTreeSet<String>,
+    /// Names of the nodes subscribed to the topic
+    pub subscribers: BTreeSet<String>,
+}
+
+/// Everything known about a service in a [Graph]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceInfo {
+    /// The type of the service e.g. `std_srvs/SetBool`, empty if the backend doesn't know it
+    pub type_name: String,
+    /// Names of the nodes providing the service
+    pub providers: BTreeSet<String>,
+}
+
+/// A single change between two [Graph] snapshots, yielded by [GraphProvider::graph_events]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphEvent {
+    NodeAdded(String),
+    NodeRemoved(String),
+    TopicAdded { topic: String, type_name: String },
+    TopicRemoved { topic: String },
+    PublisherAdded { topic: String, node: String },
+    PublisherRemoved { topic: String, node: String },
+    SubscriberAdded { topic: String, node: String },
+    SubscriberRemoved { topic: String, node: String },
+    ServiceAdded { service: String, type_name: String },
+    ServiceRemoved { service: String },
+    ServiceProviderAdded { service: String, node: String },
+    ServiceProviderRemoved { service: String, node: String },
+}
+
+impl Graph {
+    /// Adds `node` as a publisher of `topic`, creating the topic and node as needed
+    pub fn add_publisher(&mut self, topic: &str, type_name: &str, node: &str) {
+        self.topic_entry(topic, type_name)
+            .publishers
+            .insert(node.to_string());
+        self.nodes.insert(node.to_string());
+    }
+
+    /// Adds `node` as a subscriber of `topic`, creating the topic and node as needed
+    pub fn add_subscriber(&mut self, topic: &str, type_name: &str, node: &str) {
+        self.topic_entry(topic, type_name)
+            .subscribers
+            .insert(node.to_string());
+        self.nodes.insert(node.to_string());
+    }
+
+    /// Adds `node` as a provider of `service`, creating the service and node as needed
+    pub fn add_service_provider(&mut self, service: &str, type_name: &str, node: &str) {
+        let entry = self.services.entry(service.to_string()).or_default();
+        if entry.type_name.is_empty() {
+            entry.type_name = type_name.to_string();
+        }
+        entry.providers.insert(node.to_string());
+        self.nodes.insert(node.to_string());
+    }
+
+    fn topic_entry(&mut self, topic: &str, type_name: &str) -> &mut TopicInfo {
+        let entry = self.topics.entry(topic.to_string()).or_default();
+        if entry.type_name.is_empty() {
+            entry.type_name = type_name.to_string();
+        }
+        entry
+    }
+
+    /// Returns the events that turn this graph into `newer`.
+    ///
+    /// Removals come first, then additions. Publishers, subscribers and providers are removed before the
+    /// topics, services and nodes they refer to, and added after them. A topic or service whose type
+    /// changed is reported as removed and then added again.
+    pub fn diff(&self, newer: &Graph) -> Vec<GraphEvent> {
+        let empty_topic = TopicInfo::default();
+        let empty_service = ServiceInfo::default();
+        // Removed users (publishers, subscribers, providers), removed topics and services, removed nodes
+        let mut removed_users = vec![];
+        let mut removed = vec![];
+        // Added nodes, added topics and services, added users
+        let mut added = vec![];
+        let mut added_users = vec![];
+
+        for (topic, old) in &self.topics {
+            let new = match newer.topics.get(topic) {
+                Some(new) if new.type_name == old.type_name => new,
+                _ => {
+                    removed.push(GraphEvent::TopicRemoved {
+                        topic: topic.clone(),
+                    });
+                    &empty_topic
+                }
+            };
+            for node in old.publishers.difference(&new.publishers) {
+                removed_users.push(GraphEvent::PublisherRemoved {
+                    topic: topic.clone(),
+                    node: node.clone(),
+                });
+            }
+            for node in old.subscribers.difference(&new.subscribers) {
+                removed_users.push(GraphEvent::SubscriberRemoved {
+                    topic: topic.clone(),
+                    node: node.clone(),
+                });
+            }
+        }
+        for (service, old) in &self.services {
+            let new = match newer.services.get(service) {
+                Some(new) if new.type_name == old.type_name => new,
+                _ => {
+                    removed.push(GraphEvent::ServiceRemoved {
+                        service: service.clone(),
+                    });
+                    &empty_service
+                }
+            };
+            for node in old.providers.difference(&new.providers) {
+                removed_users.push(GraphEvent::ServiceProviderRemoved {
+                    service: service.clone(),
+                    node: node.clone(),
+                });
+            }
+        }
+        for node in self.nodes.difference(&newer.nodes) {
+            removed.push(GraphEvent::NodeRemoved(node.clone()));
+        }
+
+        for node in newer.nodes.difference(&self.nodes) {
+            added.push(GraphEvent::NodeAdded(node.clone()));
+        }
+        for (topic, new) in &newer.topics {
+            let old = match self.topics.get(topic) {
+                Some(old) if old.type_name == new.type_name => old,
+                _ => {
+                    added.push(GraphEvent::TopicAdded {
+                        topic: topic.clone(),
+                        type_name: new.type_name.clone(),
+                    });
+                    &empty_topic
+                }
+            };
+            for node in new.publishers.difference(&old.publishers) {
+                added_users.push(GraphEvent::PublisherAdded {
+                    topic: topic.clone(),
+                    node: node.clone(),
+                });
+            }
+            for node in new.subscribers.difference(&old.subscribers) {
+                added_users.push(GraphEvent::SubscriberAdded {
+                    topic: topic.clone(),
+                    node: node.clone(),
+                });
+            }
+        }
+        for (service, new) in &newer.services {
+            let old = match self.services.get(service) {
+                Some(old) if old.type_name == new.type_name => old,
+                _ => {
+                    added.push(GraphEvent::ServiceAdded {
+                        service: service.clone(),
+                        type_name: new.type_name.clone(),
+                    });
+                    &empty_service
+                }
+            };
+            for node in new.providers.difference(&old.providers) {
+                added_users.push(GraphEvent::ServiceProviderAdded {
+                    service: service.clone(),
+                    node: node.clone(),
+                });
+            }
+        }
+
+        removed_users.extend(removed);
+        removed_users.extend(added);
+        removed_users.extend(added_users);
+        removed_users
+    }
+}
+
+/// Represents the ability to introspect the ROS graph: which nodes exist, which topics and services they
+/// provide and use, and how that changes over time.
+///
+/// This is implemented by each backend using whatever it has available, e.g. the ROS1 master's
+/// `getSystemState` or the rosapi node when using rosbridge, so what a backend can report varies.
+/// See the documentation of each implementation for its limitations.
+pub trait GraphProvider {
+    /// Returns a snapshot of the current state of the graph
+    fn graph(&self) -> impl Future<Output = Result<Graph>> + Send;
+
+    /// Returns a stream of the changes made to the graph.
+    ///
+    /// The stream starts by reporting everything in the graph as added, so a consumer can build up
+    /// its own view of the graph from the stream alone. Errors fetching the graph are yielded and the stream continues.
+    /// The stream doesn't keep the backend alive, and ends once it is dropped.
+    fn graph_events(&self)
+        -> impl futures_core::Stream<Item = Result<GraphEvent>> + Send + 'static;
+}
+
+/// Helper for implementing [GraphProvider::graph_events] for backends which have to poll for changes.
+///
+/// `fetch` is called to get the latest graph, `wait` is awaited between calls to it and can be a timer
+/// or a notification that the graph has changed. The stream ends when `fetch` returns `None`, which
+/// backends do once the client they fetch the graph through has been dropped.
+pub fn poll_graph_events<F, Fut, W, WFut>(
+    mut fetch: F,
+    mut wait: W,
+) -> impl futures_core::Stream<Item = Result<GraphEvent>> + Send + 'static
+where
+    F: FnMut() -> Option<Fut> + Send + 'static,
+    Fut: Future<Output = Result<Graph>> + Send,
+    W: FnMut() -> WFut + Send + 'static,
+    WFut: Future<Output = ()> + Send,
+{
+    async_stream::stream! {
+        let mut last = Graph::default();
+        while let Some(graph) = fetch() {
+            match graph.await {
+                Ok(graph) => {
+                    for event in last.diff(&graph) {
+                        yield Ok(event);
+                    }
+                    last = graph;
+                }
+                Err(e) => yield Err(e),
+            }
+            wait().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diff_orders_events() {
+        let mut old = Graph::default();
+        old.add_publisher("/chatter", "std_msgs/String", "/talker");
+        old.add_subscriber("/chatter", "std_msgs/String", "/listener");
+        old.add_service_provider("/talker/set_logger_level", "", "/talker");
+
+        let mut new = Graph::default();
+        new.add_subscriber("/chatter", "std_msgs/String", "/listener");
+        new.add_publisher("/odom", "nav_msgs/Odometry", "/robot");
+
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                GraphEvent::PublisherRemoved {
+                    topic: "/chatter".to_string(),
+                    node: "/talker".to_string()
+                },
+                GraphEvent::ServiceProviderRemoved {
+                    service: "/talker/set_logger_level".to_string(),
+                    node: "/talker".to_string()
+                },
+                GraphEvent::ServiceRemoved {
+                    service: "/talker/set_logger_level".to_string()
+                },
+                GraphEvent::NodeRemoved("/talker".to_string()),
+                GraphEvent::NodeAdded("/robot".to_string()),
+                GraphEvent::TopicAdded {
+                    topic: "/odom".to_string(),
+                    type_name: "nav_msgs/Odometry".to_string()
+                },
+                GraphEvent::PublisherAdded {
+                    topic: "/odom".to_string(),
+                    node: "/robot".to_string()
+                },
+            ]
+        );
+        assert!(new.diff(&new).is_empty());
+    }
+
+    #[test]
+    fn type_change_readds_topic() {
+        let mut old = Graph::default();
+        old.add_publisher("/data", "std_msgs/String", "/a");
+        let mut new = Graph::default();
+        new.add_publisher("/data", "std_msgs/Int32", "/a");
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                GraphEvent::PublisherRemoved {
+                    topic: "/data".to_string(),
+                    node: "/a".to_string()
+                },
+                GraphEvent::TopicRemoved {
+                    topic: "/data".to_string()
+                },
+                GraphEvent::TopicAdded {
+                    topic: "/data".to_string(),
+                    type_name: "std_msgs/Int32".to_string()
+                },
+                GraphEvent::PublisherAdded {
+                    topic: "/data".to_string(),
+                    node: "/a".to_string()
+                },
+            ]
+        );
+    }
+}
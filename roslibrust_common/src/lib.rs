@@ -73,6 +73,24 @@ pub mod md5sum;
 pub mod traits;
 pub use traits::*; // Bring topic provider traits into root namespace
 
+/// Introspection of the nodes, topics and services in the ROS graph.
+pub mod graph;
+pub use graph::GraphProvider;
+
+/// Access to the `std_msgs/Header` of stamped messages, and a publisher wrapper which fills it in.
+pub mod stamped;
+pub use stamped::*;
+
+/// Serde-free encoding of messages in the ROS1 and CDR wire formats, used by generated code when enabled.
+pub mod codec;
+
+/// ROS2 type descriptions, and decoding of CDR messages whose type is only known at runtime.
+pub mod type_description;
+
 /// Contains the validation logic for topic, service, and action names.
 pub mod topic_name;
 pub use topic_name::*; // Bring topic name validation into root namespace
+
+/// Bridges the [log](https://docs.rs/log) and [tracing](https://docs.rs/tracing) ecosystems into `/rosout`.
+#[cfg(feature = "rosout")]
+pub mod rosout;
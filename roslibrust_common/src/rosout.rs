@@ -0,0 +1,518 @@
+//! Bridges rust logging into the ROS `/rosout` topic.
+//!
+//! [RosoutLogger] implements [log::Log] and (with the `tracing` feature) [RosoutLayer] implements
+//! a [tracing_subscriber::Layer]. Both convert each record into a [LogEntry] and publish it through
+//! any [TopicProvider] as a message type implementing [RosoutMessage].
+//!
+//! Because ROS1 (`rosgraph_msgs/Log`) and ROS2 (`rcl_interfaces/Log`) use different message types, and those
+//! types are generated by codegen in the user's crate, the conversion is described by the [RosoutMessage] trait:
+//!
+//! ```ignore
+//! impl roslibrust::rosout::RosoutMessage for rosgraph_msgs::Log {
+//!     fn from_entry(entry: &roslibrust::rosout::LogEntry) -> Self {
+//!         let (secs, nsecs) = entry.stamp_secs_nsecs();
+//!         rosgraph_msgs::Log {
+//!             header: std_msgs::Header {
+//!                 stamp: Time { secs, nsecs },
+//!                 ..Default::default()
+//!             },
+//!             level: entry.level.ros1_level(),
+//!             name: entry.node_name.clone(),
+//!             msg: entry.msg.clone(),
+//!             file: entry.file.clone(),
+//!             function: entry.function.clone(),
+//!             line: entry.line,
+//!             topics: vec![],
+//!         }
+//!     }
+//! }
+//!
+//! let (logger, task) = RosoutLogger::new::<rosgraph_msgs::Log, _>(&ros, RosoutOptions::new("/my_node")).await?;
+//! tokio::spawn(task);
+//! logger.install()?;
+//! ```
+//!
+//! The logger never blocks the caller: records are queued to a background task and dropped if the queue is full.
+
+use crate::{Publish, Result, RosMessageType, TopicProvider};
+use futures_channel::mpsc;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// The topic all log messages are published on.
+pub const ROSOUT_TOPIC: &str = "/rosout";
+
+/// Severity of a [LogEntry], mirrors the levels ROS defines for rosout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl LogLevel {
+    /// Value of this level in `rosgraph_msgs/Log` (DEBUG=1, INFO=2, WARN=4, ERROR=8, FATAL=16)
+    pub fn ros1_level(&self) -> u8 {
+        match self {
+            LogLevel::Debug => 1,
+            LogLevel::Info => 2,
+            LogLevel::Warn => 4,
+            LogLevel::Error => 8,
+            LogLevel::Fatal => 16,
+        }
+    }
+
+    /// Value of this level in `rcl_interfaces/Log` (DEBUG=10, INFO=20, WARN=30, ERROR=40, FATAL=50)
+    pub fn ros2_level(&self) -> u8 {
+        match self {
+            LogLevel::Debug => 10,
+            LogLevel::Info => 20,
+            LogLevel::Warn => 30,
+            LogLevel::Error => 40,
+            LogLevel::Fatal => 50,
+        }
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            // ROS has no trace level, so trace is folded into debug
+            log::Level::Trace | log::Level::Debug => LogLevel::Debug,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Error => LogLevel::Error,
+        }
+    }
+}
+
+/// A single log record in a backend neutral form, ready to be converted into a rosout message.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// Wall clock time the record was created
+    pub stamp: SystemTime,
+    pub level: LogLevel,
+    /// Name of the node producing the log, taken from [RosoutOptions::new]
+    pub node_name: String,
+    pub msg: String,
+    /// Source file of the log call, empty if unknown
+    pub file: String,
+    /// Rust has no equivalent of `__FUNCTION__`, the module path of the log call is used instead
+    pub function: String,
+    /// Source line of the log call, 0 if unknown
+    pub line: u32,
+}
+
+impl LogEntry {
+    /// Splits [LogEntry::stamp] into seconds and nanoseconds since the unix epoch as used by ROS time types.
+    pub fn stamp_secs_nsecs(&self) -> (u32, u32) {
+        let since_epoch = self
+            .stamp
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        (since_epoch.as_secs() as u32, since_epoch.subsec_nanos())
+    }
+}
+
+/// Describes how to build a rosout message from a [LogEntry].
+///
+/// Implement this for the generated `rosgraph_msgs::Log` (ROS1) or `rcl_interfaces::Log` (ROS2) type
+/// to use it with [RosoutLogger] or [RosoutLayer].
+pub trait RosoutMessage: RosMessageType {
+    fn from_entry(entry: &LogEntry) -> Self;
+}
+
+/// Configuration for [RosoutLogger] and [RosoutLayer]
+pub struct RosoutOptions {
+    node_name: String,
+    level: log::LevelFilter,
+    max_messages_per_second: Option<u32>,
+    queue_size: usize,
+    forward: Option<Box<dyn log::Log>>,
+}
+
+impl RosoutOptions {
+    /// Creates options with defaults: level Info, no rate limit, queue of 100 records, no forwarding
+    pub fn new<S: Into<String>>(node_name: S) -> RosoutOptions {
+        RosoutOptions {
+            node_name: node_name.into(),
+            level: log::LevelFilter::Info,
+            max_messages_per_second: None,
+            queue_size: 100,
+            forward: None,
+        }
+    }
+
+    /// Records less severe than this level are not published to rosout
+    pub fn level(mut self, level: log::LevelFilter) -> RosoutOptions {
+        self.level = level;
+        self
+    }
+
+    /// Caps the number of messages published to rosout per second, extra records are dropped
+    pub fn max_messages_per_second(mut self, limit: u32) -> RosoutOptions {
+        self.max_messages_per_second = Some(limit);
+        self
+    }
+
+    /// Number of records that can be waiting to be published before new records are dropped
+    pub fn queue_size(mut self, queue_size: usize) -> RosoutOptions {
+        self.queue_size = queue_size;
+        self
+    }
+
+    /// Every record is also passed to this logger (e.g. env_logger) regardless of rosout filtering.
+    /// Only used by [RosoutLogger].
+    pub fn forward_to(mut self, logger: Box<dyn log::Log>) -> RosoutOptions {
+        self.forward = Some(logger);
+        self
+    }
+}
+
+/// Fixed one second window rate limiter
+struct RateLimiter {
+    max_per_window: u32,
+    // (start of the current window, messages sent in the window)
+    window: Mutex<(Instant, u32)>,
+}
+
+impl RateLimiter {
+    fn new(max_per_window: u32) -> Self {
+        Self {
+            max_per_window,
+            window: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    fn allow(&self) -> bool {
+        let mut window = self.window.lock().unwrap();
+        let now = Instant::now();
+        if now.duration_since(window.0) >= Duration::from_secs(1) {
+            *window = (now, 0);
+        }
+        if window.1 < self.max_per_window {
+            window.1 += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Shared front half of the logger and layer, filters records and queues them for the publish task
+#[derive(Clone)]
+struct RosoutSink {
+    node_name: Arc<str>,
+    level: log::LevelFilter,
+    limiter: Option<Arc<RateLimiter>>,
+    // Every clone of a futures sender gets a guaranteed slot in the channel, so a single shared sender is used to
+    // keep the queue bounded
+    sender: Arc<Mutex<mpsc::Sender<LogEntry>>>,
+}
+
+impl RosoutSink {
+    fn enabled(&self, level: log::Level, target: &str) -> bool {
+        // Publishing to rosout goes through roslibrust, which itself logs.
+        // Feeding those records back into rosout could loop forever so they are never published.
+        level <= self.level && !target.starts_with("roslibrust")
+    }
+
+    fn send(&self, entry: LogEntry) {
+        if let Some(limiter) = &self.limiter {
+            if !limiter.allow() {
+                return;
+            }
+        }
+        // Dropping records is preferable to blocking the caller, try_send only fails if full or closed
+        if let Ok(mut sender) = self.sender.lock() {
+            let _ = sender.try_send(entry);
+        }
+    }
+}
+
+/// Creates the sink and the future that drains it into a publisher on [ROSOUT_TOPIC]
+async fn create_sink<MsgType: RosoutMessage, T: TopicProvider>(
+    ros: &T,
+    options: &RosoutOptions,
+) -> Result<(RosoutSink, impl Future<Output = ()> + Send + 'static)> {
+    let publisher = ros.advertise::<MsgType>(ROSOUT_TOPIC).await?;
+    let (sender, mut receiver) = mpsc::channel::<LogEntry>(options.queue_size);
+    let task = async move {
+        use futures_core::Stream;
+        while let Some(entry) =
+            std::future::poll_fn(|cx| std::pin::Pin::new(&mut receiver).poll_next(cx)).await
+        {
+            // Nothing useful can be done with a failed publish here, logging it would just recurse
+            let _ = publisher.publish(&MsgType::from_entry(&entry)).await;
+        }
+    };
+    let sink = RosoutSink {
+        node_name: options.node_name.as_str().into(),
+        level: options.level,
+        limiter: options
+            .max_messages_per_second
+            .map(|limit| Arc::new(RateLimiter::new(limit))),
+        sender: Arc::new(Mutex::new(sender)),
+    };
+    Ok((sink, task))
+}
+
+/// A [log::Log] implementation which publishes records to `/rosout`.
+///
+/// Created with [RosoutLogger::new], which also returns a future that performs the actual publishing.
+/// That future must be spawned on an executor and completes when the logger is dropped.
+pub struct RosoutLogger {
+    sink: RosoutSink,
+    forward: Option<Box<dyn log::Log>>,
+}
+
+impl RosoutLogger {
+    /// Advertises `/rosout` with message type `MsgType` on the provided backend.
+    pub async fn new<MsgType: RosoutMessage, T: TopicProvider>(
+        ros: &T,
+        mut options: RosoutOptions,
+    ) -> Result<(RosoutLogger, impl Future<Output = ()> + Send + 'static)> {
+        let (sink, task) = create_sink::<MsgType, T>(ros, &options).await?;
+        let logger = RosoutLogger {
+            sink,
+            forward: options.forward.take(),
+        };
+        Ok((logger, task))
+    }
+
+    /// Installs this logger as the global logger for the [log] crate.
+    ///
+    /// The max level is set to the more verbose of the rosout level and the forwarded logger's needs,
+    /// so forwarded loggers still filter on their own.
+    pub fn install(self) -> std::result::Result<(), log::SetLoggerError> {
+        let max_level = if self.forward.is_some() {
+            log::LevelFilter::Trace
+        } else {
+            self.sink.level
+        };
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl log::Log for RosoutLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.sink.enabled(metadata.level(), metadata.target())
+            || self
+                .forward
+                .as_ref()
+                .is_some_and(|forward| forward.enabled(metadata))
+    }
+
+    fn log(&self, record: &log::Record) {
+        if let Some(forward) = &self.forward {
+            forward.log(record);
+        }
+        if !self.sink.enabled(record.level(), record.target()) {
+            return;
+        }
+        self.sink.send(LogEntry {
+            stamp: SystemTime::now(),
+            level: record.level().into(),
+            node_name: self.sink.node_name.to_string(),
+            msg: record.args().to_string(),
+            file: record.file().unwrap_or_default().to_string(),
+            function: record.module_path().unwrap_or_default().to_string(),
+            line: record.line().unwrap_or_default(),
+        });
+    }
+
+    fn flush(&self) {
+        if let Some(forward) = &self.forward {
+            forward.flush();
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub use layer::RosoutLayer;
+
+#[cfg(feature = "tracing")]
+mod layer {
+    use super::*;
+    use tracing_core::{field::Visit, Event, Field, Subscriber};
+    use tracing_subscriber::layer::Context;
+
+    /// A [tracing_subscriber::Layer] which publishes events to `/rosout`.
+    ///
+    /// Forwarding to other outputs is done by composing layers, so [RosoutOptions::forward_to] is ignored.
+    pub struct RosoutLayer {
+        sink: RosoutSink,
+    }
+
+    impl RosoutLayer {
+        /// Advertises `/rosout` with message type `MsgType` on the provided backend.
+        ///
+        /// The returned future performs the publishing and must be spawned on an executor.
+        pub async fn new<MsgType: RosoutMessage, T: TopicProvider>(
+            ros: &T,
+            options: RosoutOptions,
+        ) -> Result<(RosoutLayer, impl Future<Output = ()> + Send + 'static)> {
+            let (sink, task) = create_sink::<MsgType, T>(ros, &options).await?;
+            Ok((RosoutLayer { sink }, task))
+        }
+    }
+
+    fn to_log_level(level: &tracing_core::Level) -> log::Level {
+        match *level {
+            tracing_core::Level::TRACE => log::Level::Trace,
+            tracing_core::Level::DEBUG => log::Level::Debug,
+            tracing_core::Level::INFO => log::Level::Info,
+            tracing_core::Level::WARN => log::Level::Warn,
+            _ => log::Level::Error,
+        }
+    }
+
+    /// Collects the `message` field of an event, and appends any other fields as `key=value`
+    #[derive(Default)]
+    struct MessageVisitor {
+        message: String,
+        fields: String,
+    }
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.message = format!("{value:?}");
+            } else {
+                self.fields
+                    .push_str(&format!(" {}={value:?}", field.name()));
+            }
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message = value.to_string();
+            } else {
+                self.record_debug(field, &value);
+            }
+        }
+    }
+
+    impl<S: Subscriber> tracing_subscriber::Layer<S> for RosoutLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+            let level = to_log_level(metadata.level());
+            if !self.sink.enabled(level, metadata.target()) {
+                return;
+            }
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+            self.sink.send(LogEntry {
+                stamp: SystemTime::now(),
+                level: level.into(),
+                node_name: self.sink.node_name.to_string(),
+                msg: visitor.message + &visitor.fields,
+                file: metadata.file().unwrap_or_default().to_string(),
+                function: metadata.module_path().unwrap_or_default().to_string(),
+                line: metadata.line().unwrap_or_default(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Takes a queued entry without waiting, `None` when the queue is empty
+    fn try_recv(receiver: &mut mpsc::Receiver<LogEntry>) -> Option<LogEntry> {
+        use futures_core::Stream;
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        match std::pin::Pin::new(receiver).poll_next(&mut cx) {
+            std::task::Poll::Ready(entry) => entry,
+            std::task::Poll::Pending => None,
+        }
+    }
+
+    #[test]
+    fn level_mapping_matches_ros() {
+        assert_eq!(LogLevel::from(log::Level::Trace).ros1_level(), 1);
+        assert_eq!(LogLevel::from(log::Level::Warn).ros1_level(), 4);
+        assert_eq!(LogLevel::from(log::Level::Error).ros2_level(), 40);
+        assert_eq!(LogLevel::Fatal.ros2_level(), 50);
+    }
+
+    #[test]
+    fn rate_limiter_caps_window() {
+        let limiter = RateLimiter::new(3);
+        assert!(limiter.allow());
+        assert!(limiter.allow());
+        assert!(limiter.allow());
+        assert!(!limiter.allow());
+    }
+
+    #[test]
+    fn sink_filters_level_and_internal_targets() {
+        let (sender, mut receiver) = mpsc::channel(10);
+        let sink = RosoutSink {
+            node_name: "/test_node".into(),
+            level: log::LevelFilter::Info,
+            limiter: Some(Arc::new(RateLimiter::new(1))),
+            sender: Arc::new(Mutex::new(sender)),
+        };
+        assert!(sink.enabled(log::Level::Warn, "my_crate"));
+        assert!(!sink.enabled(log::Level::Debug, "my_crate"));
+        assert!(!sink.enabled(log::Level::Error, "roslibrust_ros1::publisher"));
+
+        let entry = LogEntry {
+            stamp: SystemTime::UNIX_EPOCH + Duration::new(5, 7),
+            level: LogLevel::Info,
+            node_name: "/test_node".to_string(),
+            msg: "hello".to_string(),
+            file: "main.rs".to_string(),
+            function: "my_crate".to_string(),
+            line: 1,
+        };
+        assert_eq!(entry.stamp_secs_nsecs(), (5, 7));
+        sink.send(entry.clone());
+        // Second message is over the rate limit and dropped
+        sink.send(entry);
+        assert_eq!(try_recv(&mut receiver).unwrap().msg, "hello");
+        assert!(try_recv(&mut receiver).is_none());
+    }
+
+    #[test]
+    fn sink_drops_records_when_queue_is_full() {
+        let (sender, mut receiver) = mpsc::channel(2);
+        let sink = RosoutSink {
+            node_name: "/test_node".into(),
+            level: log::LevelFilter::Info,
+            limiter: None,
+            sender: Arc::new(Mutex::new(sender)),
+        };
+        // Clones share the sender, so they don't add capacity
+        let clone = sink.clone();
+        for i in 0..10 {
+            let entry = LogEntry {
+                stamp: SystemTime::UNIX_EPOCH,
+                level: LogLevel::Info,
+                node_name: "/test_node".to_string(),
+                msg: i.to_string(),
+                file: "main.rs".to_string(),
+                function: "my_crate".to_string(),
+                line: 1,
+            };
+            if i % 2 == 0 {
+                sink.send(entry);
+            } else {
+                clone.send(entry);
+            }
+        }
+        // A futures channel holds its buffer size plus one slot for the single sender
+        let mut received = vec![];
+        while let Some(entry) = try_recv(&mut receiver) {
+            received.push(entry.msg);
+        }
+        assert_eq!(received, ["0", "1", "2"]);
+    }
+}
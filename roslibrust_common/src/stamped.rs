@@ -0,0 +1,92 @@
+use crate::{Publish, Result, RosMessageType};
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Implemented by codegen for every message whose first field is a `std_msgs/Header`
+///
+/// Stamps are exposed as `(sec, nanosec)` regardless of ROS version, the same representation
+/// used by `roslibrust_transforms::RosTimestamp`, so generic code can order messages by time or
+/// look up transforms at a message's stamp without knowing which Header it was generated against.
+pub trait HasHeader: RosMessageType {
+    /// The generated `std_msgs/Header` type of the message
+    type Header;
+
+    fn header(&self) -> &Self::Header;
+    fn header_mut(&mut self) -> &mut Self::Header;
+    /// The header's stamp as (seconds, nanoseconds)
+    fn stamp(&self) -> (i32, u32);
+    fn set_stamp(&mut self, sec: i32, nanosec: u32);
+    fn frame_id(&self) -> &str;
+    /// The header's sequence number, ROS2 headers don't have one and return None
+    fn seq(&self) -> Option<u32> {
+        None
+    }
+    /// Sets the header's sequence number, does nothing for ROS2 headers
+    fn set_seq(&mut self, _seq: u32) {}
+}
+
+/// Wraps any [Publish] implementation and fills in the header of each message before it is sent
+///
+/// By default both the sequence number (incremented once per publish) and the stamp (set to the
+/// current system time) are filled in, either can be turned off with [fill_seq](Self::fill_seq)
+/// and [fill_stamp](Self::fill_stamp).
+///
+/// ```
+/// # async fn example<P: roslibrust_common::Publish<M> + Sync, M: roslibrust_common::HasHeader>(publisher: P, msg: M) -> roslibrust_common::Result<()> {
+/// use roslibrust_common::{Publish, StampingPublisher};
+/// let publisher = StampingPublisher::new(publisher).fill_stamp(false);
+/// // Sent with seq 0, the stamp is left as is
+/// publisher.publish(&msg).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct StampingPublisher<P> {
+    inner: P,
+    fill_seq: bool,
+    fill_stamp: bool,
+    seq: AtomicU32,
+}
+
+impl<P> StampingPublisher<P> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            fill_seq: true,
+            fill_stamp: true,
+            seq: AtomicU32::new(0),
+        }
+    }
+
+    /// Whether to overwrite the sequence number of published messages, defaults to true
+    pub fn fill_seq(mut self, fill_seq: bool) -> Self {
+        self.fill_seq = fill_seq;
+        self
+    }
+
+    /// Whether to overwrite the stamp of published messages with the current time, defaults to true
+    pub fn fill_stamp(mut self, fill_stamp: bool) -> Self {
+        self.fill_stamp = fill_stamp;
+        self
+    }
+
+    /// Access the wrapped publisher
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+impl<T: HasHeader, P: Publish<T> + Sync> Publish<T> for StampingPublisher<P> {
+    fn publish(&self, data: &T) -> impl Future<Output = Result<()>> + Send {
+        let mut msg = data.clone();
+        if self.fill_seq {
+            msg.set_seq(self.seq.fetch_add(1, Ordering::Relaxed));
+        }
+        if self.fill_stamp {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default();
+            msg.set_stamp(now.as_secs() as i32, now.subsec_nanos());
+        }
+        async move { self.inner.publish(&msg).await }
+    }
+}
@@ -23,6 +23,9 @@ pub trait RosMessageType:
     /// The computed ROS2 hash of the message file and its dependencies
     /// This field is optional, and only needed when using ros2 native communication
     const ROS2_HASH: &'static [u8; 32] = &[0; 32];
+    /// Encoders which bypass serde, set by codegen when `generate_wire_codecs` is enabled and the message is simple enough
+    /// Backends use this in place of serde when it is available
+    const WIRE_CODEC: Option<crate::codec::WireCodecFns<Self>> = None;
 }
 
 // This special impl allows for services with no args / returns
@@ -118,6 +121,64 @@ where
     }
 }
 
+/// How a topic should be delivered, see [Reliability]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reliability {
+    /// Messages are retransmitted until they are received, the default for ROS1 (TCPROS) and ROS2
+    #[default]
+    Reliable,
+    /// Messages may be dropped in favour of latency, e.g. ROS2 best effort QoS or UDPROS in ROS1
+    BestEffort,
+}
+
+/// Backend neutral options for [TopicProvider::advertise_with] and [TopicProvider::subscribe_with].
+///
+/// Each backend maps these to whatever it supports, and ignores those it has no equivalent for:
+///  - ros1 uses `queue_size` for the publisher or subscriber queue, `latched` for latching and prefers UDPROS
+///    for best effort subscriptions.
+///  - ros2 maps them to a QoS profile with keep last history, transient local durability when latched and the given reliability.
+///  - rosbridge sends `queue_size` as the `queue_length` of subscriptions and the `queue_size` of publishers, and latches publishers.
+///
+/// ```
+/// use roslibrust_common::{Reliability, TopicOptions};
+/// let options = TopicOptions::new().queue_size(1).latched(true);
+/// let sensor_options = TopicOptions::new().reliability(Reliability::BestEffort);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TopicOptions {
+    /// Number of messages buffered before older ones are dropped, None uses the backend's default
+    pub queue_size: Option<usize>,
+    /// Whether the last published message should be delivered to subscribers which connect later
+    /// (latching in ROS1, transient local durability in ROS2)
+    pub latched: bool,
+    pub reliability: Reliability,
+}
+
+impl TopicOptions {
+    /// Returns the options [TopicProvider::advertise] and [TopicProvider::subscribe] use
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of messages buffered before older ones are dropped.
+    ///
+    /// A queue size of 0 is treated as 1 by every backend, subscribers always keep at least the latest message.
+    pub fn queue_size(mut self, queue_size: usize) -> Self {
+        self.queue_size = Some(queue_size);
+        self
+    }
+
+    pub fn latched(mut self, latched: bool) -> Self {
+        self.latched = latched;
+        self
+    }
+
+    pub fn reliability(mut self, reliability: Reliability) -> Self {
+        self.reliability = reliability;
+        self
+    }
+}
+
 // ANCHOR: topic_provider
 /// This trait generically describes the capability of something to act as an async interface to a set of topics
 ///
@@ -151,6 +212,30 @@ pub trait TopicProvider {
         &self,
         topic: impl ToGlobalTopicName,
     ) -> impl Future<Output = Result<Self::Subscriber<MsgType>>> + Send;
+
+    /// Same as [TopicProvider::advertise], but with control over queueing, latching and reliability.
+    ///
+    /// The default implementation ignores the options, see [TopicOptions] for how each backend uses them.
+    fn advertise_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl ToGlobalTopicName,
+        options: TopicOptions,
+    ) -> impl Future<Output = Result<Self::Publisher<MsgType>>> + Send {
+        let _ = options;
+        self.advertise(topic)
+    }
+
+    /// Same as [TopicProvider::subscribe], but with control over queueing, latching and reliability.
+    ///
+    /// The default implementation ignores the options, see [TopicOptions] for how each backend uses them.
+    fn subscribe_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl ToGlobalTopicName,
+        options: TopicOptions,
+    ) -> impl Future<Output = Result<Self::Subscriber<MsgType>>> + Send {
+        let _ = options;
+        self.subscribe(topic)
+    }
 }
 // ANCHOR_END: topic_provider
 
@@ -203,6 +288,9 @@ pub trait ServiceProvider {
 ///
 /// Implementors of this trait are expected to be "self de-registering", when the last node handle for a given
 /// node is dropped, the underlying node is expected to be shut down and clean-up after itself
+///
+/// Graph introspection is left out so backends which can't provide it still qualify, code which needs it
+/// can require [GraphProvider](crate::GraphProvider) alongside this trait.
 pub trait Ros: 'static + Send + Sync + TopicProvider + ServiceProvider + Clone {}
 // ANCHOR_END: ros_trait
 
@@ -0,0 +1,477 @@
+//! ROS2 type descriptions, and decoding CDR messages with them when their type isn't known at compile time.
+//!
+//! These are the structures of the JSON files ROS2 calculates type hashes from (see
+//! <https://github.com/ros-infrastructure/rep/pull/381/files>), shared with roslibrust_codegen which generates them
+//! to calculate hashes. The same information is returned at runtime by a node's `~/get_type_description` service.
+
+use crate::codec::{WireError, WireFormat, WirePrimitive, WireReader, CDR_LE_HEADER};
+
+/// The JSON file ROS2 generates alongside each interface, e.g. `share/std_msgs/msg/String.json`
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct TypeDescriptionFile {
+    pub type_description_msg: TypeDescriptionMsg,
+    pub type_hashes: Vec<TypeHash>,
+}
+
+/// The description of a type along with every type it references, directly or indirectly
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct TypeDescriptionMsg {
+    pub type_description: TypeDescription,
+    pub referenced_type_descriptions: Vec<TypeDescription>,
+}
+
+/// The fields of a single type
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct TypeDescription {
+    /// Name of the type in its ROS2 form e.g. `std_msgs/msg/String`
+    pub type_name: String,
+    pub fields: Vec<Field>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: FieldType,
+    // Value is not used in hashing, included for completeness
+    #[serde(skip)]
+    pub default_value: String,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct FieldType {
+    /// One of the `FIELD_TYPE_*` constants of `type_description_interfaces/FieldType`, see [FieldKind::from_type_id]
+    pub type_id: u8,
+    /// Length of an array, or the maximum length of a bounded sequence
+    pub capacity: u32,
+    /// Maximum length of a bounded string, or of the strings in an array or sequence
+    pub string_capacity: u32,
+    /// Name of the type for nested types, and arrays and sequences of them
+    pub nested_type_name: String,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct TypeHash {
+    pub type_name: String,
+    /// The hash in the form `RIHS01_<hex>`
+    pub hash_string: String,
+}
+
+/// The element type encoded in a [FieldType::type_id]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Nested,
+    Int8,
+    Uint8,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Int64,
+    Uint64,
+    Float32,
+    Float64,
+    LongDouble,
+    Char,
+    WChar,
+    Bool,
+    Byte,
+    String,
+    WString,
+    FixedString,
+    FixedWString,
+    BoundedString,
+    BoundedWString,
+}
+
+/// Whether a [FieldType::type_id] is a single value, or which kind of collection of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldShape {
+    Single,
+    /// Fixed length array, of [FieldType::capacity] elements
+    Array,
+    BoundedSequence,
+    UnboundedSequence,
+}
+
+impl FieldKind {
+    /// Splits a type id into its element type and shape, returns None for `FIELD_TYPE_NOT_SET` and unknown ids.
+    ///
+    /// The ids of `type_description_interfaces/FieldType` are laid out in blocks of 48: single values start at 1,
+    /// arrays at 49, bounded sequences at 97 and unbounded sequences at 145, each in the same order.
+    pub fn from_type_id(type_id: u8) -> Option<(FieldKind, FieldShape)> {
+        const KINDS: [FieldKind; 22] = [
+            FieldKind::Nested,
+            FieldKind::Int8,
+            FieldKind::Uint8,
+            FieldKind::Int16,
+            FieldKind::Uint16,
+            FieldKind::Int32,
+            FieldKind::Uint32,
+            FieldKind::Int64,
+            FieldKind::Uint64,
+            FieldKind::Float32,
+            FieldKind::Float64,
+            FieldKind::LongDouble,
+            FieldKind::Char,
+            FieldKind::WChar,
+            FieldKind::Bool,
+            FieldKind::Byte,
+            FieldKind::String,
+            FieldKind::WString,
+            FieldKind::FixedString,
+            FieldKind::FixedWString,
+            FieldKind::BoundedString,
+            FieldKind::BoundedWString,
+        ];
+        let index = type_id.checked_sub(1)?;
+        let shape = match index / 48 {
+            0 => FieldShape::Single,
+            1 => FieldShape::Array,
+            2 => FieldShape::BoundedSequence,
+            3 => FieldShape::UnboundedSequence,
+            _ => return None,
+        };
+        let kind = *KINDS.get((index % 48) as usize)?;
+        Some((kind, shape))
+    }
+}
+
+/// Errors which can occur in [TypeDescriptionMsg::decode_cdr]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum DynamicDecodeError {
+    #[error(transparent)]
+    Wire(#[from] WireError),
+    #[error("type {0} is not part of the type description")]
+    UnknownType(String),
+    #[error("field {field} has unsupported type id {type_id}")]
+    UnsupportedFieldType { field: String, type_id: u8 },
+    /// Messages nest deeper than [MAX_NESTING_DEPTH], which a type description referring to itself can cause
+    #[error("type {0} is nested more than {MAX_NESTING_DEPTH} messages deep")]
+    TooDeeplyNested(String),
+}
+
+/// How many messages deep [TypeDescriptionMsg::decode_cdr] follows nested types before giving up.
+///
+/// Far deeper than any real message, but keeps a description whose types refer to each other from overflowing the stack.
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+/// A single value of a [DynamicMessage]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicValue {
+    Bool(bool),
+    Int8(i8),
+    Uint8(u8),
+    Int16(i16),
+    Uint16(u16),
+    Int32(i32),
+    Uint32(u32),
+    Int64(i64),
+    Uint64(u64),
+    Float32(f32),
+    Float64(f64),
+    String(String),
+    /// Arrays and sequences of `uint8`, `byte` and `char`, kept together for efficiency
+    Bytes(Vec<u8>),
+    /// Arrays and sequences of all other types
+    Array(Vec<DynamicValue>),
+    Message(DynamicMessage),
+}
+
+/// A message decoded by [TypeDescriptionMsg::decode_cdr]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicMessage {
+    /// Name of the type in its ROS2 form e.g. `std_msgs/msg/String`
+    pub type_name: String,
+    /// The fields in the order they are defined
+    pub fields: Vec<(String, DynamicValue)>,
+}
+
+impl DynamicMessage {
+    /// Returns the value of the field named `name`
+    pub fn get(&self, name: &str) -> Option<&DynamicValue> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl TypeDescriptionMsg {
+    /// Returns the description of `type_name`, which is either the described type or one it references
+    pub fn find(&self, type_name: &str) -> Option<&TypeDescription> {
+        std::iter::once(&self.type_description)
+            .chain(&self.referenced_type_descriptions)
+            .find(|description| description.type_name == type_name)
+    }
+
+    /// Decodes a CDR serialized message of the described type, starting with its encapsulation header.
+    ///
+    /// `wchar`, `wstring` and `long double` fields aren't supported, as their encoding differs between DDS implementations.
+    pub fn decode_cdr(&self, data: &[u8]) -> Result<DynamicMessage, DynamicDecodeError> {
+        let header = data.get(..4).ok_or(WireError::UnexpectedEof {
+            needed: 4,
+            remaining: data.len(),
+        })?;
+        if header != CDR_LE_HEADER {
+            return Err(WireError::UnsupportedEncapsulation(header.try_into().unwrap()).into());
+        }
+        let mut reader = WireReader::new(&data[4..], WireFormat::Cdr);
+        self.decode_message(&self.type_description, &mut reader, 0)
+    }
+
+    fn decode_message(
+        &self,
+        description: &TypeDescription,
+        reader: &mut WireReader<'_>,
+        depth: usize,
+    ) -> Result<DynamicMessage, DynamicDecodeError> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(DynamicDecodeError::TooDeeplyNested(
+                description.type_name.clone(),
+            ));
+        }
+        let mut fields = Vec::with_capacity(description.fields.len());
+        for field in &description.fields {
+            let value = self.decode_field(field, reader, depth)?;
+            fields.push((field.name.clone(), value));
+        }
+        Ok(DynamicMessage {
+            type_name: description.type_name.clone(),
+            fields,
+        })
+    }
+
+    fn decode_field(
+        &self,
+        field: &Field,
+        reader: &mut WireReader<'_>,
+        depth: usize,
+    ) -> Result<DynamicValue, DynamicDecodeError> {
+        let type_id = field.field_type.type_id;
+        let unsupported = || DynamicDecodeError::UnsupportedFieldType {
+            field: field.name.clone(),
+            type_id,
+        };
+        let (kind, shape) = FieldKind::from_type_id(type_id).ok_or_else(unsupported)?;
+        if matches!(
+            kind,
+            FieldKind::LongDouble
+                | FieldKind::WChar
+                | FieldKind::WString
+                | FieldKind::FixedWString
+                | FieldKind::BoundedWString
+        ) {
+            return Err(unsupported());
+        }
+
+        let is_bytes = matches!(kind, FieldKind::Uint8 | FieldKind::Byte | FieldKind::Char);
+        let len = match shape {
+            FieldShape::Single => return self.decode_value(kind, field, reader, depth),
+            FieldShape::Array => field.field_type.capacity as usize,
+            FieldShape::BoundedSequence | FieldShape::UnboundedSequence if is_bytes => {
+                return Ok(DynamicValue::Bytes(reader.get_byte_seq()?.to_vec()));
+            }
+            FieldShape::BoundedSequence | FieldShape::UnboundedSequence => reader.get_len()?,
+        };
+        if is_bytes {
+            let bytes = (0..len).map(|_| reader.get()).collect::<Result<_, _>>()?;
+            return Ok(DynamicValue::Bytes(bytes));
+        }
+        // Every element takes at least one byte, which bounds the allocation for corrupt lengths
+        let mut items = Vec::with_capacity(len.min(reader.remaining()));
+        for _ in 0..len {
+            items.push(self.decode_value(kind, field, reader, depth)?);
+        }
+        Ok(DynamicValue::Array(items))
+    }
+
+    /// Decodes a single value of `field`, `depth` being how many messages deep the field is
+    fn decode_value(
+        &self,
+        kind: FieldKind,
+        field: &Field,
+        reader: &mut WireReader<'_>,
+        depth: usize,
+    ) -> Result<DynamicValue, DynamicDecodeError> {
+        fn get<T: WirePrimitive>(reader: &mut WireReader<'_>) -> Result<T, DynamicDecodeError> {
+            Ok(reader.get::<T>()?)
+        }
+        Ok(match kind {
+            FieldKind::Nested => {
+                let type_name = &field.field_type.nested_type_name;
+                let description = self
+                    .find(type_name)
+                    .ok_or_else(|| DynamicDecodeError::UnknownType(type_name.clone()))?;
+                DynamicValue::Message(self.decode_message(description, reader, depth + 1)?)
+            }
+            FieldKind::Int8 => DynamicValue::Int8(get(reader)?),
+            FieldKind::Uint8 | FieldKind::Byte | FieldKind::Char => {
+                DynamicValue::Uint8(get(reader)?)
+            }
+            FieldKind::Int16 => DynamicValue::Int16(get(reader)?),
+            FieldKind::Uint16 => DynamicValue::Uint16(get(reader)?),
+            FieldKind::Int32 => DynamicValue::Int32(get(reader)?),
+            FieldKind::Uint32 => DynamicValue::Uint32(get(reader)?),
+            FieldKind::Int64 => DynamicValue::Int64(get(reader)?),
+            FieldKind::Uint64 => DynamicValue::Uint64(get(reader)?),
+            FieldKind::Float32 => DynamicValue::Float32(get(reader)?),
+            FieldKind::Float64 => DynamicValue::Float64(get(reader)?),
+            FieldKind::Bool => DynamicValue::Bool(get(reader)?),
+            FieldKind::String | FieldKind::FixedString | FieldKind::BoundedString => {
+                DynamicValue::String(reader.get_string()?)
+            }
+            // Rejected by decode_field
+            FieldKind::LongDouble
+            | FieldKind::WChar
+            | FieldKind::WString
+            | FieldKind::FixedWString
+            | FieldKind::BoundedWString => {
+                return Err(DynamicDecodeError::UnsupportedFieldType {
+                    field: field.name.clone(),
+                    type_id: field.field_type.type_id,
+                })
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::codec::{BytesMut, WireWriter};
+
+    fn field(name: &str, type_id: u8, capacity: u32, nested_type_name: &str) -> Field {
+        Field {
+            name: name.to_string(),
+            field_type: FieldType {
+                type_id,
+                capacity,
+                string_capacity: 0,
+                nested_type_name: nested_type_name.to_string(),
+            },
+            default_value: String::new(),
+        }
+    }
+
+    #[test]
+    fn splits_type_ids() {
+        assert_eq!(FieldKind::from_type_id(0), None);
+        assert_eq!(
+            FieldKind::from_type_id(1),
+            Some((FieldKind::Nested, FieldShape::Single))
+        );
+        assert_eq!(
+            FieldKind::from_type_id(65),
+            Some((FieldKind::String, FieldShape::Array))
+        );
+        assert_eq!(
+            FieldKind::from_type_id(102),
+            Some((FieldKind::Int32, FieldShape::BoundedSequence))
+        );
+        assert_eq!(
+            FieldKind::from_type_id(166),
+            Some((FieldKind::BoundedWString, FieldShape::UnboundedSequence))
+        );
+        assert_eq!(FieldKind::from_type_id(193), None);
+    }
+
+    #[test]
+    fn decodes_nested_message() {
+        // A std_msgs/msg/MultiArrayLayout like type, with a fixed array and some bytes thrown in
+        let description = TypeDescriptionMsg {
+            type_description: TypeDescription {
+                type_name: "test_msgs/msg/Layout".to_string(),
+                fields: vec![
+                    field("dim", 145, 0, "test_msgs/msg/Dimension"),
+                    field("data_offset", 7, 0, ""),
+                    field("flags", 63, 2, ""),
+                    field("data", 147, 0, ""),
+                ],
+            },
+            referenced_type_descriptions: vec![TypeDescription {
+                type_name: "test_msgs/msg/Dimension".to_string(),
+                fields: vec![
+                    field("label", 17, 0, ""),
+                    field("size", 7, 0, ""),
+                    field("scale", 11, 0, ""),
+                ],
+            }],
+        };
+
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(&CDR_LE_HEADER);
+        let mut writer = WireWriter::new(&mut buf, WireFormat::Cdr);
+        writer.put_len(1);
+        writer.put_str("height");
+        writer.put(480u32);
+        writer.put(0.5f64);
+        writer.put(3u32);
+        writer.put(true);
+        writer.put(false);
+        writer.put_seq(&[1u8, 2, 3]);
+
+        let message = description.decode_cdr(&buf).unwrap();
+        assert_eq!(message.type_name, "test_msgs/msg/Layout");
+        assert_eq!(
+            message.get("dim"),
+            Some(&DynamicValue::Array(vec![DynamicValue::Message(
+                DynamicMessage {
+                    type_name: "test_msgs/msg/Dimension".to_string(),
+                    fields: vec![
+                        (
+                            "label".to_string(),
+                            DynamicValue::String("height".to_string())
+                        ),
+                        ("size".to_string(), DynamicValue::Uint32(480)),
+                        ("scale".to_string(), DynamicValue::Float64(0.5)),
+                    ],
+                }
+            )]))
+        );
+        assert_eq!(message.get("data_offset"), Some(&DynamicValue::Uint32(3)));
+        assert_eq!(
+            message.get("flags"),
+            Some(&DynamicValue::Array(vec![
+                DynamicValue::Bool(true),
+                DynamicValue::Bool(false)
+            ]))
+        );
+        assert_eq!(
+            message.get("data"),
+            Some(&DynamicValue::Bytes(vec![1, 2, 3]))
+        );
+
+        // Truncated messages and types missing from the description are reported
+        assert!(matches!(
+            description.decode_cdr(&buf[..buf.len() - 1]),
+            Err(DynamicDecodeError::Wire(WireError::UnexpectedEof { .. }))
+        ));
+        let mut missing = description.clone();
+        missing.referenced_type_descriptions.clear();
+        assert_eq!(
+            missing.decode_cdr(&buf),
+            Err(DynamicDecodeError::UnknownType(
+                "test_msgs/msg/Dimension".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_self_referencing_types() {
+        // Nothing in the data ends the recursion, a single nested field is always decoded
+        let description = TypeDescriptionMsg {
+            type_description: TypeDescription {
+                type_name: "test_msgs/msg/Node".to_string(),
+                fields: vec![field("next", 1, 0, "test_msgs/msg/Node")],
+            },
+            referenced_type_descriptions: vec![],
+        };
+        assert_eq!(
+            description.decode_cdr(&CDR_LE_HEADER),
+            Err(DynamicDecodeError::TooDeeplyNested(
+                "test_msgs/msg/Node".to_string()
+            ))
+        );
+    }
+}
@@ -13,7 +13,12 @@ pub fn prepare_environment<'a>(
     env.add_function("is_intrinsic_type", is_intrinsic_type);
     env.add_function("is_vector", is_vector_type);
     env.add_function("is_fixed_array", is_fixed_size_array_type);
+    env.add_function("is_byte_buffer", is_byte_buffer);
     env.add_filter("fixed_size_array_size", fixed_size_array_size);
+    env.add_function("dependencies", dependencies);
+    env.add_filter("snake_case", snake_case);
+    env.add_filter("quote", quote);
+    env.add_function("message_schema", crate::json_schema::message_schema);
     if let Some(map) = typename_conversion_mapping {
         env.add_filter("typename_conversion", move |v: Value| {
             let value = serde_json::to_value(v).unwrap();
@@ -82,6 +87,18 @@ pub fn is_vector_type(value: Value) -> bool {
     }
 }
 
+pub fn is_byte_buffer(value: Value) -> bool {
+    if let Ok(value) = serde_json::to_value(value) {
+        if let Ok(field) = serde_json::from_value::<Field>(value) {
+            field.is_byte_buffer()
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
+
 pub fn is_fixed_size_array_type(value: Value) -> bool {
     if let Ok(value) = serde_json::to_value(value) {
         if let Ok(field) = serde_json::from_value::<Field>(value) {
@@ -106,3 +123,61 @@ pub fn fixed_size_array_size(value: Value) -> Value {
     };
     Value::from_serialize(fixed_size)
 }
+
+/// Given a message specification returns each distinct non-intrinsic field type it references
+pub fn dependencies(value: Value) -> Value {
+    let mut deps: Vec<Field> = vec![];
+    if let Ok(value) = serde_json::to_value(value) {
+        if let Ok(spec) = serde_json::from_value::<MessageSpecification>(value) {
+            for field in spec.fields {
+                if !field.is_intrinsic_type()
+                    && !deps.iter().any(|dep| {
+                        dep.package == field.package && dep.field_type == field.field_type
+                    })
+                {
+                    deps.push(field);
+                }
+            }
+        }
+    }
+    Value::from_serialize(&deps)
+}
+
+/// Converts a CamelCase type name to the snake_case used for ROS2 header names,
+/// e.g. `BatteryState` to `battery_state` and `UInt8` to `u_int8`
+pub fn snake_case(value: String) -> String {
+    let chars = value.chars().collect::<Vec<_>>();
+    let mut out = String::with_capacity(value.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Renders a string constant as a double quoted, escaped literal
+///
+/// String constants are stored as written in the message file, surrounding quotes are dropped
+/// before escaping so either form ends up as a single literal.
+pub fn quote(value: String) -> String {
+    let trimmed = value.trim();
+    let unquoted = trimmed
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| {
+            trimmed
+                .strip_prefix('\'')
+                .and_then(|v| v.strip_suffix('\''))
+        })
+        .unwrap_or(trimmed);
+    serde_json::to_string(unquoted).unwrap()
+}
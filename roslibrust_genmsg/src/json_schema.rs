@@ -0,0 +1,134 @@
+use crate::spec::{ArrayInfo, Field, MessageSpecification};
+use minijinja::value::Value;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::json;
+
+pub const MESSAGE_TMPL: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/msg.schema.json.j2"
+));
+pub const SERVICE_TMPL: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/srv.schema.json.j2"
+));
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Keeps the properties of a schema in the order the fields are declared in
+struct Properties(Vec<(String, serde_json::Value)>);
+
+impl Serialize for Properties {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, schema) in &self.0 {
+            map.serialize_entry(name, schema)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(serde::Serialize)]
+struct MessageSchema {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    title: String,
+    #[serde(rename = "type")]
+    schema_type: &'static str,
+    properties: Properties,
+    required: Vec<String>,
+}
+
+fn integer(minimum: i128, maximum: i128) -> serde_json::Value {
+    json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+}
+
+fn int32_pair(first: &str, second: &str) -> serde_json::Value {
+    let int32 = integer(i32::MIN.into(), i32::MAX.into());
+    json!({
+        "type": "object",
+        "properties": { first: int32, second: int32 },
+        "required": [first, second],
+    })
+}
+
+/// The schema of a single element of a field, following the serde encoding of the Rust type
+/// roslibrust_codegen generates for it
+fn element_schema(field: &Field) -> serde_json::Value {
+    let mut schema = match field.field_type.as_str() {
+        "bool" => json!({ "type": "boolean" }),
+        "int8" => integer(i8::MIN.into(), i8::MAX.into()),
+        "uint8" | "byte" | "char" => integer(0, u8::MAX.into()),
+        "int16" => integer(i16::MIN.into(), i16::MAX.into()),
+        "uint16" => integer(0, u16::MAX.into()),
+        "int32" => integer(i32::MIN.into(), i32::MAX.into()),
+        "uint32" => integer(0, u32::MAX.into()),
+        "int64" => integer(i64::MIN.into(), i64::MAX.into()),
+        "uint64" => integer(0, u64::MAX.into()),
+        "float32" | "float64" => json!({ "type": "number" }),
+        // integral_types::WString serializes as a plain string in JSON
+        "string" | "wstring" => json!({ "type": "string" }),
+        // ROS1 time and duration are integral_types::Time and integral_types::Duration
+        "time" => int32_pair("secs", "nsecs"),
+        "duration" => int32_pair("sec", "nsec"),
+        message => json!({
+            "$ref": format!(
+                "../{}/{message}.schema.json",
+                field.package.as_deref().unwrap_or_default()
+            )
+        }),
+    };
+    // Capacities are in bytes for strings and UTF-16 code units for wstrings, so this is only exact for ASCII
+    if let ("string" | "wstring", Some(capacity)) =
+        (field.field_type.as_str(), field.string_capacity)
+    {
+        schema["maxLength"] = capacity.into();
+    }
+    schema
+}
+
+fn field_schema(field: &Field) -> serde_json::Value {
+    if field.is_byte_buffer() {
+        let mut schema = json!({ "type": "string", "contentEncoding": "base64" });
+        if let ArrayInfo::BoundedVector(bound) = field.array_info {
+            schema["maxLength"] = (bound.div_ceil(3) * 4).into();
+        }
+        return schema;
+    }
+    let items = element_schema(field);
+    match field.array_info {
+        ArrayInfo::NotAnArray => items,
+        ArrayInfo::Vector => json!({ "type": "array", "items": items }),
+        ArrayInfo::BoundedVector(bound) => {
+            json!({ "type": "array", "items": items, "maxItems": bound })
+        }
+        ArrayInfo::Array(len) => {
+            json!({ "type": "array", "items": items, "minItems": len, "maxItems": len })
+        }
+    }
+}
+
+/// Given a message specification renders a JSON Schema document for the JSON roslibrust's
+/// serde encoding (and rosbridge) produces for the message
+///
+/// Every field is required as none of the generated fields are skipped or defaulted by serde,
+/// unknown properties are allowed because serde ignores them.
+pub fn message_schema(value: Value) -> Result<String, minijinja::Error> {
+    let spec = serde_json::to_value(value)
+        .and_then(serde_json::from_value::<MessageSpecification>)
+        .map_err(|e| {
+            minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string())
+        })?;
+    let schema = MessageSchema {
+        schema: SCHEMA_DIALECT,
+        title: format!("{}/{}", spec.package, spec.short_name),
+        schema_type: "object",
+        properties: Properties(
+            spec.fields
+                .iter()
+                .map(|field| (field.name.clone(), field_schema(field)))
+                .collect(),
+        ),
+        required: spec.fields.iter().map(|field| field.name.clone()).collect(),
+    };
+    Ok(serde_json::to_string_pretty(&schema).unwrap())
+}
@@ -1,14 +1,18 @@
 use cpp::ROS_TYPE_TO_CPP_TYPE_MAP;
 use minijinja::{context, Template};
 use roslibrust_codegen::{MessageFile, ServiceFile};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use roslibrust_codegen::utils::Package;
 
 mod cpp;
 mod helpers;
+mod json_schema;
+mod python;
 mod spec;
+mod typescript;
 
 use spec::{MessageSpecification, ServiceSpecification};
 
@@ -60,6 +64,91 @@ pub struct ServiceGenOutput {
     pub service_source: String,
 }
 
+/// A source file produced by batch generation
+pub struct GeneratedFile {
+    /// Path of the file relative to the output directory
+    pub path: PathBuf,
+    /// The generated source
+    pub source: String,
+}
+
+/// The languages with built-in templates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    /// ROS1 (roscpp) style C++ headers, e.g. `std_msgs/Header.h`
+    Cpp,
+    /// ROS2 (rclcpp) style C++ headers, e.g. `std_msgs/msg/header.hpp`
+    CppRos2,
+    /// Python dataclasses, e.g. `std_msgs/Header.py`
+    Python,
+    /// TypeScript interfaces, e.g. `std_msgs/Header.ts`
+    TypeScript,
+    /// JSON Schema documents, e.g. `std_msgs/Header.schema.json`
+    JsonSchema,
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cpp" => Ok(Language::Cpp),
+            "cpp-ros2" => Ok(Language::CppRos2),
+            "python" => Ok(Language::Python),
+            "typescript" => Ok(Language::TypeScript),
+            "json-schema" => Ok(Language::JsonSchema),
+            _ => Err(format!(
+                "Unknown language: {s}, expected one of: cpp, cpp-ros2, python, typescript, json-schema"
+            )),
+        }
+    }
+}
+
+impl Language {
+    fn source_path(&self, package: &str, kind: &str, name: &str) -> PathBuf {
+        let mut path = PathBuf::from(package);
+        match self {
+            Language::Cpp => path.push(format!("{name}.h")),
+            Language::CppRos2 => {
+                path.push(kind);
+                path.push(format!("{}.hpp", helpers::snake_case(name.to_owned())));
+            }
+            Language::Python => path.push(format!("{name}.py")),
+            Language::TypeScript => path.push(format!("{name}.ts")),
+            Language::JsonSchema => path.push(format!("{name}.schema.json")),
+        }
+        path
+    }
+
+    /// The file a generated message is written to
+    pub fn message_file(&self, msg: &MessageGenOutput) -> GeneratedFile {
+        GeneratedFile {
+            path: self.source_path(&msg.package_name, "msg", &msg.message_name),
+            source: msg.message_source.clone(),
+        }
+    }
+
+    /// The files a generated service is written to: the service itself, its request and its response
+    pub fn service_files(&self, srv: &ServiceGenOutput) -> [GeneratedFile; 3] {
+        let package = &srv.package_name;
+        let name = &srv.service_name;
+        [
+            GeneratedFile {
+                path: self.source_path(package, "srv", name),
+                source: srv.service_source.clone(),
+            },
+            GeneratedFile {
+                path: self.source_path(package, "srv", &format!("{name}Request")),
+                source: srv.request_source.clone(),
+            },
+            GeneratedFile {
+                path: self.source_path(package, "srv", &format!("{name}Response")),
+                source: srv.response_source.clone(),
+            },
+        ]
+    }
+}
+
 type Filter = dyn Fn(minijinja::value::Value) -> minijinja::value::Value + Send + Sync;
 
 /// A builder to customize the generation of code from ROS message and service
@@ -149,6 +238,7 @@ impl<'a> CodeGenerator<'a> {
                 let message_source = fill_message_template(
                     &self.template_environment.get_template("message").unwrap(),
                     msg,
+                    "msg",
                 )?;
                 Ok(MessageGenOutput {
                     message_name: msg.get_short_name(),
@@ -167,10 +257,12 @@ impl<'a> CodeGenerator<'a> {
                 let request_source = fill_message_template(
                     &self.template_environment.get_template("message").unwrap(),
                     srv.request(),
+                    "srv",
                 )?;
                 let response_source = fill_message_template(
                     &self.template_environment.get_template("message").unwrap(),
                     srv.response(),
+                    "srv",
                 )?;
                 let service_source = fill_service_template(
                     &self.template_environment.get_template("service").unwrap(),
@@ -192,18 +284,102 @@ impl<'a> CodeGenerator<'a> {
 pub fn make_cpp_generator<P: AsRef<Path>>(
     search_paths: &[P],
 ) -> std::io::Result<CodeGenerator<'_>> {
-    CodeGeneratorBuilder::new(search_paths, cpp::MESSAGE_HEADER_TMPL)
-        .add_type_mapping(ROS_TYPE_TO_CPP_TYPE_MAP.clone())
-        .service_template(cpp::SERVICE_HEADER_TMPL)
-        .build()
+    make_generator(Language::Cpp, search_paths)
+}
+
+/// Create a code generator for one of the built-in languages.
+pub fn make_generator<P: AsRef<Path>>(
+    language: Language,
+    search_paths: &[P],
+) -> std::io::Result<CodeGenerator<'static>> {
+    let (msg_template, srv_template, type_mapping) = match language {
+        Language::Cpp => (
+            cpp::MESSAGE_HEADER_TMPL,
+            cpp::SERVICE_HEADER_TMPL,
+            Some(&*ROS_TYPE_TO_CPP_TYPE_MAP),
+        ),
+        Language::CppRos2 => (
+            cpp::MESSAGE_HEADER_ROS2_TMPL,
+            cpp::SERVICE_HEADER_ROS2_TMPL,
+            Some(&*cpp::ROS2_TYPE_TO_CPP_TYPE_MAP),
+        ),
+        Language::Python => (
+            python::MESSAGE_TMPL,
+            python::SERVICE_TMPL,
+            Some(&*python::ROS_TYPE_TO_PYTHON_TYPE_MAP),
+        ),
+        Language::TypeScript => (
+            typescript::MESSAGE_TMPL,
+            typescript::SERVICE_TMPL,
+            Some(&*typescript::ROS_TYPE_TO_TYPESCRIPT_TYPE_MAP),
+        ),
+        // Field schemas are built by the message_schema function rather than a type mapping
+        Language::JsonSchema => (json_schema::MESSAGE_TMPL, json_schema::SERVICE_TMPL, None),
+    };
+    let mut builder =
+        CodeGeneratorBuilder::new(search_paths, msg_template).service_template(srv_template);
+    if let Some(type_mapping) = type_mapping {
+        builder = builder.add_type_mapping(type_mapping.clone());
+    }
+    builder.build()
+}
+
+/// Generates every message and service of the packages found under `package_paths`.
+///
+/// Packages are discovered with [roslibrust_codegen::utils::crawl], dependencies are resolved
+/// against `package_paths` and `search_paths` but only files for the packages themselves are
+/// returned, with paths laid out as `language` expects them.
+pub fn generate_packages<P: AsRef<Path>, Q: AsRef<Path>>(
+    package_paths: &[P],
+    search_paths: &[Q],
+    language: Language,
+) -> std::io::Result<Vec<GeneratedFile>> {
+    let packages = roslibrust_codegen::utils::crawl(package_paths)
+        .into_iter()
+        .map(|pkg| pkg.name)
+        .collect::<BTreeSet<_>>();
+    if packages.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No ROS packages found in the provided package paths",
+        ));
+    }
+
+    let paths = package_paths
+        .iter()
+        .map(|p| p.as_ref().to_owned())
+        .chain(search_paths.iter().map(|p| p.as_ref().to_owned()))
+        .collect::<Vec<_>>();
+    let generator = make_generator(language, &paths)?;
+
+    let messages = generator
+        .generate_messages()
+        .map_err(std::io::Error::other)?;
+    let services = generator
+        .generate_services()
+        .map_err(std::io::Error::other)?;
+    let files = messages
+        .iter()
+        .filter(|msg| packages.contains(&msg.package_name))
+        .map(|msg| language.message_file(msg))
+        .chain(
+            services
+                .iter()
+                .filter(|srv| packages.contains(&srv.package_name))
+                .flat_map(|srv| language.service_files(srv)),
+        )
+        .collect();
+    Ok(files)
 }
 
 fn fill_message_template(
     template: &Template,
     msg_data: &MessageFile,
+    kind: &str,
 ) -> Result<String, minijinja::Error> {
     let context = context! {
         spec => MessageSpecification::from(msg_data),
+        kind => kind,
     };
     template.render(&context)
 }
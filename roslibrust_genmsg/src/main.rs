@@ -1,5 +1,5 @@
 use clap::Parser;
-use roslibrust_genmsg::IncludedNamespace;
+use roslibrust_genmsg::{GeneratedFile, IncludedNamespace, Language};
 use std::{
     io::Write,
     path::{Path, PathBuf},
@@ -7,15 +7,29 @@ use std::{
 
 #[derive(Parser, Debug)]
 struct Args {
-    /// Path to the input .msg file
-    #[arg(long = "msg", short = 'm')]
-    msg_path: PathBuf,
+    /// Path to the input .msg, .srv or .action file
+    #[arg(
+        long = "msg",
+        short = 'm',
+        required_unless_present = "package_dirs",
+        requires = "package"
+    )]
+    msg_path: Option<PathBuf>,
     /// The package namespace for the generated message
     #[arg(long, short)]
-    package: String,
+    package: Option<String>,
+    /// Package directories to generate every message and service of, replaces --msg
+    #[arg(long = "package-dir", short = 'd', conflicts_with = "msg_path")]
+    package_dirs: Vec<PathBuf>,
+    /// Additional directories to search for dependencies of --package-dir, ROS_PACKAGE_PATH is always searched
+    #[arg(long = "search-path", short = 's', requires = "package_dirs")]
+    search_paths: Vec<PathBuf>,
     /// Output directory for generated code
     #[arg(long, short)]
     output: PathBuf,
+    /// Language to generate: cpp, cpp-ros2, python, typescript or json-schema
+    #[arg(long, default_value = "cpp")]
+    lang: Language,
     /// Include namespaces for message dependencies
     #[arg(long, short = 'I', value_parser = include_namespace_parse)]
     include: Option<Vec<IncludedNamespace>>,
@@ -46,52 +60,58 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         std::process::exit(1);
     }
 
-    let short_name = args.msg_path.file_stem().unwrap().to_str().unwrap();
-    let extension = args.msg_path.extension().unwrap().to_str().unwrap();
+    let Some(msg_path) = args.msg_path else {
+        // Batch mode, every file of the requested packages is written out under its package directory
+        let search_paths = args
+            .search_paths
+            .into_iter()
+            .chain(roslibrust_codegen::utils::get_search_paths())
+            .filter(|path| !path.as_os_str().is_empty())
+            .collect::<Vec<_>>();
+        let files =
+            roslibrust_genmsg::generate_packages(&args.package_dirs, &search_paths, args.lang)?;
+        files
+            .iter()
+            .try_for_each(|file| write_source_file(&args.output, file, &file.path))?;
+        log::info!(
+            "Generated {} files in {}",
+            files.len(),
+            args.output.display()
+        );
+        return Ok(());
+    };
+    // Guaranteed by clap, --msg requires --package
+    let package = args.package.unwrap();
+
+    let short_name = msg_path.file_stem().unwrap().to_str().unwrap();
+    let extension = msg_path.extension().unwrap().to_str().unwrap();
     let msg_paths = args
         .include
         .unwrap_or_default()
         .into_iter()
         .map(|inc| inc.path)
         .collect::<Vec<_>>();
-    let generator = roslibrust_genmsg::make_cpp_generator(&msg_paths).unwrap();
+    let generator = roslibrust_genmsg::make_generator(args.lang, &msg_paths).unwrap();
 
     match extension {
         "msg" => {
             let generated_source = generator.generate_messages().unwrap();
             let msg_source = generated_source
                 .iter()
-                .find(|msg| msg.message_name == short_name && msg.package_name == args.package)
-                .unwrap_or_else(|| {
-                    panic!("Could not find message: {}/{}", args.package, short_name)
-                });
-            write_source_file(
-                &args.output,
-                &format!("{short_name}.h"),
-                &msg_source.message_source,
-            )?;
+                .find(|msg| msg.message_name == short_name && msg.package_name == package)
+                .unwrap_or_else(|| panic!("Could not find message: {package}/{short_name}"));
+            write_flat_source_file(&args.output, &args.lang.message_file(msg_source))?;
         }
         "srv" => {
             let generated_source = generator.generate_services().unwrap();
             let srv_source = generated_source
                 .iter()
-                .find(|srv| srv.service_name == short_name && srv.package_name == args.package)
+                .find(|srv| srv.service_name == short_name && srv.package_name == package)
                 .unwrap();
-            write_source_file(
-                &args.output,
-                &format!("{short_name}.h"),
-                &srv_source.service_source,
-            )?;
-            write_source_file(
-                &args.output,
-                &format!("{short_name}Request.h"),
-                &srv_source.request_source,
-            )?;
-            write_source_file(
-                &args.output,
-                &format!("{short_name}Response.h"),
-                &srv_source.response_source,
-            )?;
+            args.lang
+                .service_files(srv_source)
+                .iter()
+                .try_for_each(|file| write_flat_source_file(&args.output, file))?;
         }
         "action" => {
             let expected_messages = [
@@ -108,17 +128,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let action_sources = generated_source
                 .into_iter()
                 .filter(|msg| {
-                    expected_messages.contains(&msg.message_name)
-                        && msg.package_name == args.package
+                    expected_messages.contains(&msg.message_name) && msg.package_name == package
                 })
                 .collect::<Vec<_>>();
             if action_sources.len() == expected_messages.len() {
-                action_sources.into_iter().try_for_each(|src| {
-                    write_source_file(
-                        &args.output,
-                        &format!("{}.h", src.message_name),
-                        &src.message_source,
-                    )
+                action_sources.iter().try_for_each(|src| {
+                    write_flat_source_file(&args.output, &args.lang.message_file(src))
                 })?;
             } else {
                 log::error!(
@@ -134,7 +149,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         _ => {
             log::error!(
                 "Unrecognized extension: {extension} in provided input file: {}",
-                args.msg_path.display()
+                msg_path.display()
             );
             std::process::exit(1);
         }
@@ -142,12 +157,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn write_source_file(out_path: &Path, filename: &str, source: &str) -> std::io::Result<()> {
-    let mut out_file_path = out_path.to_owned();
-    out_file_path.push(filename);
+/// Writes a file directly into the output directory, ignoring the package directories of its path
+fn write_flat_source_file(out_path: &Path, file: &GeneratedFile) -> std::io::Result<()> {
+    write_source_file(out_path, file, Path::new(file.path.file_name().unwrap()))
+}
+
+fn write_source_file(
+    out_path: &Path,
+    file: &GeneratedFile,
+    relative_path: &Path,
+) -> std::io::Result<()> {
+    let out_file_path = out_path.join(relative_path);
+    if let Some(parent) = out_file_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
 
     let mut out_file = std::fs::File::create(out_file_path)?;
-    out_file.write_all(source.as_bytes())
+    out_file.write_all(file.source.as_bytes())
 }
 
 #[cfg(test)]
@@ -238,6 +264,106 @@ mod test {
         );
     }
 
+    #[test]
+    fn batch_generation_matches_single_file() {
+        let files = roslibrust_genmsg::generate_packages(
+            &[STD_MSGS_PKG_PATH, STD_SRVS_PKG_PATH],
+            &[] as &[&str],
+            roslibrust_genmsg::Language::Cpp,
+        )
+        .unwrap();
+        let header = files
+            .iter()
+            .find(|file| file.path == std::path::Path::new("std_msgs/Header.h"))
+            .unwrap();
+        let current_source = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test_package/include/std_msgs/Header.h"
+        ))
+        .unwrap();
+        assert_eq!(
+            remove_whitespace(&header.source),
+            remove_whitespace(&current_source)
+        );
+        for name in ["Trigger", "TriggerRequest", "TriggerResponse"] {
+            assert!(files
+                .iter()
+                .any(|file| file.path == std::path::Path::new(&format!("std_srvs/{name}.h"))));
+        }
+    }
+
+    #[test]
+    fn batch_generation_only_emits_requested_packages() {
+        for (language, header_path) in [
+            (
+                roslibrust_genmsg::Language::CppRos2,
+                "geometry_msgs/msg/point32.hpp",
+            ),
+            (
+                roslibrust_genmsg::Language::Python,
+                "geometry_msgs/Point32.py",
+            ),
+            (
+                roslibrust_genmsg::Language::TypeScript,
+                "geometry_msgs/Point32.ts",
+            ),
+        ] {
+            let files = roslibrust_genmsg::generate_packages(
+                &[GEOMETRY_MSGS_PKG_PATH],
+                &[STD_MSGS_PKG_PATH],
+                language,
+            )
+            .unwrap();
+            assert!(files
+                .iter()
+                .all(|file| file.path.starts_with("geometry_msgs")));
+            assert!(files
+                .iter()
+                .any(|file| file.path == std::path::Path::new(header_path)));
+        }
+    }
+
+    #[test]
+    fn web_types_follow_serde_encoding() {
+        let schema_files = roslibrust_genmsg::generate_packages(
+            &[SENSOR_MSGS_PKG_PATH],
+            &[STD_MSGS_PKG_PATH, GEOMETRY_MSGS_PKG_PATH],
+            roslibrust_genmsg::Language::JsonSchema,
+        )
+        .unwrap();
+        let image = schema_files
+            .iter()
+            .find(|file| {
+                file.path == std::path::Path::new("sensor_msgs/CompressedImage.schema.json")
+            })
+            .unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&image.source).unwrap();
+        assert_eq!(
+            schema["properties"]["header"]["$ref"],
+            "../std_msgs/Header.schema.json"
+        );
+        // uint8[] goes through serde_rosmsg_bytes and is a base64 string in JSON
+        assert_eq!(schema["properties"]["data"]["type"], "string");
+        assert_eq!(schema["properties"]["data"]["contentEncoding"], "base64");
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["header", "format", "data"])
+        );
+
+        let ts_files = roslibrust_genmsg::generate_packages(
+            &[SENSOR_MSGS_PKG_PATH],
+            &[STD_MSGS_PKG_PATH, GEOMETRY_MSGS_PKG_PATH],
+            roslibrust_genmsg::Language::TypeScript,
+        )
+        .unwrap();
+        let image = ts_files
+            .iter()
+            .find(|file| file.path == std::path::Path::new("sensor_msgs/CompressedImage.ts"))
+            .unwrap();
+        assert!(image.source.contains("data: string;"));
+        assert!(image.source.contains("header: Header;"));
+    }
+
     #[test]
     fn transform_stamped_with_user_template() {
         let mapping =
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+pub const MESSAGE_TMPL: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/msg.py.j2"));
+pub const SERVICE_TMPL: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/srv.py.j2"));
+
+lazy_static::lazy_static! {
+    pub static ref ROS_TYPE_TO_PYTHON_TYPE_MAP: HashMap<String, String> = vec![
+        ("bool", "bool"),
+        ("int8", "int"),
+        ("uint8", "int"),
+        ("byte", "int"),
+        ("char", "int"),
+        ("int16", "int"),
+        ("uint16", "int"),
+        ("int32", "int"),
+        ("uint32", "int"),
+        ("int64", "int"),
+        ("uint64", "int"),
+        ("float32", "float"),
+        ("float64", "float"),
+        ("string", "str"),
+        ("wstring", "str"),
+        // (secs, nsecs)
+        ("time", "typing.Tuple[int, int]"),
+        ("duration", "typing.Tuple[int, int]"),
+    ].into_iter().map(|(k, v)| (k.to_owned(), v.to_owned())).collect();
+}
@@ -3,13 +3,15 @@ use serde::{Deserialize, Serialize};
 
 pub static ROS_TYPENAMES: &[&str] = &[
     "bool", "int8", "uint8", "byte", "char", "int16", "uint16", "int32", "uint32", "int64",
-    "uint64", "float32", "float64", "string", "time", "duration",
+    "uint64", "float32", "float64", "string", "wstring", "time", "duration",
 ];
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub enum ArrayInfo {
     NotAnArray,
     Vector,
+    /// A ROS2 bounded sequence, e.g. `int32[<=5]`
+    BoundedVector(usize),
     Array(usize),
 }
 
@@ -19,6 +21,8 @@ pub struct Field {
     pub field_type: String,
     pub package: Option<String>,
     pub array_info: ArrayInfo,
+    /// The bound of ROS2 bounded strings, e.g. `string<=10`
+    pub string_capacity: Option<usize>,
 }
 
 impl From<&FieldInfo> for Field {
@@ -30,9 +34,11 @@ impl From<&FieldInfo> for Field {
             // TODO this is a little silly, we should probably modify genmsg to use ArrayType instead...
             array_info: match value.field_type.array_info {
                 ArrayType::FixedLength(n) => ArrayInfo::Array(n),
-                ArrayType::Bounded(_) | ArrayType::Unbounded => ArrayInfo::Vector,
+                ArrayType::Bounded(n) => ArrayInfo::BoundedVector(n),
+                ArrayType::Unbounded => ArrayInfo::Vector,
                 ArrayType::NotArray => ArrayInfo::NotAnArray,
             },
+            string_capacity: value.field_type.string_capacity,
         }
     }
 }
@@ -43,7 +49,16 @@ impl Field {
     }
 
     pub fn is_vector_type(&self) -> bool {
-        matches!(self.array_info, ArrayInfo::Vector)
+        matches!(
+            self.array_info,
+            ArrayInfo::Vector | ArrayInfo::BoundedVector(_)
+        )
+    }
+
+    /// Whether the field is a `uint8[]` or `byte[]` sequence, which roslibrust's serde encoding
+    /// (see `roslibrust_codegen::serde_rosmsg_bytes`) writes to JSON as a base64 string
+    pub fn is_byte_buffer(&self) -> bool {
+        self.is_vector_type() && matches!(self.field_type.as_str(), "uint8" | "byte")
     }
 
     pub fn is_fixed_size_array_type(&self) -> bool {
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+pub const MESSAGE_TMPL: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/msg.ts.j2"));
+pub const SERVICE_TMPL: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/srv.ts.j2"));
+
+lazy_static::lazy_static! {
+    // Matches the JSON produced by the serde encoding of generated messages, which is also what rosbridge uses.
+    // 64 bit integers are plain JSON numbers and lose precision beyond 2^53 in JavaScript.
+    pub static ref ROS_TYPE_TO_TYPESCRIPT_TYPE_MAP: HashMap<String, String> = vec![
+        ("bool", "boolean"),
+        ("int8", "number"),
+        ("uint8", "number"),
+        ("byte", "number"),
+        ("char", "number"),
+        ("int16", "number"),
+        ("uint16", "number"),
+        ("int32", "number"),
+        ("uint32", "number"),
+        ("int64", "number"),
+        ("uint64", "number"),
+        ("float32", "number"),
+        ("float64", "number"),
+        ("string", "string"),
+        // integral_types::WString serializes as a plain string in JSON
+        ("wstring", "string"),
+        ("time", "{ secs: number; nsecs: number }"),
+        ("duration", "{ sec: number; nsec: number }"),
+    ].into_iter().map(|(k, v)| (k.to_owned(), v.to_owned())).collect();
+}
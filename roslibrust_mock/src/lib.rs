@@ -29,6 +29,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use roslibrust_common::graph::{poll_graph_events, Graph, GraphEvent};
 use roslibrust_common::topic_name::{GlobalTopicName, ToGlobalTopicName};
 use roslibrust_common::*;
 
@@ -47,9 +48,14 @@ type TypeErasedCallback = Arc<
 // Internal type for storing services
 type ServiceStore = RwLock<BTreeMap<String, TypeErasedCallback>>;
 
+/// The node name MockRos reports its publishers, subscribers and services under in its [Graph]
+pub const MOCK_NODE_NAME: &str = "/mock_ros";
+
 /// A mock ROS implementation that can be substituted for any roslibrust backend in unit tests.
 ///
 /// Implements [TopicProvider] and [ServiceProvider] to provide basic ros functionality.
+/// Implements [GraphProvider] by recording everything advertised and subscribed to under [MOCK_NODE_NAME],
+/// other nodes can be simulated with [MockRos::update_graph].
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
 pub struct MockRos {
@@ -57,6 +63,7 @@ pub struct MockRos {
     // but this ends up being pretty simple
     topics: Arc<RwLock<BTreeMap<String, (Channel::Sender<Vec<u8>>, Channel::Receiver<Vec<u8>>)>>>,
     services: Arc<ServiceStore>,
+    graph: Arc<tokio::sync::watch::Sender<Graph>>,
 }
 
 impl Default for MockRos {
@@ -70,8 +77,15 @@ impl MockRos {
         Self {
             topics: Arc::new(RwLock::new(BTreeMap::new())),
             services: Arc::new(RwLock::new(BTreeMap::new())),
+            graph: Arc::new(tokio::sync::watch::Sender::new(Graph::default())),
         }
     }
+
+    /// Modifies the graph reported by [GraphProvider], useful to simulate other nodes coming and going.
+    /// Subscribers to [GraphProvider::graph_events] are notified of the change.
+    pub fn update_graph(&self, update: impl FnOnce(&mut Graph)) {
+        self.graph.send_modify(update);
+    }
 }
 
 // This is a very basic mocking of sending and receiving messages over topics
@@ -86,6 +100,9 @@ impl TopicProvider for MockRos {
     ) -> Result<Self::Publisher<MsgType>> {
         let topic: GlobalTopicName = topic.to_global_name()?;
         let topic_str = topic.as_ref();
+        self.update_graph(|graph| {
+            graph.add_publisher(topic_str, MsgType::ROS_TYPE_NAME, MOCK_NODE_NAME)
+        });
         // Check if we already have this channel
         {
             let topics = self.topics.read().await;
@@ -115,6 +132,9 @@ impl TopicProvider for MockRos {
     ) -> Result<Self::Subscriber<MsgType>> {
         let topic: GlobalTopicName = topic.to_global_name()?;
         let topic_str = topic.as_ref();
+        self.update_graph(|graph| {
+            graph.add_subscriber(topic_str, MsgType::ROS_TYPE_NAME, MOCK_NODE_NAME)
+        });
         // Check if we already have this channel
         {
             let topics = self.topics.read().await;
@@ -194,7 +214,10 @@ impl<T: RosServiceType> Service<T> for MockServiceClient<T> {
         let response = tokio::task::spawn_blocking(move || (callback)(data))
             .await
             .map_err(|_e| Error::Disconnected)?
-            .map_err(|e| Error::SerializationError(e.to_string()))?;
+            .map_err(|e| match e.downcast::<Error>() {
+                Ok(e) => *e,
+                Err(e) => Error::ServerError(e.to_string()),
+            })?;
 
         // Deserialize response
         let response = bincode::deserialize(&response[..])
@@ -244,12 +267,19 @@ impl ServiceProvider for MockRos {
         > {
             let request = bincode::deserialize(&message[..])
                 .map_err(|e| Error::SerializationError(e.to_string()))?;
-            let response = server(request)?;
+            // Like the real backends, a failing server reports back as a server error
+            let response = server(request).map_err(|e| match e.downcast::<Error>() {
+                Ok(e) => e,
+                Err(e) => Error::ServerError(e.to_string()),
+            })?;
             let bytes = bincode::serialize(&response)
                 .map_err(|e| Error::SerializationError(e.to_string()))?;
             Ok(bytes)
         };
         let erased_closure = Arc::new(erased_closure);
+        self.update_graph(|graph| {
+            graph.add_service_provider(service.as_ref(), SrvType::ROS_SERVICE_NAME, MOCK_NODE_NAME)
+        });
         let mut services = self.services.write().await;
         services.insert(String::from(service), erased_closure);
 
@@ -259,6 +289,41 @@ impl ServiceProvider for MockRos {
     }
 }
 
+// Like the rest of the mock, entries aren't removed when publishers, subscribers or services are dropped
+impl GraphProvider for MockRos {
+    async fn graph(&self) -> Result<Graph> {
+        Ok(self.graph.borrow().clone())
+    }
+
+    fn graph_events(
+        &self,
+    ) -> impl futures_core::Stream<Item = Result<GraphEvent>> + Send + 'static {
+        let receiver = Arc::new(tokio::sync::Mutex::new(self.graph.subscribe()));
+        let fetch_receiver = receiver.clone();
+        // Set once every MockRos is dropped, the graph can't change anymore so the stream ends
+        let closed = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let fetch_closed = closed.clone();
+        poll_graph_events(
+            move || {
+                if fetch_closed.load(std::sync::atomic::Ordering::SeqCst) {
+                    return None;
+                }
+                let receiver = fetch_receiver.clone();
+                Some(async move { Ok(receiver.lock().await.borrow_and_update().clone()) })
+            },
+            move || {
+                let receiver = receiver.clone();
+                let closed = closed.clone();
+                async move {
+                    if receiver.lock().await.changed().await.is_err() {
+                        closed.store(true, std::sync::atomic::Ordering::SeqCst);
+                    }
+                }
+            },
+        )
+    }
+}
+
 /// The publisher type returned by calling [MockRos::advertise].
 pub struct MockPublisher<T: RosMessageType> {
     sender: Channel::Sender<Vec<u8>>,
@@ -325,6 +390,34 @@ mod tests {
         assert_eq!(msg, received_msg);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stamping_publisher() {
+        use roslibrust_test::ros1::geometry_msgs::PointStamped;
+        let mock_ros = MockRos::new();
+
+        let pub_handle = StampingPublisher::new(
+            mock_ros
+                .advertise::<PointStamped>("/test_topic")
+                .await
+                .unwrap(),
+        );
+        let mut sub_handle = mock_ros
+            .subscribe::<PointStamped>("/test_topic")
+            .await
+            .unwrap();
+
+        let msg = PointStamped::default();
+        pub_handle.publish(&msg).await.unwrap();
+        pub_handle.publish(&msg).await.unwrap();
+
+        let first = sub_handle.next().await.unwrap();
+        let second = sub_handle.next().await.unwrap();
+        assert_eq!(first.seq(), Some(0));
+        assert_eq!(second.seq(), Some(1));
+        assert!(second.stamp() >= first.stamp());
+        assert_ne!(second.stamp(), msg.stamp());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_mock_services() {
         let mock_topics = MockRos::new();
@@ -353,6 +446,59 @@ mod tests {
         assert_eq!(response.message, "You set my bool!");
     }
 
+    #[tokio::test]
+    async fn test_graph_events() {
+        use futures::StreamExt;
+        use roslibrust_common::graph::GraphEvent;
+
+        let mock_ros = MockRos::new();
+        let _publisher = mock_ros
+            .advertise::<std_msgs::String>("/chatter")
+            .await
+            .unwrap();
+        let mut events = std::pin::pin!(mock_ros.graph_events());
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            GraphEvent::NodeAdded(MOCK_NODE_NAME.to_string())
+        );
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            GraphEvent::TopicAdded {
+                topic: "/chatter".to_string(),
+                type_name: "std_msgs/String".to_string()
+            }
+        );
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            GraphEvent::PublisherAdded {
+                topic: "/chatter".to_string(),
+                node: MOCK_NODE_NAME.to_string()
+            }
+        );
+
+        // Simulate another node subscribing
+        mock_ros
+            .update_graph(|graph| graph.add_subscriber("/chatter", "std_msgs/String", "/listener"));
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            GraphEvent::NodeAdded("/listener".to_string())
+        );
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            GraphEvent::SubscriberAdded {
+                topic: "/chatter".to_string(),
+                node: "/listener".to_string()
+            }
+        );
+
+        let graph = mock_ros.graph().await.unwrap();
+        assert!(graph.topics["/chatter"].publishers.contains(MOCK_NODE_NAME));
+
+        // The stream ends once the MockRos is gone
+        drop(mock_ros);
+        assert!(events.next().await.is_none());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_mock_node() {
         // Proves that MockRos impls the Ros trait (via auto impl in roslibrust_common)
@@ -0,0 +1,159 @@
+//! Delivery of messages between publishers and subscribers living in the same node.
+//!
+//! When a node subscribes to a topic it also publishes, the subscription doesn't open a TCPROS connection
+//! back to itself. Instead the publication hands messages straight to the subscription over an in-memory
+//! channel. Typed publishers send the message as an `Arc<T>`, so subscribers of the same type receive it
+//! without it ever being serialized. Both sides are still registered with the master as usual.
+
+use crate::publisher::serialize_message;
+use crate::subscriber::{deserialize_message, SubscriberError};
+use bytes::Bytes;
+use roslibrust_common::RosMessageType;
+use std::{
+    any::Any,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::broadcast;
+
+/// Serializes a type erased message, returns None if the message isn't the type the function was made for
+type SerializeFn = fn(&(dyn Any + Send + Sync)) -> Option<Bytes>;
+
+/// A message as it is passed to subscribers within the node
+#[derive(Clone)]
+pub(crate) enum SharedMessage {
+    /// Serialized message including its 4 byte length header, as it appears on the wire
+    Serialized(Bytes),
+    /// Message handed over by a local publisher, along with how to serialize it for subscribers of other types
+    Typed {
+        msg: Arc<dyn Any + Send + Sync>,
+        serialize: SerializeFn,
+    },
+}
+
+fn serialize_any<T: RosMessageType>(msg: &(dyn Any + Send + Sync)) -> Option<Bytes> {
+    serialize_message(msg.downcast_ref::<T>()?).ok()
+}
+
+impl SharedMessage {
+    pub(crate) fn typed<T: RosMessageType>(msg: Arc<T>) -> Self {
+        SharedMessage::Typed {
+            msg,
+            serialize: serialize_any::<T>,
+        }
+    }
+
+    /// Returns the serialized form of the message, serializing it if it came from a local typed publisher
+    pub(crate) fn to_bytes(&self) -> Result<Bytes, SubscriberError> {
+        match self {
+            SharedMessage::Serialized(bytes) => Ok(bytes.clone()),
+            SharedMessage::Typed { msg, serialize } => serialize(msg.as_ref()).ok_or_else(|| {
+                SubscriberError::DeserializeError(
+                    "failed to serialize message from local publisher".to_owned(),
+                )
+            }),
+        }
+    }
+
+    /// Returns the message as a T, which only requires deserialization if it wasn't published locally as a T
+    pub(crate) fn to_message<T: RosMessageType>(&self) -> Result<Arc<T>, SubscriberError> {
+        if let SharedMessage::Typed { msg, .. } = self {
+            if let Ok(msg) = msg.clone().downcast::<T>() {
+                return Ok(msg);
+            }
+        }
+        let bytes = self.to_bytes()?;
+        Ok(Arc::new(deserialize_message::<T>(&bytes[..])?))
+    }
+}
+
+/// The in-process side of a publication, shared by every publisher handle for the topic
+#[derive(Clone)]
+pub(crate) struct LocalPublication {
+    sender: broadcast::Sender<SharedMessage>,
+    // Only Some for latching publications, holds the last message for local subscribers that join later
+    latched: Option<Arc<Mutex<Option<SharedMessage>>>>,
+}
+
+impl LocalPublication {
+    pub(crate) fn new(queue_size: usize, latching: bool) -> Self {
+        let (sender, _receiver) = broadcast::channel(queue_size.max(1));
+        Self {
+            sender,
+            latched: latching.then(Default::default),
+        }
+    }
+
+    pub(crate) fn is_latching(&self) -> bool {
+        self.latched.is_some()
+    }
+
+    /// True if a message sent now would be seen by anyone in this process
+    pub(crate) fn has_subscribers(&self) -> bool {
+        self.is_latching() || self.sender.receiver_count() > 0
+    }
+
+    pub(crate) fn send(&self, msg: SharedMessage) {
+        if let Some(latched) = &self.latched {
+            *latched.lock().unwrap() = Some(msg.clone());
+        }
+        // No receivers is not an error, local subscribers may come and go
+        let _ = self.sender.send(msg);
+    }
+
+    /// Returns a receiver for future messages, and the latched message if there is one
+    pub(crate) fn subscribe(&self) -> (broadcast::Receiver<SharedMessage>, Option<SharedMessage>) {
+        let receiver = self.sender.subscribe();
+        let latched = self
+            .latched
+            .as_ref()
+            .and_then(|latched| latched.lock().unwrap().clone());
+        (receiver, latched)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roslibrust_test::ros1::std_msgs::Int32 as Int;
+
+    // Same layout as std_msgs/Int32, as if generated into a second crate
+    #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+    struct OtherInt {
+        data: i32,
+    }
+
+    impl RosMessageType for OtherInt {
+        const ROS_TYPE_NAME: &'static str = "std_msgs/Int32";
+        const MD5SUM: &'static str = "da5909fbe378aeaf85e547e830cc1bb7";
+        const DEFINITION: &'static str = "int32 data";
+    }
+
+    #[test_log::test]
+    fn typed_message_is_shared_not_copied() {
+        let original = Arc::new(Int { data: 7 });
+        let msg = SharedMessage::typed(original.clone());
+        let received = msg.to_message::<Int>().unwrap();
+        assert!(Arc::ptr_eq(&original, &received));
+    }
+
+    #[test_log::test]
+    fn typed_message_converts_for_other_types() {
+        let msg = SharedMessage::typed(Arc::new(Int { data: 7 }));
+        assert_eq!(msg.to_message::<OtherInt>().unwrap().data, 7);
+        assert_eq!(&msg.to_bytes().unwrap()[..], &[4, 0, 0, 0, 7, 0, 0, 0]);
+
+        let serialized = SharedMessage::Serialized(msg.to_bytes().unwrap());
+        assert_eq!(serialized.to_message::<Int>().unwrap().data, 7);
+    }
+
+    #[test_log::test]
+    fn latched_message_is_replayed() {
+        let publication = LocalPublication::new(1, true);
+        assert!(publication.has_subscribers());
+        publication.send(SharedMessage::typed(Arc::new(Int { data: 3 })));
+
+        let (_receiver, latched) = publication.subscribe();
+        let latched = latched.unwrap().to_message::<Int>().unwrap();
+        assert_eq!(latched.data, 3);
+    }
+}
@@ -29,13 +29,16 @@
 //! }
 //! ```
 
+use roslibrust_common::graph::{poll_graph_events, Graph, GraphEvent, GraphProvider};
 use roslibrust_common::topic_name::{GlobalTopicName, ToGlobalTopicName};
 use roslibrust_common::Error;
 use roslibrust_common::{
-    Publish, RosMessageType, RosServiceType, Service, ServiceFn, ServiceProvider, Subscribe,
-    TopicProvider,
+    Publish, Reliability, RosMessageType, RosServiceType, Service, ServiceFn, ServiceProvider,
+    Subscribe, TopicOptions, TopicProvider,
 };
 
+mod intra_process;
+
 /// [master_client] module contains code for calling xmlrpc functions on the master
 mod master_client;
 pub use master_client::*;
@@ -54,9 +57,11 @@ pub use service_client::ServiceClient;
 mod subscriber;
 pub use subscriber::Subscriber;
 pub use subscriber::SubscriberAny;
+pub use subscriber::{SubscribeOptions, Transport, TransportHints};
 mod service_server;
 pub use service_server::ServiceServer;
 mod tcpros;
+mod udpros;
 
 /// Provides a common type alias for type erased service server functions.
 /// Internally we use this type to store collections of server functions.
@@ -92,6 +97,35 @@ impl TopicProvider for crate::NodeHandle {
             .await
             .map_err(|e| e.into())
     }
+
+    async fn advertise_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl ToGlobalTopicName,
+        options: TopicOptions,
+    ) -> roslibrust_common::Result<Self::Publisher<MsgType>> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        let queue_size = options.queue_size.unwrap_or(10);
+        NodeHandle::advertise::<MsgType>(self, topic.as_ref(), queue_size, options.latched)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn subscribe_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl ToGlobalTopicName,
+        options: TopicOptions,
+    ) -> roslibrust_common::Result<Self::Subscriber<MsgType>> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        let mut subscribe_options = SubscribeOptions::new(options.queue_size.unwrap_or(10));
+        // UDPROS is ROS1's unreliable transport, fall back to TCPROS for publishers which don't support it
+        if options.reliability == Reliability::BestEffort {
+            subscribe_options =
+                subscribe_options.transport_hints(TransportHints::new().udp().tcp());
+        }
+        NodeHandle::subscribe_with_options(self, topic.as_ref(), subscribe_options)
+            .await
+            .map_err(|e| e.into())
+    }
 }
 
 impl<T: RosServiceType> Service<T> for ServiceClient<T> {
@@ -110,9 +144,14 @@ impl ServiceProvider for crate::NodeHandle {
         request: SrvType::Request,
     ) -> roslibrust_common::Result<SrvType::Response> {
         let service: GlobalTopicName = service.to_global_name()?;
-        // TODO should have a more optimized version of this...
-        let client = NodeHandle::service_client::<SrvType>(self, service.as_ref()).await?;
-        client.call(&request).await
+        let client = self
+            .cached_service_client::<SrvType>(service.as_ref())
+            .await?;
+        let response = client.call(&request).await;
+        if let Err(Error::Disconnected | Error::IoError(_)) = &response {
+            self.evict_service_client(service.as_ref());
+        }
+        response
     }
 
     async fn service_client<SrvType: RosServiceType + 'static>(
@@ -170,6 +209,35 @@ impl<T: RosMessageType> Publish<T> for Publisher<T> {
     }
 }
 
+/// How often [GraphProvider::graph_events] polls the master for changes
+const GRAPH_POLL_PERIOD: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// The ROS1 master doesn't track service types, so services in the graph have no type
+impl GraphProvider for crate::NodeHandle {
+    async fn graph(&self) -> roslibrust_common::Result<Graph> {
+        NodeHandle::get_graph(self).await.map_err(|e| e.into())
+    }
+
+    fn graph_events(
+        &self,
+    ) -> impl futures_core::Stream<Item = roslibrust_common::Result<GraphEvent>> + Send + 'static
+    {
+        // Weak so that an event stream alone doesn't keep the node alive
+        let node = self.weak_clone();
+        poll_graph_events(
+            move || {
+                // The stream ends once the node has shut down
+                if !node.is_ok() {
+                    return None;
+                }
+                let node = node.clone();
+                Some(async move { GraphProvider::graph(&node).await })
+            },
+            || tokio::time::sleep(GRAPH_POLL_PERIOD),
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use roslibrust_common::Ros;
@@ -195,6 +263,87 @@ mod test {
         };
     }
 
+    #[cfg(feature = "ros1_test")]
+    #[test_log::test(tokio::test)]
+    async fn call_service_reconnects_and_times_out() {
+        use roslibrust_common::ServiceProvider;
+        use roslibrust_test::ros1::test_msgs;
+        use std::time::Duration;
+
+        let nh = crate::NodeHandle::new(
+            "http://localhost:11311",
+            "/call_service_reconnects_and_times_out",
+        )
+        .await
+        .unwrap();
+        let service = "/call_service_reconnects_and_times_out/add_two";
+        let add = |request: test_msgs::AddTwoIntsRequest| {
+            Ok(test_msgs::AddTwoIntsResponse {
+                sum: request.a + request.b,
+            })
+        };
+        let call = |a, b| {
+            ServiceProvider::call_service::<test_msgs::AddTwoInts>(
+                &nh,
+                service,
+                test_msgs::AddTwoIntsRequest { a, b },
+            )
+        };
+
+        let server = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>(service, add)
+            .await
+            .unwrap();
+        assert_eq!(call(1, 2).await.unwrap().sum, 3);
+
+        // The cached client reconnects to a restarted server
+        drop(server);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        let server = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>(service, add)
+            .await
+            .unwrap();
+        assert_eq!(call(2, 2).await.unwrap().sum, 4);
+
+        // Once the service is gone for good the client is dropped from the cache
+        drop(server);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert!(call(1, 1).await.is_err());
+        assert!(nh.service_clients.lock().unwrap().is_empty());
+
+        let _server = nh
+            .advertise_service::<test_msgs::AddTwoInts, _>(service, move |request| {
+                std::thread::sleep(Duration::from_millis(500));
+                add(request)
+            })
+            .await
+            .unwrap();
+        let client = nh
+            .cached_service_client::<test_msgs::AddTwoInts>(service)
+            .await
+            .unwrap();
+        let response = client
+            .call_with_timeout(
+                &test_msgs::AddTwoIntsRequest { a: 1, b: 2 },
+                Duration::from_millis(50),
+            )
+            .await;
+        assert!(matches!(
+            response,
+            Err(roslibrust_common::Error::Timeout(_))
+        ));
+
+        // The abandoned call leaves no half read response behind for the next one
+        let response = client
+            .call_with_timeout(
+                &test_msgs::AddTwoIntsRequest { a: 3, b: 4 },
+                Duration::from_secs(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.sum, 7);
+    }
+
     #[test]
     #[should_panic]
     #[allow(clippy::unnecessary_literal_unwrap)]
@@ -211,4 +360,105 @@ mod test {
             _client: new_mock.unwrap(),
         };
     }
+
+    #[cfg(feature = "ros1_test")]
+    #[test_log::test(tokio::test)]
+    async fn latched_advertise_with_reaches_late_subscriber() {
+        use roslibrust_common::{Subscribe, TopicOptions};
+        use roslibrust_test::ros1::std_msgs;
+        use std::time::Duration;
+
+        let publisher_nh = crate::NodeHandle::new(
+            "http://localhost:11311",
+            "/latched_advertise_with_publisher",
+        )
+        .await
+        .unwrap();
+        let subscriber_nh = crate::NodeHandle::new(
+            "http://localhost:11311",
+            "/latched_advertise_with_subscriber",
+        )
+        .await
+        .unwrap();
+
+        // A queue size of 0 is treated as 1 rather than panicking
+        let options = TopicOptions::new().queue_size(0).latched(true);
+        let publisher = publisher_nh
+            .advertise_with::<std_msgs::String>("/latched_advertise_with", options)
+            .await
+            .unwrap();
+        publisher
+            .publish(&std_msgs::String {
+                data: "latched".to_string(),
+            })
+            .await
+            .unwrap();
+
+        // Subscribing from another node only after the message was published
+        let mut subscriber = subscriber_nh
+            .subscribe_with::<std_msgs::String>(
+                "/latched_advertise_with",
+                TopicOptions::new().queue_size(0),
+            )
+            .await
+            .unwrap();
+        let msg = tokio::time::timeout(Duration::from_secs(2), Subscribe::next(&mut subscriber))
+            .await
+            .expect("Latched message should reach a late subscriber")
+            .unwrap();
+        assert_eq!(msg.data, "latched");
+    }
+
+    #[cfg(feature = "ros1_test")]
+    #[test_log::test(tokio::test)]
+    async fn next_borrowed_decodes_in_place() {
+        use roslibrust_test::ros1::sensor_msgs;
+        use std::time::Duration;
+
+        let publisher_nh =
+            crate::NodeHandle::new("http://localhost:11311", "/next_borrowed_publisher")
+                .await
+                .unwrap();
+        let subscriber_nh =
+            crate::NodeHandle::new("http://localhost:11311", "/next_borrowed_subscriber")
+                .await
+                .unwrap();
+        // Received over TCPROS from another node, and published by the same node
+        let mut subscriber = subscriber_nh
+            .subscribe::<sensor_msgs::Image>("/next_borrowed", 2)
+            .await
+            .unwrap();
+        let remote = publisher_nh
+            .advertise::<sensor_msgs::Image>("/next_borrowed", 2, false)
+            .await
+            .unwrap();
+        let local = subscriber_nh
+            .advertise::<sensor_msgs::Image>("/next_borrowed", 2, false)
+            .await
+            .unwrap();
+        // Give the subscriber time to connect to the other node
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        for (publisher, encoding) in [(&remote, "remote"), (&local, "local")] {
+            let image = sensor_msgs::Image {
+                encoding: encoding.to_string(),
+                data: vec![1, 2, 3],
+                ..Default::default()
+            };
+            publisher.publish(&image).await.unwrap();
+            let message = tokio::time::timeout(Duration::from_secs(2), subscriber.next_borrowed())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            let borrowed = message.get();
+            assert_eq!(borrowed.encoding, encoding);
+            assert_eq!(borrowed.data, [1, 2, 3]);
+            assert!(message
+                .as_bytes()
+                .as_ptr_range()
+                .contains(&borrowed.data.as_ptr()));
+            assert_eq!(message.to_owned_message(), image);
+        }
+    }
 }
@@ -1,6 +1,8 @@
 //! This module is concerned with direct communication over xmlprc between the master
 
 use log::*;
+use roslibrust_common::graph::Graph;
+use std::collections::HashMap;
 
 #[derive(thiserror::Error, Debug)]
 pub enum RosMasterError {
@@ -76,6 +78,35 @@ impl SystemState {
         };
         entry.nodes.iter().any(|name| name.as_str().eq(node))
     }
+
+    /// Combines the system state with the topic types from [MasterClient::get_topic_types] into a [Graph].
+    /// The master doesn't know the types of services, so those are left empty.
+    pub fn into_graph(self, topic_types: Vec<(String, String)>) -> Graph {
+        let topic_types: HashMap<String, String> = topic_types.into_iter().collect();
+        let type_of = |topic: &str| {
+            topic_types
+                .get(topic)
+                .map(String::as_str)
+                .unwrap_or_default()
+        };
+        let mut graph = Graph::default();
+        for entry in self.publishers {
+            for node in entry.nodes {
+                graph.add_publisher(&entry.topic, type_of(&entry.topic), &node);
+            }
+        }
+        for entry in self.subscribers {
+            for node in entry.nodes {
+                graph.add_subscriber(&entry.topic, type_of(&entry.topic), &node);
+            }
+        }
+        for entry in self.service_providers {
+            for node in entry.nodes {
+                graph.add_service_provider(&entry.topic, "", &node);
+            }
+        }
+        graph
+    }
 }
 
 impl MasterClient {
@@ -317,6 +348,11 @@ impl MasterClient {
         &self.client_uri
     }
 
+    /// Returns the caller id (the name of the node) this client identifies itself with to the master.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
     /// Hits the master's xmlrpc endpoint "getSystemState" and returns the response
     pub async fn get_system_state(&self) -> Result<SystemState, RosMasterError> {
         // Comes in order of Publishers, Subscribers, Services
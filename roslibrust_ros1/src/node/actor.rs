@@ -1,10 +1,11 @@
 use crate::{
+    intra_process::{LocalPublication, SharedMessage},
     names::Name,
-    node::{XmlRpcServer, XmlRpcServerHandle},
+    node::{RequestedProtocol, XmlRpcServer, XmlRpcServerHandle},
     publisher::Publication,
     service_client::ServiceClientLink,
     service_server::ServiceServerLink,
-    subscriber::Subscription,
+    subscriber::{SubscribeOptions, Subscription},
     MasterClient, NodeError, ProtocolParams, ServiceClient, TypeErasedCallback,
 };
 use abort_on_drop::ChildTask;
@@ -14,6 +15,13 @@ use roslibrust_common::{Error, RosMessageType, RosServiceType, ServiceFn};
 use std::{collections::HashMap, io, net::Ipv4Addr, sync::Arc};
 use tokio::sync::{broadcast, mpsc, oneshot};
 
+/// Everything a publisher handle needs: the channel for serialized messages going out over the network,
+/// the in-process side of the publication, and a sender that keeps the publication alive
+/// How often the node checks that its UDPROS subscribers are still registered with the master
+const UDP_LIVENESS_PERIOD: std::time::Duration = std::time::Duration::from_secs(5);
+
+pub(crate) type PublisherChannels = (broadcast::Sender<Bytes>, LocalPublication, mpsc::Sender<()>);
+
 // Carter TODO:
 // I kinda hate this entire Msg based abstraction internal to the server
 // Why isn't this just a regular async function call?
@@ -28,6 +36,10 @@ pub enum NodeMsg {
     GetClientUri {
         reply: oneshot::Sender<String>,
     },
+    // Hands out a copy of the node's master client so slow master queries don't block the node
+    GetMasterClient {
+        reply: oneshot::Sender<MasterClient>,
+    },
     GetSubscriptions {
         reply: oneshot::Sender<Vec<(String, String)>>,
     },
@@ -44,7 +56,7 @@ pub enum NodeMsg {
     Shutdown,
     RegisterPublisher {
         // Uses Bytes for efficient cloning (reference counted) when there are multiple subscribers
-        reply: oneshot::Sender<Result<(broadcast::Sender<Bytes>, mpsc::Sender<()>), String>>,
+        reply: oneshot::Sender<Result<PublisherChannels, String>>,
         topic: String,
         topic_type: String,
         queue_size: usize,
@@ -54,12 +66,12 @@ pub enum NodeMsg {
     },
     RegisterSubscriber {
         // Uses Bytes for efficient cloning (reference counted) when there are multiple subscribers
-        reply: oneshot::Sender<Result<broadcast::Receiver<Bytes>, String>>,
+        reply: oneshot::Sender<Result<broadcast::Receiver<SharedMessage>, String>>,
         topic: String,
         topic_type: String,
-        queue_size: usize,
         msg_definition: String,
         md5sum: String,
+        options: SubscribeOptions,
     },
     RegisterServiceClient {
         reply: oneshot::Sender<Result<ServiceClientLink, String>>,
@@ -83,12 +95,17 @@ pub enum NodeMsg {
     RequestTopic {
         reply: oneshot::Sender<Result<ProtocolParams, String>>,
         topic: String,
-        protocols: Vec<String>,
+        protocols: Vec<RequestedProtocol>,
     },
     UnregisterPublisher {
         reply: oneshot::Sender<Result<(), String>>,
         topic: String,
     },
+    // Sent by the node to itself once the master no longer lists these UDPROS subscribers
+    RemoveUdpSubscribers {
+        topic: String,
+        connection_ids: Vec<u32>,
+    },
 }
 
 /// Represents a communication handle to an underlying node server
@@ -122,6 +139,14 @@ impl NodeServerHandle {
         Ok(receiver.await?)
     }
 
+    /// Get a copy of the client the node uses to talk to the ROS master.
+    pub(crate) async fn get_master_client(&self) -> Result<MasterClient, NodeError> {
+        let (sender, receiver) = oneshot::channel();
+        self.node_server_sender
+            .send(NodeMsg::GetMasterClient { reply: sender })?;
+        Ok(receiver.await?)
+    }
+
     /// Gets the list of topics the node is currently subscribed to.
     /// Returns a tuple of (Topic Name, Topic Type) e.g. ("/rosout", "rosgraph_msgs/Log").
     pub(crate) async fn get_subscriptions(&self) -> Result<Vec<(String, String)>, NodeError> {
@@ -168,7 +193,7 @@ impl NodeServerHandle {
         topic: &str,
         queue_size: usize,
         latching: bool,
-    ) -> Result<(broadcast::Sender<Bytes>, mpsc::Sender<()>), NodeError> {
+    ) -> Result<PublisherChannels, NodeError> {
         let (sender, receiver) = oneshot::channel();
         self.node_server_sender.send(NodeMsg::RegisterPublisher {
             reply: sender,
@@ -194,7 +219,7 @@ impl NodeServerHandle {
         msg_definition: &str,
         queue_size: usize,
         latching: bool,
-    ) -> Result<(broadcast::Sender<Bytes>, mpsc::Sender<()>), NodeError> {
+    ) -> Result<PublisherChannels, NodeError> {
         let (sender, receiver) = oneshot::channel();
 
         let md5sum_res =
@@ -336,8 +361,8 @@ impl NodeServerHandle {
     pub(crate) async fn register_subscriber<T: RosMessageType>(
         &self,
         topic: &str,
-        queue_size: usize,
-    ) -> Result<broadcast::Receiver<Bytes>, NodeError> {
+        options: SubscribeOptions,
+    ) -> Result<broadcast::Receiver<SharedMessage>, NodeError> {
         // Type here is complicated, this is a channel that we're sending a channel receiver over
         // This channel is used to fire back the receiver of the underlying subscription
         let (sender, receiver) = oneshot::channel();
@@ -345,9 +370,9 @@ impl NodeServerHandle {
             reply: sender,
             topic: topic.to_owned(),
             topic_type: T::ROS_TYPE_NAME.to_owned(),
-            queue_size,
             msg_definition: T::DEFINITION.to_owned(),
             md5sum: T::MD5SUM.to_owned(),
+            options,
         })?;
         let received = receiver.await?;
         received.map_err(|err| {
@@ -364,12 +389,12 @@ impl NodeServerHandle {
     pub(crate) async fn request_topic(
         &self,
         topic: &str,
-        protocols: &[String],
+        protocols: Vec<RequestedProtocol>,
     ) -> Result<ProtocolParams, NodeError> {
         let (sender, receiver) = oneshot::channel();
         self.node_server_sender.send(NodeMsg::RequestTopic {
             topic: topic.to_owned(),
-            protocols: protocols.into(),
+            protocols,
             reply: sender,
         })?;
         let received = receiver.await?;
@@ -452,8 +477,17 @@ impl Node {
 
         let t = Arc::new(
             tokio::spawn(async move {
+                let mut udp_liveness = tokio::time::interval(UDP_LIVENESS_PERIOD);
+                udp_liveness.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
                 loop {
-                    match node.node_msg_rx.recv().await {
+                    let node_msg = tokio::select! {
+                        node_msg = node.node_msg_rx.recv() => node_msg,
+                        _ = udp_liveness.tick() => {
+                            node.check_udp_subscribers();
+                            continue;
+                        }
+                    };
+                    match node_msg {
                         Some(NodeMsg::Shutdown) => {
                             log::info!("Shutdown requested, shutting down node");
                             break;
@@ -487,6 +521,9 @@ impl Node {
             NodeMsg::GetClientUri { reply } => {
                 let _ = reply.send(self.client.client_uri().to_owned());
             }
+            NodeMsg::GetMasterClient { reply } => {
+                let _ = reply.send(self.client.clone());
+            }
             NodeMsg::GetSubscriptions { reply } => {
                 let _ = reply.send(
                     self.subscriptions
@@ -513,6 +550,10 @@ impl Node {
 
                     // Then add any new publishers
                     for publisher_uri in publishers {
+                        // Publications of this node are delivered in process, see connect_local
+                        if publisher_uri == self.client.client_uri() {
+                            continue;
+                        }
                         if let Err(err) = subscription.add_publisher_source(&publisher_uri).await {
                             log::error!(
                                 "Unable to create subscribe stream for topic {topic}: {err}"
@@ -544,11 +585,7 @@ impl Node {
                         latching,
                     )
                     .await;
-                match res {
-                    Ok(handle) => reply.send(Ok(handle)),
-                    Err(err) => reply.send(Err(err.to_string())),
-                }
-                .expect("Failed to reply on oneshot");
+                let _ = reply.send(res.map_err(|err| err.to_string()));
             }
             NodeMsg::UnregisterPublisher { reply, topic } => {
                 let _ = reply.send(
@@ -561,17 +598,17 @@ impl Node {
                 reply,
                 topic,
                 topic_type,
-                queue_size,
                 msg_definition,
                 md5sum,
+                options,
             } => {
                 let _ = reply.send(
                     self.register_subscriber(
                         &topic,
                         &topic_type,
-                        queue_size,
                         &msg_definition,
                         &md5sum,
+                        options,
                     )
                     .await
                     .map_err(|err| err.to_string()),
@@ -625,28 +662,16 @@ impl Node {
                 topic,
                 protocols,
             } => {
-                // TODO: Should move the actual implementation similar to RegisterPublisher
-                if protocols.iter().any(|proto| proto.as_str() == "TCPROS") {
-                    if let Some((_key, publishing_channel)) =
-                        self.publishers.iter().find(|(key, _pub)| *key == &topic)
-                    {
-                        let protocol_params = ProtocolParams {
-                            hostname: self.hostname.clone(),
-                            protocol: String::from("TCPROS"), // Hardcoded as the only option for now
-                            port: publishing_channel.port(),
-                        };
-                        let _ = reply.send(Ok(protocol_params));
-                    } else {
-                        let err_str = format!("Got request for topic {topic} from subscriber which this node does not publish");
-                        log::warn!("{err_str}");
-                        let _ = reply.send(Err(err_str));
+                let _ = reply.send(self.request_topic(&topic, protocols).await);
+            }
+            NodeMsg::RemoveUdpSubscribers {
+                topic,
+                connection_ids,
+            } => {
+                if let Some(publication) = self.publishers.get_mut(&topic) {
+                    for connection_id in connection_ids {
+                        publication.remove_udp_subscriber(connection_id);
                     }
-                } else {
-                    let err_str = format!(
-                        "No supported protocols in the request from the subscriber: {protocols:?}"
-                    );
-                    log::error!("{err_str}");
-                    let _ = reply.send(Err(err_str));
                 }
             }
             NodeMsg::Shutdown => {
@@ -659,34 +684,163 @@ impl Node {
         &mut self,
         topic: &str,
         topic_type: &str,
-        queue_size: usize,
         msg_definition: &str,
         md5sum: &str,
-    ) -> Result<broadcast::Receiver<Bytes>, NodeError> {
+        options: SubscribeOptions,
+    ) -> Result<broadcast::Receiver<SharedMessage>, NodeError> {
         match self.subscriptions.iter().find(|(key, _)| *key == topic) {
             Some((_topic, subscription)) => Ok(subscription.get_receiver()),
             None => {
                 let mut subscription = Subscription::new(
                     &self.node_name,
+                    &self.hostname,
                     topic,
                     topic_type,
-                    queue_size,
                     msg_definition.to_owned(),
                     md5sum.to_owned(),
+                    options,
                 );
+                // Taken before connecting anything, so the receiver sees a latched message replayed right away
+                let receiver = subscription.get_receiver();
+                if let Some(publication) = self.publishers.get(topic) {
+                    Self::connect_local(topic, publication, &mut subscription);
+                }
                 let current_publishers = self.client.register_subscriber(topic, topic_type).await?;
                 for publisher in current_publishers {
+                    // Publications of this node are delivered in process, see connect_local
+                    if publisher == self.client.client_uri() {
+                        continue;
+                    }
                     if let Err(err) = subscription.add_publisher_source(&publisher).await {
                         log::error!("Unable to create subscriber connection to {publisher} for {topic}: {err}");
                     }
                 }
-                let receiver = subscription.get_receiver();
                 self.subscriptions.insert(topic.to_owned(), subscription);
                 Ok(receiver)
             }
         }
     }
 
+    /// Responds to a subscriber's requestTopic call.
+    /// Protocols are tried in the order the subscriber listed them, the first one we can serve is used.
+    async fn request_topic(
+        &mut self,
+        topic: &str,
+        protocols: Vec<RequestedProtocol>,
+    ) -> Result<ProtocolParams, String> {
+        let Some(publication) = self.publishers.get_mut(topic) else {
+            let err_str = format!(
+                "Got request for topic {topic} from subscriber which this node does not publish"
+            );
+            log::warn!("{err_str}");
+            return Err(err_str);
+        };
+        for protocol in &protocols {
+            match protocol {
+                RequestedProtocol::Tcpros => {
+                    return Ok(ProtocolParams {
+                        hostname: self.hostname.clone(),
+                        protocol: String::from("TCPROS"),
+                        port: publication.port(),
+                        udp: None,
+                    });
+                }
+                RequestedProtocol::Udpros {
+                    header,
+                    hostname,
+                    port,
+                    max_datagram_size,
+                } => {
+                    match publication
+                        .add_udp_subscriber(header, hostname, *port, *max_datagram_size)
+                        .await
+                    {
+                        Ok((local_port, udp)) => {
+                            return Ok(ProtocolParams {
+                                hostname: self.hostname.clone(),
+                                protocol: String::from("UDPROS"),
+                                port: local_port,
+                                udp: Some(udp),
+                            });
+                        }
+                        Err(e) => {
+                            log::warn!("Unable to serve UDPROS subscriber on {topic}, trying next protocol: {e}");
+                        }
+                    }
+                }
+                RequestedProtocol::Other(name) => {
+                    log::debug!("Subscriber requested unsupported protocol {name} for {topic}");
+                }
+            }
+        }
+        let err_str =
+            format!("No supported protocols in the request from the subscriber: {protocols:?}");
+        log::error!("{err_str}");
+        Err(err_str)
+    }
+
+    /// UDP has no connection to notice a subscriber going away by, so this asks the master which of our UDPROS
+    /// subscribers are still registered. One query covers every link of the node, it runs off the node's task and
+    /// reports back with a [NodeMsg::RemoveUdpSubscribers] per topic.
+    fn check_udp_subscribers(&mut self) {
+        let links: Vec<(String, Vec<(String, u32)>)> = self
+            .publishers
+            .iter_mut()
+            .map(|(topic, publication)| (topic.clone(), publication.udp_subscribers()))
+            .filter(|(_, subscribers)| !subscribers.is_empty())
+            .collect();
+        if links.is_empty() {
+            return;
+        }
+        let client = self.client.clone();
+        let sender = self.node_handle.node_server_sender.clone();
+        tokio::spawn(async move {
+            let state = match client.get_system_state().await {
+                Ok(state) => state,
+                Err(e) => {
+                    // Failing to reach the master isn't taken as the subscribers being gone
+                    log::debug!("Unable to check UDPROS subscribers with the master: {e}");
+                    return;
+                }
+            };
+            for (topic, subscribers) in links {
+                let connection_ids: Vec<u32> = subscribers
+                    .into_iter()
+                    .filter(|(caller_id, _)| !state.is_subscribed(&topic, caller_id))
+                    .map(|(caller_id, connection_id)| {
+                        log::debug!("UDPROS subscriber {caller_id} of {topic} is no longer registered, removing");
+                        connection_id
+                    })
+                    .collect();
+                if !connection_ids.is_empty() {
+                    let _ = sender.send(NodeMsg::RemoveUdpSubscribers {
+                        topic,
+                        connection_ids,
+                    });
+                }
+            }
+        });
+    }
+
+    /// Hands messages from a publication of this node to a subscription of this node without going through TCP
+    fn connect_local(topic: &str, publication: &Publication, subscription: &mut Subscription) {
+        let compatible = match (publication.md5sum(), subscription.md5sum()) {
+            (Some(published), Some(subscribed)) => {
+                published == subscribed || published == "*" || subscribed == "*"
+            }
+            _ => true,
+        };
+        if !compatible {
+            warn!(
+                "Publisher and subscriber within this node disagree on the md5sum of {topic}: {:?} vs {:?}, not connecting them",
+                publication.md5sum(),
+                subscription.md5sum()
+            );
+            return;
+        }
+        subscription.add_local_publisher(publication.local().subscribe());
+    }
+
     async fn register_publisher(
         &mut self,
         topic: String,
@@ -695,7 +849,7 @@ impl Node {
         msg_definition: String,
         md5sum: String,
         latching: bool,
-    ) -> Result<(broadcast::Sender<Bytes>, mpsc::Sender<()>), NodeError> {
+    ) -> Result<PublisherChannels, NodeError> {
         // Return handle to existing Publication if it exists
         let existing_entry = {
             self.publishers.iter().find_map(|(key, value)| {
@@ -709,10 +863,10 @@ impl Node {
                         std::io::ErrorKind::AddrInUse,
                     ))));
                 }
-                let (sender, shutdown) = value.get_senders();
+                let (sender, local, shutdown) = value.get_senders();
                 match shutdown.upgrade() {
                     Some(shutdown) => {
-                        Some(Ok((sender, shutdown)))
+                        Some(Ok((sender, local, shutdown)))
                     }
                     None => {
                         error!("We still have an entry for a publication, but it has been shutdown");
@@ -726,12 +880,11 @@ impl Node {
         };
         // If we found an existing publication return the handle to it
         if let Some(handle) = existing_entry {
-            let (sender, shutdown) = handle?;
-            return Ok((sender, shutdown));
+            return handle;
         }
 
         // Otherwise create a new Publication and advertise
-        let (channel, sender, shutdown) = Publication::new(
+        let (channel, sender, local, shutdown) = Publication::new(
             &self.node_name,
             latching,
             &topic,
@@ -747,9 +900,13 @@ impl Node {
             log::error!("Failed to create publishing channel: {err:?}");
             err
         })?;
+        // Subscriptions in this node receive from the new publication directly
+        if let Some(subscription) = self.subscriptions.get_mut(&topic) {
+            Self::connect_local(&topic, &channel, subscription);
+        }
         self.publishers.insert(topic.clone(), channel);
         let _ = self.client.register_publisher(&topic, topic_type).await?;
-        Ok((sender, shutdown))
+        Ok((sender, local, shutdown))
     }
 
     async fn unregister_publisher(&mut self, topic: &str) -> Result<(), NodeError> {
@@ -788,6 +945,7 @@ impl Node {
 
         log::debug!("Found service at {service_uri}");
         let server_link = ServiceClientLink::new(
+            self.client.clone(),
             &self.node_name,
             &service_name,
             service_type,
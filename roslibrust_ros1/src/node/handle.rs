@@ -1,9 +1,20 @@
 use super::actor::{Node, NodeServerHandle};
 use crate::{
     names::Name, publisher::Publisher, publisher::PublisherAny, service_client::ServiceClient,
-    subscriber::Subscriber, subscriber::SubscriberAny, NodeError, ServiceServer,
+    subscriber::SubscribeOptions, subscriber::Subscriber, subscriber::SubscriberAny, NodeError,
+    ServiceServer,
 };
 use roslibrust_common::ServiceFn;
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Service clients kept alive for [roslibrust_common::ServiceProvider::call_service], keyed by service name.
+/// Values are always a `ServiceClient<T>` for the type the service was last called with.
+type ServiceClientCache = Arc<Mutex<HashMap<String, Box<dyn Any + Send + Sync>>>>;
 
 /// Represents a handle to an underlying Node. NodeHandle's can be freely cloned, moved, copied, etc.
 /// This class provides the user facing API for interacting with ROS.
@@ -11,6 +22,7 @@ use roslibrust_common::ServiceFn;
 #[derive(Clone)]
 pub struct NodeHandle {
     inner: NodeServerHandle,
+    pub(crate) service_clients: ServiceClientCache,
 }
 
 impl NodeHandle {
@@ -35,7 +47,10 @@ impl NodeHandle {
         let (addr, hostname) = super::determine_addr(master_uri).await?;
 
         let node = Node::new(master_uri, &hostname, &name, addr).await?;
-        let nh = NodeHandle { inner: node };
+        let nh = NodeHandle {
+            inner: node,
+            service_clients: ServiceClientCache::default(),
+        };
 
         Ok(nh)
     }
@@ -49,6 +64,7 @@ impl NodeHandle {
                 node_server_sender: self.inner.node_server_sender.clone(),
                 _node_task: None,
             },
+            service_clients: self.service_clients.clone(),
         }
     }
 
@@ -81,11 +97,11 @@ impl NodeHandle {
         queue_size: usize,
         latching: bool,
     ) -> Result<PublisherAny, NodeError> {
-        let (sender, shutdown) = self
+        let (sender, local, shutdown) = self
             .inner
             .register_publisher_any(topic_name, topic_type, msg_definition, queue_size, latching)
             .await?;
-        Ok(PublisherAny::new(topic_name, sender, shutdown))
+        Ok(PublisherAny::new(topic_name, sender, local, shutdown))
     }
 
     /// Create a new publisher for the given type.
@@ -101,11 +117,11 @@ impl NodeHandle {
         queue_size: usize,
         latching: bool,
     ) -> Result<Publisher<T>, NodeError> {
-        let (sender, shutdown) = self
+        let (sender, local, shutdown) = self
             .inner
             .register_publisher::<T>(topic_name, queue_size, latching)
             .await?;
-        Ok(Publisher::new(topic_name, sender, shutdown))
+        Ok(Publisher::new(topic_name, sender, local, shutdown))
     }
 
     /// Subscribe to a topic as a raw byte stream with no automatic deserialization.
@@ -121,10 +137,21 @@ impl NodeHandle {
         &self,
         topic_name: &str,
         queue_size: usize,
+    ) -> Result<SubscriberAny, NodeError> {
+        self.subscribe_any_with_options(topic_name, SubscribeOptions::new(queue_size))
+            .await
+    }
+
+    /// Same as [NodeHandle::subscribe_any], but with control over transports and buffering,
+    /// see [NodeHandle::subscribe_with_options].
+    pub async fn subscribe_any_with_options(
+        &self,
+        topic_name: &str,
+        options: SubscribeOptions,
     ) -> Result<SubscriberAny, NodeError> {
         let receiver = self
             .inner
-            .register_subscriber::<roslibrust_common::ShapeShifter>(topic_name, queue_size)
+            .register_subscriber::<roslibrust_common::ShapeShifter>(topic_name, options)
             .await?;
         Ok(SubscriberAny::new(receiver))
     }
@@ -139,10 +166,31 @@ impl NodeHandle {
         &self,
         topic_name: &str,
         queue_size: usize,
+    ) -> Result<Subscriber<T>, NodeError> {
+        self.subscribe_with_options(topic_name, SubscribeOptions::new(queue_size))
+            .await
+    }
+
+    /// Subscribe to a topic, choosing the transports (TCPROS / UDPROS), tcp_nodelay and size limits used to receive it.
+    ///
+    /// Like queue_size, the options of the FIRST subscription to a topic apply to all later subscriptions to it.
+    /// ```no_run
+    /// # async fn example(nh: roslibrust_ros1::NodeHandle) {
+    /// use roslibrust_ros1::{SubscribeOptions, TransportHints};
+    /// // Prefer UDP, but use low latency TCP for publishers which don't support UDPROS (e.g. rospy)
+    /// let hints = TransportHints::new().udp().tcp().tcp_nodelay(true);
+    /// let options = SubscribeOptions::new(10).transport_hints(hints);
+    /// let sub = nh.subscribe_with_options::<roslibrust_test::ros1::std_msgs::String>("/chatter", options).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn subscribe_with_options<T: roslibrust_common::RosMessageType>(
+        &self,
+        topic_name: &str,
+        options: SubscribeOptions,
     ) -> Result<Subscriber<T>, NodeError> {
         let receiver = self
             .inner
-            .register_subscriber::<T>(topic_name, queue_size)
+            .register_subscriber::<T>(topic_name, options)
             .await?;
         Ok(Subscriber::new(receiver))
     }
@@ -159,6 +207,95 @@ impl NodeHandle {
         Ok(sender)
     }
 
+    /// Returns a service client for the service which is shared between calls.
+    ///
+    /// The first call for a given service creates the client, after that its persistent connection is reused.
+    /// If the server goes away the client looks the service up again on its next call, and is dropped from the
+    /// cache if it can't be reached (see [NodeHandle::evict_service_client]).
+    pub(crate) async fn cached_service_client<T: roslibrust_common::RosServiceType>(
+        &self,
+        service_name: &str,
+    ) -> Result<ServiceClient<T>, NodeError> {
+        let cached = self
+            .service_clients
+            .lock()
+            .unwrap()
+            .get(service_name)
+            .and_then(|client| client.downcast_ref::<ServiceClient<T>>())
+            .cloned();
+        if let Some(client) = cached {
+            return Ok(client);
+        }
+
+        let client = self.service_client::<T>(service_name).await?;
+        self.service_clients
+            .lock()
+            .unwrap()
+            .insert(service_name.to_owned(), Box::new(client.clone()));
+        Ok(client)
+    }
+
+    /// Drops the cached client for the service, so the next call creates a new one.
+    /// Used once a client's connection is lost and can't be re-established, e.g. because the service is gone.
+    pub(crate) fn evict_service_client(&self, service_name: &str) {
+        self.service_clients.lock().unwrap().remove(service_name);
+    }
+
+    /// Waits until a provider of the service is registered with the ROS master.
+    ///
+    /// The master is polled every 100ms. Returns [NodeError::Timeout] if the service hasn't appeared
+    /// within `timeout`, passing `None` waits forever.
+    pub async fn wait_for_service(
+        &self,
+        service_name: &str,
+        timeout: Option<Duration>,
+    ) -> Result<(), NodeError> {
+        const POLL_PERIOD: Duration = Duration::from_millis(100);
+
+        let master_client = self.inner.get_master_client().await?;
+        let service_name = Name::new(service_name)?
+            .resolve_to_global(&Name::new(master_client.id())?)
+            .to_string();
+
+        let poll = async {
+            loop {
+                match master_client.lookup_service(&service_name).await {
+                    Ok(uri) => {
+                        log::debug!("Service {service_name} is available at {uri}");
+                        return;
+                    }
+                    Err(e) => {
+                        log::trace!("Service {service_name} not yet available: {e}");
+                    }
+                }
+                tokio::time::sleep(POLL_PERIOD).await;
+            }
+        };
+
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, poll).await.map_err(|_| {
+                NodeError::Timeout(format!(
+                    "Service {service_name} was not available within {timeout:?}"
+                ))
+            }),
+            None => {
+                poll.await;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the nodes, topics and services currently registered with the ROS master.
+    ///
+    /// Combines the master's `getSystemState` and `getTopicTypes`, service types aren't known to the
+    /// master and are left empty.
+    pub async fn get_graph(&self) -> Result<roslibrust_common::graph::Graph, NodeError> {
+        let master_client = self.inner.get_master_client().await?;
+        let state = master_client.get_system_state().await?;
+        let topic_types = master_client.get_topic_types().await?;
+        Ok(state.into_graph(topic_types))
+    }
+
     pub async fn advertise_service<T, F>(
         &self,
         service_name: &str,
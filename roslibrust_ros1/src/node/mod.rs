@@ -4,7 +4,7 @@
 use log::*;
 use roslibrust_common::Error;
 
-use super::{names::InvalidNameError, RosMasterError};
+use super::{names::InvalidNameError, tcpros::ConnectionHeader, RosMasterError};
 use std::{
     io,
     net::{IpAddr, Ipv4Addr},
@@ -24,6 +24,31 @@ pub struct ProtocolParams {
     pub hostname: String,
     pub protocol: String,
     pub port: u16,
+    /// Additional parameters only present when protocol is "UDPROS"
+    pub udp: Option<UdpProtocolParams>,
+}
+
+/// The extra fields a publisher responds with when a subscriber is accepted via UDPROS
+#[derive(Debug)]
+pub struct UdpProtocolParams {
+    pub connection_id: u32,
+    pub max_datagram_size: u32,
+    /// Connection header of the publisher, without the leading 4 byte length
+    pub header: Vec<u8>,
+}
+
+/// A single transport offered by a subscriber in a requestTopic call
+#[derive(Debug)]
+pub(crate) enum RequestedProtocol {
+    Tcpros,
+    Udpros {
+        header: ConnectionHeader,
+        hostname: String,
+        port: u16,
+        max_datagram_size: usize,
+    },
+    // Protocols we don't support, retained for logging
+    Other(String),
 }
 
 // TODO at the end of the day I'd like to offer a builder pattern for configuration that allow manual setting of this or "ros idiomatic" behavior - Carter
@@ -166,6 +191,8 @@ pub enum NodeError {
     XmlRpcError(#[from] XmlRpcError),
     #[error(transparent)]
     IoError(#[from] io::Error),
+    #[error("timed out: {0}")]
+    Timeout(String),
 }
 
 impl From<oneshot::error::RecvError> for NodeError {
@@ -195,6 +222,7 @@ impl From<NodeError> for Error {
             NodeError::InvalidName(e) => Error::InvalidName(e.to_string()),
             NodeError::XmlRpcError(e) => Error::SerializationError(e.to_string()),
             NodeError::IoError(e) => Error::IoError(e),
+            NodeError::Timeout(e) => Error::Timeout(e),
         }
     }
 }
@@ -1,4 +1,5 @@
-use super::NodeServerHandle;
+use super::{NodeServerHandle, RequestedProtocol};
+use crate::tcpros::ConnectionHeader;
 use abort_on_drop::ChildTask;
 use hyper::{Body, Response, StatusCode};
 use log::*;
@@ -187,18 +188,25 @@ impl XmlRpcServer {
             }
             "requestTopic" => {
                 debug!("requestTopic called by {args:?}");
-                let (caller_id, topic, protocols): (String, String, Vec<Vec<String>>) =
-                    serde_xmlrpc::from_values(args).map_err(|e| {
-                        Self::make_error_response(
-                            e,
+                let (caller_id, topic, protocols) = match &args[..] {
+                    [serde_xmlrpc::Value::String(caller_id), serde_xmlrpc::Value::String(topic), serde_xmlrpc::Value::Array(protocols)] => {
+                        (caller_id, topic, protocols)
+                    }
+                    _ => {
+                        return Err(Box::new(Self::make_error_response(
+                            std::io::Error::from(std::io::ErrorKind::InvalidInput),
                             "Failed to parse arguments to requestTopic",
                             StatusCode::BAD_REQUEST,
-                        )
-                    })?;
-                let protocols = protocols.iter().flatten().cloned().collect::<Vec<_>>();
+                        )))
+                    }
+                };
+                let protocols = protocols
+                    .iter()
+                    .map(parse_requested_protocol)
+                    .collect::<Vec<_>>();
                 debug!("Request for topic {topic} from {caller_id} via protocols {protocols:?}");
                 let params = node_server
-                    .request_topic(&topic, &protocols)
+                    .request_topic(topic, protocols)
                     .await
                     .map_err(|e| {
                         Self::make_error_response(
@@ -208,11 +216,27 @@ impl XmlRpcServer {
                         )
                     })?;
 
+                let value = match params.udp {
+                    // UDPROS responds with: [protocol, hostname, port, connection_id, max_datagram_size, header]
+                    Some(udp) => serde_xmlrpc::Value::Array(vec![
+                        params.protocol.into(),
+                        params.hostname.clone().into(),
+                        (params.port as i32).into(),
+                        (udp.connection_id as i32).into(),
+                        (udp.max_datagram_size as i32).into(),
+                        serde_xmlrpc::Value::Base64(udp.header),
+                    ]),
+                    None => serde_xmlrpc::to_value((
+                        params.protocol,
+                        params.hostname.clone(),
+                        params.port,
+                    ))
+                    .unwrap(),
+                };
                 let response = Self::make_success_response(
                     RosXmlStatusCode::Success,
-                    format!("ready on {}:{}", params.hostname.clone(), params.port).as_str(),
-                    serde_xmlrpc::to_value((params.protocol, params.hostname, params.port))
-                        .unwrap(),
+                    format!("ready on {}:{}", params.hostname, params.port).as_str(),
+                    value,
                 );
 
                 log::debug!("Sending response for requested topic {response:?}");
@@ -330,6 +354,37 @@ impl XmlRpcServer {
     }
 }
 
+/// Converts a single entry of the protocol list sent with requestTopic
+/// TCPROS is sent as just ["TCPROS"]
+/// UDPROS is sent as ["UDPROS", header, hostname, port, max_datagram_size]
+fn parse_requested_protocol(value: &serde_xmlrpc::Value) -> RequestedProtocol {
+    use serde_xmlrpc::Value;
+    let Value::Array(fields) = value else {
+        return RequestedProtocol::Other(format!("{value:?}"));
+    };
+    match &fields[..] {
+        [Value::String(name), ..] if name == "TCPROS" => RequestedProtocol::Tcpros,
+        [Value::String(name), Value::Base64(header), Value::String(hostname), Value::Int(port), Value::Int(max_datagram_size)]
+            if name == "UDPROS" =>
+        {
+            match ConnectionHeader::from_bytes(header) {
+                Ok(header) => RequestedProtocol::Udpros {
+                    header,
+                    hostname: hostname.clone(),
+                    port: *port as u16,
+                    max_datagram_size: *max_datagram_size as usize,
+                },
+                Err(e) => {
+                    warn!("Failed to parse connection header sent with UDPROS request: {e:?}");
+                    RequestedProtocol::Other(name.clone())
+                }
+            }
+        }
+        [Value::String(name), ..] => RequestedProtocol::Other(name.clone()),
+        _ => RequestedProtocol::Other(format!("{value:?}")),
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum XmlRpcError {
     #[error(transparent)]
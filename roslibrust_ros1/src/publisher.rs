@@ -1,17 +1,21 @@
 use crate::{
+    intra_process::{LocalPublication, SharedMessage},
     names::Name,
     tcpros::{self, ConnectionHeader},
+    udpros, UdpProtocolParams,
 };
 use abort_on_drop::ChildTask;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use log::*;
 use roslibrust_common::RosMessageType;
 use std::{
     marker::PhantomData,
     net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
 };
 use tokio::{
     io::AsyncWriteExt,
+    net::UdpSocket,
     sync::broadcast::{self, error::RecvError},
 };
 
@@ -24,6 +28,8 @@ pub struct Publisher<T> {
     // Actual channel on which messages are sent to be published
     // Uses Bytes for efficient cloning (reference counted) when there are multiple subscribers
     sender: broadcast::Sender<Bytes>,
+    // Delivers messages to subscribers within this node without going through TCP
+    local: LocalPublication,
     // When the last publisher for a given topic is dropped, this channel is used to signal to cleanup
     // for the underlying publication
     _shutdown_channel: tokio::sync::mpsc::Sender<()>,
@@ -35,39 +41,82 @@ impl<T: RosMessageType> Publisher<T> {
     pub(crate) fn new(
         topic_name: &str,
         sender: broadcast::Sender<Bytes>,
+        local: LocalPublication,
         shutdown_channel: tokio::sync::mpsc::Sender<()>,
     ) -> Self {
         Self {
             topic_name: topic_name.to_owned(),
             sender,
+            local,
             _shutdown_channel: shutdown_channel,
             phantom: PhantomData,
         }
     }
 
     /// Queues a message to be sent on the related topic.
+    ///
+    /// Subscribers within the same node receive a copy of the message without it being serialized,
+    /// see [Publisher::publish_shared] to avoid the copy as well.
     // TODO Major this no longer needs to be (or should be) async
     pub async fn publish(&self, data: &T) -> Result<(), PublisherError> {
-        let data = roslibrust_serde_rosmsg::to_vec(&data)?;
+        if self.local.has_subscribers() {
+            return self.publish_shared(Arc::new(data.clone())).await;
+        }
+        self.publish_serialized(data)
+    }
+
+    /// Queues a message to be sent on the related topic, handing the same allocation to every subscriber
+    /// of type T within this node.
+    ///
+    /// The message is only serialized if there are subscribers outside of this node (or the topic is latched).
+    // TODO Major this no longer needs to be (or should be) async
+    pub async fn publish_shared(&self, data: Arc<T>) -> Result<(), PublisherError> {
+        // The publication always holds one receiver itself to track the latched message
+        if self.sender.receiver_count() > 1 || self.local.is_latching() {
+            self.publish_serialized(&data)?;
+        } else if self.sender.receiver_count() == 0 {
+            return Err(PublisherError::StreamClosed);
+        }
+        self.local.send(SharedMessage::typed(data));
+        Ok(())
+    }
+
+    fn publish_serialized(&self, data: &T) -> Result<(), PublisherError> {
+        let data = serialize_message(data)?;
         // TODO this is a pretty dumb...
         // because of the internal channel used for re-direction this future doesn't
         // actually complete when the data is sent, but merely when it is queued to be sent
         // This function could probably be non-async
         // Or we should do some significant re-work to have it only yield when the data is sent.
         self.sender
-            .send(data.into())
+            .send(data)
             .map_err(|_| PublisherError::StreamClosed)?;
         debug!("Publishing data on topic {}", self.topic_name);
         Ok(())
     }
 }
 
+/// Serializes a message with its 4 byte length prepended, as it is sent over TCPROS
+/// Uses the message's generated wire codec when it has one, and serde otherwise
+pub(crate) fn serialize_message<T: RosMessageType>(msg: &T) -> Result<Bytes, PublisherError> {
+    let Some(codec) = T::WIRE_CODEC else {
+        return Ok(roslibrust_serde_rosmsg::to_vec(msg)?.into());
+    };
+    let mut buf = BytesMut::new();
+    buf.put_u32_le(0);
+    codec.encode_ros1_into(msg, &mut buf);
+    let body_len = (buf.len() - 4) as u32;
+    buf[..4].copy_from_slice(&body_len.to_le_bytes());
+    Ok(buf.freeze())
+}
+
 /// A specialty publisher used when message type is not known at compile time.
 ///
 /// Relies on user to provide serialized data. Typically used with playback from bag files.
 pub struct PublisherAny {
     topic_name: String,
     sender: broadcast::Sender<Bytes>,
+    local: LocalPublication,
     // When the last publisher for a given topic is dropped, this channel is used to signal to cleanup
     // Don't need to send a message, simply dropping the last handle lets to node know to clean up
     // Note: this has to be used because tokio::sync::broadcast doesn't have a WeakSender
@@ -79,11 +128,13 @@ impl PublisherAny {
     pub(crate) fn new(
         topic_name: &str,
         sender: broadcast::Sender<Bytes>,
+        local: LocalPublication,
         shutdown: tokio::sync::mpsc::Sender<()>,
     ) -> Self {
         Self {
             topic_name: topic_name.to_owned(),
             sender,
+            local,
             _shutdown: shutdown,
             phantom: PhantomData,
         }
@@ -108,11 +159,7 @@ impl PublisherAny {
         // This function could probably be non-async
         // Or we should do some significant re-work to have it only yield when the data is sent.
         let bytes = Bytes::copy_from_slice(data.as_ref());
-        self.sender
-            .send(bytes)
-            .map_err(|_| PublisherError::StreamClosed)?;
-        debug!("Publishing data on topic {}", self.topic_name);
-        Ok(())
+        self.publish_bytes(bytes).await
     }
 
     /// Queues a message to be sent on the related topic using pre-constructed Bytes.
@@ -121,6 +168,9 @@ impl PublisherAny {
     /// as it avoids any copying.
     // TODO this no longer needs to be (or should be) async
     pub async fn publish_bytes(&self, data: Bytes) -> Result<(), PublisherError> {
+        if self.local.has_subscribers() {
+            self.local.send(SharedMessage::Serialized(data.clone()));
+        }
         self.sender
             .send(data)
             .map_err(|_| PublisherError::StreamClosed)?;
@@ -129,11 +179,31 @@ impl PublisherAny {
     }
 }
 
+/// A UDPROS subscriber we're sending to, its task is aborted when the link is dropped
+struct UdpLink {
+    caller_id: String,
+    connection_id: u32,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for UdpLink {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
 pub(crate) struct Publication {
     topic_type: String,
     listener_port: u16,
+    host_addr: Ipv4Addr,
+    // Header we respond to subscribers with, kept to answer UDPROS requests which arrive via xmlrpc
+    responding_conn_header: ConnectionHeader,
     _tcp_accept_task: ChildTask<()>,
+    // One link per UDPROS subscriber, TCP subscribers are owned by the accept task
+    // The node drops links whose subscriber is no longer registered, see Node::check_udp_subscribers
+    udp_links: Vec<UdpLink>,
     publish_sender: broadcast::Sender<Bytes>,
+    local: LocalPublication,
     // We store a weak handle to the shutdown channel
     // This allows us to create new Publisher with a shutdown sender, but doesn't keep the shutdown channel alive
     // Had to add this because broadcast doesn't have a weak sender equivalent
@@ -159,18 +229,21 @@ impl Publication {
         (
             Self,
             broadcast::Sender<Bytes>,
+            LocalPublication,
             tokio::sync::mpsc::Sender<()>,
         ),
         std::io::Error,
     > {
         // Get a socket for receiving connections on
-        let host_addr = SocketAddr::from((host_addr, 0));
-        let tcp_listener = tokio::net::TcpListener::bind(host_addr).await?;
+        let bind_addr = SocketAddr::from((host_addr, 0));
+        let tcp_listener = tokio::net::TcpListener::bind(bind_addr).await?;
         let listener_port = tcp_listener.local_addr().unwrap().port();
 
         // Setup the channel will will receive messages to be published on
         // Using Bytes for efficient cloning (reference counted) when there are multiple subscribers
-        let (sender, receiver) = broadcast::channel::<Bytes>(queue_size);
+        // A broadcast channel needs room for at least one message, so a queue size of 0 is treated as 1
+        let (sender, receiver) = broadcast::channel::<Bytes>(queue_size.max(1));
+        let local = LocalPublication::new(queue_size, latching);
 
         // Setup the ROS connection header that we'll respond to all incoming connections with
         let responding_conn_header = ConnectionHeader {
@@ -192,14 +265,16 @@ impl Publication {
 
         // Create the task that will accept new TCP connections
         let topic_name_copy = topic_name.to_owned();
+        let responding_conn_header_copy = responding_conn_header.clone();
+        let accept_node_handle = node_handle.clone();
         let tcp_accept_handle = tokio::spawn(async move {
             Self::tcp_accept_task(
                 tcp_listener,
                 topic_name_copy,
-                responding_conn_header,
+                responding_conn_header_copy,
                 receiver,
                 shutdown_rx,
-                node_handle,
+                accept_node_handle,
             )
             .await
         });
@@ -209,24 +284,43 @@ impl Publication {
             Self {
                 topic_type: topic_type.to_owned(),
                 _tcp_accept_task: tcp_accept_handle.into(),
+                udp_links: vec![],
                 listener_port,
+                host_addr,
+                responding_conn_header,
                 publish_sender: sender,
+                local: local.clone(),
                 weak_shutdown_channel,
             },
             sender_copy,
+            local,
             shutdown_tx,
         ))
     }
 
     pub(crate) fn get_senders(
         &self,
-    ) -> (broadcast::Sender<Bytes>, tokio::sync::mpsc::WeakSender<()>) {
+    ) -> (
+        broadcast::Sender<Bytes>,
+        LocalPublication,
+        tokio::sync::mpsc::WeakSender<()>,
+    ) {
         (
             self.publish_sender.clone(),
+            self.local.clone(),
             self.weak_shutdown_channel.clone(),
         )
     }
 
+    /// The in-process side of this publication, used to connect subscriptions of the same node
+    pub(crate) fn local(&self) -> &LocalPublication {
+        &self.local
+    }
+
+    pub(crate) fn md5sum(&self) -> Option<&str> {
+        self.responding_conn_header.md5sum.as_deref()
+    }
+
     pub(crate) fn port(&self) -> u16 {
         self.listener_port
     }
@@ -235,6 +329,77 @@ impl Publication {
         &self.topic_type
     }
 
+    /// Starts sending to a subscriber which requested this topic via UDPROS.
+    /// Returns the local port datagrams are sent from and the parameters to respond to requestTopic with.
+    /// Note: latched messages are not re-sent to UDPROS subscribers.
+    pub(crate) async fn add_udp_subscriber(
+        &mut self,
+        subscriber_header: &ConnectionHeader,
+        subscriber_host: &str,
+        subscriber_port: u16,
+        max_datagram_size: usize,
+    ) -> Result<(u16, UdpProtocolParams), std::io::Error> {
+        if let (Some(requested), Some(local)) = (
+            &subscriber_header.md5sum,
+            &self.responding_conn_header.md5sum,
+        ) {
+            if requested != "*" && requested != local {
+                warn!(
+                    "Got UDPROS subscribe request for {:?}, but md5sums do not match. Expected {local:?}, received {requested:?}",
+                    subscriber_header.topic
+                );
+                return Err(std::io::ErrorKind::InvalidData.into());
+            }
+        }
+
+        let max_datagram_size = if max_datagram_size == 0 {
+            udpros::DEFAULT_MAX_DATAGRAM_SIZE
+        } else {
+            max_datagram_size
+        };
+        let socket = UdpSocket::bind(SocketAddr::from((self.host_addr, 0))).await?;
+        socket.connect((subscriber_host, subscriber_port)).await?;
+        let local_port = socket.local_addr()?.port();
+        let connection_id = udpros::next_connection_id();
+
+        let mut header = self.responding_conn_header.to_bytes(false)?;
+        // The header is sent as an xmlrpc binary value without the leading length
+        header.drain(..4);
+
+        let rx = self.publish_sender.subscribe();
+        let topic = self
+            .responding_conn_header
+            .topic
+            .clone()
+            .unwrap_or_default();
+        let caller_id = subscriber_header.caller_id.clone();
+        let task = tokio::spawn(Self::udp_publish_task(
+            rx,
+            socket,
+            connection_id,
+            max_datagram_size,
+            topic,
+        ));
+        // Links whose subscriber went away have ended on their own, and a subscriber requesting the topic again
+        // replaces its previous link
+        self.udp_links
+            .retain(|link| !link.task.is_finished() && link.caller_id != caller_id);
+        self.udp_links.push(UdpLink {
+            caller_id,
+            connection_id,
+            task,
+        });
+
+        Ok((
+            local_port,
+            UdpProtocolParams {
+                connection_id,
+                max_datagram_size: max_datagram_size as u32,
+                header,
+            },
+        ))
+    }
+
     /// Wraps the functionality that the publish task will perform
     /// this task is spawned by new, and canceled when the Publication is dropped
     /// This task constantly pulls new messages from the main publish buffer and
@@ -287,6 +452,69 @@ impl Publication {
         debug!("Publish task has exited for publication: {topic} connection to {peer:?}");
     }
 
+    /// The caller id and connection id of every UDPROS subscriber still being sent to
+    pub(crate) fn udp_subscribers(&mut self) -> Vec<(String, u32)> {
+        self.udp_links.retain(|link| !link.task.is_finished());
+        self.udp_links
+            .iter()
+            .map(|link| (link.caller_id.clone(), link.connection_id))
+            .collect()
+    }
+
+    /// Stops sending to the UDPROS subscriber with the given connection id, if it is still connected
+    pub(crate) fn remove_udp_subscriber(&mut self, connection_id: u32) {
+        self.udp_links
+            .retain(|link| link.connection_id != connection_id);
+    }
+
+    /// Sends every published message to a single UDPROS subscriber, fragmenting as needed.
+    ///
+    /// UDP has no connection to notice the subscriber going away by, so the task ends after a failed send or
+    /// is aborted once the node finds the subscriber is no longer registered with the master.
+    async fn udp_publish_task(
+        mut rx: broadcast::Receiver<Bytes>,
+        socket: UdpSocket,
+        connection_id: u32,
+        max_datagram_size: usize,
+        topic: String,
+    ) {
+        debug!("UDP publish task has started for publication: {topic} connection {connection_id}");
+        let mut msg_id: u8 = 0;
+        loop {
+            match rx.recv().await {
+                Ok(msg_to_publish) => {
+                    let datagrams = match udpros::fragment(
+                        connection_id,
+                        msg_id,
+                        &msg_to_publish[..],
+                        max_datagram_size,
+                    ) {
+                        Ok(datagrams) => datagrams,
+                        Err(e) => {
+                            error!("Unable to fragment message for UDPROS on {topic}: {e}");
+                            continue;
+                        }
+                    };
+                    msg_id = msg_id.wrapping_add(1);
+                    for datagram in datagrams {
+                        if let Err(err) = socket.send(&datagram).await {
+                            // Typically connection refused once the subscriber has gone away
+                            debug!("Failed to send datagram to subscriber: {err}, removing");
+                            return;
+                        }
+                    }
+                }
+                Err(RecvError::Lagged(num)) => {
+                    debug!("UDP connection {connection_id} is lagging behind, {num} messages were skipped");
+                }
+                Err(RecvError::Closed) => {
+                    debug!("No more senders for the publisher channel, ending task");
+                    break;
+                }
+            }
+        }
+    }
+
     /// Wraps the functionality that the tcp_accept task will perform
     /// This task is spawned by new, and canceled when the Publication is dropped
     /// This task constantly accepts new TCP connections and adds them to the list of streams to send data to.
@@ -366,6 +594,11 @@ impl Publication {
                 "Received subscribe request for {:?} with md5sum {:?}",
                 connection_header.topic, connection_header.md5sum
             );
+            if connection_header.tcp_nodelay {
+                if let Err(e) = stream.set_nodelay(true) {
+                    warn!("Unable to set tcp_nodelay requested by subscriber {peer_addr}: {e}");
+                }
+            }
             // I can't find documentation for this anywhere, but when using
             // `rostopic hz` with one of our publishers I discovered that the rospy code sent "*" as the md5sum
             // To indicate a "generic subscription"...
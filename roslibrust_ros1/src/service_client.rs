@@ -1,11 +1,12 @@
 use crate::{
     names::Name,
     tcpros::{establish_connection, ConnectionHeader},
+    MasterClient, NodeError,
 };
 use abort_on_drop::ChildTask;
 use bytes::Bytes;
 use roslibrust_common::{Error, RosServiceType};
-use std::{marker::PhantomData, sync::Arc};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::TcpStream,
@@ -13,26 +14,46 @@ use tokio::{
         mpsc::{self, UnboundedReceiver},
         oneshot,
     },
+    time::Instant,
 };
 
 use super::tcpros;
 
-pub type CallServiceRequest = (Vec<u8>, oneshot::Sender<CallServiceResponse>);
+/// Raw request bytes, the deadline for the call (if any), and where to send the result
+pub type CallServiceRequest = (
+    Vec<u8>,
+    Option<Instant>,
+    oneshot::Sender<CallServiceResponse>,
+);
 pub type CallServiceResponse = roslibrust_common::Result<Bytes>;
 
 // Note: ServiceClient is clone, and this is expressly different behavior than calling .service_client() twice on NodeHandle
 // clonning a ServiceClient does not create a new connection to the service, but instead creates a second handle to the
 // same underlying service client.
-#[derive(Clone)]
 pub struct ServiceClient<T: RosServiceType> {
     service_name: Name,
     sender: mpsc::UnboundedSender<CallServiceRequest>,
+    // Deadline applied to calls made with call(), None waits forever
+    timeout: Option<Duration>,
     _phantom: PhantomData<T>,
     // A given copy of a service client is actually just a handle to an underlying actor
     // When the last ServiceClient is dropped this will shut down the underlying actor and TCP connection
     _link: Arc<ServiceClientLink>,
 }
 
+// Implemented by hand as deriving would require T: Clone, which service types aren't
+impl<T: RosServiceType> Clone for ServiceClient<T> {
+    fn clone(&self) -> Self {
+        Self {
+            service_name: self.service_name.clone(),
+            sender: self.sender.clone(),
+            timeout: self.timeout,
+            _phantom: PhantomData,
+            _link: self._link.clone(),
+        }
+    }
+}
+
 impl<T: RosServiceType> ServiceClient<T> {
     pub(crate) fn new(
         service_name: &Name,
@@ -42,6 +63,7 @@ impl<T: RosServiceType> ServiceClient<T> {
         Self {
             service_name: service_name.to_owned(),
             sender,
+            timeout: None,
             _phantom: PhantomData,
             _link: Arc::new(link),
         }
@@ -51,16 +73,56 @@ impl<T: RosServiceType> ServiceClient<T> {
         &self.service_name
     }
 
+    /// Sets a timeout applied to every call made with [ServiceClient::call].
+    /// Calls which don't complete in time return [Error::Timeout].
+    /// Clones of this client made after this point share the timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     pub async fn call(&self, request: &T::Request) -> std::result::Result<T::Response, Error> {
+        self.call_inner(request, self.timeout).await
+    }
+
+    /// Same as [ServiceClient::call], but with a timeout for this call only that overrides any
+    /// timeout set with [ServiceClient::with_timeout].
+    pub async fn call_with_timeout(
+        &self,
+        request: &T::Request,
+        timeout: Duration,
+    ) -> std::result::Result<T::Response, Error> {
+        self.call_inner(request, Some(timeout)).await
+    }
+
+    async fn call_inner(
+        &self,
+        request: &T::Request,
+        timeout: Option<Duration>,
+    ) -> std::result::Result<T::Response, Error> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
         let request_payload = roslibrust_serde_rosmsg::to_vec(request)
             .map_err(|err| Error::SerializationError(err.to_string()))?;
         let (response_tx, response_rx) = oneshot::channel();
 
         self.sender
-            .send((request_payload, response_tx))
+            .send((request_payload, deadline, response_tx))
             .map_err(|_err| Error::Disconnected)?;
 
-        match response_rx.await {
+        let response = match deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline, response_rx)
+                .await
+                .map_err(|_elapsed| {
+                    Error::Timeout(format!(
+                        "Call to service {} did not complete in {:?}",
+                        self.service_name,
+                        timeout.unwrap_or_default()
+                    ))
+                })?,
+            None => response_rx.await,
+        };
+
+        match response {
             Ok(Ok(result_payload)) => {
                 log::trace!(
                     "Service client for {} got response: {:?}",
@@ -77,13 +139,43 @@ impl<T: RosServiceType> ServiceClient<T> {
     }
 }
 
+/// Why a call over a connection failed
+struct CallFailure {
+    error: std::io::Error,
+    // True if the server can't have seen the request: none of it was written, or the connection was closed before
+    // any of the response arrived (e.g. the server dropped an idle persistent connection)
+    retryable: bool,
+}
+
+impl CallFailure {
+    fn fatal(error: std::io::Error) -> Self {
+        Self {
+            error,
+            retryable: false,
+        }
+    }
+}
+
 pub struct ServiceClientLink {
     call_sender: mpsc::UnboundedSender<CallServiceRequest>,
     _actor_task: ChildTask<()>,
 }
 
+/// State owned by the actor of a [ServiceClientLink]
+/// Holds onto a persistent connection to the service server, and the information needed to find it again if it moves
+struct ServiceConnection {
+    master_client: MasterClient,
+    node_name: Name,
+    service_name: String,
+    service_uri: String,
+    header: ConnectionHeader,
+    // None when the previous connection was lost or abandoned mid-call
+    stream: Option<TcpStream>,
+}
+
 impl ServiceClientLink {
     pub async fn new(
+        master_client: MasterClient,
         node_name: &Name,
         service_name: &str,
         service_type: &str,
@@ -108,12 +200,21 @@ impl ServiceClientLink {
 
         let (call_tx, call_rx) = mpsc::unbounded_channel::<CallServiceRequest>();
 
-        let stream = establish_connection(node_name, service_name, service_uri, header).await.map_err(|err| {
+        let stream = establish_connection(node_name, service_name, service_uri, header.clone()).await.map_err(|err| {
             log::error!("Failed to establish connection to service URI {service_uri} for service {service_name}: {err}");
             Error::from(err)
         })?;
 
-        let actor_context = Self::actor_context(stream, service_name.to_owned(), call_rx);
+        let connection = ServiceConnection {
+            master_client,
+            node_name: node_name.to_owned(),
+            service_name: service_name.to_owned(),
+            service_uri: service_uri.to_owned(),
+            header,
+            stream: Some(stream),
+        };
+
+        let actor_context = Self::actor_context(connection, call_rx);
 
         let handle = tokio::spawn(actor_context);
 
@@ -128,30 +229,42 @@ impl ServiceClientLink {
     }
 
     async fn actor_context(
-        mut stream: TcpStream,
-        service_name: String,
+        mut connection: ServiceConnection,
         mut call_rx: UnboundedReceiver<CallServiceRequest>,
     ) {
         // Listen on a receiver for calls to forward to the service
         while let Some(request) = call_rx.recv().await {
-            Self::handle_service_call(&mut stream, &service_name, request).await
+            Self::handle_service_call(&mut connection, request).await
         }
     }
 
     /// Infallible version of handle_service_call that regardless of what occurs
-    /// Sends the response back on the response channel, delegates work to handle_service_call_fallible
+    /// Sends the response back on the response channel, delegates work to ServiceConnection::call
     async fn handle_service_call(
-        stream: &mut TcpStream,
-        service_name: &str,
-        (request, response_sender): CallServiceRequest,
+        connection: &mut ServiceConnection,
+        (request, deadline, response_sender): CallServiceRequest,
     ) {
-        let response = Self::handle_service_call_fallible(stream, request).await;
-        let response: roslibrust_common::Result<Bytes> = response.map_err(|err| {
+        let service_name = connection.service_name.clone();
+        let response = match deadline {
+            // The caller has already given up waiting, the request shouldn't reach the server
+            Some(deadline) if deadline <= Instant::now() => Err(Error::Timeout(format!(
+                "Call to service {service_name} timed out before it was sent"
+            ))),
+            Some(deadline) => {
+                match tokio::time::timeout_at(deadline, connection.call(&request)).await {
+                    Ok(response) => response,
+                    Err(_elapsed) => Err(Error::Timeout(format!(
+                        "Call to service {service_name} timed out"
+                    ))),
+                }
+            }
+            None => connection.call(&request).await,
+        };
+        if let Err(err) = &response {
             log::error!(
                 "Failed to send and receive service call for service {service_name}: {err:?}"
             );
-            Error::from(err)
-        });
+        }
         let send_result = response_sender.send(response);
         if let Err(_err) = send_result {
             log::error!("Failed to send service call result back to handle for service {service_name}, channel closed");
@@ -161,47 +274,142 @@ impl ServiceClientLink {
     /// Helper function for calling a service
     /// Send the raw bytes of the request out
     /// Receives the full raw bytes of the response and returns them if nothing goes wrong
+    /// The inner result holds the error message if the server reported a failure
     async fn handle_service_call_fallible(
         stream: &mut TcpStream,
-        request: Vec<u8>,
-    ) -> Result<Bytes, std::io::Error> {
-        // Send the bytes of the request to the service
-        stream.write_all(&request).await?;
+        request: &[u8],
+    ) -> Result<Result<Bytes, String>, CallFailure> {
+        // Send the bytes of the request to the service, keeping track of whether any of it went out
+        let mut written = 0;
+        while written < request.len() {
+            match stream.write(&request[written..]).await {
+                Ok(0) => {
+                    return Err(CallFailure {
+                        error: std::io::ErrorKind::WriteZero.into(),
+                        retryable: written == 0,
+                    })
+                }
+                Ok(count) => written += count,
+                Err(error) => {
+                    return Err(CallFailure {
+                        error,
+                        retryable: written == 0,
+                    })
+                }
+            }
+        }
 
         // Service calls magically have an extra byte in the TCPROS spec that indicates success/failure
         let mut success_byte = [0u8; 1];
-        let _success_byte_read = stream.read_exact(&mut success_byte).await?;
+        if let Err(error) = stream.read_exact(&mut success_byte).await {
+            let retryable = error.kind() == std::io::ErrorKind::UnexpectedEof;
+            return Err(CallFailure { error, retryable });
+        }
         if success_byte[0] != 1 && success_byte[0] != 0 {
             log::error!(
                 "Invalid service call success byte {}, value should be 1 or 0",
                 success_byte[0]
             );
-            return Err(std::io::Error::new(
+            return Err(CallFailure::fatal(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
                 "Invalid service call success byte",
-            ));
+            )));
         }
         let success = success_byte[0] == 1;
 
         if success {
             // Parse length of the payload body
-            let body = tcpros::receive_body(stream).await?;
-            Ok(body)
+            let body = tcpros::receive_body(stream)
+                .await
+                .map_err(CallFailure::fatal)?;
+            Ok(Ok(body))
         } else {
             // Parse an error message as the body
-            let error_body = tcpros::receive_body(stream).await?;
+            let error_body = tcpros::receive_body(stream)
+                .await
+                .map_err(CallFailure::fatal)?;
             let err_msg: String =
                 roslibrust_serde_rosmsg::from_slice(&error_body).map_err(|err| {
                     log::error!("Failed to parse service call error message: {err}");
-                    std::io::Error::new(
+                    CallFailure::fatal(std::io::Error::new(
                         std::io::ErrorKind::InvalidData,
                         "Failed to parse service call error message",
-                    )
+                    ))
                 })?;
-            // TODO probably specific error type for this
-            Err(std::io::Error::other(format!(
-                "Failure response from service server: {err_msg}"
-            )))
+            Ok(Err(err_msg))
+        }
+    }
+}
+
+impl ServiceConnection {
+    /// Performs a single call, reconnecting if the persistent connection has gone away.
+    ///
+    /// If a call over an existing connection fails before the server could have received the request, the service
+    /// is looked up again with the master and the call is retried once over a new connection. This handles servers
+    /// which have restarted or moved. Other failures are returned, as the service may already have run.
+    async fn call(&mut self, request: &[u8]) -> roslibrust_common::Result<Bytes> {
+        let reused = self.stream.is_some();
+        match self.call_once(request).await {
+            Err(failure) if reused && failure.retryable => {
+                log::warn!(
+                    "Persistent connection to service {} failed: {}, reconnecting",
+                    self.service_name,
+                    failure.error
+                );
+                self.call_once(request).await
+            }
+            result => result,
+        }
+        .map_err(|failure| Error::from(failure.error))?
+        .map_err(|err_msg| {
+            Error::ServerError(format!("Failure response from service server: {err_msg}"))
+        })
+    }
+
+    async fn call_once(&mut self, request: &[u8]) -> Result<Result<Bytes, String>, CallFailure> {
+        // The stream is only put back once a call fully completes, so a failed or abandoned call
+        // leaves no connection in an unknown state behind
+        let mut stream = match self.stream.take() {
+            Some(stream) => stream,
+            None => self.reconnect().await.map_err(CallFailure::fatal)?,
+        };
+        let result = ServiceClientLink::handle_service_call_fallible(&mut stream, request).await;
+        if result.is_ok() {
+            self.stream = Some(stream);
+        }
+        result
+    }
+
+    /// Looks the service up with the master again and connects to wherever it is now hosted
+    async fn reconnect(&mut self) -> Result<TcpStream, std::io::Error> {
+        match self.master_client.lookup_service(&self.service_name).await {
+            Ok(service_uri) => {
+                if service_uri != self.service_uri {
+                    log::debug!(
+                        "Service {} moved from {} to {service_uri}",
+                        self.service_name,
+                        self.service_uri
+                    );
+                    self.service_uri = service_uri;
+                }
+            }
+            Err(err) => {
+                log::error!(
+                    "Failed to look up service {} while reconnecting: {err}",
+                    self.service_name
+                );
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::NotConnected,
+                    NodeError::from(err).to_string(),
+                ));
+            }
         }
+        establish_connection(
+            &self.node_name,
+            &self.service_name,
+            &self.service_uri,
+            self.header.clone(),
+        )
+        .await
     }
 }
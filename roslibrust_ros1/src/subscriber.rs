@@ -1,12 +1,13 @@
-use crate::{names::Name, tcpros::ConnectionHeader};
+use crate::{intra_process::SharedMessage, names::Name, tcpros::ConnectionHeader, udpros};
 use abort_on_drop::ChildTask;
 use bytes::Bytes;
 use log::*;
+use roslibrust_common::codec::{BorrowedMessage, HasBorrowed};
 use roslibrust_common::{RosMessageType, ShapeShifter};
 use std::{collections::HashMap, marker::PhantomData, sync::Arc, time::Duration};
 use tokio::{
     io::AsyncWriteExt,
-    net::TcpStream,
+    net::{TcpStream, UdpSocket},
     sync::{
         broadcast::{self, error::RecvError},
         RwLock,
@@ -17,13 +18,13 @@ use tokio_util::sync::CancellationToken;
 use super::tcpros;
 
 pub struct Subscriber<T> {
-    // Uses Bytes for efficient cloning (reference counted) when there are multiple subscribers
-    receiver: broadcast::Receiver<Bytes>,
+    // Messages are reference counted for efficient cloning when there are multiple subscribers
+    receiver: broadcast::Receiver<SharedMessage>,
     _phantom: PhantomData<T>,
 }
 
 impl<T: RosMessageType> Subscriber<T> {
-    pub(crate) fn new(receiver: broadcast::Receiver<Bytes>) -> Self {
+    pub(crate) fn new(receiver: broadcast::Receiver<SharedMessage>) -> Self {
         Self {
             receiver,
             _phantom: PhantomData,
@@ -31,6 +32,14 @@ impl<T: RosMessageType> Subscriber<T> {
     }
 
     pub async fn next(&mut self) -> Option<Result<T, SubscriberError>> {
+        Some(self.next_shared().await?.map(Arc::unwrap_or_clone))
+    }
+
+    /// Like [Subscriber::next], but returns the message behind an Arc.
+    ///
+    /// Messages published as a T by the same node are received without being serialized or copied,
+    /// every local subscriber shares the publisher's allocation.
+    pub async fn next_shared(&mut self) -> Option<Result<Arc<T>, SubscriberError>> {
         trace!("Subscriber of type {:?} awaiting recv()", T::ROS_TYPE_NAME);
         let data = match self.receiver.recv().await {
             Ok(v) => {
@@ -45,7 +54,7 @@ impl<T: RosMessageType> Subscriber<T> {
             T::ROS_TYPE_NAME
         );
         let tick = tokio::time::Instant::now();
-        match roslibrust_serde_rosmsg::from_slice::<T>(&data[..]) {
+        match data.to_message::<T>() {
             Ok(p) => {
                 let duration = tick.elapsed();
                 trace!(
@@ -54,19 +63,48 @@ impl<T: RosMessageType> Subscriber<T> {
                 );
                 Some(Ok(p))
             }
-            Err(e) => Some(Err(e.into())),
+            Err(e) => Some(Err(e)),
         }
     }
 }
 
+impl<T: HasBorrowed> Subscriber<T> {
+    /// Like [Subscriber::next], but returns the message in the buffer it was received in.
+    ///
+    /// The message is decoded into its generated borrowed variant, see [BorrowedMessage::get], whose
+    /// strings and byte sequences point into that buffer instead of being copied out of it.
+    /// Messages published by the same node are serialized first.
+    pub async fn next_borrowed(&mut self) -> Option<Result<BorrowedMessage<T>, SubscriberError>> {
+        let data = match self.receiver.recv().await {
+            Ok(v) => v,
+            Err(RecvError::Closed) => return None,
+            Err(RecvError::Lagged(n)) => return Some(Err(SubscriberError::Lagged(n))),
+        };
+        let bytes = match data.to_bytes() {
+            Ok(bytes) => bytes,
+            Err(e) => return Some(Err(e)),
+        };
+        if bytes.len() < 4 {
+            return Some(Err(SubscriberError::DeserializeError(
+                "message is missing its length prefix".to_owned(),
+            )));
+        }
+        // Slicing off the length prefix shares the buffer rather than copying it
+        Some(
+            BorrowedMessage::from_ros1(bytes.slice(4..))
+                .map_err(|e| SubscriberError::DeserializeError(e.to_string())),
+        )
+    }
+}
+
 pub struct SubscriberAny {
-    // Uses Bytes for efficient cloning (reference counted) when there are multiple subscribers
-    receiver: broadcast::Receiver<Bytes>,
+    // Messages are reference counted for efficient cloning when there are multiple subscribers
+    receiver: broadcast::Receiver<SharedMessage>,
     _phantom: PhantomData<ShapeShifter>,
 }
 
 impl SubscriberAny {
-    pub(crate) fn new(receiver: broadcast::Receiver<Bytes>) -> Self {
+    pub(crate) fn new(receiver: broadcast::Receiver<SharedMessage>) -> Self {
         Self {
             receiver,
             _phantom: PhantomData,
@@ -84,7 +122,170 @@ impl SubscriberAny {
             Err(RecvError::Closed) => return None,
             Err(RecvError::Lagged(n)) => return Some(Err(SubscriberError::Lagged(n))),
         };
-        Some(Ok(data))
+        Some(data.to_bytes())
+    }
+}
+
+/// A transport a subscriber can receive messages over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Tcp,
+    Udp,
+}
+
+/// Describes which transports a subscriber is willing to use, mirroring roscpp's `ros::TransportHints`.
+///
+/// Transports are requested from publishers in the order they were added, so
+/// `TransportHints::new().udp().tcp()` prefers UDPROS and falls back to TCPROS for publishers that don't support it.
+/// If no transport is added, TCPROS is used.
+#[derive(Debug, Clone)]
+pub struct TransportHints {
+    transports: Vec<Transport>,
+    max_datagram_size: usize,
+    tcp_nodelay: bool,
+}
+
+impl Default for TransportHints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransportHints {
+    pub fn new() -> Self {
+        Self {
+            transports: vec![],
+            max_datagram_size: udpros::DEFAULT_MAX_DATAGRAM_SIZE,
+            tcp_nodelay: false,
+        }
+    }
+
+    /// Requests that TCPROS connections disable Nagle's algorithm, trading bandwidth for latency on small messages.
+    /// This is sent to publishers in the connection header (`tcp_nodelay=1`) and applied on both ends of the socket.
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp_nodelay = nodelay;
+        self
+    }
+
+    /// Adds TCPROS as the next preferred transport
+    pub fn tcp(mut self) -> Self {
+        if !self.transports.contains(&Transport::Tcp) {
+            self.transports.push(Transport::Tcp);
+        }
+        self
+    }
+
+    /// Adds UDPROS as the next preferred transport
+    pub fn udp(mut self) -> Self {
+        if !self.transports.contains(&Transport::Udp) {
+            self.transports.push(Transport::Udp);
+        }
+        self
+    }
+
+    /// Largest datagram publishers should send when using UDPROS, defaults to 1500 bytes
+    pub fn max_datagram_size(mut self, size: usize) -> Self {
+        self.max_datagram_size = size;
+        self
+    }
+
+    /// The transports to request in order of preference
+    pub fn transports(&self) -> &[Transport] {
+        if self.transports.is_empty() {
+            &[Transport::Tcp]
+        } else {
+            &self.transports
+        }
+    }
+}
+
+/// Options for creating a subscription with [crate::NodeHandle::subscribe_with_options].
+///
+/// Like roscpp, the options of the FIRST subscriber to a topic within a node apply to the underlying subscription,
+/// later subscribers to the same topic share it.
+#[derive(Debug, Clone)]
+pub struct SubscribeOptions {
+    queue_size: usize,
+    transport_hints: TransportHints,
+    max_message_size: Option<usize>,
+}
+
+impl SubscribeOptions {
+    pub fn new(queue_size: usize) -> Self {
+        Self {
+            queue_size,
+            transport_hints: TransportHints::default(),
+            max_message_size: None,
+        }
+    }
+
+    /// Number of messages buffered for each subscriber before older ones are dropped
+    pub fn queue_size(mut self, queue_size: usize) -> Self {
+        self.queue_size = queue_size;
+        self
+    }
+
+    pub fn transport_hints(mut self, transport_hints: TransportHints) -> Self {
+        self.transport_hints = transport_hints;
+        self
+    }
+
+    /// Messages with a serialized body larger than this are dropped without being buffered
+    pub fn max_message_size(mut self, size: usize) -> Self {
+        self.max_message_size = Some(size);
+        self
+    }
+}
+
+/// An established connection to a single publisher
+enum PublisherLink {
+    Tcp(TcpStream),
+    Udp {
+        socket: UdpSocket,
+        reassembler: udpros::Reassembler,
+        buffer: Vec<u8>,
+    },
+}
+
+impl PublisherLink {
+    /// Reads the next complete message from the publisher, skipping messages with a body over `max_message_size`.
+    /// The returned Bytes includes the length of the body at the front as serde_rosmsg expects.
+    async fn next_message(
+        &mut self,
+        max_message_size: Option<usize>,
+    ) -> Result<Bytes, std::io::Error> {
+        let max_message_size = max_message_size.unwrap_or(usize::MAX);
+        loop {
+            let msg = match self {
+                PublisherLink::Tcp(stream) => {
+                    tcpros::receive_body_up_to(stream, max_message_size).await?
+                }
+                PublisherLink::Udp {
+                    socket,
+                    reassembler,
+                    buffer,
+                } => {
+                    let (len, _peer) = socket.recv_from(buffer).await?;
+                    // Over UDP the size is only known once all blocks of a message have arrived
+                    match reassembler.push(&buffer[..len]) {
+                        // Every message starts with its 4 byte length, anything shorter is malformed
+                        Some(msg) if msg.len() < 4 => {
+                            warn!("Dropped malformed UDPROS message of {} bytes", msg.len());
+                            continue;
+                        }
+                        Some(msg) if msg.len() - 4 > max_message_size => None,
+                        Some(msg) => Some(msg),
+                        None => continue,
+                    }
+                }
+            };
+            match msg {
+                Some(msg) => return Ok(msg),
+                None => warn!(
+                    "Dropped message larger than the max message size of {max_message_size} bytes"
+                ),
+            }
+        }
     }
 }
 
@@ -108,9 +309,12 @@ struct PublisherConnectionState {
 
 pub struct Subscription {
     subscription_tasks: Vec<ChildTask<()>>,
-    // Uses Bytes for efficient cloning (reference counted) when there are multiple subscribers
-    _msg_receiver: broadcast::Receiver<Bytes>,
-    msg_sender: broadcast::Sender<Bytes>,
+    // Hostname of this node, sent to publishers so they know where to send UDPROS datagrams
+    hostname: String,
+    options: SubscribeOptions,
+    // Messages are reference counted for efficient cloning when there are multiple subscribers
+    _msg_receiver: broadcast::Receiver<SharedMessage>,
+    msg_sender: broadcast::Sender<SharedMessage>,
     connection_header: ConnectionHeader,
     /// Shared state tracking all publisher connections
     publisher_state: Arc<RwLock<PublisherConnectionState>>,
@@ -119,14 +323,15 @@ pub struct Subscription {
 impl Subscription {
     pub fn new(
         node_name: &Name,
+        hostname: &str,
         topic_name: &str,
         topic_type: &str,
-        queue_size: usize,
         msg_definition: String,
         md5sum: String,
+        options: SubscribeOptions,
     ) -> Self {
-        // Using Bytes for efficient cloning (reference counted) when there are multiple subscribers
-        let (sender, receiver) = broadcast::channel::<Bytes>(queue_size);
+        // A broadcast channel needs room for at least one message, so a queue size of 0 is treated as 1
+        let (sender, receiver) = broadcast::channel::<SharedMessage>(options.queue_size.max(1));
         let connection_header = ConnectionHeader {
             caller_id: node_name.to_string(),
             latching: false,
@@ -134,13 +339,15 @@ impl Subscription {
             md5sum: Some(md5sum),
             topic: Some(topic_name.to_owned()),
             topic_type: topic_type.to_owned(),
-            tcp_nodelay: false,
+            tcp_nodelay: options.transport_hints.tcp_nodelay,
             service: None,
             persistent: None,
         };
 
         Self {
             subscription_tasks: vec![],
+            hostname: hostname.to_owned(),
+            options,
             _msg_receiver: receiver,
             msg_sender: sender,
             connection_header,
@@ -154,10 +361,45 @@ impl Subscription {
         self.connection_header.topic_type.as_str()
     }
 
-    pub fn get_receiver(&self) -> broadcast::Receiver<Bytes> {
+    pub fn get_receiver(&self) -> broadcast::Receiver<SharedMessage> {
         self.msg_sender.subscribe()
     }
 
+    pub(crate) fn md5sum(&self) -> Option<&str> {
+        self.connection_header.md5sum.as_deref()
+    }
+
+    /// Feeds messages from a publication of this same node into the subscription.
+    /// The subscription should not also connect to this node over TCP, or every message would arrive twice.
+    pub(crate) fn add_local_publisher(
+        &mut self,
+        (mut receiver, latched): (broadcast::Receiver<SharedMessage>, Option<SharedMessage>),
+    ) {
+        let sender = self.msg_sender.clone();
+        let topic_name = self.connection_header.topic.clone().unwrap_or_default();
+        let handle = tokio::spawn(async move {
+            debug!("Subscription to {topic_name} is receiving from a publisher within this node");
+            if let Some(msg) = latched {
+                let _ = sender.send(msg);
+            }
+            loop {
+                match receiver.recv().await {
+                    Ok(msg) => {
+                        if sender.send(msg).is_err() {
+                            break;
+                        }
+                    }
+                    Err(RecvError::Lagged(num)) => {
+                        debug!("Local subscription to {topic_name} is lagging behind, {num} messages were skipped");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+            debug!("Publisher within this node for {topic_name} has gone away");
+        });
+        self.subscription_tasks.push(handle.into());
+    }
+
     pub async fn add_publisher_source(
         &mut self,
         publisher_uri: &str,
@@ -194,6 +436,8 @@ impl Subscription {
             let sender = self.msg_sender.clone();
             let publisher_state = self.publisher_state.clone();
             let publisher_uri = publisher_uri.to_owned();
+            let hostname = self.hostname.clone();
+            let options = self.options.clone();
 
             trace!("Creating new subscription connection for {publisher_uri} on {topic_name}");
 
@@ -205,6 +449,8 @@ impl Subscription {
                     node_name,
                     topic_name,
                     connection_header,
+                    hostname,
+                    options,
                     sender,
                 )
                 .await;
@@ -241,6 +487,7 @@ impl Subscription {
 
 /// The main reader task for a publisher connection.
 /// Handles connection establishment, reading messages, and retry with exponential backoff.
+#[allow(clippy::too_many_arguments)]
 async fn publisher_reader_task(
     cancel_token: CancellationToken,
     publisher_state: Arc<RwLock<PublisherConnectionState>>,
@@ -248,7 +495,9 @@ async fn publisher_reader_task(
     node_name: String,
     topic_name: String,
     conn_header: ConnectionHeader,
-    sender: broadcast::Sender<Bytes>,
+    hostname: String,
+    options: SubscribeOptions,
+    sender: broadcast::Sender<SharedMessage>,
 ) {
     let mut retry_period = INITIAL_RETRY_PERIOD;
     let mut tcp_endpoint: Option<String> = None;
@@ -263,12 +512,14 @@ async fn publisher_reader_task(
         }
 
         // Attempt to establish or re-establish connection
-        let stream_result = if let Some(ref endpoint) = tcp_endpoint {
+        let link_result = if let Some(ref endpoint) = tcp_endpoint {
             // Retry: connect directly to cached endpoint (skip XMLRPC)
             log::debug!("Retrying direct connection to {endpoint} for topic {topic_name}");
-            connect_and_handshake(endpoint, &conn_header, &topic_name).await
+            connect_and_handshake(endpoint, &conn_header, &topic_name)
+                .await
+                .map(PublisherLink::Tcp)
         } else {
-            // First connection: go through XMLRPC to get the TCP endpoint
+            // First connection: go through XMLRPC to negotiate a transport
             log::debug!(
                 "Establishing initial connection to {publisher_uri} for topic {topic_name}"
             );
@@ -276,31 +527,35 @@ async fn publisher_reader_task(
                 &node_name,
                 &topic_name,
                 &publisher_uri,
-                conn_header.clone(),
+                &conn_header,
+                &hostname,
+                &options.transport_hints,
             )
             .await
             {
-                Ok((stream, endpoint)) => {
-                    // Cache the endpoint for future reconnections
-                    tcp_endpoint = Some(endpoint.clone());
-
-                    // Update shared state with the TCP endpoint
-                    if let Some(conn) = publisher_state
-                        .write()
-                        .await
-                        .connections
-                        .get_mut(&publisher_uri)
-                    {
-                        conn.tcp_endpoint = Some(endpoint);
+                Ok((link, endpoint)) => {
+                    // Cache the endpoint for future reconnections, only TCP links have one
+                    if let Some(endpoint) = endpoint {
+                        tcp_endpoint = Some(endpoint.clone());
+
+                        // Update shared state with the TCP endpoint
+                        if let Some(conn) = publisher_state
+                            .write()
+                            .await
+                            .connections
+                            .get_mut(&publisher_uri)
+                        {
+                            conn.tcp_endpoint = Some(endpoint);
+                        }
                     }
 
-                    Ok(stream)
+                    Ok(link)
                 }
                 Err(e) => Err(e),
             }
         };
 
-        let mut stream = match stream_result {
+        let mut link = match link_result {
             Ok(s) => {
                 log::info!("Connected to publisher {publisher_uri} for topic {topic_name}");
                 retry_period = INITIAL_RETRY_PERIOD; // Reset backoff on successful connection
@@ -335,13 +590,13 @@ async fn publisher_reader_task(
                     log::debug!("Publisher reader task for {publisher_uri} cancelled during read");
                     break 'connection_loop;
                 }
-                result = tcpros::receive_body(&mut stream) => {
+                result = link.next_message(options.max_message_size) => {
                     match result {
                         Ok(body) => {
                             trace!(
                                 "Subscription to {topic_name} received message from {publisher_uri}"
                             );
-                            if sender.send(body).is_err() {
+                            if sender.send(SharedMessage::Serialized(body)).is_err() {
                                 log::error!(
                                     "Unable to send message data due to dropped channel, closing connection to {publisher_uri}"
                                 );
@@ -388,16 +643,68 @@ async fn publisher_reader_task(
 }
 
 /// Establishes a connection to a publisher via XMLRPC negotiation.
-/// Returns both the TcpStream and the TCP endpoint string for potential reconnection.
+/// Returns the link and, for TCPROS, the TCP endpoint string for potential reconnection.
 async fn establish_publisher_connection(
     node_name: &str,
     topic_name: &str,
     publisher_uri: &str,
-    conn_header: ConnectionHeader,
-) -> Result<(TcpStream, String), std::io::Error> {
-    let tcp_endpoint = send_topic_request(node_name, topic_name, publisher_uri).await?;
-    let stream = connect_and_handshake(&tcp_endpoint, &conn_header, topic_name).await?;
-    Ok((stream, tcp_endpoint))
+    conn_header: &ConnectionHeader,
+    hostname: &str,
+    transport_hints: &TransportHints,
+) -> Result<(PublisherLink, Option<String>), std::io::Error> {
+    // The UDP socket must exist before requesting the topic, as its port is part of the request
+    let mut udp_socket = None;
+    let mut protocols = vec![];
+    for transport in transport_hints.transports() {
+        match transport {
+            Transport::Tcp => {
+                protocols.push(serde_xmlrpc::Value::Array(vec!["TCPROS".into()]));
+            }
+            Transport::Udp => {
+                let socket = UdpSocket::bind("0.0.0.0:0").await?;
+                let port = socket.local_addr()?.port();
+                let mut header = conn_header.to_bytes(true)?;
+                // The header is sent as an xmlrpc binary value without the leading length
+                header.drain(..4);
+                protocols.push(serde_xmlrpc::Value::Array(vec![
+                    "UDPROS".into(),
+                    serde_xmlrpc::Value::Base64(header),
+                    hostname.into(),
+                    (port as i32).into(),
+                    (transport_hints.max_datagram_size as i32).into(),
+                ]));
+                udp_socket = Some(socket);
+            }
+        }
+    }
+
+    match send_topic_request(node_name, topic_name, publisher_uri, protocols).await? {
+        TopicEndpoint::Tcp(tcp_endpoint) => {
+            let stream = connect_and_handshake(&tcp_endpoint, conn_header, topic_name).await?;
+            Ok((PublisherLink::Tcp(stream), Some(tcp_endpoint)))
+        }
+        TopicEndpoint::Udp {
+            connection_id,
+            max_datagram_size,
+            header,
+        } => {
+            let Some(socket) = udp_socket else {
+                log::error!("Publisher responded with UDPROS which was not requested");
+                return Err(std::io::ErrorKind::Unsupported.into());
+            };
+            let responded_header = ConnectionHeader::from_bytes(&header)?;
+            check_md5sum(conn_header, &responded_header, topic_name)?;
+            log::debug!("Established UDPROS connection {connection_id} for {topic_name}");
+            Ok((
+                PublisherLink::Udp {
+                    socket,
+                    reassembler: udpros::Reassembler::new(connection_id),
+                    buffer: vec![0u8; max_datagram_size.max(udpros::DEFAULT_MAX_DATAGRAM_SIZE)],
+                },
+                None,
+            ))
+        }
+    }
 }
 
 /// Connects directly to a TCP endpoint and performs the TCPROS handshake.
@@ -408,6 +715,9 @@ async fn connect_and_handshake(
     topic_name: &str,
 ) -> Result<TcpStream, std::io::Error> {
     let mut stream = TcpStream::connect(tcp_endpoint).await?;
+    if conn_header.tcp_nodelay {
+        stream.set_nodelay(true)?;
+    }
 
     let conn_header_bytes = conn_header.to_bytes(true)?;
     stream.write_all(&conn_header_bytes[..]).await?;
@@ -426,6 +736,16 @@ async fn connect_and_handshake(
         }
     };
 
+    check_md5sum(conn_header, &responded_header, topic_name)?;
+    Ok(stream)
+}
+
+/// Confirms the publisher's header is compatible with ours, "*" matches anything
+fn check_md5sum(
+    conn_header: &ConnectionHeader,
+    responded_header: &ConnectionHeader,
+    topic_name: &str,
+) -> Result<(), std::io::Error> {
     if conn_header.md5sum == Some("*".to_string())
         || responded_header.md5sum == Some("*".to_string())
         || conn_header.md5sum == responded_header.md5sum
@@ -434,7 +754,7 @@ async fn connect_and_handshake(
             "Established connection with publisher for {:?}",
             conn_header.topic
         );
-        Ok(stream)
+        Ok(())
     } else {
         log::error!(
             "Tried to subscribe to {}, but md5sums do not match. Expected {:?}, received {:?}",
@@ -442,24 +762,34 @@ async fn connect_and_handshake(
             conn_header.md5sum,
             responded_header.md5sum
         );
-        Err(std::io::ErrorKind::InvalidData)
+        Err(std::io::ErrorKind::InvalidData.into())
     }
-    .map_err(std::io::Error::from)
+}
+
+/// Where a publisher told us to receive a topic from in response to requestTopic
+enum TopicEndpoint {
+    /// "host:port" to connect to
+    Tcp(String),
+    Udp {
+        connection_id: u32,
+        max_datagram_size: usize,
+        /// Publisher's connection header without the leading length
+        header: Vec<u8>,
+    },
 }
 
 async fn send_topic_request(
     node_name: &str,
     topic_name: &str,
     publisher_uri: &str,
-) -> Result<String, std::io::Error> {
+    protocols: Vec<serde_xmlrpc::Value>,
+) -> Result<TopicEndpoint, std::io::Error> {
+    use serde_xmlrpc::Value;
+
     let xmlrpc_client = reqwest::Client::new();
     let body = serde_xmlrpc::request_to_string(
         "requestTopic",
-        vec![
-            node_name.into(),
-            topic_name.into(),
-            serde_xmlrpc::Value::Array(vec![serde_xmlrpc::Value::Array(vec!["TCPROS".into()])]),
-        ],
+        vec![node_name.into(), topic_name.into(), Value::Array(protocols)],
     )
     .unwrap();
 
@@ -472,34 +802,44 @@ async fn send_topic_request(
             log::error!("Unable to send subscribe request to publisher: {err}");
             std::io::ErrorKind::ConnectionAborted
         })?;
-    if response.status().is_success() {
-        if let Ok(response_data) = response.text().await {
-            if let Ok((_code, _description, (protocol, hostname, port))) =
-                serde_xmlrpc::response_from_str::<(i8, String, (String, String, u16))>(
-                    &response_data,
-                )
-            {
-                if protocol == "TCPROS" {
-                    let tcpros_endpoint = format!("{hostname}:{port}");
-                    log::debug!("Got a TCPROS publisher endpoint at {tcpros_endpoint}");
-                    Ok(tcpros_endpoint)
-                } else {
-                    log::error!("Got unsupported protocol {protocol}");
-                    Err(std::io::ErrorKind::Unsupported.into())
-                }
-            } else {
-                log::error!("Failed to deserialize requestTopic response {response_data}");
-                Err(std::io::ErrorKind::InvalidData.into())
-            }
-        } else {
-            log::error!("No data received with the response");
-            Err(std::io::ErrorKind::InvalidData.into())
-        }
-    } else {
+    if !response.status().is_success() {
         log::error!(
             "Failed to request topic data from the publisher's XMLRPC server for {publisher_uri}: {response:#?}"
         );
-        Err(std::io::ErrorKind::ConnectionRefused.into())
+        return Err(std::io::ErrorKind::ConnectionRefused.into());
+    }
+    let Ok(response_data) = response.text().await else {
+        log::error!("No data received with the response");
+        return Err(std::io::ErrorKind::InvalidData.into());
+    };
+    let Ok((_code, _description, params)) =
+        serde_xmlrpc::response_from_str::<(i8, String, Vec<Value>)>(&response_data)
+    else {
+        log::error!("Failed to deserialize requestTopic response {response_data}");
+        return Err(std::io::ErrorKind::InvalidData.into());
+    };
+    match &params[..] {
+        [Value::String(protocol), Value::String(hostname), Value::Int(port)]
+            if protocol == "TCPROS" =>
+        {
+            let tcpros_endpoint = format!("{hostname}:{port}");
+            log::debug!("Got a TCPROS publisher endpoint at {tcpros_endpoint}");
+            Ok(TopicEndpoint::Tcp(tcpros_endpoint))
+        }
+        [Value::String(protocol), Value::String(_hostname), Value::Int(_port), Value::Int(connection_id), Value::Int(max_datagram_size), Value::Base64(header)]
+            if protocol == "UDPROS" =>
+        {
+            log::debug!("Got a UDPROS publisher connection id {connection_id}");
+            Ok(TopicEndpoint::Udp {
+                connection_id: *connection_id as u32,
+                max_datagram_size: *max_datagram_size as usize,
+                header: header.clone(),
+            })
+        }
+        _ => {
+            log::error!("Got unsupported protocol parameters {params:?}");
+            Err(std::io::ErrorKind::Unsupported.into())
+        }
     }
 }
 
@@ -517,3 +857,17 @@ impl From<roslibrust_serde_rosmsg::Error> for SubscriberError {
         Self::DeserializeError(value.to_string())
     }
 }
+
+/// Deserializes a message received over TCPROS, `data` includes the 4 byte length prefix
+/// Uses the message's generated wire codec when it has one, and serde otherwise
+pub(crate) fn deserialize_message<T: RosMessageType>(data: &[u8]) -> Result<T, SubscriberError> {
+    let Some(codec) = T::WIRE_CODEC else {
+        return Ok(roslibrust_serde_rosmsg::from_slice(data)?);
+    };
+    let body = data.get(4..).ok_or_else(|| {
+        SubscriberError::DeserializeError("message is missing its length prefix".to_owned())
+    })?;
+    codec
+        .decode_ros1(body)
+        .map_err(|e| SubscriberError::DeserializeError(e.to_string()))
+}
@@ -270,10 +270,40 @@ pub async fn receive_header(stream: &mut TcpStream) -> Result<ConnectionHeader,
 /// It first reads the length of the body, then reads the body itself.
 /// The returned Bytes includes the length of the body at the front as serde_rosmsg expects.
 pub async fn receive_body(stream: &mut TcpStream) -> Result<Bytes, std::io::Error> {
-    use bytes::{BufMut, BytesMut};
     use tokio::io::AsyncReadExt;
 
     let body_len = stream.read_u32_le().await? as usize;
+    read_body(stream, body_len).await
+}
+
+/// Same as [receive_body], but a message with a body larger than `max_len` is read off the stream without being
+/// buffered and None is returned in its place.
+pub async fn receive_body_up_to(
+    stream: &mut TcpStream,
+    max_len: usize,
+) -> Result<Option<Bytes>, std::io::Error> {
+    use tokio::io::AsyncReadExt;
+
+    let body_len = stream.read_u32_le().await? as usize;
+    if body_len > max_len {
+        let skipped = tokio::io::copy(
+            &mut (&mut *stream).take(body_len as u64),
+            &mut tokio::io::sink(),
+        )
+        .await?;
+        if skipped < body_len as u64 {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+        }
+        return Ok(None);
+    }
+    read_body(stream, body_len).await.map(Some)
+}
+
+/// Reads a body of the given length, returned with its length prepended as serde_rosmsg expects
+async fn read_body(stream: &mut TcpStream, body_len: usize) -> Result<Bytes, std::io::Error> {
+    use bytes::{BufMut, BytesMut};
+    use tokio::io::AsyncReadExt;
+
     let total_len = 4 + body_len;
 
     let mut buf = BytesMut::with_capacity(total_len);
@@ -344,4 +374,33 @@ mod test {
             Some("992ce8a1687cec8c8bd883ec73ca41d1".to_string())
         );
     }
+
+    #[test_log::test(tokio::test)]
+    async fn receive_body_up_to_skips_large_messages() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let writer = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut data = vec![];
+            data.extend_from_slice(&100u32.to_le_bytes());
+            data.extend_from_slice(&[1u8; 100]);
+            data.extend_from_slice(&2u32.to_le_bytes());
+            data.extend_from_slice(&[2u8; 2]);
+            stream.write_all(&data).await.unwrap();
+        });
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        assert!(super::receive_body_up_to(&mut stream, 10)
+            .await
+            .unwrap()
+            .is_none());
+        let body = super::receive_body_up_to(&mut stream, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(&body[..], &[2, 0, 0, 0, 2, 2]);
+        writer.await.unwrap();
+    }
 }
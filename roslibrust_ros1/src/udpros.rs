@@ -0,0 +1,245 @@
+//! Implementation of the UDPROS transport.
+//!
+//! Based on the ROS documentation here:
+//! https://wiki.ros.org/ROS/UDPROS
+//! and the roscpp implementation in transport_udp.cpp
+//!
+//! Every datagram starts with an 8 byte header followed by a chunk of the serialized message.
+//! Messages larger than a single datagram are split into blocks, the first block (DATA0) carries the
+//! total number of blocks and following blocks (DATAN) carry their index within the message.
+
+use bytes::{Bytes, BytesMut};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Size of the header at the start of each datagram
+pub(crate) const UDP_HEADER_LEN: usize = 8;
+
+/// roscpp uses 1500 when no max datagram size is requested, matching a typical ethernet MTU
+pub const DEFAULT_MAX_DATAGRAM_SIZE: usize = 1500;
+
+/// Connection ids only need to be unique within this process
+static NEXT_CONNECTION_ID: AtomicU32 = AtomicU32::new(1);
+
+pub(crate) fn next_connection_id() -> u32 {
+    NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OpCode {
+    /// First block of a message, block field is the total number of blocks
+    Data0 = 0,
+    /// Subsequent block of a message, block field is the index of the block
+    DataN = 1,
+    Ping = 2,
+    Err = 3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DatagramHeader {
+    pub connection_id: u32,
+    pub op_code: OpCode,
+    pub msg_id: u8,
+    pub block: u16,
+}
+
+impl DatagramHeader {
+    pub fn to_bytes(self) -> [u8; UDP_HEADER_LEN] {
+        let mut bytes = [0u8; UDP_HEADER_LEN];
+        bytes[0..4].copy_from_slice(&self.connection_id.to_le_bytes());
+        bytes[4] = self.op_code as u8;
+        bytes[5] = self.msg_id;
+        bytes[6..8].copy_from_slice(&self.block.to_le_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
+        if bytes.len() < UDP_HEADER_LEN {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        let op_code = match bytes[4] {
+            0 => OpCode::Data0,
+            1 => OpCode::DataN,
+            2 => OpCode::Ping,
+            3 => OpCode::Err,
+            _ => return Err(std::io::ErrorKind::InvalidData.into()),
+        };
+        Ok(Self {
+            connection_id: u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+            op_code,
+            msg_id: bytes[5],
+            block: u16::from_le_bytes(bytes[6..8].try_into().unwrap()),
+        })
+    }
+}
+
+/// Splits a serialized message (including its 4 byte length prefix) into datagrams no larger than `max_datagram_size`
+pub(crate) fn fragment(
+    connection_id: u32,
+    msg_id: u8,
+    data: &[u8],
+    max_datagram_size: usize,
+) -> std::io::Result<Vec<Vec<u8>>> {
+    if max_datagram_size <= UDP_HEADER_LEN {
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    let payload_size = max_datagram_size - UDP_HEADER_LEN;
+    let block_count = data.len().div_ceil(payload_size).max(1);
+    let block_count: u16 = block_count.try_into().map_err(|_| {
+        log::error!(
+            "Message of {} bytes is too large to send via UDPROS",
+            data.len()
+        );
+        std::io::Error::from(std::io::ErrorKind::InvalidInput)
+    })?;
+
+    let mut chunks = data.chunks(payload_size);
+    let mut datagrams = Vec::with_capacity(block_count as usize);
+    for idx in 0..block_count {
+        let header = if idx == 0 {
+            DatagramHeader {
+                connection_id,
+                op_code: OpCode::Data0,
+                msg_id,
+                block: block_count,
+            }
+        } else {
+            DatagramHeader {
+                connection_id,
+                op_code: OpCode::DataN,
+                msg_id,
+                block: idx,
+            }
+        };
+        let chunk = chunks.next().unwrap_or_default();
+        let mut datagram = Vec::with_capacity(UDP_HEADER_LEN + chunk.len());
+        datagram.extend_from_slice(&header.to_bytes());
+        datagram.extend_from_slice(chunk);
+        datagrams.push(datagram);
+    }
+    Ok(datagrams)
+}
+
+/// Rebuilds complete messages from datagrams received for a single connection.
+///
+/// Like roscpp, only one message is assembled at a time. If a block goes missing or a new message starts
+/// before the current one is complete, the partial message is discarded.
+pub(crate) struct Reassembler {
+    connection_id: u32,
+    current: Option<PartialMessage>,
+}
+
+struct PartialMessage {
+    msg_id: u8,
+    block_count: u16,
+    next_block: u16,
+    buffer: BytesMut,
+}
+
+impl Reassembler {
+    pub fn new(connection_id: u32) -> Self {
+        Self {
+            connection_id,
+            current: None,
+        }
+    }
+
+    /// Feeds a received datagram in, returns a message once all of its blocks have arrived
+    pub fn push(&mut self, datagram: &[u8]) -> Option<Bytes> {
+        let header = match DatagramHeader::from_bytes(datagram) {
+            Ok(header) => header,
+            Err(e) => {
+                log::debug!("Dropping malformed UDPROS datagram: {e}");
+                return None;
+            }
+        };
+        if header.connection_id != self.connection_id {
+            log::trace!(
+                "Ignoring UDPROS datagram for connection {}, expected {}",
+                header.connection_id,
+                self.connection_id
+            );
+            return None;
+        }
+        let payload = &datagram[UDP_HEADER_LEN..];
+        match header.op_code {
+            OpCode::Data0 => {
+                if self.current.is_some() {
+                    log::debug!("UDPROS message started before previous was complete, dropping partial message");
+                }
+                self.current = Some(PartialMessage {
+                    msg_id: header.msg_id,
+                    block_count: header.block,
+                    next_block: 1,
+                    buffer: BytesMut::from(payload),
+                });
+            }
+            OpCode::DataN => {
+                let partial = self.current.as_mut()?;
+                if partial.msg_id != header.msg_id || partial.next_block != header.block {
+                    log::debug!("UDPROS block received out of order, dropping partial message");
+                    self.current = None;
+                    return None;
+                }
+                partial.buffer.extend_from_slice(payload);
+                partial.next_block += 1;
+            }
+            OpCode::Ping | OpCode::Err => return None,
+        }
+
+        if self
+            .current
+            .as_ref()
+            .is_some_and(|partial| partial.next_block >= partial.block_count)
+        {
+            return self.current.take().map(|partial| partial.buffer.freeze());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test_log::test]
+    fn header_round_trip() {
+        let header = DatagramHeader {
+            connection_id: 42,
+            op_code: OpCode::DataN,
+            msg_id: 7,
+            block: 3,
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(bytes, [42, 0, 0, 0, 1, 7, 3, 0]);
+        assert_eq!(DatagramHeader::from_bytes(&bytes).unwrap(), header);
+    }
+
+    #[test_log::test]
+    fn fragment_and_reassemble() {
+        let data: Vec<u8> = (0..5000u32).map(|i| i as u8).collect();
+        let datagrams = fragment(9, 1, &data, 1500).unwrap();
+        assert_eq!(datagrams.len(), 4);
+        assert!(datagrams.iter().all(|d| d.len() <= 1500));
+
+        let mut reassembler = Reassembler::new(9);
+        let (last, rest) = datagrams.split_last().unwrap();
+        for datagram in rest {
+            assert!(reassembler.push(datagram).is_none());
+        }
+        assert_eq!(&reassembler.push(last).unwrap()[..], &data[..]);
+    }
+
+    #[test_log::test]
+    fn reassembler_drops_incomplete_and_foreign() {
+        let first = fragment(9, 1, &[1u8; 3000], 1500).unwrap();
+        let second = fragment(9, 2, &[2u8; 10], 1500).unwrap();
+        let other_connection = fragment(10, 1, &[3u8; 10], 1500).unwrap();
+
+        let mut reassembler = Reassembler::new(9);
+        assert!(reassembler.push(&first[0]).is_none());
+        assert!(reassembler.push(&other_connection[0]).is_none());
+        // A new message before the first completes discards the first
+        assert_eq!(&reassembler.push(&second[0]).unwrap()[..], &[2u8; 10]);
+        assert!(reassembler.push(&first[1]).is_none());
+    }
+}
@@ -0,0 +1,429 @@
+//! Publishing and subscribing with types only known at runtime, working directly on the zenoh session with the key
+//! expressions and attachments rmw_zenoh uses, plus a client for the `~/get_type_description` service every ROS2
+//! node provides to find out what those types look like.
+
+use crate::graph::demangle_type;
+use crate::ZenohClient;
+use roslibrust_common::codec::{
+    Bytes, BytesMut, WireError, WireFormat, WireReader, WireWriter, CDR_LE_HEADER,
+};
+use roslibrust_common::type_description::{Field, FieldType, TypeDescription, TypeDescriptionMsg};
+use roslibrust_common::{Error, GlobalTopicName, Result, ToGlobalTopicName};
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// How long [ZenohClient::get_type_description] waits for the node to reply
+const TYPE_DESCRIPTION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Turns a ROS2 type name like `std_msgs/msg/String` into the DDS form `std_msgs::msg::dds_::String_` used in keys
+fn mangle_type(type_name: &str) -> String {
+    match type_name.rsplit_once('/') {
+        Some((prefix, name)) => format!("{}::dds_::{name}_", prefix.replace('/', "::")),
+        None => format!("{type_name}_"),
+    }
+}
+
+/// Parses a data key of the form `{domain_id}/{topic}/{type}/{type_hash}` into the topic, ROS2 type and type hash
+fn parse_data_key(key: &str) -> Option<(String, String, String)> {
+    let (_domain_id, rest) = key.split_once('/')?;
+    let (rest, type_hash) = rest.rsplit_once('/')?;
+    let (topic, type_name) = rest.rsplit_once('/')?;
+    Some((
+        format!("/{topic}"),
+        demangle_type(type_name),
+        type_hash.to_string(),
+    ))
+}
+
+/// The payload of the error reply zenoh makes up locally when a query runs out of time
+const ZENOH_TIMEOUT_PAYLOAD: &str = "Timeout";
+
+/// Converts an error reply to a `get_type_description` query into the error the caller gets.
+///
+/// Returns `None` for the reply zenoh sends when the query times out, which unlike replies from the node has no
+/// replier and carries [ZENOH_TIMEOUT_PAYLOAD].
+fn server_error_from_reply(payload: String, replier_known: bool) -> Option<Error> {
+    if !replier_known && payload == ZENOH_TIMEOUT_PAYLOAD {
+        None
+    } else {
+        Some(Error::ServerError(payload))
+    }
+}
+
+/// Builds the attachment rmw_zenoh expects on every publication and service request, and drops messages without.
+///
+/// This is the keyed format used by rmw_zenoh in Jazzy: the sequence number, the source timestamp in nanoseconds
+/// and the 16 byte GID of the sender.
+fn attachment(sequence_number: i64, gid: [u8; 16]) -> zenoh::bytes::ZBytes {
+    let source_timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as i64)
+        .unwrap_or_default();
+    attachment_at(sequence_number, source_timestamp, gid)
+}
+
+/// See [attachment], with the source timestamp given
+fn attachment_at(
+    sequence_number: i64,
+    source_timestamp: i64,
+    gid: [u8; 16],
+) -> zenoh::bytes::ZBytes {
+    let mut serializer = zenoh_ext::ZSerializer::new();
+    serializer.serialize("sequence_number");
+    serializer.serialize(sequence_number);
+    serializer.serialize("source_timestamp");
+    serializer.serialize(source_timestamp);
+    serializer.serialize("source_gid");
+    // rmw_zenoh reads the GID as a length prefixed sequence, which arrays aren't serialized as
+    serializer.serialize(&gid[..]);
+    serializer.finish()
+}
+
+/// Returns the CDR payload following the encapsulation header, checking it is one we can work with
+fn strip_cdr_header(data: &[u8]) -> Result<&[u8]> {
+    match data.split_first_chunk::<4>() {
+        Some((header, body)) if *header == CDR_LE_HEADER => Ok(body),
+        Some((header, _)) => Err(Error::SerializationError(
+            WireError::UnsupportedEncapsulation(*header).to_string(),
+        )),
+        None => Err(Error::SerializationError(format!(
+            "Message of {} bytes is too short for a CDR encapsulation header",
+            data.len()
+        ))),
+    }
+}
+
+/// Encodes a `type_description_interfaces/srv/GetTypeDescription` request, sources aren't requested
+fn encode_type_description_request(type_name: &str, type_hash: &str) -> Vec<u8> {
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(&CDR_LE_HEADER);
+    let mut writer = WireWriter::new(&mut buf, WireFormat::Cdr);
+    writer.put_str(type_name);
+    writer.put_str(type_hash);
+    // include_type_sources
+    writer.put(false);
+    buf.into()
+}
+
+/// Decodes a `type_description_interfaces/srv/GetTypeDescription` response, ignoring the type sources and extra
+/// information which follow the description
+fn decode_type_description_response(data: &[u8]) -> Result<TypeDescriptionMsg> {
+    fn read_field(reader: &mut WireReader<'_>) -> std::result::Result<Field, WireError> {
+        Ok(Field {
+            name: reader.get_string()?,
+            field_type: FieldType {
+                type_id: reader.get()?,
+                // The service uses 64 bit capacities, which are far beyond anything a message can hold
+                capacity: u32::try_from(reader.get::<u64>()?).unwrap_or(u32::MAX),
+                string_capacity: u32::try_from(reader.get::<u64>()?).unwrap_or(u32::MAX),
+                nested_type_name: reader.get_string()?,
+            },
+            default_value: reader.get_string()?,
+        })
+    }
+
+    fn read_description(
+        reader: &mut WireReader<'_>,
+    ) -> std::result::Result<TypeDescription, WireError> {
+        Ok(TypeDescription {
+            type_name: reader.get_string()?,
+            fields: reader.get_seq_with(read_field)?,
+        })
+    }
+
+    let wire_error =
+        |e: WireError| Error::SerializationError(format!("Failed to decode type description: {e}"));
+    let mut reader = WireReader::new(strip_cdr_header(data)?, WireFormat::Cdr);
+    let successful: bool = reader.get().map_err(wire_error)?;
+    let failure_reason = reader.get_string().map_err(wire_error)?;
+    if !successful {
+        return Err(Error::ServerError(failure_reason));
+    }
+    Ok(TypeDescriptionMsg {
+        type_description: read_description(&mut reader).map_err(wire_error)?,
+        referenced_type_descriptions: reader.get_seq_with(read_description).map_err(wire_error)?,
+    })
+}
+
+/// A message received by [ZenohSubscriberAny::next_sample]
+#[derive(Debug, Clone)]
+pub struct ZenohSampleAny {
+    /// The topic the message was received on
+    pub topic: String,
+    /// ROS2 type of the message e.g. `std_msgs/msg/String`
+    pub type_name: String,
+    /// Hash of the type in the form `RIHS01_<hex>`
+    pub type_hash: String,
+    /// The CDR serialized message, starting with its encapsulation header
+    pub data: Bytes,
+}
+
+/// The subscriber type returned by [ZenohClient::subscribe_any].
+/// This type is self de-registering, and dropping the subscriber will automatically unsubscribe from the topic.
+pub struct ZenohSubscriberAny {
+    subscriber:
+        zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>,
+}
+
+impl ZenohSubscriberAny {
+    /// Returns the raw CDR bytes of the next message, including the encapsulation header, or None once the session
+    /// is closed.
+    pub async fn next(&mut self) -> Option<Bytes> {
+        self.next_sample().await.map(|sample| sample.data)
+    }
+
+    /// Same as [ZenohSubscriberAny::next], but also returns the type and type hash the message was published with
+    pub async fn next_sample(&mut self) -> Option<ZenohSampleAny> {
+        loop {
+            let sample = self.subscriber.recv_async().await.ok()?;
+            let Some((topic, type_name, type_hash)) = parse_data_key(sample.key_expr().as_str())
+            else {
+                log::warn!(
+                    "Ignoring sample with unrecognized key {}",
+                    sample.key_expr()
+                );
+                continue;
+            };
+            return Some(ZenohSampleAny {
+                topic,
+                type_name,
+                type_hash,
+                data: Bytes::copy_from_slice(&sample.payload().to_bytes()),
+            });
+        }
+    }
+}
+
+/// The publisher type returned by [ZenohClient::advertise_any].
+/// This type is self de-registering, and dropping the publisher will automatically un-advertise the topic.
+pub struct ZenohPublisherAny {
+    publisher: zenoh::pubsub::Publisher<'static>,
+    gid: [u8; 16],
+    sequence_number: AtomicI64,
+}
+
+impl ZenohPublisherAny {
+    /// Publishes a CDR serialized message, which is expected to start with its encapsulation header
+    pub async fn publish(&self, data: impl AsRef<[u8]>) -> Result<()> {
+        let data = data.as_ref();
+        strip_cdr_header(data)?;
+        let sequence_number = self.sequence_number.fetch_add(1, Ordering::Relaxed);
+        self.publisher
+            .put(data.to_vec())
+            .attachment(attachment(sequence_number, self.gid))
+            .await
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))
+    }
+}
+
+impl ZenohClient {
+    // ros-z has no accessor for this, it is a public field at the revision pinned in Cargo.toml
+    /// The ROS2 domain the node is part of, which prefixes every data key
+    fn domain_id(&self) -> usize {
+        self.inner.node.entity.domain_id
+    }
+
+    /// Subscribes to a topic without knowing its type, receiving the raw CDR serialized messages.
+    ///
+    /// The subscription matches the topic with any type and type hash, see [ZenohSubscriberAny::next_sample] to find
+    /// out which type each message has and [ZenohClient::get_type_description] to find out how to decode it.
+    /// The subscription isn't announced to the ROS2 graph.
+    pub async fn subscribe_any(&self, topic: impl ToGlobalTopicName) -> Result<ZenohSubscriberAny> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        let key = format!(
+            "{}/{}/*/*",
+            self.domain_id(),
+            topic.as_ref().trim_start_matches('/')
+        );
+        let subscriber = self
+            .session()
+            .declare_subscriber(key)
+            .await
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+        Ok(ZenohSubscriberAny { subscriber })
+    }
+
+    /// Advertises a topic whose type isn't known at compile time, publishing raw CDR serialized messages.
+    ///
+    /// `type_name` is the ROS2 type e.g. `std_msgs/msg/String` and `type_hash` its hash in the form `RIHS01_<hex>`,
+    /// e.g. as found in a [ZenohSampleAny] or read from a bag file. The publisher isn't announced to the ROS2 graph.
+    pub async fn advertise_any(
+        &self,
+        topic: impl ToGlobalTopicName,
+        type_name: &str,
+        type_hash: &str,
+    ) -> Result<ZenohPublisherAny> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        let key = format!(
+            "{}/{}/{}/{type_hash}",
+            self.domain_id(),
+            topic.as_ref().trim_start_matches('/'),
+            mangle_type(type_name)
+        );
+        let session = self.session();
+        let publisher = session
+            .declare_publisher(key)
+            .await
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+        Ok(ZenohPublisherAny {
+            publisher,
+            gid: session.zid().to_le_bytes(),
+            sequence_number: AtomicI64::new(1),
+        })
+    }
+
+    /// Asks `node` (e.g. `/talker`) for the description of a type it uses, via its `~/get_type_description` service.
+    ///
+    /// The result can decode messages of that type with [TypeDescriptionMsg::decode_cdr]. Nodes only describe the
+    /// types of their own publishers, subscriptions, services and clients, and are free to not provide the service.
+    pub async fn get_type_description(
+        &self,
+        node: impl ToGlobalTopicName,
+        type_name: &str,
+        type_hash: &str,
+    ) -> Result<TypeDescriptionMsg> {
+        let node: GlobalTopicName = node.to_global_name()?;
+        // Any hash, as the hash of the service type itself isn't worth hard coding
+        let key = format!(
+            "{}/{}/get_type_description/{}/*",
+            self.domain_id(),
+            node.as_ref().trim_start_matches('/'),
+            mangle_type("type_description_interfaces/srv/GetTypeDescription")
+        );
+        let session = self.session();
+        let replies = session
+            .get(&key)
+            .payload(encode_type_description_request(type_name, type_hash))
+            .attachment(attachment(1, session.zid().to_le_bytes()))
+            .timeout(TYPE_DESCRIPTION_TIMEOUT)
+            .await
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+
+        // Zenoh also reports hitting the timeout as an error reply, see [server_error_from_reply]
+        let mut error = None;
+        while let Ok(reply) = replies.recv_async().await {
+            let replier_known = reply.replier_id().is_some();
+            match reply.into_result() {
+                Ok(sample) => {
+                    return decode_type_description_response(&sample.payload().to_bytes())
+                }
+                Err(e) => {
+                    let payload = e.payload().try_to_string().unwrap_or_default().to_string();
+                    if let Some(server_error) = server_error_from_reply(payload, replier_known) {
+                        error = Some(server_error);
+                    }
+                }
+            }
+        }
+        Err(error
+            .unwrap_or_else(|| Error::Timeout(format!("No type description received from {key}"))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn attachment_layout() {
+        let gid: [u8; 16] = std::array::from_fn(|i| i as u8);
+        let mut expected = vec![15];
+        expected.extend_from_slice(b"sequence_number");
+        expected.extend_from_slice(&5i64.to_le_bytes());
+        expected.push(16);
+        expected.extend_from_slice(b"source_timestamp");
+        expected.extend_from_slice(&1_700_000_000_000_000_000i64.to_le_bytes());
+        expected.push(10);
+        expected.extend_from_slice(b"source_gid");
+        expected.push(16);
+        expected.extend_from_slice(&gid);
+        assert_eq!(
+            attachment_at(5, 1_700_000_000_000_000_000, gid).to_bytes(),
+            expected
+        );
+    }
+
+    #[test]
+    fn mangles_keys() {
+        assert_eq!(
+            mangle_type("std_msgs/msg/String"),
+            "std_msgs::msg::dds_::String_"
+        );
+        assert_eq!(
+            demangle_type(&mangle_type("std_msgs/msg/String")),
+            "std_msgs/msg/String"
+        );
+        assert_eq!(
+            parse_data_key("0/robot/chatter/std_msgs::msg::dds_::String_/RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18"),
+            Some((
+                "/robot/chatter".to_string(),
+                "std_msgs/msg/String".to_string(),
+                "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18".to_string()
+            ))
+        );
+        assert_eq!(parse_data_key("0/chatter"), None);
+    }
+
+    #[test]
+    fn decodes_type_description_response() {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(&CDR_LE_HEADER);
+        let mut writer = WireWriter::new(&mut buf, WireFormat::Cdr);
+        writer.put(true);
+        writer.put_str("");
+        writer.put_str("std_msgs/msg/String");
+        writer.put_len(1);
+        writer.put_str("data");
+        writer.put(17u8);
+        writer.put(0u64);
+        writer.put(0u64);
+        writer.put_str("");
+        writer.put_str("");
+        // No referenced types, type sources or extra information
+        writer.put_len(0);
+        writer.put_len(0);
+        writer.put_len(0);
+
+        let description = decode_type_description_response(&buf).unwrap();
+        assert_eq!(
+            description.type_description.type_name,
+            "std_msgs/msg/String"
+        );
+        assert_eq!(description.type_description.fields[0].name, "data");
+        assert_eq!(
+            description.type_description.fields[0].field_type.type_id,
+            17
+        );
+        assert!(description.referenced_type_descriptions.is_empty());
+
+        // The description can decode messages of the type
+        let mut msg = BytesMut::new();
+        msg.extend_from_slice(&CDR_LE_HEADER);
+        WireWriter::new(&mut msg, WireFormat::Cdr).put_str("hello");
+        let decoded = description.decode_cdr(&msg).unwrap();
+        assert_eq!(
+            decoded.get("data"),
+            Some(&roslibrust_common::type_description::DynamicValue::String(
+                "hello".to_string()
+            ))
+        );
+
+        let mut failed = BytesMut::new();
+        failed.extend_from_slice(&CDR_LE_HEADER);
+        let mut writer = WireWriter::new(&mut failed, WireFormat::Cdr);
+        writer.put(false);
+        writer.put_str("Type not found");
+        assert!(matches!(
+            decode_type_description_response(&failed),
+            Err(Error::ServerError(reason)) if reason == "Type not found"
+        ));
+    }
+
+    #[test]
+    fn timeout_reply_is_not_a_server_error() {
+        assert!(server_error_from_reply("Timeout".to_string(), false).is_none());
+        assert!(matches!(
+            server_error_from_reply("Timeout".to_string(), true),
+            Some(Error::ServerError(message)) if message == "Timeout"
+        ));
+    }
+}
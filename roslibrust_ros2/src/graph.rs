@@ -0,0 +1,265 @@
+//! Implementation of [GraphProvider] for ROS2, built on the liveliness tokens rmw_zenoh declares for every
+//! node, publisher, subscription, service and client.
+
+use crate::ZenohClient;
+use roslibrust_common::graph::{poll_graph_events, Graph, GraphEvent, GraphProvider};
+use roslibrust_common::{Error, Result};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use zenoh::handlers::FifoChannelHandler;
+use zenoh::sample::{Sample, SampleKind};
+
+/// Key expression matching every liveliness token declared by rmw_zenoh
+const LIVELINESS_KEY: &str = "@ros2_lv/**";
+
+/// How long [GraphProvider::graph_events] waits before trying again when it fails to watch the liveliness tokens
+const WATCH_RETRY_PERIOD: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// What a liveliness token declared by rmw_zenoh announces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntityKind {
+    Node,
+    Publisher,
+    Subscription,
+    Service,
+    Client,
+}
+
+/// An entity parsed out of a liveliness token
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LivelinessEntity {
+    kind: EntityKind,
+    /// Fully qualified name of the node owning the entity e.g. `/ns/talker`
+    node: String,
+    /// Topic or service name, empty for nodes
+    name: String,
+    /// ROS2 type e.g. `std_msgs/msg/String`, empty for nodes
+    type_name: String,
+}
+
+/// rmw_zenoh replaces the `/` in names with `%` so they fit in a single key chunk
+fn demangle_name(name: &str) -> String {
+    let name = name.replace('%', "/");
+    if name.starts_with('/') {
+        name
+    } else {
+        format!("/{name}")
+    }
+}
+
+/// Turns a DDS type name like `std_msgs::msg::dds_::String_` into `std_msgs/msg/String`
+pub(crate) fn demangle_type(type_name: &str) -> String {
+    type_name
+        .split("::")
+        .filter(|part| *part != "dds_")
+        .collect::<Vec<_>>()
+        .join("/")
+        .trim_end_matches('_')
+        .to_string()
+}
+
+/// Parses a key of the form
+/// `@ros2_lv/{domain_id}/{session_id}/{node_id}/{entity_id}/{kind}/{enclave}/{namespace}/{node_name}`
+/// followed by `/{topic}/{type}/{type_hash}/{qos}` for everything but nodes.
+fn parse_liveliness_key(key: &str) -> Option<LivelinessEntity> {
+    let parts: Vec<&str> = key.split('/').collect();
+    if parts.len() < 9 || parts[0] != "@ros2_lv" {
+        return None;
+    }
+    let kind = match parts[5] {
+        "NN" => EntityKind::Node,
+        "MP" => EntityKind::Publisher,
+        "MS" => EntityKind::Subscription,
+        "SS" => EntityKind::Service,
+        "SC" => EntityKind::Client,
+        _ => return None,
+    };
+    let namespace = demangle_name(parts[7]);
+    let node = format!("{}/{}", namespace.trim_end_matches('/'), parts[8]);
+    let (name, type_name) = match kind {
+        EntityKind::Node => (String::new(), String::new()),
+        _ => (demangle_name(parts.get(9)?), demangle_type(parts.get(10)?)),
+    };
+    Some(LivelinessEntity {
+        kind,
+        node,
+        name,
+        type_name,
+    })
+}
+
+/// Queries the liveliness tokens currently alive
+async fn query_tokens(session: &zenoh::Session) -> Result<BTreeSet<String>> {
+    let replies = session
+        .liveliness()
+        .get(LIVELINESS_KEY)
+        .await
+        .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+    let mut tokens = BTreeSet::new();
+    while let Ok(reply) = replies.recv_async().await {
+        if let Ok(sample) = reply.result() {
+            tokens.insert(sample.key_expr().to_string());
+        }
+    }
+    Ok(tokens)
+}
+
+/// Builds the graph from the keys of liveliness tokens
+fn graph_from_tokens(tokens: &BTreeSet<String>) -> Graph {
+    let mut graph = Graph::default();
+    for token in tokens {
+        let Some(entity) = parse_liveliness_key(token) else {
+            log::debug!("Ignoring unrecognized liveliness token {token}");
+            continue;
+        };
+        let LivelinessEntity {
+            kind,
+            node,
+            name,
+            type_name,
+        } = entity;
+        match kind {
+            EntityKind::Node => {
+                graph.nodes.insert(node);
+            }
+            EntityKind::Publisher => graph.add_publisher(&name, &type_name, &node),
+            EntityKind::Subscription => graph.add_subscriber(&name, &type_name, &node),
+            EntityKind::Service => graph.add_service_provider(&name, &type_name, &node),
+            EntityKind::Client => {}
+        }
+    }
+    graph
+}
+
+/// Keeps track of the liveliness tokens which are alive by following their changes with a liveliness subscriber
+struct LivelinessTokens {
+    subscriber: zenoh::pubsub::Subscriber<FifoChannelHandler<Sample>>,
+    tokens: BTreeSet<String>,
+}
+
+impl LivelinessTokens {
+    async fn new(session: &zenoh::Session) -> Result<Self> {
+        // Declared before querying the tokens that are already alive, so no change in between is missed
+        let subscriber = session
+            .liveliness()
+            .declare_subscriber(LIVELINESS_KEY)
+            .await
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+        let tokens = query_tokens(session).await?;
+        Ok(Self { subscriber, tokens })
+    }
+
+    /// Waits for tokens to change and applies the changes, returns false once the subscriber is closed
+    async fn changed(&mut self) -> bool {
+        let Ok(sample) = self.subscriber.recv_async().await else {
+            return false;
+        };
+        self.apply(&sample);
+        // Tokens tend to change in bursts, e.g. when a node starts, so everything already received is applied at once
+        while let Ok(Some(sample)) = self.subscriber.try_recv() {
+            self.apply(&sample);
+        }
+        true
+    }
+
+    fn apply(&mut self, sample: &Sample) {
+        let key = sample.key_expr().to_string();
+        match sample.kind() {
+            SampleKind::Put => {
+                self.tokens.insert(key);
+            }
+            SampleKind::Delete => {
+                self.tokens.remove(&key);
+            }
+        }
+    }
+}
+
+/// Types are reported in their ROS2 form e.g. `std_msgs/msg/String`. Service clients aren't part of the [Graph].
+///
+/// Every domain reachable over the zenoh session is included, as the tokens of other domains aren't filtered out.
+/// [GraphProvider::graph_events] follows the tokens with a liveliness subscriber instead of polling for them.
+impl GraphProvider for ZenohClient {
+    async fn graph(&self) -> Result<Graph> {
+        Ok(graph_from_tokens(&query_tokens(&self.session()).await?))
+    }
+
+    fn graph_events(
+        &self,
+    ) -> impl futures_core::Stream<Item = Result<GraphEvent>> + Send + 'static {
+        // Weak so that an event stream alone doesn't keep the node alive, the stream ends once it is dropped
+        let client = self.downgrade();
+        let shutdown = self.inner.shutdown.clone();
+        // Created on the first fetch, and again after the subscriber is closed or failed to be declared
+        let watcher = Arc::new(tokio::sync::Mutex::new(None::<LivelinessTokens>));
+        let fetch_watcher = watcher.clone();
+        poll_graph_events(
+            move || {
+                let session = client.upgrade()?.session();
+                let watcher = fetch_watcher.clone();
+                Some(async move {
+                    let mut watcher = watcher.lock().await;
+                    if watcher.is_none() {
+                        *watcher = Some(LivelinessTokens::new(&session).await?);
+                    }
+                    Ok(watcher
+                        .as_ref()
+                        .map(|watched| graph_from_tokens(&watched.tokens))
+                        .unwrap_or_default())
+                })
+            },
+            move || {
+                let watcher = watcher.clone();
+                let shutdown = shutdown.clone();
+                async move {
+                    let mut watcher = watcher.lock().await;
+                    let Some(watched) = watcher.as_mut() else {
+                        tokio::time::sleep(WATCH_RETRY_PERIOD).await;
+                        return;
+                    };
+                    let open = tokio::select! {
+                        // Lets the next fetch notice the node is gone and end the stream
+                        _ = shutdown.cancelled() => true,
+                        open = watched.changed() => open,
+                    };
+                    if !open {
+                        *watcher = None;
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_liveliness_keys() {
+        assert_eq!(
+            parse_liveliness_key(
+                "@ros2_lv/0/22363ddafe5dc19901b95b42cce0393b/0/0/NN/%/%/_ros2cli_1106"
+            ),
+            Some(LivelinessEntity {
+                kind: EntityKind::Node,
+                node: "/_ros2cli_1106".to_string(),
+                name: String::new(),
+                type_name: String::new(),
+            })
+        );
+        assert_eq!(
+            parse_liveliness_key("@ros2_lv/0/22363ddafe5dc19901b95b42cce0393b/0/4/MS/%/%robot/listener/%robot%chatter/std_msgs::msg::dds_::String_/RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18/::,5:,:,:,,"),
+            Some(LivelinessEntity {
+                kind: EntityKind::Subscription,
+                node: "/robot/listener".to_string(),
+                name: "/robot/chatter".to_string(),
+                type_name: "std_msgs/msg/String".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_liveliness_key("0/chatter/std_msgs::msg::dds_::String_"),
+            None
+        );
+    }
+}
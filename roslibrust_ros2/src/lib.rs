@@ -14,6 +14,10 @@ use ros_z::{
 /// re-export ros_z for consumers
 pub use ros_z;
 
+mod dynamic;
+pub use dynamic::{ZenohPublisherAny, ZenohSampleAny, ZenohSubscriberAny};
+mod graph;
+
 /// Wrapper type that implements WithTypeInfo for RosMessageType
 /// This allows RosMessageType implementations to work with ros-z's type system
 pub struct RosMessageWrapper<T: RosMessageType>(pub T);
@@ -33,14 +37,26 @@ impl<T: RosMessageType> WithTypeInfo for RosMessageWrapper<T> {}
 // Custom serializer that treats RosMessageWrapper<T> as T for serialization purposes
 pub struct WrapperSerdes<T: RosMessageType>(std::marker::PhantomData<T>);
 
+// Messages with a generated wire codec are encoded with it when we produce a new buffer,
+// everything else goes through ros_z's serde based CDR
 impl<T: RosMessageType> ros_z::msg::ZSerializer for WrapperSerdes<T> {
     type Input<'a> = &'a RosMessageWrapper<T>;
     fn serialize(input: Self::Input<'_>) -> Vec<u8> {
-        ros_z::msg::CdrSerdes::<T>::serialize(&input.0)
+        match T::WIRE_CODEC {
+            Some(codec) => {
+                let mut buf = roslibrust_common::codec::BytesMut::new();
+                codec.encode_cdr_into(&input.0, &mut buf);
+                buf.into()
+            }
+            None => ros_z::msg::CdrSerdes::<T>::serialize(&input.0),
+        }
     }
 
     fn serialize_to_zbuf(input: Self::Input<'_>) -> zenoh_buffers::ZBuf {
-        ros_z::msg::CdrSerdes::<T>::serialize_to_zbuf(&input.0)
+        match T::WIRE_CODEC {
+            Some(_) => Self::serialize(input).into(),
+            None => ros_z::msg::CdrSerdes::<T>::serialize_to_zbuf(&input.0),
+        }
     }
 
     fn serialize_to_buf(input: Self::Input<'_>, buffer: &mut Vec<u8>) {
@@ -51,7 +67,12 @@ impl<T: RosMessageType> ros_z::msg::ZSerializer for WrapperSerdes<T> {
         input: Self::Input<'_>,
         capacity_hint: usize,
     ) -> zenoh_buffers::ZBuf {
-        ros_z::msg::CdrSerdes::<T>::serialize_to_zbuf_with_hint(&input.0, capacity_hint)
+        match T::WIRE_CODEC {
+            Some(_) => Self::serialize(input).into(),
+            None => {
+                ros_z::msg::CdrSerdes::<T>::serialize_to_zbuf_with_hint(&input.0, capacity_hint)
+            }
+        }
     }
 
     fn serialize_to_shm(
@@ -68,6 +89,11 @@ impl<T: RosMessageType> ros_z::msg::ZDeserializer for WrapperSerdes<T> {
     type Output = RosMessageWrapper<T>;
     type Error = ros_z::msg::CdrError;
     fn deserialize(data: Self::Input<'_>) -> std::result::Result<Self::Output, Self::Error> {
+        // Anything the wire codec can't handle (e.g. big endian CDR) is left to serde, which also
+        // produces the error type ros_z expects
+        if let Some(Ok(inner)) = T::WIRE_CODEC.map(|codec| codec.decode_cdr(data)) {
+            return Ok(RosMessageWrapper(inner));
+        }
         let inner = ros_z::msg::CdrSerdes::<T>::deserialize(data)?;
         Ok(RosMessageWrapper(inner))
     }
@@ -77,10 +103,73 @@ impl<T: RosMessageType> ros_z::msg::ZMessage for RosMessageWrapper<T> {
     type Serdes = WrapperSerdes<T>;
 }
 
-/// A "newtype" wrapper around ZNode so we can implement roslibrust's traits for it.
+/// Wrapper type which carries the raw CDR payload of a message of type T, used by [ZenohBorrowedSubscriber]
+pub struct RawMessageWrapper<T: RosMessageType>(pub codec::Bytes, std::marker::PhantomData<T>);
+
+impl<T: RosMessageType> ros_z::ros_msg::MessageTypeInfo for RawMessageWrapper<T> {
+    fn type_name() -> &'static str {
+        T::ROS2_TYPE_NAME
+    }
+
+    fn type_hash() -> TypeHash {
+        TypeHash::new(1, *T::ROS2_HASH)
+    }
+}
+
+impl<T: RosMessageType> WithTypeInfo for RawMessageWrapper<T> {}
+
+// "Deserializer" which keeps the payload as is, so it can be decoded into a borrowed message later
+// ros-z only hands deserializers a slice of the (possibly fragmented) zenoh payload, so this makes the one
+// copy of the message on the borrowed path, into a contiguous buffer that the borrowed message then points into.
+pub struct RawSerdes<T: RosMessageType>(std::marker::PhantomData<T>);
+
+impl<T: RosMessageType> ros_z::msg::ZDeserializer for RawSerdes<T> {
+    type Input<'a> = &'a [u8];
+    type Output = RawMessageWrapper<T>;
+    type Error = ros_z::msg::CdrError;
+    fn deserialize(data: Self::Input<'_>) -> std::result::Result<Self::Output, Self::Error> {
+        Ok(RawMessageWrapper(
+            codec::Bytes::copy_from_slice(data),
+            std::marker::PhantomData,
+        ))
+    }
+}
+
+impl<T: RosMessageType> ros_z::msg::ZMessage for RawMessageWrapper<T> {
+    type Serdes = RawSerdes<T>;
+}
+
+/// A handle to a ROS2 node, implementing roslibrust's traits on top of a ros-z [ZNode](ros_z::node::ZNode).
+///
+/// Cloning the handle is cheap and all clones refer to the same node. When the last clone is dropped the node is
+/// shut down, which also stops any service servers still advertised through it. Publishers and subscribers are not
+/// shut down with it: they keep working, and keep the node's zenoh session open, until they are dropped too.
+#[derive(Clone)]
 pub struct ZenohClient {
-    // TODO: We'll probably end up wrapping node in an Arc<> so we can make this clone for users
+    inner: std::sync::Arc<ZenohClientInner>,
+}
+
+struct ZenohClientInner {
     node: ros_z::node::ZNode,
+    // Parent of the cancellation tokens of the service server tasks
+    shutdown: tokio_util::sync::CancellationToken,
+}
+
+/// See [ZenohClient::downgrade]
+struct WeakZenohClient(std::sync::Weak<ZenohClientInner>);
+
+impl WeakZenohClient {
+    /// Returns a handle to the node if it is still alive
+    fn upgrade(&self) -> Option<ZenohClient> {
+        self.0.upgrade().map(|inner| ZenohClient { inner })
+    }
+}
+
+impl Drop for ZenohClientInner {
+    fn drop(&mut self) {
+        debug!("Last handle to ROS2 node dropped, shutting down");
+        self.shutdown.cancel();
+    }
 }
 
 // A "newtype" wrapper around ZPub so we can implement roslibrust's traits for it.
@@ -119,6 +208,30 @@ impl<T: RosMessageType> Subscribe<T> for ZenohSubscriber<T> {
     }
 }
 
+/// The subscriber type returned by [ZenohClient::subscribe_borrowed].
+/// Messages are copied once out of the zenoh payload and decoded into the message's borrowed variant, which
+/// points into that copy. Unlike ROS1's `Subscriber::next_borrowed` this is not zero-copy.
+pub struct ZenohBorrowedSubscriber<T: codec::HasBorrowed> {
+    subscriber: ZSub<RawMessageWrapper<T>, zenoh::sample::Sample, RawSerdes<T>>,
+}
+
+impl<T: codec::HasBorrowed> ZenohBorrowedSubscriber<T> {
+    /// Waits for the next message, which is decoded once into its borrowed variant.
+    ///
+    /// ros-z only hands the payload out as a slice, so it is copied into a buffer the message can borrow from.
+    /// Its strings and byte sequences aren't copied again when decoding, which saves their allocations compared to
+    /// [ZenohClient::subscribe] but not the copy.
+    pub async fn next(&mut self) -> Result<codec::BorrowedMessage<T>> {
+        let raw = self
+            .subscriber
+            .async_recv()
+            .await
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+        codec::BorrowedMessage::from_cdr(raw.0)
+            .map_err(|e| Error::SerializationError(e.to_string()))
+    }
+}
+
 impl ZenohClient {
     // TODO don't love error type here... could work with ros_z to get better errors
     /// Creates a new node with the specified name and return a handle to it.
@@ -127,7 +240,65 @@ impl ZenohClient {
         name: impl AsRef<str>,
     ) -> StdResult<Self, Box<dyn std::error::Error + Send + Sync + 'static>> {
         let node = ctx.create_node(name.as_ref()).build()?;
-        Ok(Self { node })
+        Ok(Self {
+            inner: std::sync::Arc::new(ZenohClientInner {
+                node,
+                shutdown: tokio_util::sync::CancellationToken::new(),
+            }),
+        })
+    }
+
+    /// A handle to the node which doesn't keep it alive
+    fn downgrade(&self) -> WeakZenohClient {
+        WeakZenohClient(std::sync::Arc::downgrade(&self.inner))
+    }
+
+    // ros-z has no accessor for this, it is a public field at the revision pinned in Cargo.toml
+    /// The zenoh session the node communicates over
+    fn session(&self) -> std::sync::Arc<zenoh::Session> {
+        self.inner.node.session.clone()
+    }
+
+    /// Subscribes to a topic, receiving messages as [codec::BorrowedMessage] instead of deserializing them.
+    /// Requires the message type to be generated with `CodegenOptions::generate_borrowed_types`.
+    /// See [ZenohBorrowedSubscriber::next] for the copy this still makes.
+    pub async fn subscribe_borrowed<MsgType: codec::HasBorrowed>(
+        &self,
+        topic: impl roslibrust_common::topic_name::ToGlobalTopicName + Send,
+    ) -> Result<ZenohBorrowedSubscriber<MsgType>> {
+        let topic: roslibrust_common::GlobalTopicName = topic.to_global_name()?;
+        let subscriber = self
+            .inner
+            .node
+            .create_sub::<RawMessageWrapper<MsgType>>(topic.as_ref())
+            .with_serdes::<RawSerdes<MsgType>>()
+            .build()
+            // TODO better errors
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+        Ok(ZenohBorrowedSubscriber { subscriber })
+    }
+}
+
+/// Converts [TopicOptions] to the equivalent QoS profile, leaving the settings they don't cover at ros-z's defaults
+fn qos_profile(options: &TopicOptions) -> ros_z::qos::QosProfile {
+    use ros_z::qos::{QosDurability, QosHistory, QosProfile, QosReliability};
+    let defaults = QosProfile::default();
+    QosProfile {
+        reliability: match options.reliability {
+            Reliability::Reliable => QosReliability::Reliable,
+            Reliability::BestEffort => QosReliability::BestEffort,
+        },
+        durability: if options.latched {
+            QosDurability::TransientLocal
+        } else {
+            defaults.durability
+        },
+        history: match options.queue_size {
+            // Keep last with a depth of 0 keeps nothing, so 0 is treated as 1
+            Some(depth) => QosHistory::KeepLast(depth.max(1)),
+            None => defaults.history,
+        },
+        ..defaults
     }
 }
 
@@ -141,6 +312,7 @@ impl roslibrust_common::TopicProvider for ZenohClient {
     ) -> Result<Self::Publisher<MsgType>> {
         let topic: roslibrust_common::GlobalTopicName = topic.to_global_name()?;
         let publisher = self
+            .inner
             .node
             .create_pub::<RosMessageWrapper<MsgType>>(topic.as_ref())
             .with_serdes::<WrapperSerdes<MsgType>>()
@@ -160,6 +332,7 @@ impl roslibrust_common::TopicProvider for ZenohClient {
     ) -> Result<Self::Subscriber<MsgType>> {
         let topic: roslibrust_common::GlobalTopicName = topic.to_global_name()?;
         let sub = self
+            .inner
             .node
             .create_sub::<RosMessageWrapper<MsgType>>(topic.as_ref())
             .with_serdes::<WrapperSerdes<MsgType>>()
@@ -172,6 +345,48 @@ impl roslibrust_common::TopicProvider for ZenohClient {
             _marker: std::marker::PhantomData,
         })
     }
+
+    async fn advertise_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl roslibrust_common::topic_name::ToGlobalTopicName + Send,
+        options: TopicOptions,
+    ) -> Result<Self::Publisher<MsgType>> {
+        let topic: roslibrust_common::GlobalTopicName = topic.to_global_name()?;
+        let publisher = self
+            .inner
+            .node
+            .create_pub::<RosMessageWrapper<MsgType>>(topic.as_ref())
+            .with_serdes::<WrapperSerdes<MsgType>>()
+            .with_qos(qos_profile(&options))
+            .build()
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+
+        Ok(ZenohPublisher {
+            publisher,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    async fn subscribe_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl roslibrust_common::topic_name::ToGlobalTopicName + Send,
+        options: TopicOptions,
+    ) -> Result<Self::Subscriber<MsgType>> {
+        let topic: roslibrust_common::GlobalTopicName = topic.to_global_name()?;
+        let sub = self
+            .inner
+            .node
+            .create_sub::<RosMessageWrapper<MsgType>>(topic.as_ref())
+            .with_serdes::<WrapperSerdes<MsgType>>()
+            .with_qos(qos_profile(&options))
+            .build()
+            .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
+
+        Ok(ZenohSubscriber {
+            subscriber: sub,
+            _marker: std::marker::PhantomData,
+        })
+    }
 }
 
 // TODO MAJOR: problem here ZService trait can't be implemented for our example messages due to orphan rule...
@@ -244,6 +459,7 @@ impl roslibrust_common::ServiceProvider for ZenohClient {
     ) -> Result<Self::ServiceClient<SrvType>> {
         let service: roslibrust_common::GlobalTopicName = service.to_global_name()?;
         let client = self
+            .inner
             .node
             .create_client::<Fake<SrvType>>(service.as_ref())
             .build()
@@ -274,12 +490,14 @@ impl roslibrust_common::ServiceProvider for ZenohClient {
         }
 
         let mut svc = self
+            .inner
             .node
             .create_service::<Fake<SrvType>>(service.as_ref())
             .build()
             .map_err(|e| Error::Unexpected(anyhow::anyhow!(e)))?;
 
-        let cancellation_token = tokio_util::sync::CancellationToken::new();
+        // Cancelled when either the server or the last handle to the node is dropped
+        let cancellation_token = self.inner.shutdown.child_token();
 
         // Build a clone wrapper for the users's function
         let server = std::sync::Arc::new(server);
@@ -340,8 +558,46 @@ impl roslibrust_common::ServiceProvider for ZenohClient {
     }
 }
 
+// Compiles only if ZenohClient can be used by code generic over Ros and GraphProvider
+const _: fn() = || {
+    fn takes_ros<T: Ros + GraphProvider>() {}
+    takes_ros::<ZenohClient>();
+};
+
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn qos_profile_from_topic_options() {
+        use crate::qos_profile;
+        use ros_z::qos::{QosDurability, QosHistory, QosProfile, QosReliability};
+        use roslibrust_common::{Reliability, TopicOptions};
+
+        // The default options leave ros-z's defaults untouched
+        let defaults = QosProfile::default();
+        let qos = qos_profile(&TopicOptions::new());
+        assert!(matches!(qos.reliability, QosReliability::Reliable));
+        assert_eq!(
+            std::mem::discriminant(&qos.durability),
+            std::mem::discriminant(&defaults.durability)
+        );
+        assert_eq!(
+            std::mem::discriminant(&qos.history),
+            std::mem::discriminant(&defaults.history)
+        );
+
+        let options = TopicOptions::new()
+            .queue_size(5)
+            .latched(true)
+            .reliability(Reliability::BestEffort);
+        let qos = qos_profile(&options);
+        assert!(matches!(qos.reliability, QosReliability::BestEffort));
+        assert!(matches!(qos.durability, QosDurability::TransientLocal));
+        assert!(matches!(qos.history, QosHistory::KeepLast(5)));
+
+        // Keeping the last 0 messages would keep nothing
+        let qos = qos_profile(&TopicOptions::new().queue_size(0));
+        assert!(matches!(qos.history, QosHistory::KeepLast(1)));
+    }
 
     #[cfg(feature = "ros2_zenoh_test")]
     mod integration_tests {
@@ -427,6 +683,48 @@ mod tests {
             .expect("Failed to receive message within 2 seconds");
         }
 
+        #[tokio::test(flavor = "multi_thread")]
+        async fn service_server_stops_with_last_client_clone() {
+            let ctx = make_test_context();
+            let client = ZenohClient::new(&ctx, "test_service_server_shutdown_node")
+                .await
+                .unwrap();
+
+            // Held by the server task through its service function, released once the task stops
+            let alive = std::sync::Arc::new(());
+            let alive_copy = alive.clone();
+            let server_fn = move |request: roslibrust_test::ros2::std_srvs::SetBoolRequest| {
+                let _alive = &alive_copy;
+                Ok(roslibrust_test::ros2::std_srvs::SetBoolResponse {
+                    message: String::new(),
+                    success: request.data,
+                })
+            };
+            let _service = client
+                .advertise_service::<roslibrust_test::ros2::std_srvs::SetBool, _>(
+                    "/test_service_server_shutdown_set_bool",
+                    server_fn,
+                )
+                .await
+                .unwrap();
+
+            // Dropping a clone leaves the server running
+            let clone = client.clone();
+            drop(client);
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            assert_eq!(std::sync::Arc::strong_count(&alive), 2);
+
+            // Dropping the last one stops it, even though the server handle is still held
+            drop(clone);
+            tokio::time::timeout(tokio::time::Duration::from_secs(2), async {
+                while std::sync::Arc::strong_count(&alive) > 1 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+                }
+            })
+            .await
+            .expect("Service server should stop once the last client clone is dropped");
+        }
+
         // Test is currently failing... Want to merge this code and then file issues to gradually fix
         #[ignore]
         #[tokio::test(flavor = "multi_thread")]
@@ -0,0 +1,213 @@
+use crate::{rosapi, rosapi_msgs, ParamError, Ros2RosApi, RosApi};
+use roslibrust::ServiceProvider;
+
+/// Talks to either a ROS1 or ROS2 rosapi node, picked at runtime with [AnyRosApi::detect]
+pub enum AnyRosApi<T> {
+    Ros1(T),
+    Ros2(Ros2RosApi<T>),
+}
+
+impl<T: ServiceProvider + Send + Sync> AnyRosApi<T> {
+    /// Determines which version of rosapi is running by calling `/rosapi/get_ros_version`.
+    ///
+    /// That service only exists on ROS2 rosapi nodes, so the server reporting it can't be found
+    /// is taken to mean we're talking to a ROS1 node. Any other error is returned.
+    pub async fn detect(client: T) -> roslibrust::Result<Self> {
+        let response = client
+            .call_service::<rosapi_msgs::GetROSVersion>(
+                "/rosapi/get_ros_version",
+                rosapi_msgs::GetROSVersionRequest {},
+            )
+            .await;
+        match response {
+            Ok(response) if response.version >= 2 => Ok(Self::Ros2(Ros2RosApi::new(client))),
+            Ok(_) => Ok(Self::Ros1(client)),
+            Err(roslibrust::Error::ServerError(message)) if is_service_not_found(&message) => {
+                Ok(Self::Ros1(client))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns true if the detected rosapi node is a ROS2 node
+    pub fn is_ros2(&self) -> bool {
+        matches!(self, Self::Ros2(_))
+    }
+
+    /// Access the wrapped client
+    pub fn inner(&self) -> &T {
+        match self {
+            Self::Ros1(client) => client,
+            Self::Ros2(api) => api.inner(),
+        }
+    }
+}
+
+/// Backends report calling a service which doesn't exist as a [roslibrust::Error::ServerError],
+/// so the only way to tell that apart from a failing server is the message each one uses:
+///  - rosbridge: "Service /rosapi/get_ros_version does not exist"
+///  - ros1: the master's "no provider" response to lookupService
+///  - zenoh and mock: "No service server ..."
+fn is_service_not_found(message: &str) -> bool {
+    const NOT_FOUND_MESSAGES: [&str; 3] = ["does not exist", "no provider", "No service server"];
+    NOT_FOUND_MESSAGES
+        .iter()
+        .any(|not_found| message.contains(not_found))
+}
+
+// Forwards each RosApi method to whichever implementation was detected
+macro_rules! dispatch {
+    ($self:ident.$method:ident($($arg:ident),*)) => {
+        match $self {
+            Self::Ros1(client) => RosApi::$method(client, $($arg),*).await,
+            Self::Ros2(api) => RosApi::$method(api, $($arg),*).await,
+        }
+    };
+}
+
+impl<T: ServiceProvider + Send + Sync> RosApi for AnyRosApi<T> {
+    async fn get_time(&self) -> roslibrust::Result<rosapi::GetTimeResponse> {
+        dispatch!(self.get_time())
+    }
+
+    async fn topics(&self) -> roslibrust::Result<rosapi::TopicsResponse> {
+        dispatch!(self.topics())
+    }
+
+    async fn get_topic_type(
+        &self,
+        topic: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::TopicTypeResponse> {
+        dispatch!(self.get_topic_type(topic))
+    }
+
+    async fn get_topics_for_type(
+        &self,
+        topic_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::TopicsForTypeResponse> {
+        dispatch!(self.get_topics_for_type(topic_type))
+    }
+
+    async fn get_nodes(&self) -> roslibrust::Result<rosapi::NodesResponse> {
+        dispatch!(self.get_nodes())
+    }
+
+    async fn get_node_details(
+        &self,
+        node: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::NodeDetailsResponse> {
+        dispatch!(self.get_node_details(node))
+    }
+
+    async fn get_node_for_service(
+        &self,
+        service: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceNodeResponse> {
+        dispatch!(self.get_node_for_service(service))
+    }
+
+    async fn set_param(
+        &self,
+        param_name: impl Into<String> + Send,
+        param_value: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::SetParamResponse> {
+        dispatch!(self.set_param(param_name, param_value))
+    }
+
+    async fn get_param(
+        &self,
+        param_name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::GetParamResponse> {
+        dispatch!(self.get_param(param_name))
+    }
+
+    async fn get_param_names(&self) -> roslibrust::Result<rosapi::GetParamNamesResponse> {
+        dispatch!(self.get_param_names())
+    }
+
+    async fn has_param(
+        &self,
+        param: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::HasParamResponse> {
+        dispatch!(self.has_param(param))
+    }
+
+    async fn get_param_tree(
+        &self,
+        namespace: impl Into<String> + Send,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, ParamError> {
+        dispatch!(self.get_param_tree(namespace))
+    }
+
+    async fn delete_param(
+        &self,
+        name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::DeleteParamResponse> {
+        dispatch!(self.delete_param(name))
+    }
+
+    async fn message_details(
+        &self,
+        message_name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::MessageDetailsResponse> {
+        dispatch!(self.message_details(message_name))
+    }
+
+    async fn publishers(
+        &self,
+        topic: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::PublishersResponse> {
+        dispatch!(self.publishers(topic))
+    }
+
+    async fn service_host(
+        &self,
+        service: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceHostResponse> {
+        dispatch!(self.service_host(service))
+    }
+
+    async fn service_providers(
+        &self,
+        service_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceProvidersResponse> {
+        dispatch!(self.service_providers(service_type))
+    }
+
+    async fn get_service_request_details(
+        &self,
+        service_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceRequestDetailsResponse> {
+        dispatch!(self.get_service_request_details(service_type))
+    }
+
+    async fn get_service_response_details(
+        &self,
+        service_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceRequestDetailsResponse> {
+        dispatch!(self.get_service_response_details(service_type))
+    }
+
+    async fn get_service_type(
+        &self,
+        service_name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceTypeResponse> {
+        dispatch!(self.get_service_type(service_name))
+    }
+
+    async fn get_services(&self) -> roslibrust::Result<rosapi::ServicesResponse> {
+        dispatch!(self.get_services())
+    }
+
+    async fn topics_and_raw_types(&self) -> roslibrust::Result<rosapi::TopicsAndRawTypesResponse> {
+        dispatch!(self.topics_and_raw_types())
+    }
+
+    async fn get_action_servers(&self) -> roslibrust::Result<rosapi::GetActionServersResponse> {
+        dispatch!(self.get_action_servers())
+    }
+
+    async fn interfaces(&self) -> roslibrust::Result<rosapi_msgs::InterfacesResponse> {
+        dispatch!(self.interfaces())
+    }
+}
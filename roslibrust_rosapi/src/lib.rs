@@ -2,14 +2,29 @@
 //! provided by the [rosapi](http://wiki.ros.org/rosapi) node.
 //!
 //! Ensure rosapi is running on your target system before attempting to utilize these features!
+//!
+//! ROS1 and ROS2 rosapi nodes provide the same services, but with different types. Any [ServiceProvider]
+//! talks to the ROS1 node through [RosApi] directly, wrap it in [Ros2RosApi] to talk to the ROS2 node
+//! instead, or use [AnyRosApi::detect] to pick between the two at runtime.
 
 use roslibrust::ServiceProvider;
+use serde::{de::DeserializeOwned, Serialize};
+
+mod any;
+mod params;
+mod ros2;
+pub use any::AnyRosApi;
+pub use params::ParamError;
+pub use ros2::Ros2RosApi;
 
 // TODO major issue here for folks who actually try to use rosapi in their project
 // This macro isn't going to expand correctly when not used from this crate's workspace
 // We almost certainly need to generate and commit the resulting messages, or
 // do some include_str!() hax to be able to ship these types with the crate...
-roslibrust::find_and_generate_ros_messages!("assets/ros1_common_interfaces/rosapi");
+roslibrust::find_and_generate_ros_messages!(
+    "assets/ros1_common_interfaces/rosapi",
+    "assets/ros2_rosapi_msgs"
+);
 
 /// Represents the ability to interact with the interfaces provided by the rosapi node.
 /// This trait is implemented for ClientHandle when the `rosapi` feature is enabled.
@@ -107,10 +122,110 @@ pub trait RosApi {
     fn get_services(
         &self,
     ) -> impl std::future::Future<Output = roslibrust::Result<rosapi::ServicesResponse>> + Send;
+
+    fn topics_and_raw_types(
+        &self,
+    ) -> impl std::future::Future<Output = roslibrust::Result<rosapi::TopicsAndRawTypesResponse>> + Send;
+
+    fn get_action_servers(
+        &self,
+    ) -> impl std::future::Future<Output = roslibrust::Result<rosapi::GetActionServersResponse>> + Send;
+
+    fn interfaces(
+        &self,
+    ) -> impl std::future::Future<Output = roslibrust::Result<rosapi_msgs::InterfacesResponse>> + Send;
+
+    /// Gets a parameter and deserializes it from the JSON returned by rosapi.
+    ///
+    /// Returns [ParamError::Missing] if the parameter isn't set and [ParamError::Decode] if its value doesn't fit `T`.
+    /// A parameter set to `null` is decoded like any other value, so it can be read as an `Option`.
+    /// rosapi returns `null` for missing parameters too, so getting one makes an extra `has_param` call.
+    fn get_param_as<T: DeserializeOwned + Send>(
+        &self,
+        param_name: impl Into<String> + Send,
+    ) -> impl std::future::Future<Output = Result<T, ParamError>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let param_name = param_name.into();
+            let response = self.get_param(param_name.clone()).await?;
+            if params::is_null(&response.value) && !self.has_param(param_name.clone()).await?.exists
+            {
+                return Err(ParamError::Missing(param_name));
+            }
+            params::decode_param(&param_name, &response.value)
+        }
+    }
+
+    /// Like [get_param_as](Self::get_param_as), but returns `default` if the parameter isn't set.
+    fn get_param_or<T: DeserializeOwned + Send>(
+        &self,
+        param_name: impl Into<String> + Send,
+        default: T,
+    ) -> impl std::future::Future<Output = Result<T, ParamError>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            match self.get_param_as(param_name).await {
+                Err(ParamError::Missing(_)) => Ok(default),
+                result => result,
+            }
+        }
+    }
+
+    /// Serializes `value` to JSON and sets it as the parameter's value.
+    fn set_param_from<T: Serialize + ?Sized>(
+        &self,
+        param_name: impl Into<String> + Send,
+        value: &T,
+    ) -> impl std::future::Future<Output = Result<(), ParamError>> + Send
+    where
+        Self: Sync,
+    {
+        let param_name = param_name.into();
+        let value = serde_json::to_string(value).map_err(|source| ParamError::Encode {
+            name: param_name.clone(),
+            source,
+        });
+        async move {
+            self.set_param(param_name, value?).await?;
+            Ok(())
+        }
+    }
+
+    /// Gets every parameter under `namespace` as a nested JSON object.
+    ///
+    /// Nesting follows `/` for ROS1 names and `:` and `.` for the `{node}:{param}` names of ROS2 rosapi,
+    /// so the tree of `/talker` holds `/talker:qos.depth` at `["qos"]["depth"]`.
+    /// Returns [ParamError::Missing] if no parameters are under the namespace.
+    ///
+    /// ROS1 rosapi returns a whole namespace from a single `get_param` call, which this default makes.
+    fn get_param_tree(
+        &self,
+        namespace: impl Into<String> + Send,
+    ) -> impl std::future::Future<
+        Output = Result<serde_json::Map<String, serde_json::Value>, ParamError>,
+    > + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let namespace = namespace.into();
+            let value = self.get_param(namespace.clone()).await?.value;
+            match params::decode_param(&namespace, &value)? {
+                serde_json::Value::Object(tree) if !tree.is_empty() => Ok(tree),
+                // Either nothing is set or the namespace is a single parameter
+                _ => Err(ParamError::Missing(namespace)),
+            }
+        }
+    }
 }
 
 /// A Generic implementation of the RosApi trait for any type that implements ServiceProvider
-/// Note, this will not work on ROS2 systems or systems without the rosapi node running.
+/// Note, this talks to the ROS1 rosapi node, use [Ros2RosApi] on ROS2 systems.
+/// This will not work on systems without the rosapi node running.
 impl<T: ServiceProvider + Send + Sync> RosApi for T {
     /// Get the current time
     async fn get_time(&self) -> roslibrust::Result<rosapi::GetTimeResponse> {
@@ -355,9 +470,37 @@ impl<T: ServiceProvider + Send + Sync> RosApi for T {
             .await
     }
 
+    /// Get the list of topics active, their types and the full definitions of those types
+    async fn topics_and_raw_types(&self) -> roslibrust::Result<rosapi::TopicsAndRawTypesResponse> {
+        self.call_service::<rosapi::TopicsAndRawTypes>(
+            "/rosapi/topics_and_raw_types",
+            rosapi::TopicsAndRawTypesRequest {},
+        )
+        .await
+    }
+
+    /// Get the list of action servers, found by looking for topics of the form `{action}/goal`
+    async fn get_action_servers(&self) -> roslibrust::Result<rosapi::GetActionServersResponse> {
+        self.call_service::<rosapi::GetActionServers>(
+            "/rosapi/action_servers",
+            rosapi::GetActionServersRequest {},
+        )
+        .await
+    }
+
+    /// Get the list of message, service and action types known to the system.
+    /// Only the ROS2 rosapi node provides this, ROS1 rosapi nodes will return an error.
+    async fn interfaces(&self) -> roslibrust::Result<rosapi_msgs::InterfacesResponse> {
+        self.call_service::<rosapi_msgs::Interfaces>(
+            "/rosapi/interfaces",
+            rosapi_msgs::InterfacesRequest {},
+        )
+        .await
+    }
+
     /*
      List of rosapi services pulled from `rosservice list`
-     /rosapi/action_servers - Probably won't support
+     /rosapi/action_servers - Done
      /rosapi/delete_param - Done
      /rosapi/get_loggers - ??
      /rosapi/get_param - Done
@@ -375,14 +518,14 @@ impl<T: ServiceProvider + Send + Sync> RosApi for T {
      /rosapi/service_request_details - Done
      /rosapi/service_response_details - Done
      /rosapi/service_type - Done
-     /rosapi/services - TODO
+     /rosapi/services - Done
      /rosapi/services_for_type - Done
      /rosapi/set_logger_level - ??
      /rosapi/set_param - Done
      /rosapi/subscribers - Done
      /rosapi/topic_type - Done
      /rosapi/topics - Done
-     /rosapi/topics_and_raw_types - ??
+     /rosapi/topics_and_raw_types - Done
      /rosapi/topics_for_type - Done
     */
 }
@@ -552,3 +695,245 @@ mod test {
         assert!(!response.services.is_empty());
     }
 }
+
+#[cfg(test)]
+mod mock_test {
+    use super::{params, rosapi, rosapi_msgs, AnyRosApi, ParamError, RosApi};
+    use roslibrust::mock::MockRos;
+    use roslibrust::ServiceProvider;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    #[test_log::test(tokio::test)]
+    async fn detects_ros2_rosapi() {
+        let ros = MockRos::new();
+        ros.advertise_service::<rosapi_msgs::GetROSVersion, _>("/rosapi/get_ros_version", |_| {
+            Ok(rosapi_msgs::GetROSVersionResponse {
+                version: 2,
+                distro: "jazzy".to_string(),
+            })
+        })
+        .await
+        .unwrap();
+        ros.advertise_service::<rosapi_msgs::GetTime, _>("/rosapi/get_time", |_| {
+            Ok(rosapi_msgs::GetTimeResponse {
+                time: super::builtin_interfaces::Time {
+                    sec: 10,
+                    nanosec: 500,
+                },
+            })
+        })
+        .await
+        .unwrap();
+
+        let api = AnyRosApi::detect(ros).await.unwrap();
+        assert!(api.is_ros2());
+        let response = api.get_time().await.unwrap();
+        assert_eq!(response.time.secs, 10);
+        assert_eq!(response.time.nsecs, 500);
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn detects_ros1_rosapi() {
+        let api = AnyRosApi::detect(MockRos::new()).await.unwrap();
+        assert!(!api.is_ros2());
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn detect_returns_errors_other_than_service_not_found() {
+        let ros = MockRos::new();
+        ros.advertise_service::<rosapi_msgs::GetROSVersion, _>("/rosapi/get_ros_version", |_| {
+            Err(roslibrust::Error::ServerError("rosapi is broken".to_string()).into())
+        })
+        .await
+        .unwrap();
+        assert!(matches!(
+            AnyRosApi::detect(ros).await,
+            Err(roslibrust::Error::ServerError(message)) if message == "rosapi is broken"
+        ));
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn detect_treats_a_failing_server_as_an_error() {
+        let ros = MockRos::new();
+        ros.advertise_service::<rosapi_msgs::GetROSVersion, _>("/rosapi/get_ros_version", |_| {
+            Err(std::io::Error::other("rosapi crashed").into())
+        })
+        .await
+        .unwrap();
+        assert!(matches!(
+            AnyRosApi::detect(ros).await,
+            Err(roslibrust::Error::ServerError(message)) if message == "rosapi crashed"
+        ));
+    }
+
+    /// Serves the ROS1 rosapi parameter services from an in memory parameter server.
+    /// Like ROS1, getting a namespace returns everything under it as one object.
+    async fn mock_param_server() -> MockRos {
+        let ros = MockRos::new();
+        let params = Arc::new(Mutex::new(BTreeMap::<String, String>::new()));
+        let store = params.clone();
+        ros.advertise_service::<rosapi::SetParam, _>("/rosapi/set_param", move |request| {
+            store.lock().unwrap().insert(request.name, request.value);
+            Ok(rosapi::SetParamResponse {})
+        })
+        .await
+        .unwrap();
+        let store = params.clone();
+        ros.advertise_service::<rosapi::GetParam, _>("/rosapi/get_param", move |request| {
+            let store = store.lock().unwrap();
+            if let Some(value) = store.get(&request.name) {
+                return Ok(rosapi::GetParamResponse {
+                    value: value.clone(),
+                });
+            }
+            let mut tree = serde_json::Map::new();
+            for (name, value) in store.iter() {
+                if let Some(path) = params::relative_path(&request.name, name) {
+                    params::insert_param(&mut tree, &path, serde_json::from_str(value)?);
+                }
+            }
+            let value = match tree.is_empty() {
+                true => "null".to_string(),
+                false => serde_json::Value::Object(tree).to_string(),
+            };
+            Ok(rosapi::GetParamResponse { value })
+        })
+        .await
+        .unwrap();
+        let store = params.clone();
+        ros.advertise_service::<rosapi::HasParam, _>("/rosapi/has_param", move |request| {
+            Ok(rosapi::HasParamResponse {
+                exists: store.lock().unwrap().contains_key(&request.name),
+            })
+        })
+        .await
+        .unwrap();
+        ros.advertise_service::<rosapi::GetParamNames, _>("/rosapi/get_param_names", move |_| {
+            Ok(rosapi::GetParamNamesResponse {
+                names: params.lock().unwrap().keys().cloned().collect(),
+            })
+        })
+        .await
+        .unwrap();
+        ros
+    }
+
+    /// Serves the ROS2 rosapi parameter services for a fixed set of `{node}:{param}` parameters
+    async fn mock_ros2_param_server(params: &[(&str, &str)]) -> MockRos {
+        let ros = MockRos::new();
+        let params: Arc<BTreeMap<String, String>> = Arc::new(
+            params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        );
+        let store = params.clone();
+        ros.advertise_service::<rosapi_msgs::GetParam, _>("/rosapi/get_param", move |request| {
+            Ok(rosapi_msgs::GetParamResponse {
+                value: store
+                    .get(&request.name)
+                    .cloned()
+                    .unwrap_or_else(|| "null".to_string()),
+            })
+        })
+        .await
+        .unwrap();
+        ros.advertise_service::<rosapi_msgs::GetParamNames, _>(
+            "/rosapi/get_param_names",
+            move |_| {
+                Ok(rosapi_msgs::GetParamNamesResponse {
+                    names: params.keys().cloned().collect(),
+                })
+            },
+        )
+        .await
+        .unwrap();
+        ros
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn typed_params() {
+        let api = mock_param_server().await;
+        api.set_param_from("/robot/max_speed", &1.5).await.unwrap();
+        api.set_param_from("/robot/arm/joints", &["shoulder", "elbow"])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            api.get_param_as::<f64>("/robot/max_speed").await.unwrap(),
+            1.5
+        );
+        assert!(matches!(
+            api.get_param_as::<f64>("/robot/missing").await,
+            Err(ParamError::Missing(_))
+        ));
+        assert!(matches!(
+            api.get_param_as::<String>("/robot/max_speed").await,
+            Err(ParamError::Decode { .. })
+        ));
+        assert_eq!(api.get_param_or("/robot/missing", 2.0).await.unwrap(), 2.0);
+
+        let tree = api.get_param_tree("/robot").await.unwrap();
+        assert_eq!(
+            serde_json::Value::Object(tree),
+            serde_json::json!({"max_speed": 1.5, "arm": {"joints": ["shoulder", "elbow"]}})
+        );
+        assert!(matches!(
+            api.get_param_tree("/other").await,
+            Err(ParamError::Missing(_))
+        ));
+        assert!(matches!(
+            api.get_param_tree("/robot/max_speed").await,
+            Err(ParamError::Missing(_))
+        ));
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn null_params_are_not_missing() {
+        let api = mock_param_server().await;
+        api.set_param_from("/robot/offset", &None::<f64>)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            api.get_param_as::<Option<f64>>("/robot/offset")
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(matches!(
+            api.get_param_as::<f64>("/robot/offset").await,
+            Err(ParamError::Decode { .. })
+        ));
+        assert!(matches!(
+            api.get_param_or("/robot/offset", 2.0).await,
+            Err(ParamError::Decode { .. })
+        ));
+        assert!(matches!(
+            api.get_param_as::<Option<f64>>("/robot/missing").await,
+            Err(ParamError::Missing(_))
+        ));
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn ros2_param_tree() {
+        let ros = mock_ros2_param_server(&[
+            ("/talker:qos.depth", "10"),
+            ("/talker:use_sim_time", "false"),
+            ("/listener:use_sim_time", "true"),
+        ])
+        .await;
+        let api = super::Ros2RosApi::new(ros);
+
+        let tree = api.get_param_tree("/talker").await.unwrap();
+        assert_eq!(
+            serde_json::Value::Object(tree),
+            serde_json::json!({"qos": {"depth": 10}, "use_sim_time": false})
+        );
+        assert!(matches!(
+            api.get_param_tree("/other").await,
+            Err(ParamError::Missing(_))
+        ));
+    }
+}
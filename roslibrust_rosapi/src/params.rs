@@ -0,0 +1,111 @@
+use serde_json::{Map, Value};
+
+/// Error type returned by the typed parameter helpers on [RosApi](crate::RosApi)
+#[derive(thiserror::Error, Debug)]
+pub enum ParamError {
+    /// The parameter (or for [get_param_tree](crate::RosApi::get_param_tree) any parameter under the namespace) isn't set
+    #[error("Parameter {0} is not set")]
+    Missing(String),
+    /// The parameter is set, but its value couldn't be deserialized into the requested type
+    #[error("Failed to decode parameter {name}: {source}")]
+    Decode {
+        name: String,
+        source: serde_json::Error,
+    },
+    /// The value couldn't be serialized to JSON to be sent to rosapi
+    #[error("Failed to encode parameter {name}: {source}")]
+    Encode {
+        name: String,
+        source: serde_json::Error,
+    },
+    /// Calling the rosapi service failed
+    #[error(transparent)]
+    Ros(#[from] roslibrust::Error),
+}
+
+/// Whether rosapi returned `null` for a parameter.
+///
+/// We never send rosapi a default value, so it returns `null` both for a parameter that isn't set and
+/// for one set to `null`, and the caller has to ask `has_param` to tell them apart.
+pub(crate) fn is_null(value: &str) -> bool {
+    value.is_empty() || value == "null"
+}
+
+/// Parses the JSON value rosapi returned for `name`, which is known to be set
+pub(crate) fn decode_param<T: serde::de::DeserializeOwned>(
+    name: &str,
+    value: &str,
+) -> Result<T, ParamError> {
+    let value = if value.is_empty() { "null" } else { value };
+    serde_json::from_str(value).map_err(|source| ParamError::Decode {
+        name: name.to_string(),
+        source,
+    })
+}
+
+/// Returns the path of `name` relative to `namespace`, or None if it isn't inside it.
+///
+/// ROS1 names are separated with `/`, ROS2 rosapi names parameters `{node}:{param}` and nests them with `.`,
+/// so `/talker:qos.depth` is at `["qos", "depth"]` in namespace `/talker`.
+pub(crate) fn relative_path<'a>(namespace: &str, name: &'a str) -> Option<Vec<&'a str>> {
+    let namespace = namespace.trim_end_matches('/');
+    let remainder = name.strip_prefix(namespace)?;
+    let remainder = remainder.strip_prefix(['/', ':', '.'])?;
+    if remainder.is_empty() {
+        return None;
+    }
+    Some(remainder.split(['/', ':', '.']).collect())
+}
+
+/// Inserts `value` into `tree` at `path`, creating nested objects as needed
+pub(crate) fn insert_param(tree: &mut Map<String, Value>, path: &[&str], value: Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut node = tree;
+    for part in parents {
+        let entry = node
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !entry.is_object() {
+            *entry = Value::Object(Map::new());
+        }
+        node = entry.as_object_mut().unwrap();
+    }
+    node.insert(last.to_string(), value);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn relative_paths() {
+        assert_eq!(relative_path("/ns", "/ns/a/b"), Some(vec!["a", "b"]));
+        assert_eq!(relative_path("/ns/", "/ns/a"), Some(vec!["a"]));
+        assert_eq!(relative_path("/ns", "/nsx/a"), None);
+        assert_eq!(relative_path("/ns", "/ns"), None);
+        assert_eq!(relative_path("/", "/a/b"), Some(vec!["a", "b"]));
+        assert_eq!(
+            relative_path("/talker", "/talker:qos.depth"),
+            Some(vec!["qos", "depth"])
+        );
+    }
+
+    #[test]
+    fn null_and_invalid_values() {
+        assert!(is_null("null"));
+        assert!(is_null(""));
+        assert!(!is_null("\"null\""));
+        assert_eq!(decode_param::<Option<f64>>("/a", "null").unwrap(), None);
+        assert!(matches!(
+            decode_param::<f64>("/a", "null"),
+            Err(ParamError::Decode { .. })
+        ));
+        assert!(matches!(
+            decode_param::<f64>("/a", "\"text\""),
+            Err(ParamError::Decode { .. })
+        ));
+        assert_eq!(decode_param::<f64>("/a", "1.5").unwrap(), 1.5);
+    }
+}
@@ -0,0 +1,428 @@
+use crate::{params, rosapi, rosapi_msgs, ParamError, RosApi};
+use roslibrust::ServiceProvider;
+
+/// Implements [RosApi] for the ROS2 rosapi node, which provides the same services as the ROS1 node
+/// under the same names but with `rosapi_msgs` types.
+///
+/// Responses are converted to the same types returned for ROS1 so code written against [RosApi] works
+/// with either. Parameters belong to nodes in ROS2, the ROS2 rosapi node names them `{node}:{param}`
+/// e.g. `/talker:use_sim_time`, and implements its parameter services with the node's rcl_interfaces
+/// parameter services.
+///
+/// ```no_run
+/// # async fn example<T: roslibrust::ServiceProvider + Send + Sync>(client: T) -> roslibrust::Result<()> {
+/// use roslibrust_rosapi::{RosApi, Ros2RosApi};
+/// let api = Ros2RosApi::new(client);
+/// let topics = api.topics_and_raw_types().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Ros2RosApi<T>(T);
+
+impl<T> Ros2RosApi<T> {
+    pub fn new(client: T) -> Self {
+        Self(client)
+    }
+
+    /// Access the wrapped client
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ServiceProvider + Send + Sync> RosApi for Ros2RosApi<T> {
+    async fn get_time(&self) -> roslibrust::Result<rosapi::GetTimeResponse> {
+        self.0
+            .call_service::<rosapi_msgs::GetTime>(
+                "/rosapi/get_time",
+                rosapi_msgs::GetTimeRequest {},
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn topics(&self) -> roslibrust::Result<rosapi::TopicsResponse> {
+        self.0
+            .call_service::<rosapi_msgs::Topics>("/rosapi/topics", rosapi_msgs::TopicsRequest {})
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_topic_type(
+        &self,
+        topic: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::TopicTypeResponse> {
+        self.0
+            .call_service::<rosapi_msgs::TopicType>(
+                "/rosapi/topic_type",
+                rosapi_msgs::TopicTypeRequest {
+                    topic: topic.into(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_topics_for_type(
+        &self,
+        topic_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::TopicsForTypeResponse> {
+        self.0
+            .call_service::<rosapi_msgs::TopicsForType>(
+                "/rosapi/topics_for_type",
+                rosapi_msgs::TopicsForTypeRequest {
+                    r#type: topic_type.into(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_nodes(&self) -> roslibrust::Result<rosapi::NodesResponse> {
+        self.0
+            .call_service::<rosapi_msgs::Nodes>("/rosapi/nodes", rosapi_msgs::NodesRequest {})
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_node_details(
+        &self,
+        node: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::NodeDetailsResponse> {
+        self.0
+            .call_service::<rosapi_msgs::NodeDetails>(
+                "/rosapi/node_details",
+                rosapi_msgs::NodeDetailsRequest { node: node.into() },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_node_for_service(
+        &self,
+        service: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceNodeResponse> {
+        self.0
+            .call_service::<rosapi_msgs::ServiceNode>(
+                "/rosapi/service_node",
+                rosapi_msgs::ServiceNodeRequest {
+                    service: service.into(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    /// Sets a parameter, `param_name` is of the form `{node}:{param}`
+    async fn set_param(
+        &self,
+        param_name: impl Into<String> + Send,
+        param_value: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::SetParamResponse> {
+        self.0
+            .call_service::<rosapi_msgs::SetParam>(
+                "/rosapi/set_param",
+                rosapi_msgs::SetParamRequest {
+                    name: param_name.into(),
+                    value: param_value.into(),
+                },
+            )
+            .await
+            .map(|_| rosapi::SetParamResponse {})
+    }
+
+    /// Gets the current value for a parameter, `param_name` is of the form `{node}:{param}`
+    async fn get_param(
+        &self,
+        param_name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::GetParamResponse> {
+        self.0
+            .call_service::<rosapi_msgs::GetParam>(
+                "/rosapi/get_param",
+                rosapi_msgs::GetParamRequest {
+                    name: param_name.into(),
+                    default_value: "".to_string(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_param_names(&self) -> roslibrust::Result<rosapi::GetParamNamesResponse> {
+        self.0
+            .call_service::<rosapi_msgs::GetParamNames>(
+                "/rosapi/get_param_names",
+                rosapi_msgs::GetParamNamesRequest {},
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn has_param(
+        &self,
+        param: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::HasParamResponse> {
+        self.0
+            .call_service::<rosapi_msgs::HasParam>(
+                "/rosapi/has_param",
+                rosapi_msgs::HasParamRequest { name: param.into() },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn delete_param(
+        &self,
+        name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::DeleteParamResponse> {
+        self.0
+            .call_service::<rosapi_msgs::DeleteParam>(
+                "/rosapi/delete_param",
+                rosapi_msgs::DeleteParamRequest { name: name.into() },
+            )
+            .await
+            .map(|_| rosapi::DeleteParamResponse {})
+    }
+
+    /// Returns detailed information about a given message type e.g. 'std_msgs/msg/Header'
+    async fn message_details(
+        &self,
+        message_name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::MessageDetailsResponse> {
+        self.0
+            .call_service::<rosapi_msgs::MessageDetails>(
+                "/rosapi/message_details",
+                rosapi_msgs::MessageDetailsRequest {
+                    r#type: message_name.into(),
+                },
+            )
+            .await
+            .map(|response| rosapi::MessageDetailsResponse {
+                typedefs: convert_typedefs(response.typedefs),
+            })
+    }
+
+    async fn publishers(
+        &self,
+        topic: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::PublishersResponse> {
+        self.0
+            .call_service::<rosapi_msgs::Publishers>(
+                "/rosapi/publishers",
+                rosapi_msgs::PublishersRequest {
+                    topic: topic.into(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    /// The ROS2 rosapi node doesn't provide `/rosapi/service_host`, this will return the error reported for the missing service
+    async fn service_host(
+        &self,
+        service: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceHostResponse> {
+        self.0
+            .call_service::<rosapi::ServiceHost>(
+                "/rosapi/service_host",
+                rosapi::ServiceHostRequest {
+                    service: service.into(),
+                },
+            )
+            .await
+    }
+
+    async fn service_providers(
+        &self,
+        service_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceProvidersResponse> {
+        self.0
+            .call_service::<rosapi_msgs::ServiceProviders>(
+                "/rosapi/service_providers",
+                rosapi_msgs::ServiceProvidersRequest {
+                    service: service_type.into(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_service_request_details(
+        &self,
+        service_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceRequestDetailsResponse> {
+        self.0
+            .call_service::<rosapi_msgs::ServiceRequestDetails>(
+                "/rosapi/service_request_details",
+                rosapi_msgs::ServiceRequestDetailsRequest {
+                    r#type: service_type.into(),
+                },
+            )
+            .await
+            .map(|response| rosapi::ServiceRequestDetailsResponse {
+                typedefs: convert_typedefs(response.typedefs),
+            })
+    }
+
+    async fn get_service_response_details(
+        &self,
+        service_type: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceRequestDetailsResponse> {
+        self.0
+            .call_service::<rosapi_msgs::ServiceResponseDetails>(
+                "/rosapi/service_response_details",
+                rosapi_msgs::ServiceResponseDetailsRequest {
+                    r#type: service_type.into(),
+                },
+            )
+            .await
+            .map(|response| rosapi::ServiceRequestDetailsResponse {
+                typedefs: convert_typedefs(response.typedefs),
+            })
+    }
+
+    async fn get_service_type(
+        &self,
+        service_name: impl Into<String> + Send,
+    ) -> roslibrust::Result<rosapi::ServiceTypeResponse> {
+        self.0
+            .call_service::<rosapi_msgs::ServiceType>(
+                "/rosapi/service_type",
+                rosapi_msgs::ServiceTypeRequest {
+                    service: service_name.into(),
+                },
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_services(&self) -> roslibrust::Result<rosapi::ServicesResponse> {
+        self.0
+            .call_service::<rosapi_msgs::Services>(
+                "/rosapi/services",
+                rosapi_msgs::ServicesRequest {},
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn topics_and_raw_types(&self) -> roslibrust::Result<rosapi::TopicsAndRawTypesResponse> {
+        self.0
+            .call_service::<rosapi_msgs::TopicsAndRawTypes>(
+                "/rosapi/topics_and_raw_types",
+                rosapi_msgs::TopicsAndRawTypesRequest {},
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn get_action_servers(&self) -> roslibrust::Result<rosapi::GetActionServersResponse> {
+        self.0
+            .call_service::<rosapi_msgs::GetActionServers>(
+                "/rosapi/action_servers",
+                rosapi_msgs::GetActionServersRequest {},
+            )
+            .await
+            .map(Into::into)
+    }
+
+    async fn interfaces(&self) -> roslibrust::Result<rosapi_msgs::InterfacesResponse> {
+        self.0
+            .call_service::<rosapi_msgs::Interfaces>(
+                "/rosapi/interfaces",
+                rosapi_msgs::InterfacesRequest {},
+            )
+            .await
+    }
+
+    /// ROS2 rosapi can only get parameters one at a time, so this lists the parameter names and
+    /// gets each one under `namespace`.
+    async fn get_param_tree(
+        &self,
+        namespace: impl Into<String> + Send,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, ParamError> {
+        let namespace = namespace.into();
+        let mut tree = serde_json::Map::new();
+        for name in self.get_param_names().await?.names {
+            let Some(path) = params::relative_path(&namespace, &name) else {
+                continue;
+            };
+            let value = self.get_param(name.clone()).await?.value;
+            // Deleted since we listed the names
+            if params::is_null(&value) {
+                continue;
+            }
+            params::insert_param(&mut tree, &path, params::decode_param(&name, &value)?);
+        }
+        if tree.is_empty() {
+            return Err(ParamError::Missing(namespace));
+        }
+        Ok(tree)
+    }
+}
+
+// Conversions from the ROS2 responses to their identically shaped ROS1 counterparts
+macro_rules! convert_from_ros2 {
+    ($($name:ident { $($field:ident),+ }),+ $(,)?) => {
+        $(
+            impl From<rosapi_msgs::$name> for rosapi::$name {
+                fn from(value: rosapi_msgs::$name) -> Self {
+                    Self {
+                        $($field: value.$field),+
+                    }
+                }
+            }
+        )+
+    };
+}
+
+convert_from_ros2!(
+    TopicsResponse { topics, types },
+    TopicTypeResponse { r#type },
+    TopicsForTypeResponse { topics },
+    NodesResponse { nodes },
+    NodeDetailsResponse {
+        subscribing,
+        publishing,
+        services
+    },
+    ServiceNodeResponse { node },
+    GetParamResponse { value },
+    GetParamNamesResponse { names },
+    HasParamResponse { exists },
+    PublishersResponse { publishers },
+    ServiceProvidersResponse { providers },
+    ServiceTypeResponse { r#type },
+    ServicesResponse { services },
+    TopicsAndRawTypesResponse {
+        topics,
+        types,
+        typedefs_full_text
+    },
+    GetActionServersResponse { action_servers },
+    TypeDef {
+        r#type,
+        fieldnames,
+        fieldtypes,
+        fieldarraylen,
+        examples,
+        constnames,
+        constvalues
+    },
+);
+
+impl From<rosapi_msgs::GetTimeResponse> for rosapi::GetTimeResponse {
+    fn from(value: rosapi_msgs::GetTimeResponse) -> Self {
+        Self {
+            time: roslibrust::codegen::integral_types::Time {
+                secs: value.time.sec,
+                // nanosec is always below 1e9
+                nsecs: value.time.nanosec as i32,
+            },
+        }
+    }
+}
+
+fn convert_typedefs(typedefs: Vec<rosapi_msgs::TypeDef>) -> Vec<rosapi::TypeDef> {
+    typedefs.into_iter().map(Into::into).collect()
+}
@@ -25,6 +25,7 @@ use super::{
 pub struct ClientHandleOptions {
     url: String,
     timeout: Option<Duration>,
+    pub(crate) graph_poll_period: Duration,
 }
 
 impl ClientHandleOptions {
@@ -33,6 +34,7 @@ impl ClientHandleOptions {
         ClientHandleOptions {
             url: url.into(),
             timeout: None,
+            graph_poll_period: Duration::from_secs(5),
         }
     }
 
@@ -43,6 +45,14 @@ impl ClientHandleOptions {
         self.timeout = Some(duration.into());
         self
     }
+
+    /// Configures how often [GraphProvider::graph_events](roslibrust_common::GraphProvider::graph_events)
+    /// asks rosapi for changes to the graph, every 5 seconds by default.
+    /// Each poll makes a rosapi service call per node, so polling often can load a busy rosapi node.
+    pub fn graph_poll_period<T: Into<Duration>>(mut self, period: T) -> ClientHandleOptions {
+        self.graph_poll_period = period.into();
+        self
+    }
 }
 
 /// The ClientHandle is the fundamental object through which users of this library are expected to interact with it.
@@ -118,7 +128,11 @@ impl ClientHandle {
     }
 
     // Internal implementation of subscribe
-    async fn _subscribe<Msg>(&self, topic_name: &str) -> Result<Subscriber<Msg>>
+    async fn _subscribe<Msg>(
+        &self,
+        topic_name: &str,
+        options: TopicOptions,
+    ) -> Result<Subscriber<Msg>>
     where
         Msg: RosMessageType,
     {
@@ -130,15 +144,20 @@ impl ClientHandle {
             .or_insert(Subscription {
                 handles: HashMap::new(),
                 topic_type: Msg::ROS_TYPE_NAME.to_string(),
+                options,
             });
 
         // TODO Possible bug here? We send a subscribe message each time even if already subscribed
         // Send subscribe message to rosbridge to initiate it sending us messages
         let mut stream = client.writer.write().await;
-        stream.subscribe(topic_name, Msg::ROS_TYPE_NAME).await?;
+        stream
+            .subscribe(topic_name, Msg::ROS_TYPE_NAME, &options)
+            .await?;
 
         // Create a new watch channel for this topic
-        let queue = Arc::new(MessageQueue::new(QUEUE_SIZE));
+        // A queue without room for a message would never deliver anything, so 0 is treated as 1
+        let queue_size = options.queue_size.unwrap_or(QUEUE_SIZE).max(1);
+        let queue = Arc::new(MessageQueue::new(queue_size));
 
         // Move the tx into a callback that takes raw string data
         // This allows us to store the callbacks generic on type, Msg conversion is embedded here
@@ -239,13 +258,29 @@ impl ClientHandle {
     /// # }
     /// ```
     pub async fn subscribe<Msg>(&self, topic_name: &str) -> Result<Subscriber<Msg>>
+    where
+        Msg: RosMessageType,
+    {
+        self.subscribe_with(topic_name, TopicOptions::default())
+            .await
+    }
+
+    /// Same as [ClientHandle::subscribe], but `options.queue_size` sets both the `queue_length` rosbridge buffers
+    /// messages with and the size of the returned subscriber's queue. Other options are ignored.
+    ///
+    /// The options of the first subscription to a topic are used for all subscriptions to it.
+    pub async fn subscribe_with<Msg>(
+        &self,
+        topic_name: &str,
+        options: TopicOptions,
+    ) -> Result<Subscriber<Msg>>
     where
         Msg: RosMessageType,
     {
         self.check_for_disconnect()?;
         timeout(
             self.inner.read().await.opts.timeout,
-            self._subscribe(topic_name),
+            self._subscribe(topic_name, options),
         )
         .await
     }
@@ -291,6 +326,19 @@ impl ClientHandle {
     /// # }
     /// ```
     pub async fn advertise<T>(&self, topic: &str) -> Result<Publisher<T>>
+    where
+        T: RosMessageType,
+    {
+        self.advertise_with(topic, TopicOptions::default()).await
+    }
+
+    /// Same as [ClientHandle::advertise], but sending rosbridge `options.queue_size` as the publisher's queue size
+    /// and latching the topic when `options.latched` is set. Reliability is ignored.
+    pub async fn advertise_with<T>(
+        &self,
+        topic: &str,
+        options: TopicOptions,
+    ) -> Result<Publisher<T>>
     where
         T: RosMessageType,
     {
@@ -306,6 +354,7 @@ impl ClientHandle {
                 topic.to_string(),
                 PublisherHandle {
                     topic_type: T::ROS_TYPE_NAME.to_string(),
+                    options,
                 },
             );
         }
@@ -313,7 +362,7 @@ impl ClientHandle {
         {
             let mut stream = client.writer.write().await;
             debug!("Advertise got lock on comm");
-            stream.advertise::<T>(topic).await?;
+            stream.advertise::<T>(topic, &options).await?;
         }
         Ok(Publisher::new(topic.to_string(), self.clone()))
     }
@@ -562,7 +611,7 @@ pub(crate) struct Client {
     // Contains any outstanding service calls we're waiting for a response on
     // Map key will be a uniquely generated id for each call
     service_calls: DashMap<String, tokio::sync::oneshot::Sender<Value>>,
-    opts: ClientHandleOptions,
+    pub(crate) opts: ClientHandleOptions,
 }
 
 impl Client {
@@ -732,22 +781,29 @@ impl Client {
         // Re-advertise all publishers
         for publisher in self.publishers.iter() {
             let topic = publisher.key();
-            let topic_type = &publisher.value().topic_type;
+            let PublisherHandle {
+                topic_type,
+                options,
+            } = publisher.value();
             let mut lock = self.writer.write().await;
-            lock.advertise_str(topic, topic_type).await?;
+            lock.advertise_str(topic, topic_type, options).await?;
         }
 
         // Resend rosbridge our subscription requests to re-establish inflight subscriptions
         // Clone here is dumb, but required due to async
-        let mut subs: Vec<(String, String)> = vec![];
+        let mut subs: Vec<(String, String, TopicOptions)> = vec![];
         {
             for sub in self.subscriptions.iter() {
-                subs.push((sub.key().clone(), sub.value().topic_type.clone()))
+                subs.push((
+                    sub.key().clone(),
+                    sub.value().topic_type.clone(),
+                    sub.value().options,
+                ))
             }
         }
         let mut stream = self.writer.write().await;
-        for (topic, topic_type) in &subs {
-            stream.subscribe(topic, topic_type).await?;
+        for (topic, topic_type, options) in &subs {
+            stream.subscribe(topic, topic_type, options).await?;
         }
 
         Ok(())
@@ -3,7 +3,7 @@ use crate::Writer;
 use anyhow::bail;
 use futures_util::SinkExt;
 use log::debug;
-use roslibrust_common::{Error, Result, RosMessageType};
+use roslibrust_common::{Error, Result, RosMessageType, TopicOptions};
 use serde_json::json;
 use std::{fmt::Display, str::FromStr, string::ToString};
 use tokio_tungstenite::tungstenite::Message;
@@ -89,11 +89,25 @@ impl FromStr for Ops {
 /// using this trait for mocking. I'm inclined to replace it, and move the
 /// impls directly into some wrapper around [Writer]
 pub(crate) trait RosBridgeComm {
-    async fn subscribe(&mut self, topic: &str, msg_type: &str) -> Result<()>;
+    async fn subscribe(
+        &mut self,
+        topic: &str,
+        msg_type: &str,
+        options: &TopicOptions,
+    ) -> Result<()>;
     async fn unsubscribe(&mut self, topic: &str) -> Result<()>;
     async fn publish<T: RosMessageType>(&mut self, topic: &str, msg: &T) -> Result<()>;
-    async fn advertise<T: RosMessageType>(&mut self, topic: &str) -> Result<()>;
-    async fn advertise_str(&mut self, topic: &str, msg_type: &str) -> Result<()>;
+    async fn advertise<T: RosMessageType>(
+        &mut self,
+        topic: &str,
+        options: &TopicOptions,
+    ) -> Result<()>;
+    async fn advertise_str(
+        &mut self,
+        topic: &str,
+        msg_type: &str,
+        options: &TopicOptions,
+    ) -> Result<()>;
     async fn call_service<Req: RosMessageType>(
         &mut self,
         service: &str,
@@ -112,16 +126,48 @@ pub(crate) trait RosBridgeComm {
     ) -> Result<()>;
 }
 
-impl RosBridgeComm for Writer {
-    async fn subscribe(&mut self, topic: &str, msg_type: &str) -> Result<()> {
-        let msg = json!(
+/// Builds a subscribe op, `options.queue_size` becomes rosbridge's `queue_length`
+fn subscribe_msg(topic: &str, msg_type: &str, options: &TopicOptions) -> serde_json::Value {
+    let mut msg = json!(
+    {
+    "op": Ops::Subscribe.to_string(),
+    "topic": topic,
+    "type": msg_type,
+    }
+    );
+    if let Some(queue_length) = options.queue_size {
+        // See TopicOptions::queue_size, 0 is treated as 1 like the other backends do
+        msg["queue_length"] = json!(queue_length.max(1));
+    }
+    msg
+}
+
+/// Builds an advertise op, with the publisher's `queue_size` and `latch` taken from `options`
+fn advertise_msg(topic: &str, topic_type: &str, options: &TopicOptions) -> serde_json::Value {
+    let mut msg = json!(
         {
-        "op": Ops::Subscribe.to_string(),
-        "topic": topic,
-        "type": msg_type,
+            "op": Ops::Advertise.to_string(),
+            "topic": topic.to_string(),
+            "type": topic_type,
         }
-        );
-        let msg = Message::Text(msg.to_string());
+    );
+    if let Some(queue_size) = options.queue_size {
+        msg["queue_size"] = json!(queue_size.max(1));
+    }
+    if options.latched {
+        msg["latch"] = json!(true);
+    }
+    msg
+}
+
+impl RosBridgeComm for Writer {
+    async fn subscribe(
+        &mut self,
+        topic: &str,
+        msg_type: &str,
+        options: &TopicOptions,
+    ) -> Result<()> {
+        let msg = Message::Text(subscribe_msg(topic, msg_type, options).to_string());
         debug!("Sending subscribe: {:?}", &msg);
         self.send(msg).await.map_to_roslibrust()?;
         Ok(())
@@ -155,22 +201,24 @@ impl RosBridgeComm for Writer {
         Ok(())
     }
 
-    async fn advertise<T: RosMessageType>(&mut self, topic: &str) -> Result<()> {
-        self.advertise_str(topic, T::ROS_TYPE_NAME).await
+    async fn advertise<T: RosMessageType>(
+        &mut self,
+        topic: &str,
+        options: &TopicOptions,
+    ) -> Result<()> {
+        self.advertise_str(topic, T::ROS_TYPE_NAME, options).await
     }
 
     // Identical to advertise, but allows providing a string argument for the topic type
     // This is important as the type is erased in our list of publishers, and not available
     // when we try to reconnect
-    async fn advertise_str(&mut self, topic: &str, topic_type: &str) -> Result<()> {
-        let msg = json!(
-            {
-                "op": Ops::Advertise.to_string(),
-                "topic": topic.to_string(),
-                "type": topic_type,
-            }
-        );
-        let msg = Message::Text(msg.to_string());
+    async fn advertise_str(
+        &mut self,
+        topic: &str,
+        topic_type: &str,
+        options: &TopicOptions,
+    ) -> Result<()> {
+        let msg = Message::Text(advertise_msg(topic, topic_type, options).to_string());
         debug!("Sending advertise: {:?}", &msg);
         self.send(msg).await.map_to_roslibrust()?;
         Ok(())
@@ -265,3 +313,43 @@ impl RosBridgeComm for Writer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn subscribe_msg_sends_queue_length() {
+        let msg = subscribe_msg("/chatter", "std_msgs/String", &TopicOptions::new());
+        assert_eq!(
+            msg,
+            json!({"op": "subscribe", "topic": "/chatter", "type": "std_msgs/String"})
+        );
+
+        // Latching and reliability have no rosbridge equivalent for subscriptions
+        let options = TopicOptions::new().queue_size(5).latched(true);
+        let msg = subscribe_msg("/chatter", "std_msgs/String", &options);
+        assert_eq!(msg["queue_length"], json!(5));
+        assert!(msg.get("latch").is_none());
+
+        let msg = subscribe_msg("/chatter", "std_msgs/String", &options.queue_size(0));
+        assert_eq!(msg["queue_length"], json!(1));
+    }
+
+    #[test]
+    fn advertise_msg_sends_queue_size_and_latch() {
+        let msg = advertise_msg("/chatter", "std_msgs/String", &TopicOptions::new());
+        assert_eq!(
+            msg,
+            json!({"op": "advertise", "topic": "/chatter", "type": "std_msgs/String"})
+        );
+
+        let options = TopicOptions::new().queue_size(3).latched(true);
+        let msg = advertise_msg("/chatter", "std_msgs/String", &options);
+        assert_eq!(msg["queue_size"], json!(3));
+        assert_eq!(msg["latch"], json!(true));
+
+        let msg = advertise_msg("/chatter", "std_msgs/String", &options.queue_size(0));
+        assert_eq!(msg["queue_size"], json!(1));
+    }
+}
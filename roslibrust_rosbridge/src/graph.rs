@@ -0,0 +1,177 @@
+//! Implementation of [GraphProvider] for rosbridge, built on the services of the rosapi node
+//! which is launched alongside rosbridge_server.
+
+use crate::ClientHandle;
+use log::*;
+use roslibrust_common::graph::{poll_graph_events, Graph, GraphEvent, GraphProvider};
+use roslibrust_common::{Result, RosMessageType, RosServiceType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+// Minimal definitions of the rosapi services we need, these are identical for the ROS1 and ROS2 rosapi nodes.
+// We can't depend on generated types here, and rosbridge doesn't check types when calling services.
+macro_rules! rosapi_service {
+    ($module:ident, $name:literal, { $($req_field:ident : $req_ty:ty),* }, { $($res_field:ident : $res_ty:ty),* }) => {
+        mod $module {
+            use super::*;
+
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            pub(super) struct Request {
+                $(pub(super) $req_field: $req_ty),*
+            }
+
+            impl RosMessageType for Request {
+                const ROS_TYPE_NAME: &'static str = concat!($name, "Request");
+            }
+
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            pub(super) struct Response {
+                $(pub(super) $res_field: $res_ty),*
+            }
+
+            impl RosMessageType for Response {
+                const ROS_TYPE_NAME: &'static str = concat!($name, "Response");
+            }
+
+            pub(super) struct Service;
+
+            impl RosServiceType for Service {
+                const ROS_SERVICE_NAME: &'static str = $name;
+                type Request = Request;
+                type Response = Response;
+            }
+        }
+    };
+}
+
+rosapi_service!(topics, "rosapi/Topics", {}, { topics: Vec<String>, types: Vec<String> });
+rosapi_service!(nodes, "rosapi/Nodes", {}, { nodes: Vec<String> });
+rosapi_service!(node_details, "rosapi/NodeDetails", { node: String }, {
+    subscribing: Vec<String>,
+    publishing: Vec<String>,
+    services: Vec<String>
+});
+rosapi_service!(service_type, "rosapi/ServiceType", { service: String }, { r#type: String });
+
+/// Requires the rosapi node to be running, which is the case when using rosbridge_server's launch files.
+///
+/// Building the graph calls `/rosapi/node_details` once per node and `/rosapi/service_type` once per service.
+/// [GraphProvider::graph_events] rebuilds it every [ClientHandleOptions::graph_poll_period](crate::ClientHandleOptions::graph_poll_period),
+/// only looking up the types of services which weren't there on the previous poll. rosapi can't tell us
+/// which nodes changed, so each poll still fetches the details of every node.
+/// Nodes and services whose lookup fails are left out of the graph with a warning.
+impl GraphProvider for ClientHandle {
+    async fn graph(&self) -> Result<Graph> {
+        self.build_graph(&BTreeMap::new()).await
+    }
+
+    fn graph_events(&self) -> impl futures::Stream<Item = Result<GraphEvent>> + Send + 'static {
+        // Weak so that an event stream alone doesn't keep the client alive
+        let client = Arc::downgrade(&self.inner);
+        let is_disconnected = self.is_disconnected.clone();
+        // The types of the services in the last graph, a service keeps its type for as long as it exists
+        let service_types = Arc::new(Mutex::new(BTreeMap::new()));
+        let wait_client = client.clone();
+        poll_graph_events(
+            move || {
+                let client = ClientHandle {
+                    inner: client.upgrade()?,
+                    is_disconnected: is_disconnected.clone(),
+                };
+                let service_types = service_types.clone();
+                Some(async move {
+                    let known = service_types.lock().unwrap().clone();
+                    let graph = client.build_graph(&known).await?;
+                    *service_types.lock().unwrap() = graph
+                        .services
+                        .iter()
+                        .map(|(service, info)| (service.clone(), info.type_name.clone()))
+                        .collect();
+                    Ok(graph)
+                })
+            },
+            move || {
+                let client = wait_client.clone();
+                async move {
+                    let Some(client) = client.upgrade() else {
+                        return;
+                    };
+                    let period = client.read().await.opts.graph_poll_period;
+                    drop(client);
+                    tokio::time::sleep(period).await;
+                }
+            },
+        )
+    }
+}
+
+impl ClientHandle {
+    /// Builds the graph from rosapi, using the types in `service_types` instead of looking them up
+    async fn build_graph(&self, service_types: &BTreeMap<String, String>) -> Result<Graph> {
+        let topics = self
+            .call_service::<topics::Service>("/rosapi/topics", topics::Request {})
+            .await?;
+        let types: BTreeMap<String, String> = topics.topics.into_iter().zip(topics.types).collect();
+        let type_of = |topic: &str| types.get(topic).map(String::as_str).unwrap_or_default();
+
+        let mut graph = Graph::default();
+        let nodes = self
+            .call_service::<nodes::Service>("/rosapi/nodes", nodes::Request {})
+            .await?;
+        for node in nodes.nodes {
+            // Nodes and services can go away while the graph is being built, so failures are skipped
+            let details = match self
+                .call_service::<node_details::Service>(
+                    "/rosapi/node_details",
+                    node_details::Request { node: node.clone() },
+                )
+                .await
+            {
+                Ok(details) => details,
+                Err(e) => {
+                    warn!("Skipping node {node} in graph, failed to get its details: {e}");
+                    continue;
+                }
+            };
+            for topic in details.publishing {
+                graph.add_publisher(&topic, type_of(&topic), &node);
+            }
+            for topic in details.subscribing {
+                graph.add_subscriber(&topic, type_of(&topic), &node);
+            }
+            for service in details.services {
+                let known_type = service_types
+                    .get(&service)
+                    .or_else(|| graph.services.get(&service).map(|info| &info.type_name))
+                    .cloned();
+                if let Some(service_type) = known_type {
+                    graph.add_service_provider(&service, &service_type, &node);
+                    continue;
+                }
+                let service_type = match self
+                    .call_service::<service_type::Service>(
+                        "/rosapi/service_type",
+                        service_type::Request {
+                            service: service.clone(),
+                        },
+                    )
+                    .await
+                {
+                    Ok(service_type) => service_type,
+                    Err(e) => {
+                        warn!("Skipping service {service} in graph, failed to get its type: {e}");
+                        continue;
+                    }
+                };
+                graph.add_service_provider(&service, &service_type.r#type, &node);
+            }
+            graph.nodes.insert(node);
+        }
+        // Topics nobody currently publishes or subscribes to can still be known to rosapi
+        for (topic, type_name) in &types {
+            graph.topics.entry(topic.clone()).or_default().type_name = type_name.clone();
+        }
+        Ok(graph)
+    }
+}
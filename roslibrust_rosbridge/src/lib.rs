@@ -50,6 +50,9 @@ pub use publisher::*;
 mod client;
 pub use client::*;
 
+// Implements GraphProvider using the rosapi node, adds nothing public beyond the trait impl
+mod graph;
+
 // Tests are fully private module
 #[cfg(test)]
 mod integration_tests;
@@ -136,7 +139,7 @@ type Writer = SplitSink<Socket, Message>;
 /// Topics have a fundamental queue *per subscriber* this is te queue type used for each subscriber.
 type MessageQueue<T> = deadqueue::limited::Queue<T>;
 
-// TODO queue size should be configurable for subscribers
+/// Size of each subscriber's queue unless set with [ClientHandle::subscribe_with]
 const QUEUE_SIZE: usize = 1_000;
 
 /// Internal tracking structure used to maintain information about each subscription our client has
@@ -149,10 +152,13 @@ pub(crate) struct Subscription {
     pub(crate) handles: HashMap<uuid::Uuid, Callback>,
     /// Name of ros type (package_name/message_name), used for re-subscribes
     pub(crate) topic_type: String,
+    /// Options of the first subscriber to the topic, used for re-subscribes
+    pub(crate) options: TopicOptions,
 }
 
 pub(crate) struct PublisherHandle {
     pub(crate) topic_type: String,
+    pub(crate) options: TopicOptions,
 }
 
 // Implement the generic Service trait for our ServiceClient
@@ -214,6 +220,24 @@ impl TopicProvider for crate::ClientHandle {
         let topic: GlobalTopicName = topic.to_global_name()?;
         ClientHandle::subscribe(self, topic.as_ref()).await
     }
+
+    async fn advertise_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl ToGlobalTopicName,
+        options: TopicOptions,
+    ) -> Result<Self::Publisher<MsgType>> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        ClientHandle::advertise_with::<MsgType>(self, topic.as_ref(), options).await
+    }
+
+    async fn subscribe_with<MsgType: RosMessageType>(
+        &self,
+        topic: impl ToGlobalTopicName,
+        options: TopicOptions,
+    ) -> Result<Self::Subscriber<MsgType>> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        ClientHandle::subscribe_with(self, topic.as_ref(), options).await
+    }
 }
 
 impl<T: RosMessageType> Subscribe<T> for crate::Subscriber<T> {
@@ -15,13 +15,7 @@ use roslibrust_common::RosMessageType;
 // same reference when you advertise multiple times? Would require non-mut references?
 pub struct Publisher<T: RosMessageType> {
     topic: String,
-    // auto incrementing sequence number increased once per publish
-    // TODO have to somehow detect if message has header
-    // We likely want to implement a Stamped trait and impl it automatically in message gen
-    // if we detect a `Header header` in the .msg/.srv
-    // For now, leaving seq off of Publisher until it is fully supported
-    // #[allow(dead_code)]
-    // seq: usize,
+    // Filling in header seq / stamp is left to roslibrust_common::StampingPublisher which works for every backend
     // Stores a copy of the client so that we can de-register ourselves
     client: ClientHandle,
     _marker: std::marker::PhantomData<T>,
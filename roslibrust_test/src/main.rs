@@ -35,20 +35,29 @@ lazy_static! {
     ];
 }
 
+/// The messages are generated with wire codecs and borrowed variants, so the tests and benchmarks in this crate exercise them
+fn codegen_options() -> roslibrust::codegen::CodegenOptions {
+    roslibrust::codegen::CodegenOptions {
+        generate_wire_codecs: true,
+        generate_borrowed_types: true,
+        ..Default::default()
+    }
+}
+
 /// This main function is used to generate the contents of ros1.rs, ros2.rs
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
-    let (source, _paths) =
-        roslibrust::codegen::find_and_generate_ros_messages_without_ros_package_path(
-            (*ROS_1_PATHS).clone(),
-        )?;
+    let (source, _paths) = roslibrust::codegen::find_and_generate_ros_messages_with_options(
+        (*ROS_1_PATHS).clone(),
+        &codegen_options(),
+    )?;
     let source = format_rust_source(source.to_string().as_str()).to_string();
     std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/src/ros1.rs"), source)?;
 
-    let (source, _paths) =
-        roslibrust::codegen::find_and_generate_ros_messages_without_ros_package_path(
-            (*ROS_2_PATHS).clone(),
-        )?;
+    let (source, _paths) = roslibrust::codegen::find_and_generate_ros_messages_with_options(
+        (*ROS_2_PATHS).clone(),
+        &codegen_options(),
+    )?;
     let source = format_rust_source(source.to_string().as_str()).to_string();
     std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/src/ros2.rs"), source)?;
     Ok(())
@@ -86,11 +95,11 @@ mod test {
     /// Confirms that codegen has been run and changes committed
     #[test]
     fn ros1_lib_is_up_to_date() {
-        let (source, _paths) =
-            roslibrust::codegen::find_and_generate_ros_messages_without_ros_package_path(
-                (*ROS_1_PATHS).clone(),
-            )
-            .unwrap();
+        let (source, _paths) = roslibrust::codegen::find_and_generate_ros_messages_with_options(
+            (*ROS_1_PATHS).clone(),
+            &codegen_options(),
+        )
+        .unwrap();
         let source = format_rust_source(source.to_string().as_str()).to_string();
         let lib_path = env!("CARGO_MANIFEST_DIR").to_string() + "/src/ros1.rs";
         let lib_contents =
@@ -108,11 +117,11 @@ mod test {
     /// Confirms that codegen has been run and changes committed
     #[test]
     fn ros2_lib_is_up_to_date() {
-        let (source, _paths) =
-            roslibrust::codegen::find_and_generate_ros_messages_without_ros_package_path(
-                (*ROS_2_PATHS).clone(),
-            )
-            .unwrap();
+        let (source, _paths) = roslibrust::codegen::find_and_generate_ros_messages_with_options(
+            (*ROS_2_PATHS).clone(),
+            &codegen_options(),
+        )
+        .unwrap();
         let source = format_rust_source(source.to_string().as_str()).to_string();
         let lib_path = env!("CARGO_MANIFEST_DIR").to_string() + "/src/ros2.rs";
         let lib_contents =
@@ -48,6 +48,53 @@ string id"####;
             0x9c, 0x83, 0x47, 0x23,
         ];
         const ROS2_TYPE_NAME: &'static str = "actionlib_msgs::msg::dds_::GoalID_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GoalID {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            writer.put_str(&self.r#id);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#id: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GoalID], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalIDRef<'a> {
+        pub r#stamp: ::roslibrust::codegen::integral_types::Time,
+        pub r#id: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GoalIDRef<'a> {
+        type Owned = GoalID;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#id: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> GoalID {
+            GoalID {
+                r#stamp: self.r#stamp.clone(),
+                r#id: self.r#id.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GoalID {
+        type Borrowed<'a> = GoalIDRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -107,6 +154,8 @@ string id"####;
             0xd6, 0xa1, 0xc2, 0x2f,
         ];
         const ROS2_TYPE_NAME: &'static str = "actionlib_msgs::msg::dds_::GoalStatus_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl GoalStatus {
@@ -121,6 +170,58 @@ string id"####;
         pub const r#RECALLED: u8 = 8u8;
         pub const r#LOST: u8 = 9u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GoalStatus {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#goal_id, writer);
+            writer.put(self.r#status);
+            writer.put_str(&self.r#text);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#goal_id: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: reader.get()?,
+                r#text: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GoalStatus], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalStatusRef<'a> {
+        pub r#goal_id: self::GoalIDRef<'a>,
+        pub r#status: u8,
+        pub r#text: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GoalStatusRef<'a> {
+        type Owned = GoalStatus;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#goal_id: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: reader.get()?,
+                r#text: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> GoalStatus {
+            GoalStatus {
+                r#goal_id: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#goal_id,
+                ),
+                r#status: self.r#status,
+                r#text: self.r#text.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GoalStatus {
+        type Borrowed<'a> = GoalStatusRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -210,6 +311,87 @@ string frame_id"####;
             0xc1, 0x1f, 0xad, 0xda,
         ];
         const ROS2_TYPE_NAME: &'static str = "actionlib_msgs::msg::dds_::GoalStatusArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GoalStatusArray {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GoalStatusArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#status_list, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status_list: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GoalStatusArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalStatusArrayRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status_list: ::std::vec::Vec<self::GoalStatusRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GoalStatusArrayRef<'a> {
+        type Owned = GoalStatusArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status_list: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> GoalStatusArray {
+            GoalStatusArray {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status_list: self
+                    .r#status_list
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GoalStatusArray {
+        type Borrowed<'a> = GoalStatusArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -265,6 +447,41 @@ uint32 nanosec"####;
             0x95, 0x99, 0xd9, 0x3b,
         ];
         const ROS2_TYPE_NAME: &'static str = "builtin_interfaces::msg::dds_::Duration_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Duration {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sec);
+            writer.put(self.r#nanosec);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sec: reader.get()?,
+                r#nanosec: reader.get()?,
+            })
+        }
+    }
+    pub type DurationRef<'a> = Duration;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Duration {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Duration {
+        type Borrowed<'a> = DurationRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -301,6 +518,41 @@ uint32 nanosec"####;
             0xac, 0xf9, 0xc1, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "builtin_interfaces::msg::dds_::Time_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Time {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sec);
+            writer.put(self.r#nanosec);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sec: reader.get()?,
+                r#nanosec: reader.get()?,
+            })
+        }
+    }
+    pub type TimeRef<'a> = Time;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Time {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Time {
+        type Borrowed<'a> = TimeRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -386,6 +638,87 @@ string frame_id"####;
             0x50, 0x1a, 0x95, 0x1e,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::DiagnosticArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for DiagnosticArray {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DiagnosticArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#status, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DiagnosticArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DiagnosticArrayRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status: ::std::vec::Vec<self::DiagnosticStatusRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DiagnosticArrayRef<'a> {
+        type Owned = DiagnosticArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> DiagnosticArray {
+            DiagnosticArray {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status: self
+                    .r#status
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DiagnosticArray {
+        type Borrowed<'a> = DiagnosticArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -432,6 +765,8 @@ string value # a value to track over time"####;
             0x2f, 0x03, 0x80, 0x2b,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::DiagnosticStatus_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl DiagnosticStatus {
@@ -440,6 +775,75 @@ string value # a value to track over time"####;
         pub const r#ERROR: u8 = 2u8;
         pub const r#STALE: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DiagnosticStatus {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#level);
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#message);
+            writer.put_str(&self.r#hardware_id);
+            writer.put_seq_with(&self.r#values, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#level: reader.get()?,
+                r#name: reader.get_string()?,
+                r#message: reader.get_string()?,
+                r#hardware_id: reader.get_string()?,
+                r#values: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DiagnosticStatus], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DiagnosticStatusRef<'a> {
+        pub r#level: u8,
+        pub r#name: &'a str,
+        pub r#message: &'a str,
+        pub r#hardware_id: &'a str,
+        pub r#values: ::std::vec::Vec<self::KeyValueRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DiagnosticStatusRef<'a> {
+        type Owned = DiagnosticStatus;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#level: reader.get()?,
+                r#name: reader.get_str()?,
+                r#message: reader.get_str()?,
+                r#hardware_id: reader.get_str()?,
+                r#values: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> DiagnosticStatus {
+            DiagnosticStatus {
+                r#level: self.r#level,
+                r#name: self.r#name.to_owned(),
+                r#message: self.r#message.to_owned(),
+                r#hardware_id: self.r#hardware_id.to_owned(),
+                r#values: self
+                    .r#values
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DiagnosticStatus {
+        type Borrowed<'a> = DiagnosticStatusRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -466,6 +870,53 @@ string value # a value to track over time"####;
             0x04, 0x13, 0xbb, 0x42,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::KeyValue_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for KeyValue {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#key);
+            writer.put_str(&self.r#value);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#key: reader.get_string()?,
+                r#value: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [KeyValue], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct KeyValueRef<'a> {
+        pub r#key: &'a str,
+        pub r#value: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for KeyValueRef<'a> {
+        type Owned = KeyValue;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#key: reader.get_str()?,
+                r#value: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> KeyValue {
+            KeyValue {
+                r#key: self.r#key.to_owned(),
+                r#value: self.r#value.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for KeyValue {
+        type Borrowed<'a> = KeyValueRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -507,6 +958,48 @@ string load_namespace"####;
             0x3f, 0x8c, 0x82, 0xd4,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::AddDiagnosticsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddDiagnosticsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#load_namespace);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#load_namespace: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AddDiagnosticsRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AddDiagnosticsRequestRef<'a> {
+        pub r#load_namespace: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddDiagnosticsRequestRef<'a> {
+        type Owned = AddDiagnosticsRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#load_namespace: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> AddDiagnosticsRequest {
+            AddDiagnosticsRequest {
+                r#load_namespace: self.r#load_namespace.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddDiagnosticsRequest {
+        type Borrowed<'a> = AddDiagnosticsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -540,6 +1033,53 @@ string message"####;
             0x9d, 0xca, 0x5e, 0xac,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::AddDiagnosticsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddDiagnosticsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AddDiagnosticsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AddDiagnosticsResponseRef<'a> {
+        pub r#success: bool,
+        pub r#message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddDiagnosticsResponseRef<'a> {
+        type Owned = AddDiagnosticsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> AddDiagnosticsResponse {
+            AddDiagnosticsResponse {
+                r#success: self.r#success,
+                r#message: self.r#message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddDiagnosticsResponse {
+        type Borrowed<'a> = AddDiagnosticsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct AddDiagnostics {}
@@ -577,6 +1117,35 @@ string message"####;
             0x3d, 0x77, 0xb2, 0x29,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::SelfTestRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SelfTestRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type SelfTestRequestRef<'a> = SelfTestRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SelfTestRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SelfTestRequest {
+        type Borrowed<'a> = SelfTestRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -630,6 +1199,67 @@ string value # a value to track over time"####;
             0x6b, 0xc4, 0xce, 0x46,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::SelfTestResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SelfTestResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#id);
+            writer.put(self.r#passed);
+            writer.put_seq_with(&self.r#status, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get_string()?,
+                r#passed: reader.get()?,
+                r#status: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SelfTestResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SelfTestResponseRef<'a> {
+        pub r#id: &'a str,
+        pub r#passed: u8,
+        pub r#status: ::std::vec::Vec<self::DiagnosticStatusRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SelfTestResponseRef<'a> {
+        type Owned = SelfTestResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get_str()?,
+                r#passed: reader.get()?,
+                r#status: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> SelfTestResponse {
+            SelfTestResponse {
+                r#id: self.r#id.to_owned(),
+                r#passed: self.r#passed,
+                r#status: self
+                    .r#status
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SelfTestResponse {
+        type Borrowed<'a> = SelfTestResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SelfTest {}
@@ -702,6 +1332,41 @@ float64 z"####;
             0xa3, 0xf5, 0x7c, 0xa0,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Accel_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Accel {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#linear, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#angular, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#linear: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angular: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type AccelRef<'a> = Accel;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Accel {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Accel {
+        type Borrowed<'a> = AccelRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -774,6 +1439,78 @@ string frame_id"####;
             0x9c, 0x59, 0x53, 0x2b,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::AccelStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for AccelStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AccelStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#accel, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#accel: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AccelStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AccelStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#accel: self::AccelRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AccelStampedRef<'a> {
+        type Owned = AccelStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> AccelStamped {
+            AccelStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#accel),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AccelStamped {
+        type Borrowed<'a> = AccelStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -839,6 +1576,41 @@ float64 z"####;
             0xc5, 0xdb, 0x59, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::AccelWithCovariance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AccelWithCovariance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#accel, writer);
+            writer.put_slice(&self.r#covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#accel: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#covariance: reader.get_array()?,
+            })
+        }
+    }
+    pub type AccelWithCovarianceRef<'a> = AccelWithCovariance;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AccelWithCovariance {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AccelWithCovariance {
+        type Borrowed<'a> = AccelWithCovarianceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -952,6 +1724,78 @@ string frame_id"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "geometry_msgs::msg::dds_::AccelWithCovarianceStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for AccelWithCovarianceStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AccelWithCovarianceStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#accel, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#accel: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AccelWithCovarianceStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AccelWithCovarianceStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#accel: self::AccelWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AccelWithCovarianceStampedRef<'a> {
+        type Owned = AccelWithCovarianceStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> AccelWithCovarianceStamped {
+            AccelWithCovarianceStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#accel),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AccelWithCovarianceStamped {
+        type Borrowed<'a> = AccelWithCovarianceStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1011,6 +1855,53 @@ float64 z"####;
             0x93, 0x0b, 0x4b, 0xd7,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Inertia_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Inertia {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#m);
+            ::roslibrust::codec::WireCodec::encode(&self.r#com, writer);
+            writer.put(self.r#ixx);
+            writer.put(self.r#ixy);
+            writer.put(self.r#ixz);
+            writer.put(self.r#iyy);
+            writer.put(self.r#iyz);
+            writer.put(self.r#izz);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#m: reader.get()?,
+                r#com: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ixx: reader.get()?,
+                r#ixy: reader.get()?,
+                r#ixz: reader.get()?,
+                r#iyy: reader.get()?,
+                r#iyz: reader.get()?,
+                r#izz: reader.get()?,
+            })
+        }
+    }
+    pub type InertiaRef<'a> = Inertia;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Inertia {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Inertia {
+        type Borrowed<'a> = InertiaRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1095,6 +1986,80 @@ string frame_id"####;
             0x61, 0xdb, 0x7d, 0xe9,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::InertiaStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InertiaStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InertiaStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#inertia, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#inertia: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InertiaStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InertiaStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#inertia: self::InertiaRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InertiaStampedRef<'a> {
+        type Owned = InertiaStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#inertia: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> InertiaStamped {
+            InertiaStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#inertia: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#inertia,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InertiaStamped {
+        type Borrowed<'a> = InertiaStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1125,6 +2090,43 @@ float64 z"####;
             0x2a, 0x7f, 0x68, 0x81,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Point_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Point {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+            })
+        }
+    }
+    pub type PointRef<'a> = Point;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Point {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Point {
+        type Borrowed<'a> = PointRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1162,6 +2164,43 @@ float32 z"####;
             0x2a, 0x4b, 0xfd, 0xbc,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Point32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Point32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+            })
+        }
+    }
+    pub type Point32Ref<'a> = Point32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Point32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Point32 {
+        type Borrowed<'a> = Point32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1211,6 +2250,78 @@ string frame_id"####;
             0xfe, 0xbb, 0x77, 0x29,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PointStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PointStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#point, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#point: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#point: self::PointRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointStampedRef<'a> {
+        type Owned = PointStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#point: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PointStamped {
+            PointStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#point: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#point),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointStamped {
+        type Borrowed<'a> = PointStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1250,6 +2361,42 @@ float32 z"####;
             0xda, 0x0f, 0x78, 0x4a,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Polygon_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Polygon {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    pub type PolygonRef<'a> = Polygon;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Polygon {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Polygon {
+        type Borrowed<'a> = PolygonRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1323,6 +2470,80 @@ string frame_id"####;
             0x8f, 0x88, 0x07, 0xaf,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PolygonStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PolygonStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PolygonStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#polygon, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#polygon: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PolygonStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PolygonStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#polygon: self::PolygonRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PolygonStampedRef<'a> {
+        type Owned = PolygonStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#polygon: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PolygonStamped {
+            PolygonStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#polygon: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#polygon,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PolygonStamped {
+        type Borrowed<'a> = PolygonStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1365,6 +2586,41 @@ float64 w"####;
             0x35, 0xcc, 0x90, 0xfa,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Pose_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Pose {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#position, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#orientation, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#position: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type PoseRef<'a> = Pose;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Pose {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Pose {
+        type Borrowed<'a> = PoseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1404,6 +2660,43 @@ float64 theta"####;
             0xa4, 0x72, 0x4c, 0xcc,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Pose2D_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Pose2D {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#theta);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#theta: reader.get()?,
+            })
+        }
+    }
+    pub type Pose2DRef<'a> = Pose2D;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Pose2D {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Pose2D {
+        type Borrowed<'a> = Pose2DRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1482,6 +2775,87 @@ string frame_id"####;
             0x61, 0x5d, 0x50, 0xed,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PoseArray {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#poses, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#poses: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PoseArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PoseArrayRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#poses: ::std::vec::Vec<self::PoseRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseArrayRef<'a> {
+        type Owned = PoseArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#poses: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> PoseArray {
+            PoseArray {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#poses: self
+                    .r#poses
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseArray {
+        type Borrowed<'a> = PoseArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1558,6 +2932,78 @@ string frame_id"####;
             0x20, 0xec, 0x22, 0x6e,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PoseStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PoseStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PoseStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: self::PoseRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseStampedRef<'a> {
+        type Owned = PoseStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PoseStamped {
+            PoseStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseStamped {
+        type Borrowed<'a> = PoseStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1627,6 +3073,41 @@ float64 w"####;
             0x1c, 0x02, 0xe1, 0xbb,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseWithCovariance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseWithCovariance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put_slice(&self.r#covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#covariance: reader.get_array()?,
+            })
+        }
+    }
+    pub type PoseWithCovarianceRef<'a> = PoseWithCovariance;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseWithCovariance {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseWithCovariance {
+        type Borrowed<'a> = PoseWithCovarianceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1748,6 +3229,78 @@ string frame_id"####;
             0xe7, 0xe4, 0x48, 0x1a,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseWithCovarianceStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PoseWithCovarianceStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseWithCovarianceStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PoseWithCovarianceStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PoseWithCovarianceStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: self::PoseWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseWithCovarianceStampedRef<'a> {
+        type Owned = PoseWithCovarianceStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PoseWithCovarianceStamped {
+            PoseWithCovarianceStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseWithCovarianceStamped {
+        type Borrowed<'a> = PoseWithCovarianceStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1781,6 +3334,45 @@ float64 w"####;
             0x6f, 0x45, 0x86, 0x84,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Quaternion_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Quaternion {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+            writer.put(self.r#w);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+                r#w: reader.get()?,
+            })
+        }
+    }
+    pub type QuaternionRef<'a> = Quaternion;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Quaternion {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Quaternion {
+        type Borrowed<'a> = QuaternionRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1833,6 +3425,80 @@ string frame_id"####;
             0xff, 0x45, 0xc8, 0x5d,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::QuaternionStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for QuaternionStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for QuaternionStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#quaternion, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#quaternion: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [QuaternionStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct QuaternionStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#quaternion: self::QuaternionRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for QuaternionStampedRef<'a> {
+        type Owned = QuaternionStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#quaternion: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> QuaternionStamped {
+            QuaternionStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#quaternion: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#quaternion,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for QuaternionStamped {
+        type Borrowed<'a> = QuaternionStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1882,6 +3548,41 @@ float64 z"####;
             0x25, 0x81, 0xfd, 0xdc,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Transform_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Transform {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#translation, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#rotation, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#translation: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#rotation: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type TransformRef<'a> = Transform;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Transform {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Transform {
+        type Borrowed<'a> = TransformRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1979,6 +3680,85 @@ string frame_id"####;
             0xb9, 0xb8, 0x9d, 0x7c,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TransformStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TransformStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TransformStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#child_frame_id);
+            ::roslibrust::codec::WireCodec::encode(&self.r#transform, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#child_frame_id: reader.get_string()?,
+                r#transform: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TransformStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TransformStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#child_frame_id: &'a str,
+        pub r#transform: self::TransformRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TransformStampedRef<'a> {
+        type Owned = TransformStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#child_frame_id: reader.get_str()?,
+                r#transform: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TransformStamped {
+            TransformStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#child_frame_id: self.r#child_frame_id.to_owned(),
+                r#transform: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#transform,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TransformStamped {
+        type Borrowed<'a> = TransformStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2019,6 +3799,41 @@ float64 z"####;
             0xe6, 0xfb, 0x8d, 0x2a,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Twist_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Twist {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#linear, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#angular, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#linear: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angular: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type TwistRef<'a> = Twist;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Twist {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Twist {
+        type Borrowed<'a> = TwistRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2091,6 +3906,78 @@ string frame_id"####;
             0xa1, 0xd9, 0x73, 0xf4,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TwistStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TwistStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TwistStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TwistStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TwistStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#twist: self::TwistRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TwistStampedRef<'a> {
+        type Owned = TwistStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TwistStamped {
+            TwistStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#twist),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TwistStamped {
+        type Borrowed<'a> = TwistStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2156,6 +4043,41 @@ float64 z"####;
             0x05, 0x9c, 0x8c, 0x18,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TwistWithCovariance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TwistWithCovariance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+            writer.put_slice(&self.r#covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#covariance: reader.get_array()?,
+            })
+        }
+    }
+    pub type TwistWithCovarianceRef<'a> = TwistWithCovariance;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TwistWithCovariance {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TwistWithCovariance {
+        type Borrowed<'a> = TwistWithCovarianceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2269,6 +4191,78 @@ string frame_id"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "geometry_msgs::msg::dds_::TwistWithCovarianceStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TwistWithCovarianceStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TwistWithCovarianceStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TwistWithCovarianceStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TwistWithCovarianceStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#twist: self::TwistWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TwistWithCovarianceStampedRef<'a> {
+        type Owned = TwistWithCovarianceStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TwistWithCovarianceStamped {
+            TwistWithCovarianceStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#twist),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TwistWithCovarianceStamped {
+        type Borrowed<'a> = TwistWithCovarianceStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2305,6 +4299,43 @@ float64 z"####;
             0xdb, 0xb0, 0x76, 0x5d,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Vector3_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Vector3 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+            })
+        }
+    }
+    pub type Vector3Ref<'a> = Vector3;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Vector3 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Vector3 {
+        type Borrowed<'a> = Vector3Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2360,6 +4391,78 @@ string frame_id"####;
             0xe8, 0x26, 0x11, 0xfa,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Vector3Stamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Vector3Stamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Vector3Stamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#vector, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#vector: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Vector3Stamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Vector3StampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#vector: self::Vector3Ref<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Vector3StampedRef<'a> {
+        type Owned = Vector3Stamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#vector: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> Vector3Stamped {
+            Vector3Stamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#vector: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#vector),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Vector3Stamped {
+        type Borrowed<'a> = Vector3StampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2401,6 +4504,41 @@ float64 z"####;
             0x56, 0x77, 0xce, 0x52,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Wrench_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Wrench {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#force, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#torque, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#force: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#torque: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type WrenchRef<'a> = Wrench;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Wrench {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Wrench {
+        type Borrowed<'a> = WrenchRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2474,6 +4612,78 @@ string frame_id"####;
             0x58, 0x63, 0xde, 0x17,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::WrenchStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for WrenchStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for WrenchStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#wrench, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#wrench: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [WrenchStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct WrenchStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#wrench: self::WrenchRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for WrenchStampedRef<'a> {
+        type Owned = WrenchStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#wrench: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> WrenchStamped {
+            WrenchStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#wrench: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#wrench),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for WrenchStamped {
+        type Borrowed<'a> = WrenchStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -3553,6 +5763,66 @@ string frame_id"####;
             0x9b, 0xad, 0xc4, 0xa6,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapAction_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapAction {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#action_goal, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#action_result, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#action_feedback, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#action_goal: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#action_result: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#action_feedback: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapAction], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapActionRef<'a> {
+        pub r#action_goal: self::GetMapActionGoalRef<'a>,
+        pub r#action_result: self::GetMapActionResultRef<'a>,
+        pub r#action_feedback: self::GetMapActionFeedbackRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapActionRef<'a> {
+        type Owned = GetMapAction;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#action_goal: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#action_result: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#action_feedback: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(
+                    reader,
+                )?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapAction {
+            GetMapAction {
+                r#action_goal: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#action_goal,
+                ),
+                r#action_result: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#action_result,
+                ),
+                r#action_feedback: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#action_feedback,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapAction {
+        type Borrowed<'a> = GetMapActionRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3646,6 +5916,85 @@ string frame_id"####;
             0xaa, 0x9a, 0x6e, 0x8e,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapActionFeedback_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GetMapActionFeedback {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapActionFeedback {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#status, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#feedback, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#feedback: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapActionFeedback], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapActionFeedbackRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status: actionlib_msgs::GoalStatusRef<'a>,
+        pub r#feedback: self::GetMapFeedbackRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapActionFeedbackRef<'a> {
+        type Owned = GetMapActionFeedback;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#feedback: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapActionFeedback {
+            GetMapActionFeedback {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#status),
+                r#feedback: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#feedback,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapActionFeedback {
+        type Borrowed<'a> = GetMapActionFeedbackRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3704,6 +6053,85 @@ string frame_id"####;
             0x9b, 0x6d, 0xfe, 0xbb,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapActionGoal_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GetMapActionGoal {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapActionGoal {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#goal_id, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#goal, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#goal_id: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#goal: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapActionGoal], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapActionGoalRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#goal_id: actionlib_msgs::GoalIDRef<'a>,
+        pub r#goal: self::GetMapGoalRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapActionGoalRef<'a> {
+        type Owned = GetMapActionGoal;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#goal_id: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#goal: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapActionGoal {
+            GetMapActionGoal {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#goal_id: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#goal_id,
+                ),
+                r#goal: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#goal),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapActionGoal {
+        type Borrowed<'a> = GetMapActionGoalRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4192,6 +6620,83 @@ string frame_id"####;
             0x82, 0xd1, 0x85, 0x51,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapActionResult_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GetMapActionResult {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapActionResult {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#status, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#result, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#result: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapActionResult], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapActionResultRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status: actionlib_msgs::GoalStatusRef<'a>,
+        pub r#result: self::GetMapResultRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapActionResultRef<'a> {
+        type Owned = GetMapActionResult;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#result: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapActionResult {
+            GetMapActionResult {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#status),
+                r#result: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#result),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapActionResult {
+        type Borrowed<'a> = GetMapActionResultRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4215,6 +6720,35 @@ string frame_id"####;
             0x59, 0x5d, 0xc3, 0xb7,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapFeedback_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapFeedback {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetMapFeedbackRef<'a> = GetMapFeedback;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapFeedback {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapFeedback {
+        type Borrowed<'a> = GetMapFeedbackRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4238,6 +6772,35 @@ string frame_id"####;
             0xb5, 0x4a, 0xed, 0x94,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapGoal_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapGoal {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetMapGoalRef<'a> = GetMapGoal;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapGoal {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapGoal {
+        type Borrowed<'a> = GetMapGoalRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4468,6 +7031,48 @@ string frame_id"####;
             0x23, 0x87, 0xe8, 0x85,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapResult_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapResult {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapResult], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapResultRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapResultRef<'a> {
+        type Owned = GetMapResult;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapResult {
+            GetMapResult {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapResult {
+        type Borrowed<'a> = GetMapResultRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4521,6 +7126,97 @@ string frame_id"####;
             0xa4, 0x4e, 0x28, 0x25,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GridCells_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GridCells {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GridCells {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#cell_width);
+            writer.put(self.r#cell_height);
+            writer.put_seq_with(&self.r#cells, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#cell_width: reader.get()?,
+                r#cell_height: reader.get()?,
+                r#cells: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GridCells], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GridCellsRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#cell_width: f32,
+        pub r#cell_height: f32,
+        pub r#cells: ::std::vec::Vec<geometry_msgs::PointRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GridCellsRef<'a> {
+        type Owned = GridCells;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#cell_width: reader.get()?,
+                r#cell_height: reader.get()?,
+                r#cells: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> GridCells {
+            GridCells {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#cell_width: self.r#cell_width,
+                r#cell_height: self.r#cell_height,
+                r#cells: self
+                    .r#cells
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GridCells {
+        type Borrowed<'a> = GridCellsRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4595,6 +7291,47 @@ float64 w"####;
             0x6b, 0x8e, 0x9e, 0xcb,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::MapMetaData_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MapMetaData {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map_load_time, writer);
+            writer.put(self.r#resolution);
+            writer.put(self.r#width);
+            writer.put(self.r#height);
+            ::roslibrust::codec::WireCodec::encode(&self.r#origin, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map_load_time: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#resolution: reader.get()?,
+                r#width: reader.get()?,
+                r#height: reader.get()?,
+                r#origin: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type MapMetaDataRef<'a> = MapMetaData;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MapMetaData {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MapMetaData {
+        type Borrowed<'a> = MapMetaDataRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4728,6 +7465,83 @@ string frame_id"####;
             0x77, 0x4a, 0xf1, 0x1f,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::OccupancyGrid_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for OccupancyGrid {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for OccupancyGrid {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#info, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#info: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [OccupancyGrid], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct OccupancyGridRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#info: self::MapMetaDataRef<'a>,
+        pub r#data: ::std::vec::Vec<i8>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for OccupancyGridRef<'a> {
+        type Owned = OccupancyGrid;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#info: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> OccupancyGrid {
+            OccupancyGrid {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#info: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#info),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for OccupancyGrid {
+        type Borrowed<'a> = OccupancyGridRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4923,6 +7737,88 @@ string frame_id"####;
             0x2d, 0x7d, 0x4b, 0x78,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::Odometry_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Odometry {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Odometry {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#child_frame_id);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#child_frame_id: reader.get_string()?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Odometry], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct OdometryRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#child_frame_id: &'a str,
+        pub r#pose: geometry_msgs::PoseWithCovarianceRef<'a>,
+        pub r#twist: geometry_msgs::TwistWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for OdometryRef<'a> {
+        type Owned = Odometry;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#child_frame_id: reader.get_str()?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> Odometry {
+            Odometry {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#child_frame_id: self.r#child_frame_id.to_owned(),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#twist),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Odometry {
+        type Borrowed<'a> = OdometryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5052,6 +7948,87 @@ string frame_id"####;
             0xcb, 0xc4, 0x99, 0xab,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::Path_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Path {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Path {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#poses, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#poses: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Path], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PathRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#poses: ::std::vec::Vec<geometry_msgs::PoseStampedRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PathRef<'a> {
+        type Owned = Path;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#poses: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> Path {
+            Path {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#poses: self
+                    .r#poses
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Path {
+        type Borrowed<'a> = PathRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5075,6 +8052,35 @@ string frame_id"####;
             0xdf, 0x8e, 0x02, 0xde,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetMapRequestRef<'a> = GetMapRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapRequest {
+        type Borrowed<'a> = GetMapRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5305,6 +8311,48 @@ string frame_id"####;
             0x0f, 0xfd, 0xfc, 0xed,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapResponseRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapResponseRef<'a> {
+        type Owned = GetMapResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapResponse {
+            GetMapResponse {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapResponse {
+        type Borrowed<'a> = GetMapResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetMap {}
@@ -5457,6 +8505,58 @@ string frame_id"####;
             0x40, 0x1f, 0xdf, 0x1e,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetPlanRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetPlanRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#start, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#goal, writer);
+            writer.put(self.r#tolerance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#start: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#goal: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#tolerance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetPlanRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetPlanRequestRef<'a> {
+        pub r#start: geometry_msgs::PoseStampedRef<'a>,
+        pub r#goal: geometry_msgs::PoseStampedRef<'a>,
+        pub r#tolerance: f32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetPlanRequestRef<'a> {
+        type Owned = GetPlanRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#start: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#goal: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#tolerance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> GetPlanRequest {
+            GetPlanRequest {
+                r#start: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#start),
+                r#goal: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#goal),
+                r#tolerance: self.r#tolerance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetPlanRequest {
+        type Borrowed<'a> = GetPlanRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5689,6 +8789,48 @@ string frame_id"####;
             0x9c, 0xb9, 0xa3, 0x73,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetPlanResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetPlanResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#plan, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#plan: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetPlanResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetPlanResponseRef<'a> {
+        pub r#plan: self::PathRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetPlanResponseRef<'a> {
+        type Owned = GetPlanResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#plan: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetPlanResponse {
+            GetPlanResponse {
+                r#plan: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#plan),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetPlanResponse {
+        type Borrowed<'a> = GetPlanResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetPlan {}
@@ -5731,6 +8873,48 @@ string map_url"####;
             0xf2, 0x6d, 0x19, 0xbf,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::LoadMapRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LoadMapRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#map_url);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map_url: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LoadMapRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LoadMapRequestRef<'a> {
+        pub r#map_url: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LoadMapRequestRef<'a> {
+        type Owned = LoadMapRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map_url: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> LoadMapRequest {
+            LoadMapRequest {
+                r#map_url: self.r#map_url.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LoadMapRequest {
+        type Borrowed<'a> = LoadMapRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5971,6 +9155,8 @@ string frame_id"####;
             0x37, 0x92, 0x37, 0x79,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::LoadMapResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl LoadMapResponse {
@@ -5980,6 +9166,51 @@ string frame_id"####;
         pub const r#RESULT_INVALID_MAP_METADATA: u8 = 3u8;
         pub const r#RESULT_UNDEFINED_FAILURE: u8 = 255u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LoadMapResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+            writer.put(self.r#result);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#result: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LoadMapResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LoadMapResponseRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+        pub r#result: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LoadMapResponseRef<'a> {
+        type Owned = LoadMapResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#result: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> LoadMapResponse {
+            LoadMapResponse {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+                r#result: self.r#result,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LoadMapResponse {
+        type Borrowed<'a> = LoadMapResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(dead_code)]
     pub struct LoadMap {}
     impl ::roslibrust::RosServiceType for LoadMap {
@@ -6368,6 +9599,55 @@ string frame_id"####;
             0x5f, 0x16, 0x63, 0xf3,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::SetMapRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetMapRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#initial_pose, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#initial_pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetMapRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetMapRequestRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+        pub r#initial_pose: geometry_msgs::PoseWithCovarianceStampedRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetMapRequestRef<'a> {
+        type Owned = SetMapRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#initial_pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> SetMapRequest {
+            SetMapRequest {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+                r#initial_pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#initial_pose,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetMapRequest {
+        type Borrowed<'a> = SetMapRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6393,6 +9673,39 @@ string frame_id"####;
             0xc3, 0x68, 0xaf, 0x26,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::SetMapResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetMapResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+            })
+        }
+    }
+    pub type SetMapResponseRef<'a> = SetMapResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetMapResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetMapResponse {
+        type Borrowed<'a> = SetMapResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetMap {}
@@ -6462,6 +9775,98 @@ string[] constvalues"####;
             0x28, 0xe6, 0x8a, 0x99,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TypeDef_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TypeDef {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+            writer.put_seq_with(&self.r#fieldnames, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#fieldtypes, |writer, item| writer.put_str(item));
+            writer.put_seq(&self.r#fieldarraylen);
+            writer.put_seq_with(&self.r#examples, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#constnames, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#constvalues, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+                r#fieldnames: reader.get_seq_with(|reader| reader.get_string())?,
+                r#fieldtypes: reader.get_seq_with(|reader| reader.get_string())?,
+                r#fieldarraylen: reader.get_seq()?,
+                r#examples: reader.get_seq_with(|reader| reader.get_string())?,
+                r#constnames: reader.get_seq_with(|reader| reader.get_string())?,
+                r#constvalues: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TypeDef], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TypeDefRef<'a> {
+        pub r#type: &'a str,
+        pub r#fieldnames: ::std::vec::Vec<&'a str>,
+        pub r#fieldtypes: ::std::vec::Vec<&'a str>,
+        pub r#fieldarraylen: ::std::vec::Vec<i32>,
+        pub r#examples: ::std::vec::Vec<&'a str>,
+        pub r#constnames: ::std::vec::Vec<&'a str>,
+        pub r#constvalues: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TypeDefRef<'a> {
+        type Owned = TypeDef;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+                r#fieldnames: reader.get_seq_with(|reader| reader.get_str())?,
+                r#fieldtypes: reader.get_seq_with(|reader| reader.get_str())?,
+                r#fieldarraylen: reader.get_seq()?,
+                r#examples: reader.get_seq_with(|reader| reader.get_str())?,
+                r#constnames: reader.get_seq_with(|reader| reader.get_str())?,
+                r#constvalues: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> TypeDef {
+            TypeDef {
+                r#type: self.r#type.to_owned(),
+                r#fieldnames: self
+                    .r#fieldnames
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#fieldtypes: self
+                    .r#fieldtypes
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#fieldarraylen: self.r#fieldarraylen.clone(),
+                r#examples: self
+                    .r#examples
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#constnames: self
+                    .r#constnames
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#constvalues: self
+                    .r#constvalues
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TypeDef {
+        type Borrowed<'a> = TypeDefRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6487,6 +9892,48 @@ string[] constvalues"####;
             0x06, 0x7b, 0xc9, 0x29,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::DeleteParamRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DeleteParamRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DeleteParamRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DeleteParamRequestRef<'a> {
+        pub r#name: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DeleteParamRequestRef<'a> {
+        type Owned = DeleteParamRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> DeleteParamRequest {
+            DeleteParamRequest {
+                r#name: self.r#name.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DeleteParamRequest {
+        type Borrowed<'a> = DeleteParamRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6510,6 +9957,35 @@ string[] constvalues"####;
             0xbd, 0xe8, 0x6e, 0x19,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::DeleteParamResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DeleteParamResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type DeleteParamResponseRef<'a> = DeleteParamResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DeleteParamResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DeleteParamResponse {
+        type Borrowed<'a> = DeleteParamResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct DeleteParam {}
@@ -6547,6 +10023,35 @@ string[] constvalues"####;
             0xee, 0x1b, 0x8d, 0x1f,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetActionServersRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetActionServersRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetActionServersRequestRef<'a> = GetActionServersRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetActionServersRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetActionServersRequest {
+        type Borrowed<'a> = GetActionServersRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6572,6 +10077,52 @@ string[] constvalues"####;
             0x49, 0x89, 0x56, 0x7a,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetActionServersResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetActionServersResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#action_servers, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#action_servers: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetActionServersResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetActionServersResponseRef<'a> {
+        pub r#action_servers: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetActionServersResponseRef<'a> {
+        type Owned = GetActionServersResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#action_servers: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> GetActionServersResponse {
+            GetActionServersResponse {
+                r#action_servers: self
+                    .r#action_servers
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetActionServersResponse {
+        type Borrowed<'a> = GetActionServersResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetActionServers {}
@@ -6613,6 +10164,53 @@ string default"####;
             0x37, 0x30, 0xb0, 0x35,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetParamRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetParamRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#default);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#default: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetParamRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetParamRequestRef<'a> {
+        pub r#name: &'a str,
+        pub r#default: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetParamRequestRef<'a> {
+        type Owned = GetParamRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#default: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> GetParamRequest {
+            GetParamRequest {
+                r#name: self.r#name.to_owned(),
+                r#default: self.r#default.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetParamRequest {
+        type Borrowed<'a> = GetParamRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6638,6 +10236,48 @@ string default"####;
             0x8b, 0xed, 0xf5, 0x8a,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetParamResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetParamResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#value);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#value: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetParamResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetParamResponseRef<'a> {
+        pub r#value: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetParamResponseRef<'a> {
+        type Owned = GetParamResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#value: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> GetParamResponse {
+            GetParamResponse {
+                r#value: self.r#value.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetParamResponse {
+        type Borrowed<'a> = GetParamResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetParam {}
@@ -6675,6 +10315,35 @@ string default"####;
             0xb1, 0x17, 0x52, 0x71,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetParamNamesRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetParamNamesRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetParamNamesRequestRef<'a> = GetParamNamesRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetParamNamesRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetParamNamesRequest {
+        type Borrowed<'a> = GetParamNamesRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6700,6 +10369,48 @@ string default"####;
             0xce, 0x14, 0x34, 0xf4,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetParamNamesResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetParamNamesResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#names, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#names: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetParamNamesResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetParamNamesResponseRef<'a> {
+        pub r#names: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetParamNamesResponseRef<'a> {
+        type Owned = GetParamNamesResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#names: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> GetParamNamesResponse {
+            GetParamNamesResponse {
+                r#names: self.r#names.iter().map(|item| (*item).to_owned()).collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetParamNamesResponse {
+        type Borrowed<'a> = GetParamNamesResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetParamNames {}
@@ -6737,6 +10448,35 @@ string default"####;
             0x91, 0xd5, 0x89, 0x76,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetTimeRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetTimeRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetTimeRequestRef<'a> = GetTimeRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetTimeRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetTimeRequest {
+        type Borrowed<'a> = GetTimeRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6762,6 +10502,39 @@ string default"####;
             0x4b, 0x25, 0x01, 0x1a,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::GetTimeResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetTimeResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#time, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#time: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type GetTimeResponseRef<'a> = GetTimeResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetTimeResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetTimeResponse {
+        type Borrowed<'a> = GetTimeResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetTime {}
@@ -6801,6 +10574,48 @@ string default"####;
             0x89, 0xe3, 0xba, 0x62,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::HasParamRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for HasParamRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [HasParamRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct HasParamRequestRef<'a> {
+        pub r#name: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for HasParamRequestRef<'a> {
+        type Owned = HasParamRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> HasParamRequest {
+            HasParamRequest {
+                r#name: self.r#name.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for HasParamRequest {
+        type Borrowed<'a> = HasParamRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6826,6 +10641,39 @@ string default"####;
             0x14, 0x09, 0xaa, 0x59,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::HasParamResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for HasParamResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#exists);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#exists: reader.get()?,
+            })
+        }
+    }
+    pub type HasParamResponseRef<'a> = HasParamResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for HasParamResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for HasParamResponse {
+        type Borrowed<'a> = HasParamResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct HasParam {}
@@ -6865,6 +10713,48 @@ string default"####;
             0xc6, 0x4b, 0xe9, 0xa4,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::MessageDetailsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MessageDetailsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MessageDetailsRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MessageDetailsRequestRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MessageDetailsRequestRef<'a> {
+        type Owned = MessageDetailsRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> MessageDetailsRequest {
+            MessageDetailsRequest {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MessageDetailsRequest {
+        type Borrowed<'a> = MessageDetailsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6899,6 +10789,57 @@ string[] constvalues"####;
             0xc9, 0x05, 0x9e, 0xc1,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::MessageDetailsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MessageDetailsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#typedefs, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#typedefs: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MessageDetailsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MessageDetailsResponseRef<'a> {
+        pub r#typedefs: ::std::vec::Vec<self::TypeDefRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MessageDetailsResponseRef<'a> {
+        type Owned = MessageDetailsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#typedefs: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MessageDetailsResponse {
+            MessageDetailsResponse {
+                r#typedefs: self
+                    .r#typedefs
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MessageDetailsResponse {
+        type Borrowed<'a> = MessageDetailsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct MessageDetails {}
@@ -6938,6 +10879,48 @@ string[] constvalues"####;
             0xfc, 0xeb, 0xf2, 0xed,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::NodeDetailsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NodeDetailsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#node);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#node: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [NodeDetailsRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NodeDetailsRequestRef<'a> {
+        pub r#node: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NodeDetailsRequestRef<'a> {
+        type Owned = NodeDetailsRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#node: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> NodeDetailsRequest {
+            NodeDetailsRequest {
+                r#node: self.r#node.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NodeDetailsRequest {
+        type Borrowed<'a> = NodeDetailsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6967,6 +10950,70 @@ string[] services"####;
             0x0e, 0xad, 0x6d, 0xc8,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::NodeDetailsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NodeDetailsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#subscribing, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#publishing, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#services, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#subscribing: reader.get_seq_with(|reader| reader.get_string())?,
+                r#publishing: reader.get_seq_with(|reader| reader.get_string())?,
+                r#services: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [NodeDetailsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NodeDetailsResponseRef<'a> {
+        pub r#subscribing: ::std::vec::Vec<&'a str>,
+        pub r#publishing: ::std::vec::Vec<&'a str>,
+        pub r#services: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NodeDetailsResponseRef<'a> {
+        type Owned = NodeDetailsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#subscribing: reader.get_seq_with(|reader| reader.get_str())?,
+                r#publishing: reader.get_seq_with(|reader| reader.get_str())?,
+                r#services: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> NodeDetailsResponse {
+            NodeDetailsResponse {
+                r#subscribing: self
+                    .r#subscribing
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#publishing: self
+                    .r#publishing
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#services: self
+                    .r#services
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NodeDetailsResponse {
+        type Borrowed<'a> = NodeDetailsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct NodeDetails {}
@@ -7004,6 +11051,35 @@ string[] services"####;
             0x01, 0x5c, 0xb9, 0xc4,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::NodesRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NodesRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type NodesRequestRef<'a> = NodesRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NodesRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NodesRequest {
+        type Borrowed<'a> = NodesRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7029,6 +11105,48 @@ string[] services"####;
             0x87, 0xeb, 0x3b, 0xf4,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::NodesResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NodesResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#nodes, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#nodes: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [NodesResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NodesResponseRef<'a> {
+        pub r#nodes: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NodesResponseRef<'a> {
+        type Owned = NodesResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#nodes: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> NodesResponse {
+            NodesResponse {
+                r#nodes: self.r#nodes.iter().map(|item| (*item).to_owned()).collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NodesResponse {
+        type Borrowed<'a> = NodesResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Nodes {}
@@ -7068,6 +11186,48 @@ string[] services"####;
             0x3b, 0x50, 0xde, 0xbb,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::PublishersRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PublishersRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#topic);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PublishersRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PublishersRequestRef<'a> {
+        pub r#topic: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PublishersRequestRef<'a> {
+        type Owned = PublishersRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> PublishersRequest {
+            PublishersRequest {
+                r#topic: self.r#topic.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PublishersRequest {
+        type Borrowed<'a> = PublishersRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7093,6 +11253,52 @@ string[] services"####;
             0xda, 0xe0, 0x08, 0x6e,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::PublishersResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PublishersResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#publishers, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#publishers: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PublishersResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PublishersResponseRef<'a> {
+        pub r#publishers: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PublishersResponseRef<'a> {
+        type Owned = PublishersResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#publishers: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> PublishersResponse {
+            PublishersResponse {
+                r#publishers: self
+                    .r#publishers
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PublishersResponse {
+        type Borrowed<'a> = PublishersResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Publishers {}
@@ -7132,6 +11338,48 @@ string[] services"####;
             0x30, 0x10, 0xb0, 0x78,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::SearchParamRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SearchParamRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SearchParamRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SearchParamRequestRef<'a> {
+        pub r#name: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SearchParamRequestRef<'a> {
+        type Owned = SearchParamRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SearchParamRequest {
+            SearchParamRequest {
+                r#name: self.r#name.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SearchParamRequest {
+        type Borrowed<'a> = SearchParamRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7157,6 +11405,48 @@ string[] services"####;
             0xfc, 0xc8, 0xf6, 0xa8,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::SearchParamResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SearchParamResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#global_name);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#global_name: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SearchParamResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SearchParamResponseRef<'a> {
+        pub r#global_name: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SearchParamResponseRef<'a> {
+        type Owned = SearchParamResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#global_name: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SearchParamResponse {
+            SearchParamResponse {
+                r#global_name: self.r#global_name.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SearchParamResponse {
+        type Borrowed<'a> = SearchParamResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SearchParam {}
@@ -7196,6 +11486,48 @@ string[] services"####;
             0xee, 0xbd, 0x77, 0x81,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceHostRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceHostRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#service);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceHostRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceHostRequestRef<'a> {
+        pub r#service: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceHostRequestRef<'a> {
+        type Owned = ServiceHostRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceHostRequest {
+            ServiceHostRequest {
+                r#service: self.r#service.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceHostRequest {
+        type Borrowed<'a> = ServiceHostRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7221,6 +11553,48 @@ string[] services"####;
             0xfa, 0x02, 0xde, 0xe1,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceHostResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceHostResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#host);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#host: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceHostResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceHostResponseRef<'a> {
+        pub r#host: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceHostResponseRef<'a> {
+        type Owned = ServiceHostResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#host: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceHostResponse {
+            ServiceHostResponse {
+                r#host: self.r#host.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceHostResponse {
+        type Borrowed<'a> = ServiceHostResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServiceHost {}
@@ -7260,6 +11634,48 @@ string[] services"####;
             0x28, 0xb6, 0xf8, 0x1b,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceNodeRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceNodeRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#service);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceNodeRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceNodeRequestRef<'a> {
+        pub r#service: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceNodeRequestRef<'a> {
+        type Owned = ServiceNodeRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceNodeRequest {
+            ServiceNodeRequest {
+                r#service: self.r#service.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceNodeRequest {
+        type Borrowed<'a> = ServiceNodeRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7285,6 +11701,48 @@ string[] services"####;
             0x52, 0x93, 0x33, 0x34,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceNodeResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceNodeResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#node);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#node: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceNodeResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceNodeResponseRef<'a> {
+        pub r#node: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceNodeResponseRef<'a> {
+        type Owned = ServiceNodeResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#node: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceNodeResponse {
+            ServiceNodeResponse {
+                r#node: self.r#node.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceNodeResponse {
+        type Borrowed<'a> = ServiceNodeResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServiceNode {}
@@ -7324,6 +11782,48 @@ string[] services"####;
             0x01, 0x82, 0x4a, 0x20,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceProvidersRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceProvidersRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#service);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceProvidersRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceProvidersRequestRef<'a> {
+        pub r#service: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceProvidersRequestRef<'a> {
+        type Owned = ServiceProvidersRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceProvidersRequest {
+            ServiceProvidersRequest {
+                r#service: self.r#service.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceProvidersRequest {
+        type Borrowed<'a> = ServiceProvidersRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7349,6 +11849,52 @@ string[] services"####;
             0x27, 0x74, 0x9c, 0x81,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceProvidersResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceProvidersResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#providers, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#providers: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceProvidersResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceProvidersResponseRef<'a> {
+        pub r#providers: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceProvidersResponseRef<'a> {
+        type Owned = ServiceProvidersResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#providers: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceProvidersResponse {
+            ServiceProvidersResponse {
+                r#providers: self
+                    .r#providers
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceProvidersResponse {
+        type Borrowed<'a> = ServiceProvidersResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServiceProviders {}
@@ -7388,6 +11934,48 @@ string[] services"####;
             0x10, 0x71, 0x0f, 0x18,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceRequestDetailsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceRequestDetailsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceRequestDetailsRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceRequestDetailsRequestRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceRequestDetailsRequestRef<'a> {
+        type Owned = ServiceRequestDetailsRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceRequestDetailsRequest {
+            ServiceRequestDetailsRequest {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceRequestDetailsRequest {
+        type Borrowed<'a> = ServiceRequestDetailsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7422,6 +12010,57 @@ string[] constvalues"####;
             0x6a, 0x8e, 0x08, 0x4a,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceRequestDetailsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceRequestDetailsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#typedefs, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#typedefs: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceRequestDetailsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceRequestDetailsResponseRef<'a> {
+        pub r#typedefs: ::std::vec::Vec<self::TypeDefRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceRequestDetailsResponseRef<'a> {
+        type Owned = ServiceRequestDetailsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#typedefs: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceRequestDetailsResponse {
+            ServiceRequestDetailsResponse {
+                r#typedefs: self
+                    .r#typedefs
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceRequestDetailsResponse {
+        type Borrowed<'a> = ServiceRequestDetailsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServiceRequestDetails {}
@@ -7461,6 +12100,48 @@ string[] constvalues"####;
             0x0f, 0x3f, 0x58, 0x57,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceResponseDetailsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceResponseDetailsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceResponseDetailsRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceResponseDetailsRequestRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceResponseDetailsRequestRef<'a> {
+        type Owned = ServiceResponseDetailsRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceResponseDetailsRequest {
+            ServiceResponseDetailsRequest {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceResponseDetailsRequest {
+        type Borrowed<'a> = ServiceResponseDetailsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7495,6 +12176,57 @@ string[] constvalues"####;
             0xb9, 0xaf, 0xe9, 0xe2,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceResponseDetailsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceResponseDetailsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#typedefs, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#typedefs: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceResponseDetailsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceResponseDetailsResponseRef<'a> {
+        pub r#typedefs: ::std::vec::Vec<self::TypeDefRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceResponseDetailsResponseRef<'a> {
+        type Owned = ServiceResponseDetailsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#typedefs: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceResponseDetailsResponse {
+            ServiceResponseDetailsResponse {
+                r#typedefs: self
+                    .r#typedefs
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceResponseDetailsResponse {
+        type Borrowed<'a> = ServiceResponseDetailsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServiceResponseDetails {}
@@ -7534,6 +12266,48 @@ string[] constvalues"####;
             0x12, 0x67, 0xf4, 0xcf,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceTypeRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceTypeRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#service);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceTypeRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceTypeRequestRef<'a> {
+        pub r#service: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceTypeRequestRef<'a> {
+        type Owned = ServiceTypeRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#service: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceTypeRequest {
+            ServiceTypeRequest {
+                r#service: self.r#service.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceTypeRequest {
+        type Borrowed<'a> = ServiceTypeRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7559,6 +12333,48 @@ string[] constvalues"####;
             0x76, 0x88, 0xd2, 0xd0,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServiceTypeResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceTypeResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServiceTypeResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceTypeResponseRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceTypeResponseRef<'a> {
+        type Owned = ServiceTypeResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServiceTypeResponse {
+            ServiceTypeResponse {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceTypeResponse {
+        type Borrowed<'a> = ServiceTypeResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServiceType {}
@@ -7596,6 +12412,35 @@ string[] constvalues"####;
             0xcd, 0x95, 0x77, 0xd8,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServicesRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServicesRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type ServicesRequestRef<'a> = ServicesRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServicesRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServicesRequest {
+        type Borrowed<'a> = ServicesRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7621,6 +12466,52 @@ string[] constvalues"####;
             0xc6, 0x2c, 0x5b, 0xb1,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServicesResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServicesResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#services, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#services: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServicesResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServicesResponseRef<'a> {
+        pub r#services: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServicesResponseRef<'a> {
+        type Owned = ServicesResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#services: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> ServicesResponse {
+            ServicesResponse {
+                r#services: self
+                    .r#services
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServicesResponse {
+        type Borrowed<'a> = ServicesResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Services {}
@@ -7660,6 +12551,48 @@ string[] constvalues"####;
             0xe1, 0x89, 0x43, 0x15,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServicesForTypeRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServicesForTypeRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServicesForTypeRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServicesForTypeRequestRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServicesForTypeRequestRef<'a> {
+        type Owned = ServicesForTypeRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> ServicesForTypeRequest {
+            ServicesForTypeRequest {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServicesForTypeRequest {
+        type Borrowed<'a> = ServicesForTypeRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7685,6 +12618,52 @@ string[] constvalues"####;
             0x65, 0xf2, 0x05, 0xed,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::ServicesForTypeResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServicesForTypeResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#services, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#services: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ServicesForTypeResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServicesForTypeResponseRef<'a> {
+        pub r#services: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServicesForTypeResponseRef<'a> {
+        type Owned = ServicesForTypeResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#services: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> ServicesForTypeResponse {
+            ServicesForTypeResponse {
+                r#services: self
+                    .r#services
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServicesForTypeResponse {
+        type Borrowed<'a> = ServicesForTypeResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct ServicesForType {}
@@ -7726,6 +12705,53 @@ string value"####;
             0x33, 0xfe, 0xb4, 0x57,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::SetParamRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetParamRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#value);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#value: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetParamRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetParamRequestRef<'a> {
+        pub r#name: &'a str,
+        pub r#value: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetParamRequestRef<'a> {
+        type Owned = SetParamRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#value: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SetParamRequest {
+            SetParamRequest {
+                r#name: self.r#name.to_owned(),
+                r#value: self.r#value.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetParamRequest {
+        type Borrowed<'a> = SetParamRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7749,6 +12775,35 @@ string value"####;
             0x07, 0x46, 0xbf, 0xb6,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::SetParamResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetParamResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type SetParamResponseRef<'a> = SetParamResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetParamResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetParamResponse {
+        type Borrowed<'a> = SetParamResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetParam {}
@@ -7788,6 +12843,48 @@ string value"####;
             0x9d, 0x2f, 0x79, 0x31,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::SubscribersRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SubscribersRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#topic);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SubscribersRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SubscribersRequestRef<'a> {
+        pub r#topic: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SubscribersRequestRef<'a> {
+        type Owned = SubscribersRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SubscribersRequest {
+            SubscribersRequest {
+                r#topic: self.r#topic.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SubscribersRequest {
+        type Borrowed<'a> = SubscribersRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7813,6 +12910,52 @@ string value"####;
             0x96, 0x56, 0xe2, 0x9b,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::SubscribersResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SubscribersResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#subscribers, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#subscribers: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SubscribersResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SubscribersResponseRef<'a> {
+        pub r#subscribers: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SubscribersResponseRef<'a> {
+        type Owned = SubscribersResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#subscribers: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> SubscribersResponse {
+            SubscribersResponse {
+                r#subscribers: self
+                    .r#subscribers
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SubscribersResponse {
+        type Borrowed<'a> = SubscribersResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Subscribers {}
@@ -7852,6 +12995,48 @@ string value"####;
             0x37, 0xa1, 0xfd, 0x5e,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicTypeRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicTypeRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#topic);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicTypeRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicTypeRequestRef<'a> {
+        pub r#topic: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicTypeRequestRef<'a> {
+        type Owned = TopicTypeRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicTypeRequest {
+            TopicTypeRequest {
+                r#topic: self.r#topic.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicTypeRequest {
+        type Borrowed<'a> = TopicTypeRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7877,6 +13062,48 @@ string value"####;
             0x64, 0xfe, 0xaf, 0xf7,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicTypeResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicTypeResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicTypeResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicTypeResponseRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicTypeResponseRef<'a> {
+        type Owned = TopicTypeResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicTypeResponse {
+            TopicTypeResponse {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicTypeResponse {
+        type Borrowed<'a> = TopicTypeResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct TopicType {}
@@ -7914,6 +13141,35 @@ string value"####;
             0x1a, 0x89, 0x9d, 0xbd,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type TopicsRequestRef<'a> = TopicsRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicsRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicsRequest {
+        type Borrowed<'a> = TopicsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7941,6 +13197,57 @@ string[] types"####;
             0x3c, 0x8f, 0x9e, 0xd2,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#topics, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#types, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topics: reader.get_seq_with(|reader| reader.get_string())?,
+                r#types: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicsResponseRef<'a> {
+        pub r#topics: ::std::vec::Vec<&'a str>,
+        pub r#types: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicsResponseRef<'a> {
+        type Owned = TopicsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topics: reader.get_seq_with(|reader| reader.get_str())?,
+                r#types: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicsResponse {
+            TopicsResponse {
+                r#topics: self
+                    .r#topics
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#types: self.r#types.iter().map(|item| (*item).to_owned()).collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicsResponse {
+        type Borrowed<'a> = TopicsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Topics {}
@@ -7978,6 +13285,35 @@ string[] types"####;
             0xa2, 0x62, 0x82, 0xa1,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicsAndRawTypesRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicsAndRawTypesRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type TopicsAndRawTypesRequestRef<'a> = TopicsAndRawTypesRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicsAndRawTypesRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicsAndRawTypesRequest {
+        type Borrowed<'a> = TopicsAndRawTypesRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8007,6 +13343,68 @@ string[] typedefs_full_text"####;
             0x44, 0x31, 0x78, 0x91,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicsAndRawTypesResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicsAndRawTypesResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#topics, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#types, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#typedefs_full_text, |writer, item| {
+                writer.put_str(item)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topics: reader.get_seq_with(|reader| reader.get_string())?,
+                r#types: reader.get_seq_with(|reader| reader.get_string())?,
+                r#typedefs_full_text: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicsAndRawTypesResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicsAndRawTypesResponseRef<'a> {
+        pub r#topics: ::std::vec::Vec<&'a str>,
+        pub r#types: ::std::vec::Vec<&'a str>,
+        pub r#typedefs_full_text: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicsAndRawTypesResponseRef<'a> {
+        type Owned = TopicsAndRawTypesResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topics: reader.get_seq_with(|reader| reader.get_str())?,
+                r#types: reader.get_seq_with(|reader| reader.get_str())?,
+                r#typedefs_full_text: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicsAndRawTypesResponse {
+            TopicsAndRawTypesResponse {
+                r#topics: self
+                    .r#topics
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#types: self.r#types.iter().map(|item| (*item).to_owned()).collect(),
+                r#typedefs_full_text: self
+                    .r#typedefs_full_text
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicsAndRawTypesResponse {
+        type Borrowed<'a> = TopicsAndRawTypesResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct TopicsAndRawTypes {}
@@ -8046,6 +13444,48 @@ string[] typedefs_full_text"####;
             0xe8, 0x1f, 0x87, 0x0d,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicsForTypeRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicsForTypeRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicsForTypeRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicsForTypeRequestRef<'a> {
+        pub r#type: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicsForTypeRequestRef<'a> {
+        type Owned = TopicsForTypeRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicsForTypeRequest {
+            TopicsForTypeRequest {
+                r#type: self.r#type.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicsForTypeRequest {
+        type Borrowed<'a> = TopicsForTypeRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8071,6 +13511,52 @@ string[] typedefs_full_text"####;
             0xd2, 0xbb, 0x34, 0xe9,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosapi::msg::dds_::TopicsForTypeResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicsForTypeResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#topics, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topics: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicsForTypeResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicsForTypeResponseRef<'a> {
+        pub r#topics: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicsForTypeResponseRef<'a> {
+        type Owned = TopicsForTypeResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topics: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicsForTypeResponse {
+            TopicsForTypeResponse {
+                r#topics: self
+                    .r#topics
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicsForTypeResponse {
+        type Borrowed<'a> = TopicsForTypeResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct TopicsForType {}
@@ -8131,6 +13617,39 @@ time clock"####;
             0x30, 0x63, 0xb8, 0xe4,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosgraph_msgs::msg::dds_::Clock_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Clock {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#clock, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#clock: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type ClockRef<'a> = Clock;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Clock {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Clock {
+        type Borrowed<'a> = ClockRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8196,6 +13715,33 @@ string frame_id"####;
             0x8f, 0x6c, 0x96, 0x6e,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosgraph_msgs::msg::dds_::Log_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Log {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl Log {
@@ -8205,6 +13751,85 @@ string frame_id"####;
         pub const r#ERROR: u8 = 8u8;
         pub const r#FATAL: u8 = 16u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Log {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#level);
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#msg);
+            writer.put_str(&self.r#file);
+            writer.put_str(&self.r#function);
+            writer.put(self.r#line);
+            writer.put_seq_with(&self.r#topics, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#level: reader.get()?,
+                r#name: reader.get_string()?,
+                r#msg: reader.get_string()?,
+                r#file: reader.get_string()?,
+                r#function: reader.get_string()?,
+                r#line: reader.get()?,
+                r#topics: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Log], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LogRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#level: u8,
+        pub r#name: &'a str,
+        pub r#msg: &'a str,
+        pub r#file: &'a str,
+        pub r#function: &'a str,
+        pub r#line: u32,
+        pub r#topics: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LogRef<'a> {
+        type Owned = Log;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#level: reader.get()?,
+                r#name: reader.get_str()?,
+                r#msg: reader.get_str()?,
+                r#file: reader.get_str()?,
+                r#function: reader.get_str()?,
+                r#line: reader.get()?,
+                r#topics: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> Log {
+            Log {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#level: self.r#level,
+                r#name: self.r#name.to_owned(),
+                r#msg: self.r#msg.to_owned(),
+                r#file: self.r#file.to_owned(),
+                r#function: self.r#function.to_owned(),
+                r#line: self.r#line,
+                r#topics: self
+                    .r#topics
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Log {
+        type Borrowed<'a> = LogRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -8273,6 +13898,113 @@ duration stamp_age_max"####;
             0xab, 0x97, 0xf6, 0x05,
         ];
         const ROS2_TYPE_NAME: &'static str = "rosgraph_msgs::msg::dds_::TopicStatistics_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TopicStatistics {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#topic);
+            writer.put_str(&self.r#node_pub);
+            writer.put_str(&self.r#node_sub);
+            ::roslibrust::codec::WireCodec::encode(&self.r#window_start, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#window_stop, writer);
+            writer.put(self.r#delivered_msgs);
+            writer.put(self.r#dropped_msgs);
+            writer.put(self.r#traffic);
+            ::roslibrust::codec::WireCodec::encode(&self.r#period_mean, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#period_stddev, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#period_max, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp_age_mean, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp_age_stddev, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp_age_max, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_string()?,
+                r#node_pub: reader.get_string()?,
+                r#node_sub: reader.get_string()?,
+                r#window_start: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#window_stop: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#delivered_msgs: reader.get()?,
+                r#dropped_msgs: reader.get()?,
+                r#traffic: reader.get()?,
+                r#period_mean: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#period_stddev: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#period_max: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#stamp_age_mean: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#stamp_age_stddev: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#stamp_age_max: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TopicStatistics], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TopicStatisticsRef<'a> {
+        pub r#topic: &'a str,
+        pub r#node_pub: &'a str,
+        pub r#node_sub: &'a str,
+        pub r#window_start: ::roslibrust::codegen::integral_types::Time,
+        pub r#window_stop: ::roslibrust::codegen::integral_types::Time,
+        pub r#delivered_msgs: i32,
+        pub r#dropped_msgs: i32,
+        pub r#traffic: i32,
+        pub r#period_mean: ::roslibrust::codegen::integral_types::Duration,
+        pub r#period_stddev: ::roslibrust::codegen::integral_types::Duration,
+        pub r#period_max: ::roslibrust::codegen::integral_types::Duration,
+        pub r#stamp_age_mean: ::roslibrust::codegen::integral_types::Duration,
+        pub r#stamp_age_stddev: ::roslibrust::codegen::integral_types::Duration,
+        pub r#stamp_age_max: ::roslibrust::codegen::integral_types::Duration,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TopicStatisticsRef<'a> {
+        type Owned = TopicStatistics;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#topic: reader.get_str()?,
+                r#node_pub: reader.get_str()?,
+                r#node_sub: reader.get_str()?,
+                r#window_start: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#window_stop: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#delivered_msgs: reader.get()?,
+                r#dropped_msgs: reader.get()?,
+                r#traffic: reader.get()?,
+                r#period_mean: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#period_stddev: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#period_max: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#stamp_age_mean: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#stamp_age_stddev: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#stamp_age_max: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TopicStatistics {
+            TopicStatistics {
+                r#topic: self.r#topic.to_owned(),
+                r#node_pub: self.r#node_pub.to_owned(),
+                r#node_sub: self.r#node_sub.to_owned(),
+                r#window_start: self.r#window_start.clone(),
+                r#window_stop: self.r#window_stop.clone(),
+                r#delivered_msgs: self.r#delivered_msgs,
+                r#dropped_msgs: self.r#dropped_msgs,
+                r#traffic: self.r#traffic,
+                r#period_mean: self.r#period_mean.clone(),
+                r#period_stddev: self.r#period_stddev.clone(),
+                r#period_max: self.r#period_max.clone(),
+                r#stamp_age_mean: self.r#stamp_age_mean.clone(),
+                r#stamp_age_stddev: self.r#stamp_age_stddev.clone(),
+                r#stamp_age_max: self.r#stamp_age_max.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TopicStatistics {
+        type Borrowed<'a> = TopicStatisticsRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -8396,6 +14128,33 @@ string frame_id"####;
             0xc4, 0xd3, 0xa2, 0xc3,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::BatteryState_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for BatteryState {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl BatteryState {
@@ -8421,6 +14180,121 @@ string frame_id"####;
         pub const r#POWER_SUPPLY_TECHNOLOGY_NICD: u8 = 5u8;
         pub const r#POWER_SUPPLY_TECHNOLOGY_LIMN: u8 = 6u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for BatteryState {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#voltage);
+            writer.put(self.r#temperature);
+            writer.put(self.r#current);
+            writer.put(self.r#charge);
+            writer.put(self.r#capacity);
+            writer.put(self.r#design_capacity);
+            writer.put(self.r#percentage);
+            writer.put(self.r#power_supply_status);
+            writer.put(self.r#power_supply_health);
+            writer.put(self.r#power_supply_technology);
+            writer.put(self.r#present);
+            writer.put_seq(&self.r#cell_voltage);
+            writer.put_seq(&self.r#cell_temperature);
+            writer.put_str(&self.r#location);
+            writer.put_str(&self.r#serial_number);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#voltage: reader.get()?,
+                r#temperature: reader.get()?,
+                r#current: reader.get()?,
+                r#charge: reader.get()?,
+                r#capacity: reader.get()?,
+                r#design_capacity: reader.get()?,
+                r#percentage: reader.get()?,
+                r#power_supply_status: reader.get()?,
+                r#power_supply_health: reader.get()?,
+                r#power_supply_technology: reader.get()?,
+                r#present: reader.get()?,
+                r#cell_voltage: reader.get_seq()?,
+                r#cell_temperature: reader.get_seq()?,
+                r#location: reader.get_string()?,
+                r#serial_number: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [BatteryState], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BatteryStateRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#voltage: f32,
+        pub r#temperature: f32,
+        pub r#current: f32,
+        pub r#charge: f32,
+        pub r#capacity: f32,
+        pub r#design_capacity: f32,
+        pub r#percentage: f32,
+        pub r#power_supply_status: u8,
+        pub r#power_supply_health: u8,
+        pub r#power_supply_technology: u8,
+        pub r#present: bool,
+        pub r#cell_voltage: ::std::vec::Vec<f32>,
+        pub r#cell_temperature: ::std::vec::Vec<f32>,
+        pub r#location: &'a str,
+        pub r#serial_number: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for BatteryStateRef<'a> {
+        type Owned = BatteryState;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#voltage: reader.get()?,
+                r#temperature: reader.get()?,
+                r#current: reader.get()?,
+                r#charge: reader.get()?,
+                r#capacity: reader.get()?,
+                r#design_capacity: reader.get()?,
+                r#percentage: reader.get()?,
+                r#power_supply_status: reader.get()?,
+                r#power_supply_health: reader.get()?,
+                r#power_supply_technology: reader.get()?,
+                r#present: reader.get()?,
+                r#cell_voltage: reader.get_seq()?,
+                r#cell_temperature: reader.get_seq()?,
+                r#location: reader.get_str()?,
+                r#serial_number: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> BatteryState {
+            BatteryState {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#voltage: self.r#voltage,
+                r#temperature: self.r#temperature,
+                r#current: self.r#current,
+                r#charge: self.r#charge,
+                r#capacity: self.r#capacity,
+                r#design_capacity: self.r#design_capacity,
+                r#percentage: self.r#percentage,
+                r#power_supply_status: self.r#power_supply_status,
+                r#power_supply_health: self.r#power_supply_health,
+                r#power_supply_technology: self.r#power_supply_technology,
+                r#present: self.r#present,
+                r#cell_voltage: self.r#cell_voltage.clone(),
+                r#cell_temperature: self.r#cell_temperature.clone(),
+                r#location: self.r#location.to_owned(),
+                r#serial_number: self.r#serial_number.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for BatteryState {
+        type Borrowed<'a> = BatteryStateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -8621,6 +14495,123 @@ string frame_id"####;
             0x84, 0xa9, 0x70, 0x5d,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::CameraInfo_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for CameraInfo {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for CameraInfo {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put_str(&self.r#distortion_model);
+            writer.put_seq(&self.r#D);
+            writer.put_slice(&self.r#K);
+            writer.put_slice(&self.r#R);
+            writer.put_slice(&self.r#P);
+            writer.put(self.r#binning_x);
+            writer.put(self.r#binning_y);
+            ::roslibrust::codec::WireCodec::encode(&self.r#roi, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#distortion_model: reader.get_string()?,
+                r#D: reader.get_seq()?,
+                r#K: reader.get_array()?,
+                r#R: reader.get_array()?,
+                r#P: reader.get_array()?,
+                r#binning_x: reader.get()?,
+                r#binning_y: reader.get()?,
+                r#roi: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [CameraInfo], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CameraInfoRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#height: u32,
+        pub r#width: u32,
+        pub r#distortion_model: &'a str,
+        pub r#D: ::std::vec::Vec<f64>,
+        pub r#K: [f64; 9],
+        pub r#R: [f64; 9],
+        pub r#P: [f64; 12],
+        pub r#binning_x: u32,
+        pub r#binning_y: u32,
+        pub r#roi: self::RegionOfInterestRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for CameraInfoRef<'a> {
+        type Owned = CameraInfo;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#distortion_model: reader.get_str()?,
+                r#D: reader.get_seq()?,
+                r#K: reader.get_array()?,
+                r#R: reader.get_array()?,
+                r#P: reader.get_array()?,
+                r#binning_x: reader.get()?,
+                r#binning_y: reader.get()?,
+                r#roi: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> CameraInfo {
+            CameraInfo {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#height: self.r#height,
+                r#width: self.r#width,
+                r#distortion_model: self.r#distortion_model.to_owned(),
+                r#D: self.r#D.clone(),
+                r#K: self.r#K,
+                r#R: self.r#R,
+                r#P: self.r#P,
+                r#binning_x: self.r#binning_x,
+                r#binning_y: self.r#binning_y,
+                r#roi: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#roi),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for CameraInfo {
+        type Borrowed<'a> = CameraInfoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8670,6 +14661,53 @@ float32[] values"####;
             0x58, 0xaf, 0x6c, 0xd4,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::ChannelFloat32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ChannelFloat32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put_seq(&self.r#values);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#values: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ChannelFloat32], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChannelFloat32Ref<'a> {
+        pub r#name: &'a str,
+        pub r#values: ::std::vec::Vec<f32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ChannelFloat32Ref<'a> {
+        type Owned = ChannelFloat32;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#values: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> ChannelFloat32 {
+            ChannelFloat32 {
+                r#name: self.r#name.to_owned(),
+                r#values: self.r#values.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ChannelFloat32 {
+        type Borrowed<'a> = ChannelFloat32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8725,6 +14763,83 @@ string frame_id"####;
             0xbb, 0xa6, 0x61, 0x3e,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::CompressedImage_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for CompressedImage {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for CompressedImage {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#format);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#format: reader.get_string()?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [CompressedImage], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CompressedImageRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#format: &'a str,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for CompressedImageRef<'a> {
+        type Owned = CompressedImage;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#format: reader.get_str()?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> CompressedImage {
+            CompressedImage {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#format: self.r#format.to_owned(),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for CompressedImage {
+        type Borrowed<'a> = CompressedImageRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8778,6 +14893,83 @@ string frame_id"####;
             0xb4, 0x67, 0xab, 0xad,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::FluidPressure_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for FluidPressure {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for FluidPressure {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#fluid_pressure);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#fluid_pressure: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [FluidPressure], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FluidPressureRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#fluid_pressure: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for FluidPressureRef<'a> {
+        type Owned = FluidPressure;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#fluid_pressure: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> FluidPressure {
+            FluidPressure {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#fluid_pressure: self.r#fluid_pressure,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for FluidPressure {
+        type Borrowed<'a> = FluidPressureRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8840,6 +15032,83 @@ string frame_id"####;
             0x8c, 0x3b, 0xc7, 0x48,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Illuminance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Illuminance {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Illuminance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#illuminance);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#illuminance: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Illuminance], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct IlluminanceRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#illuminance: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for IlluminanceRef<'a> {
+        type Owned = Illuminance;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#illuminance: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Illuminance {
+            Illuminance {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#illuminance: self.r#illuminance,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Illuminance {
+        type Borrowed<'a> = IlluminanceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8913,6 +15182,103 @@ string frame_id"####;
             0xf5, 0xbb, 0x93, 0x0b,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Image_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Image {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Image {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put_str(&self.r#encoding);
+            writer.put(self.r#is_bigendian);
+            writer.put(self.r#step);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#encoding: reader.get_string()?,
+                r#is_bigendian: reader.get()?,
+                r#step: reader.get()?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Image], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImageRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#height: u32,
+        pub r#width: u32,
+        pub r#encoding: &'a str,
+        pub r#is_bigendian: u8,
+        pub r#step: u32,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ImageRef<'a> {
+        type Owned = Image;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#encoding: reader.get_str()?,
+                r#is_bigendian: reader.get()?,
+                r#step: reader.get()?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Image {
+            Image {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#height: self.r#height,
+                r#width: self.r#width,
+                r#encoding: self.r#encoding.to_owned(),
+                r#is_bigendian: self.r#is_bigendian,
+                r#step: self.r#step,
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Image {
+        type Borrowed<'a> = ImageRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9002,6 +15368,113 @@ string frame_id"####;
             0x44, 0x3a, 0xfb, 0xb4,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Imu_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Imu {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Imu {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#orientation, writer);
+            writer.put_slice(&self.r#orientation_covariance);
+            ::roslibrust::codec::WireCodec::encode(&self.r#angular_velocity, writer);
+            writer.put_slice(&self.r#angular_velocity_covariance);
+            ::roslibrust::codec::WireCodec::encode(&self.r#linear_acceleration, writer);
+            writer.put_slice(&self.r#linear_acceleration_covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation_covariance: reader.get_array()?,
+                r#angular_velocity: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angular_velocity_covariance: reader.get_array()?,
+                r#linear_acceleration: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#linear_acceleration_covariance: reader.get_array()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Imu], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImuRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#orientation: geometry_msgs::QuaternionRef<'a>,
+        pub r#orientation_covariance: [f64; 9],
+        pub r#angular_velocity: geometry_msgs::Vector3Ref<'a>,
+        pub r#angular_velocity_covariance: [f64; 9],
+        pub r#linear_acceleration: geometry_msgs::Vector3Ref<'a>,
+        pub r#linear_acceleration_covariance: [f64; 9],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ImuRef<'a> {
+        type Owned = Imu;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#orientation_covariance: reader.get_array()?,
+                r#angular_velocity: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(
+                    reader,
+                )?,
+                r#angular_velocity_covariance: reader.get_array()?,
+                r#linear_acceleration: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(
+                    reader,
+                )?,
+                r#linear_acceleration_covariance: reader.get_array()?,
+            })
+        }
+        fn to_owned_message(&self) -> Imu {
+            Imu {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#orientation,
+                ),
+                r#orientation_covariance: self.r#orientation_covariance,
+                r#angular_velocity: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#angular_velocity,
+                ),
+                r#angular_velocity_covariance: self.r#angular_velocity_covariance,
+                r#linear_acceleration: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#linear_acceleration,
+                ),
+                r#linear_acceleration_covariance: self.r#linear_acceleration_covariance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Imu {
+        type Borrowed<'a> = ImuRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9071,6 +15544,93 @@ string frame_id"####;
             0x64, 0xd3, 0x1b, 0xed,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::JointState_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for JointState {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JointState {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#name, |writer, item| writer.put_str(item));
+            writer.put_seq(&self.r#position);
+            writer.put_seq(&self.r#velocity);
+            writer.put_seq(&self.r#effort);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#name: reader.get_seq_with(|reader| reader.get_string())?,
+                r#position: reader.get_seq()?,
+                r#velocity: reader.get_seq()?,
+                r#effort: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [JointState], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JointStateRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#name: ::std::vec::Vec<&'a str>,
+        pub r#position: ::std::vec::Vec<f64>,
+        pub r#velocity: ::std::vec::Vec<f64>,
+        pub r#effort: ::std::vec::Vec<f64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JointStateRef<'a> {
+        type Owned = JointState;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#name: reader.get_seq_with(|reader| reader.get_str())?,
+                r#position: reader.get_seq()?,
+                r#velocity: reader.get_seq()?,
+                r#effort: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> JointState {
+            JointState {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#name: self.r#name.iter().map(|item| (*item).to_owned()).collect(),
+                r#position: self.r#position.clone(),
+                r#velocity: self.r#velocity.clone(),
+                r#effort: self.r#effort.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JointState {
+        type Borrowed<'a> = JointStateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9116,6 +15676,83 @@ string frame_id"####;
             0x1e, 0xc6, 0x3b, 0x8a,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Joy_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Joy {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Joy {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq(&self.r#axes);
+            writer.put_seq(&self.r#buttons);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#axes: reader.get_seq()?,
+                r#buttons: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Joy], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JoyRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#axes: ::std::vec::Vec<f32>,
+        pub r#buttons: ::std::vec::Vec<i32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JoyRef<'a> {
+        type Owned = Joy;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#axes: reader.get_seq()?,
+                r#buttons: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Joy {
+            Joy {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#axes: self.r#axes.clone(),
+                r#buttons: self.r#buttons.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Joy {
+        type Borrowed<'a> = JoyRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9156,6 +15793,8 @@ float32 intensity"####;
             0x83, 0x42, 0x90, 0xcd,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::JoyFeedback_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl JoyFeedback {
@@ -9163,6 +15802,41 @@ float32 intensity"####;
         pub const r#TYPE_RUMBLE: u8 = 1u8;
         pub const r#TYPE_BUZZER: u8 = 2u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JoyFeedback {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#type);
+            writer.put(self.r#id);
+            writer.put(self.r#intensity);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get()?,
+                r#id: reader.get()?,
+                r#intensity: reader.get()?,
+            })
+        }
+    }
+    pub type JoyFeedbackRef<'a> = JoyFeedback;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JoyFeedback {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JoyFeedback {
+        type Borrowed<'a> = JoyFeedbackRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -9204,6 +15878,42 @@ float32 intensity"####;
             0x50, 0x37, 0xbc, 0xaa,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::JoyFeedbackArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JoyFeedbackArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#array, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#array: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    pub type JoyFeedbackArrayRef<'a> = JoyFeedbackArray;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JoyFeedbackArray {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JoyFeedbackArray {
+        type Borrowed<'a> = JoyFeedbackArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9233,6 +15943,39 @@ float32[] echoes  # Multiple values of ranges or intensities.
             0x58, 0x2b, 0xc9, 0x52,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::LaserEcho_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LaserEcho {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq(&self.r#echoes);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#echoes: reader.get_seq()?,
+            })
+        }
+    }
+    pub type LaserEchoRef<'a> = LaserEcho;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LaserEcho {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LaserEcho {
+        type Borrowed<'a> = LaserEchoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9310,6 +16053,118 @@ string frame_id"####;
             0x9f, 0x66, 0x61, 0xd5,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::LaserScan_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for LaserScan {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LaserScan {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#angle_min);
+            writer.put(self.r#angle_max);
+            writer.put(self.r#angle_increment);
+            writer.put(self.r#time_increment);
+            writer.put(self.r#scan_time);
+            writer.put(self.r#range_min);
+            writer.put(self.r#range_max);
+            writer.put_seq(&self.r#ranges);
+            writer.put_seq(&self.r#intensities);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader.get_seq()?,
+                r#intensities: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LaserScan], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LaserScanRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#angle_min: f32,
+        pub r#angle_max: f32,
+        pub r#angle_increment: f32,
+        pub r#time_increment: f32,
+        pub r#scan_time: f32,
+        pub r#range_min: f32,
+        pub r#range_max: f32,
+        pub r#ranges: ::std::vec::Vec<f32>,
+        pub r#intensities: ::std::vec::Vec<f32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LaserScanRef<'a> {
+        type Owned = LaserScan;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader.get_seq()?,
+                r#intensities: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> LaserScan {
+            LaserScan {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#angle_min: self.r#angle_min,
+                r#angle_max: self.r#angle_max,
+                r#angle_increment: self.r#angle_increment,
+                r#time_increment: self.r#time_increment,
+                r#scan_time: self.r#scan_time,
+                r#range_min: self.r#range_min,
+                r#range_max: self.r#range_max,
+                r#ranges: self.r#ranges.clone(),
+                r#intensities: self.r#intensities.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LaserScan {
+        type Borrowed<'a> = LaserScanRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9385,6 +16240,85 @@ string frame_id"####;
             0x42, 0x4a, 0x16, 0x6f,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::MagneticField_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MagneticField {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MagneticField {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#magnetic_field, writer);
+            writer.put_slice(&self.r#magnetic_field_covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#magnetic_field: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#magnetic_field_covariance: reader.get_array()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MagneticField], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MagneticFieldRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#magnetic_field: geometry_msgs::Vector3Ref<'a>,
+        pub r#magnetic_field_covariance: [f64; 9],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MagneticFieldRef<'a> {
+        type Owned = MagneticField;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#magnetic_field: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#magnetic_field_covariance: reader.get_array()?,
+            })
+        }
+        fn to_owned_message(&self) -> MagneticField {
+            MagneticField {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#magnetic_field: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#magnetic_field,
+                ),
+                r#magnetic_field_covariance: self.r#magnetic_field_covariance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MagneticField {
+        type Borrowed<'a> = MagneticFieldRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9535,6 +16469,124 @@ string frame_id"####;
             0xb0, 0x88, 0x47, 0x24,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::MultiDOFJointState_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MultiDOFJointState {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiDOFJointState {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#joint_names, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#transforms, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#twist, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#wrench, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_string())?,
+                r#transforms: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#twist: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#wrench: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiDOFJointState], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiDOFJointStateRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#joint_names: ::std::vec::Vec<&'a str>,
+        pub r#transforms: ::std::vec::Vec<geometry_msgs::TransformRef<'a>>,
+        pub r#twist: ::std::vec::Vec<geometry_msgs::TwistRef<'a>>,
+        pub r#wrench: ::std::vec::Vec<geometry_msgs::WrenchRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiDOFJointStateRef<'a> {
+        type Owned = MultiDOFJointState;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_str())?,
+                r#transforms: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#twist: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#wrench: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiDOFJointState {
+            MultiDOFJointState {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#joint_names: self
+                    .r#joint_names
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#transforms: self
+                    .r#transforms
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#twist: self
+                    .r#twist
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#wrench: self
+                    .r#wrench
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiDOFJointState {
+        type Borrowed<'a> = MultiDOFJointStateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9621,6 +16673,136 @@ string frame_id"####;
             0x05, 0x7e, 0xe3, 0x25,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::MultiEchoLaserScan_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MultiEchoLaserScan {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiEchoLaserScan {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#angle_min);
+            writer.put(self.r#angle_max);
+            writer.put(self.r#angle_increment);
+            writer.put(self.r#time_increment);
+            writer.put(self.r#scan_time);
+            writer.put(self.r#range_min);
+            writer.put(self.r#range_max);
+            writer.put_seq_with(&self.r#ranges, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#intensities, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#intensities: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiEchoLaserScan], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiEchoLaserScanRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#angle_min: f32,
+        pub r#angle_max: f32,
+        pub r#angle_increment: f32,
+        pub r#time_increment: f32,
+        pub r#scan_time: f32,
+        pub r#range_min: f32,
+        pub r#range_max: f32,
+        pub r#ranges: ::std::vec::Vec<self::LaserEchoRef<'a>>,
+        pub r#intensities: ::std::vec::Vec<self::LaserEchoRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiEchoLaserScanRef<'a> {
+        type Owned = MultiEchoLaserScan;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#intensities: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiEchoLaserScan {
+            MultiEchoLaserScan {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#angle_min: self.r#angle_min,
+                r#angle_max: self.r#angle_max,
+                r#angle_increment: self.r#angle_increment,
+                r#time_increment: self.r#time_increment,
+                r#scan_time: self.r#scan_time,
+                r#range_min: self.r#range_min,
+                r#range_max: self.r#range_max,
+                r#ranges: self
+                    .r#ranges
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#intensities: self
+                    .r#intensities
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiEchoLaserScan {
+        type Borrowed<'a> = MultiEchoLaserScanRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9736,6 +16918,33 @@ string frame_id"####;
             0xbe, 0x4d, 0xc7, 0x61,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::NavSatFix_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for NavSatFix {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl NavSatFix {
@@ -9744,6 +16953,76 @@ string frame_id"####;
         pub const r#COVARIANCE_TYPE_DIAGONAL_KNOWN: u8 = 2u8;
         pub const r#COVARIANCE_TYPE_KNOWN: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NavSatFix {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#status, writer);
+            writer.put(self.r#latitude);
+            writer.put(self.r#longitude);
+            writer.put(self.r#altitude);
+            writer.put_slice(&self.r#position_covariance);
+            writer.put(self.r#position_covariance_type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#latitude: reader.get()?,
+                r#longitude: reader.get()?,
+                r#altitude: reader.get()?,
+                r#position_covariance: reader.get_array()?,
+                r#position_covariance_type: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [NavSatFix], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NavSatFixRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status: self::NavSatStatusRef<'a>,
+        pub r#latitude: f64,
+        pub r#longitude: f64,
+        pub r#altitude: f64,
+        pub r#position_covariance: [f64; 9],
+        pub r#position_covariance_type: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NavSatFixRef<'a> {
+        type Owned = NavSatFix;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#latitude: reader.get()?,
+                r#longitude: reader.get()?,
+                r#altitude: reader.get()?,
+                r#position_covariance: reader.get_array()?,
+                r#position_covariance_type: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> NavSatFix {
+            NavSatFix {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#status),
+                r#latitude: self.r#latitude,
+                r#longitude: self.r#longitude,
+                r#altitude: self.r#altitude,
+                r#position_covariance: self.r#position_covariance,
+                r#position_covariance_type: self.r#position_covariance_type,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NavSatFix {
+        type Borrowed<'a> = NavSatFixRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -9790,6 +17069,8 @@ uint16 service"####;
             0x09, 0x5a, 0x32, 0x04,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::NavSatStatus_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl NavSatStatus {
@@ -9802,6 +17083,39 @@ uint16 service"####;
         pub const r#SERVICE_COMPASS: u16 = 4u16;
         pub const r#SERVICE_GALILEO: u16 = 8u16;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NavSatStatus {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#status);
+            writer.put(self.r#service);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#status: reader.get()?,
+                r#service: reader.get()?,
+            })
+        }
+    }
+    pub type NavSatStatusRef<'a> = NavSatStatus;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NavSatStatus {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NavSatStatus {
+        type Borrowed<'a> = NavSatStatusRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -9895,6 +17209,101 @@ string frame_id"####;
             0x1e, 0x5a, 0x11, 0x08,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::PointCloud_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PointCloud {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointCloud {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#channels, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#channels: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointCloud], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointCloudRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#points: ::std::vec::Vec<geometry_msgs::Point32Ref<'a>>,
+        pub r#channels: ::std::vec::Vec<self::ChannelFloat32Ref<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointCloudRef<'a> {
+        type Owned = PointCloud;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#channels: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> PointCloud {
+            PointCloud {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#channels: self
+                    .r#channels
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointCloud {
+        type Borrowed<'a> = PointCloudRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9987,6 +17396,122 @@ string frame_id"####;
             0xee, 0x21, 0x64, 0xa5,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::PointCloud2_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PointCloud2 {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointCloud2 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put_seq_with(&self.r#fields, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put(self.r#is_bigendian);
+            writer.put(self.r#point_step);
+            writer.put(self.r#row_step);
+            writer.put_seq(&self.r#data);
+            writer.put(self.r#is_dense);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#fields: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#is_bigendian: reader.get()?,
+                r#point_step: reader.get()?,
+                r#row_step: reader.get()?,
+                r#data: reader.get_seq()?,
+                r#is_dense: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointCloud2], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointCloud2Ref<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#height: u32,
+        pub r#width: u32,
+        pub r#fields: ::std::vec::Vec<self::PointFieldRef<'a>>,
+        pub r#is_bigendian: bool,
+        pub r#point_step: u32,
+        pub r#row_step: u32,
+        pub r#data: &'a [u8],
+        pub r#is_dense: bool,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointCloud2Ref<'a> {
+        type Owned = PointCloud2;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#fields: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#is_bigendian: reader.get()?,
+                r#point_step: reader.get()?,
+                r#row_step: reader.get()?,
+                r#data: reader.get_byte_seq()?,
+                r#is_dense: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> PointCloud2 {
+            PointCloud2 {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#height: self.r#height,
+                r#width: self.r#width,
+                r#fields: self
+                    .r#fields
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#is_bigendian: self.r#is_bigendian,
+                r#point_step: self.r#point_step,
+                r#row_step: self.r#row_step,
+                r#data: self.r#data.to_vec(),
+                r#is_dense: self.r#is_dense,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointCloud2 {
+        type Borrowed<'a> = PointCloud2Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10029,6 +17554,8 @@ uint32 count     # How many elements in the field"####;
             0x66, 0x37, 0xef, 0x01,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::PointField_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl PointField {
@@ -10041,6 +17568,61 @@ uint32 count     # How many elements in the field"####;
         pub const r#FLOAT32: u8 = 7u8;
         pub const r#FLOAT64: u8 = 8u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointField {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put(self.r#offset);
+            writer.put(self.r#datatype);
+            writer.put(self.r#count);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#offset: reader.get()?,
+                r#datatype: reader.get()?,
+                r#count: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointField], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointFieldRef<'a> {
+        pub r#name: &'a str,
+        pub r#offset: u32,
+        pub r#datatype: u8,
+        pub r#count: u32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointFieldRef<'a> {
+        type Owned = PointField;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#offset: reader.get()?,
+                r#datatype: reader.get()?,
+                r#count: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> PointField {
+            PointField {
+                r#name: self.r#name.to_owned(),
+                r#offset: self.r#offset,
+                r#datatype: self.r#datatype,
+                r#count: self.r#count,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointField {
+        type Borrowed<'a> = PointFieldRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -10124,12 +17706,104 @@ string frame_id"####;
             0x86, 0xea, 0xc4, 0xf1,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Range_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Range {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl Range {
         pub const r#ULTRASOUND: u8 = 0u8;
         pub const r#INFRARED: u8 = 1u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Range {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#radiation_type);
+            writer.put(self.r#field_of_view);
+            writer.put(self.r#min_range);
+            writer.put(self.r#max_range);
+            writer.put(self.r#range);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#radiation_type: reader.get()?,
+                r#field_of_view: reader.get()?,
+                r#min_range: reader.get()?,
+                r#max_range: reader.get()?,
+                r#range: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Range], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RangeRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#radiation_type: u8,
+        pub r#field_of_view: f32,
+        pub r#min_range: f32,
+        pub r#max_range: f32,
+        pub r#range: f32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RangeRef<'a> {
+        type Owned = Range;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#radiation_type: reader.get()?,
+                r#field_of_view: reader.get()?,
+                r#min_range: reader.get()?,
+                r#max_range: reader.get()?,
+                r#range: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Range {
+            Range {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#radiation_type: self.r#radiation_type,
+                r#field_of_view: self.r#field_of_view,
+                r#min_range: self.r#min_range,
+                r#max_range: self.r#max_range,
+                r#range: self.r#range,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Range {
+        type Borrowed<'a> = RangeRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -10176,6 +17850,47 @@ bool do_rectify"####;
             0xee, 0xd8, 0xab, 0xb1,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::RegionOfInterest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for RegionOfInterest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x_offset);
+            writer.put(self.r#y_offset);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put(self.r#do_rectify);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x_offset: reader.get()?,
+                r#y_offset: reader.get()?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#do_rectify: reader.get()?,
+            })
+        }
+    }
+    pub type RegionOfInterestRef<'a> = RegionOfInterest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RegionOfInterest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for RegionOfInterest {
+        type Borrowed<'a> = RegionOfInterestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10229,6 +17944,83 @@ string frame_id"####;
             0xdb, 0x08, 0xf3, 0xf1,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::RelativeHumidity_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for RelativeHumidity {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for RelativeHumidity {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#relative_humidity);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#relative_humidity: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [RelativeHumidity], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RelativeHumidityRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#relative_humidity: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RelativeHumidityRef<'a> {
+        type Owned = RelativeHumidity;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#relative_humidity: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> RelativeHumidity {
+            RelativeHumidity {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#relative_humidity: self.r#relative_humidity,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for RelativeHumidity {
+        type Borrowed<'a> = RelativeHumidityRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10278,6 +18070,83 @@ string frame_id"####;
             0x2a, 0xad, 0x0a, 0xe9,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Temperature_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Temperature {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Temperature {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#temperature);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#temperature: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Temperature], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TemperatureRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#temperature: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TemperatureRef<'a> {
+        type Owned = Temperature;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#temperature: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Temperature {
+            Temperature {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#temperature: self.r#temperature,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Temperature {
+        type Borrowed<'a> = TemperatureRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10326,6 +18195,83 @@ string frame_id"####;
             0xe3, 0x40, 0x1e, 0x28,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::TimeReference_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TimeReference {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TimeReference {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#time_ref, writer);
+            writer.put_str(&self.r#source);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#time_ref: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#source: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TimeReference], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TimeReferenceRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#time_ref: ::roslibrust::codegen::integral_types::Time,
+        pub r#source: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TimeReferenceRef<'a> {
+        type Owned = TimeReference;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#time_ref: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#source: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TimeReference {
+            TimeReference {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#time_ref: self.r#time_ref.clone(),
+                r#source: self.r#source.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TimeReference {
+        type Borrowed<'a> = TimeReferenceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10564,6 +18510,50 @@ string frame_id"####;
             0x36, 0x0a, 0x5f, 0x53,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::SetCameraInfoRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetCameraInfoRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#camera_info, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#camera_info: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetCameraInfoRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetCameraInfoRequestRef<'a> {
+        pub r#camera_info: self::CameraInfoRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetCameraInfoRequestRef<'a> {
+        type Owned = SetCameraInfoRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#camera_info: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> SetCameraInfoRequest {
+            SetCameraInfoRequest {
+                r#camera_info: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#camera_info,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetCameraInfoRequest {
+        type Borrowed<'a> = SetCameraInfoRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10591,6 +18581,53 @@ string status_message # Used to give details about success"####;
             0x29, 0x2d, 0x3d, 0x58,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::SetCameraInfoResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetCameraInfoResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#status_message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#status_message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetCameraInfoResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetCameraInfoResponseRef<'a> {
+        pub r#success: bool,
+        pub r#status_message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetCameraInfoResponseRef<'a> {
+        type Owned = SetCameraInfoResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#status_message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SetCameraInfoResponse {
+            SetCameraInfoResponse {
+                r#success: self.r#success,
+                r#status_message: self.r#status_message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetCameraInfoResponse {
+        type Borrowed<'a> = SetCameraInfoResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetCameraInfo {}
@@ -10684,6 +18721,8 @@ uint32 nanosec"####;
             0x34, 0x5a, 0x6f, 0x45,
         ];
         const ROS2_TYPE_NAME: &'static str = "service_msgs::msg::dds_::ServiceEventInfo_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl ServiceEventInfo {
@@ -10692,6 +18731,43 @@ uint32 nanosec"####;
         pub const r#RESPONSE_SENT: u8 = 2u8;
         pub const r#RESPONSE_RECEIVED: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceEventInfo {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#event_type);
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            writer.put_slice(&self.r#client_gid);
+            writer.put(self.r#sequence_number);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#event_type: reader.get()?,
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#client_gid: reader.get_array()?,
+                r#sequence_number: reader.get()?,
+            })
+        }
+    }
+    pub type ServiceEventInfoRef<'a> = ServiceEventInfo;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceEventInfo {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceEventInfo {
+        type Borrowed<'a> = ServiceEventInfoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
 }
 #[allow(unused_imports)]
 pub mod shape_msgs {
@@ -10751,6 +18827,47 @@ uint32[3] vertex_indices"####;
             0xeb, 0xb5, 0x52, 0x89,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::Mesh_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Mesh {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#triangles, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#vertices, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#triangles: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#vertices: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    pub type MeshRef<'a> = Mesh;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Mesh {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Mesh {
+        type Borrowed<'a> = MeshRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10777,6 +18894,39 @@ uint32[3] vertex_indices"####;
             0xa5, 0x02, 0x0b, 0xd0,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::MeshTriangle_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MeshTriangle {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_slice(&self.r#vertex_indices);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#vertex_indices: reader.get_array()?,
+            })
+        }
+    }
+    pub type MeshTriangleRef<'a> = MeshTriangle;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MeshTriangle {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MeshTriangle {
+        type Borrowed<'a> = MeshTriangleRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10809,6 +18959,39 @@ float64[4] coef"####;
             0xf1, 0x6c, 0x58, 0xc8,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::Plane_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Plane {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_slice(&self.r#coef);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#coef: reader.get_array()?,
+            })
+        }
+    }
+    pub type PlaneRef<'a> = Plane;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Plane {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Plane {
+        type Borrowed<'a> = PlaneRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10876,6 +19059,8 @@ uint8 CONE_RADIUS=1"####;
             0x08, 0x0c, 0x06, 0x29,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::SolidPrimitive_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl SolidPrimitive {
@@ -10892,6 +19077,39 @@ uint8 CONE_RADIUS=1"####;
         pub const r#CONE_HEIGHT: u8 = 0u8;
         pub const r#CONE_RADIUS: u8 = 1u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SolidPrimitive {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#type);
+            writer.put_seq(&self.r#dimensions);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get()?,
+                r#dimensions: reader.get_seq()?,
+            })
+        }
+    }
+    pub type SolidPrimitiveRef<'a> = SolidPrimitive;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SolidPrimitive {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SolidPrimitive {
+        type Borrowed<'a> = SolidPrimitiveRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
 }
 #[allow(unused_imports)]
 pub mod std_msgs {
@@ -10934,6 +19152,39 @@ pub mod std_msgs {
             0x45, 0x28, 0xf6, 0xc9,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Bool_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Bool {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type BoolRef<'a> = Bool;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Bool {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Bool {
+        type Borrowed<'a> = BoolRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10959,6 +19210,39 @@ pub mod std_msgs {
             0x80, 0x92, 0xf3, 0x27,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Byte_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Byte {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type ByteRef<'a> = Byte;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Byte {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Byte {
+        type Borrowed<'a> = ByteRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11028,6 +19312,53 @@ uint32 stride  # stride of given dimension"####;
             0xa7, 0x91, 0xdb, 0x7d,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::ByteMultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ByteMultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ByteMultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ByteMultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ByteMultiArrayRef<'a> {
+        type Owned = ByteMultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> ByteMultiArray {
+            ByteMultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ByteMultiArray {
+        type Borrowed<'a> = ByteMultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11053,6 +19384,39 @@ uint32 stride  # stride of given dimension"####;
             0xa6, 0xa5, 0x86, 0xa9,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Char_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Char {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type CharRef<'a> = Char;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Char {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Char {
+        type Borrowed<'a> = CharRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11084,6 +19448,45 @@ float32 a"####;
             0xc7, 0x81, 0x3f, 0xe8,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::ColorRGBA_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ColorRGBA {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#r);
+            writer.put(self.r#g);
+            writer.put(self.r#b);
+            writer.put(self.r#a);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#r: reader.get()?,
+                r#g: reader.get()?,
+                r#b: reader.get()?,
+                r#a: reader.get()?,
+            })
+        }
+    }
+    pub type ColorRGBARef<'a> = ColorRGBA;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ColorRGBA {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ColorRGBA {
+        type Borrowed<'a> = ColorRGBARef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11109,6 +19512,39 @@ float32 a"####;
             0xfe, 0x1a, 0x37, 0x94,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Duration_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Duration {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#data, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type DurationRef<'a> = Duration;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Duration {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Duration {
+        type Borrowed<'a> = DurationRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11132,6 +19568,35 @@ float32 a"####;
             0x26, 0xa9, 0x63, 0x12,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Empty_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Empty {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type EmptyRef<'a> = Empty;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Empty {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Empty {
+        type Borrowed<'a> = EmptyRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11157,6 +19622,39 @@ float32 a"####;
             0x4d, 0x83, 0xd6, 0xe2,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Float32Ref<'a> = Float32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float32 {
+        type Borrowed<'a> = Float32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11225,6 +19723,53 @@ uint32 stride  # stride of given dimension"####;
             0x1f, 0xa8, 0xbf, 0xe0,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float32MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float32MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Float32MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Float32MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<f32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float32MultiArrayRef<'a> {
+        type Owned = Float32MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Float32MultiArray {
+            Float32MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float32MultiArray {
+        type Borrowed<'a> = Float32MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11250,6 +19795,39 @@ uint32 stride  # stride of given dimension"####;
             0xe7, 0x90, 0x18, 0x2a,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float64_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float64 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Float64Ref<'a> = Float64;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float64 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float64 {
+        type Borrowed<'a> = Float64Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11318,6 +19896,53 @@ uint32 stride  # stride of given dimension"####;
             0x8c, 0x55, 0x39, 0x7f,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float64MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float64MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Float64MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Float64MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<f64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float64MultiArrayRef<'a> {
+        type Owned = Float64MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Float64MultiArray {
+            Float64MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float64MultiArray {
+        type Borrowed<'a> = Float64MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11357,6 +19982,58 @@ string frame_id"####;
             0x3d, 0xc7, 0x96, 0x0f,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Header_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Header {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#seq);
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            writer.put_str(&self.r#frame_id);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#seq: reader.get()?,
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#frame_id: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Header], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct HeaderRef<'a> {
+        pub r#seq: u32,
+        pub r#stamp: ::roslibrust::codegen::integral_types::Time,
+        pub r#frame_id: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for HeaderRef<'a> {
+        type Owned = Header;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#seq: reader.get()?,
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#frame_id: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> Header {
+            Header {
+                r#seq: self.r#seq,
+                r#stamp: self.r#stamp.clone(),
+                r#frame_id: self.r#frame_id.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Header {
+        type Borrowed<'a> = HeaderRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11382,6 +20059,39 @@ string frame_id"####;
             0x8e, 0xee, 0x17, 0xe5,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int16_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int16 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int16Ref<'a> = Int16;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int16 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int16 {
+        type Borrowed<'a> = Int16Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11450,6 +20160,53 @@ uint32 stride  # stride of given dimension"####;
             0x52, 0xa0, 0x76, 0xce,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int16MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int16MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int16MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int16MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i16>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int16MultiArrayRef<'a> {
+        type Owned = Int16MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int16MultiArray {
+            Int16MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int16MultiArray {
+        type Borrowed<'a> = Int16MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11475,6 +20232,39 @@ uint32 stride  # stride of given dimension"####;
             0x1c, 0xef, 0x0d, 0xeb,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int32Ref<'a> = Int32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int32 {
+        type Borrowed<'a> = Int32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11543,6 +20333,53 @@ uint32 stride  # stride of given dimension"####;
             0xde, 0xfd, 0x17, 0x01,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int32MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int32MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int32MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int32MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int32MultiArrayRef<'a> {
+        type Owned = Int32MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int32MultiArray {
+            Int32MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int32MultiArray {
+        type Borrowed<'a> = Int32MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11568,6 +20405,39 @@ uint32 stride  # stride of given dimension"####;
             0x77, 0x4f, 0x4b, 0x49,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int64_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int64 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int64Ref<'a> = Int64;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int64 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int64 {
+        type Borrowed<'a> = Int64Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11636,6 +20506,53 @@ uint32 stride  # stride of given dimension"####;
             0x1c, 0x89, 0xb4, 0x22,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int64MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int64MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int64MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int64MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int64MultiArrayRef<'a> {
+        type Owned = Int64MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int64MultiArray {
+            Int64MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int64MultiArray {
+        type Borrowed<'a> = Int64MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11661,6 +20578,39 @@ uint32 stride  # stride of given dimension"####;
             0x3b, 0xd0, 0xf4, 0x40,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int8_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int8 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int8Ref<'a> = Int8;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int8 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int8 {
+        type Borrowed<'a> = Int8Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11729,6 +20679,53 @@ uint32 stride  # stride of given dimension"####;
             0x4d, 0x21, 0x88, 0xdb,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int8MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int8MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int8MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int8MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i8>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int8MultiArrayRef<'a> {
+        type Owned = Int8MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int8MultiArray {
+            Int8MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int8MultiArray {
+        type Borrowed<'a> = Int8MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11758,6 +20755,58 @@ uint32 stride  # stride of given dimension"####;
             0x28, 0x2c, 0x9c, 0xbe,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::MultiArrayDimension_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiArrayDimension {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#label);
+            writer.put(self.r#size);
+            writer.put(self.r#stride);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#label: reader.get_string()?,
+                r#size: reader.get()?,
+                r#stride: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiArrayDimension], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiArrayDimensionRef<'a> {
+        pub r#label: &'a str,
+        pub r#size: u32,
+        pub r#stride: u32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiArrayDimensionRef<'a> {
+        type Owned = MultiArrayDimension;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#label: reader.get_str()?,
+                r#size: reader.get()?,
+                r#stride: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiArrayDimension {
+            MultiArrayDimension {
+                r#label: self.r#label.to_owned(),
+                r#size: self.r#size,
+                r#stride: self.r#stride,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiArrayDimension {
+        type Borrowed<'a> = MultiArrayDimensionRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11814,6 +20863,62 @@ uint32 stride  # stride of given dimension"####;
             0xcb, 0x50, 0xda, 0xc6,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::MultiArrayLayout_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiArrayLayout {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#dim, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put(self.r#data_offset);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#dim: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#data_offset: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiArrayLayout], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiArrayLayoutRef<'a> {
+        pub r#dim: ::std::vec::Vec<self::MultiArrayDimensionRef<'a>>,
+        pub r#data_offset: u32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiArrayLayoutRef<'a> {
+        type Owned = MultiArrayLayout;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#dim: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#data_offset: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiArrayLayout {
+            MultiArrayLayout {
+                r#dim: self
+                    .r#dim
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#data_offset: self.r#data_offset,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiArrayLayout {
+        type Borrowed<'a> = MultiArrayLayoutRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11839,6 +20944,48 @@ uint32 stride  # stride of given dimension"####;
             0x8f, 0x6b, 0x1a, 0x18,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::String_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for String {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [String], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct StringRef<'a> {
+        pub r#data: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for StringRef<'a> {
+        type Owned = String;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> String {
+            String {
+                r#data: self.r#data.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for String {
+        type Borrowed<'a> = StringRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11864,6 +21011,39 @@ uint32 stride  # stride of given dimension"####;
             0x3d, 0x47, 0x84, 0xa0,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Time_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Time {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#data, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type TimeRef<'a> = Time;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Time {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Time {
+        type Borrowed<'a> = TimeRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11889,6 +21069,39 @@ uint32 stride  # stride of given dimension"####;
             0x3e, 0x77, 0x57, 0xb1,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt16_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt16 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt16Ref<'a> = UInt16;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt16 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt16 {
+        type Borrowed<'a> = UInt16Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11957,6 +21170,53 @@ uint32 stride  # stride of given dimension"####;
             0x51, 0x29, 0x49, 0x2b,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt16MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt16MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt16MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt16MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<u16>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt16MultiArrayRef<'a> {
+        type Owned = UInt16MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt16MultiArray {
+            UInt16MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt16MultiArray {
+        type Borrowed<'a> = UInt16MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11982,6 +21242,39 @@ uint32 stride  # stride of given dimension"####;
             0x35, 0x16, 0xfb, 0x4a,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt32Ref<'a> = UInt32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt32 {
+        type Borrowed<'a> = UInt32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12050,6 +21343,53 @@ uint32 stride  # stride of given dimension"####;
             0xe1, 0x6b, 0xb5, 0xb0,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt32MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt32MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt32MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt32MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<u32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt32MultiArrayRef<'a> {
+        type Owned = UInt32MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt32MultiArray {
+            UInt32MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt32MultiArray {
+        type Borrowed<'a> = UInt32MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12075,6 +21415,39 @@ uint32 stride  # stride of given dimension"####;
             0x98, 0xe8, 0xc9, 0x43,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt64_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt64 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt64Ref<'a> = UInt64;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt64 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt64 {
+        type Borrowed<'a> = UInt64Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12143,6 +21516,53 @@ uint32 stride  # stride of given dimension"####;
             0xf9, 0x81, 0xb3, 0x66,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt64MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt64MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt64MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt64MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<u64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt64MultiArrayRef<'a> {
+        type Owned = UInt64MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt64MultiArray {
+            UInt64MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt64MultiArray {
+        type Borrowed<'a> = UInt64MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12168,6 +21588,39 @@ uint32 stride  # stride of given dimension"####;
             0x2e, 0x72, 0xf0, 0x10,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt8_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt8 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt8Ref<'a> = UInt8;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt8 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt8 {
+        type Borrowed<'a> = UInt8Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12237,6 +21690,53 @@ uint32 stride  # stride of given dimension"####;
             0xad, 0xe9, 0xf3, 0x85,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt8MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt8MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt8MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt8MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt8MultiArrayRef<'a> {
+        type Owned = UInt8MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt8MultiArray {
+            UInt8MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt8MultiArray {
+        type Borrowed<'a> = UInt8MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -12278,6 +21778,35 @@ pub mod std_srvs {
             0x4d, 0x30, 0x15, 0x32,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::EmptyRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for EmptyRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type EmptyRequestRef<'a> = EmptyRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for EmptyRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for EmptyRequest {
+        type Borrowed<'a> = EmptyRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12301,6 +21830,35 @@ pub mod std_srvs {
             0x7c, 0xbc, 0xc5, 0xf8,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::EmptyResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for EmptyResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type EmptyResponseRef<'a> = EmptyResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for EmptyResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for EmptyResponse {
+        type Borrowed<'a> = EmptyResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Empty {}
@@ -12341,6 +21899,39 @@ pub mod std_srvs {
             0x0a, 0x04, 0x85, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::SetBoolRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetBoolRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type SetBoolRequestRef<'a> = SetBoolRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetBoolRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetBoolRequest {
+        type Borrowed<'a> = SetBoolRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12368,6 +21959,53 @@ string message # informational, e.g. for error messages"####;
             0x8a, 0xc5, 0x70, 0x71,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::SetBoolResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetBoolResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetBoolResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetBoolResponseRef<'a> {
+        pub r#success: bool,
+        pub r#message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetBoolResponseRef<'a> {
+        type Owned = SetBoolResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SetBoolResponse {
+            SetBoolResponse {
+                r#success: self.r#success,
+                r#message: self.r#message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetBoolResponse {
+        type Borrowed<'a> = SetBoolResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetBool {}
@@ -12405,6 +22043,35 @@ string message # informational, e.g. for error messages"####;
             0x8f, 0x51, 0xf0, 0xaf,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::TriggerRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TriggerRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type TriggerRequestRef<'a> = TriggerRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TriggerRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TriggerRequest {
+        type Borrowed<'a> = TriggerRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12432,6 +22099,53 @@ string message # informational, e.g. for error messages"####;
             0xa9, 0xbb, 0xdf, 0xd9,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::TriggerResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TriggerResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TriggerResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TriggerResponseRef<'a> {
+        pub r#success: bool,
+        pub r#message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TriggerResponseRef<'a> {
+        type Owned = TriggerResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TriggerResponse {
+            TriggerResponse {
+                r#success: self.r#success,
+                r#message: self.r#message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TriggerResponse {
+        type Borrowed<'a> = TriggerResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Trigger {}
@@ -12604,6 +22318,110 @@ string frame_id"####;
             0xb4, 0x06, 0x82, 0xa1,
         ];
         const ROS2_TYPE_NAME: &'static str = "stereo_msgs::msg::dds_::DisparityImage_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for DisparityImage {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DisparityImage {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#image, writer);
+            writer.put(self.r#f);
+            writer.put(self.r#T);
+            ::roslibrust::codec::WireCodec::encode(&self.r#valid_window, writer);
+            writer.put(self.r#min_disparity);
+            writer.put(self.r#max_disparity);
+            writer.put(self.r#delta_d);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#image: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#f: reader.get()?,
+                r#T: reader.get()?,
+                r#valid_window: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#min_disparity: reader.get()?,
+                r#max_disparity: reader.get()?,
+                r#delta_d: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DisparityImage], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DisparityImageRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#image: sensor_msgs::ImageRef<'a>,
+        pub r#f: f32,
+        pub r#T: f32,
+        pub r#valid_window: sensor_msgs::RegionOfInterestRef<'a>,
+        pub r#min_disparity: f32,
+        pub r#max_disparity: f32,
+        pub r#delta_d: f32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DisparityImageRef<'a> {
+        type Owned = DisparityImage;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#image: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#f: reader.get()?,
+                r#T: reader.get()?,
+                r#valid_window: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#min_disparity: reader.get()?,
+                r#max_disparity: reader.get()?,
+                r#delta_d: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> DisparityImage {
+            DisparityImage {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#image: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#image),
+                r#f: self.r#f,
+                r#T: self.r#T,
+                r#valid_window: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#valid_window,
+                ),
+                r#min_disparity: self.r#min_disparity,
+                r#max_disparity: self.r#max_disparity,
+                r#delta_d: self.r#delta_d,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DisparityImage {
+        type Borrowed<'a> = DisparityImageRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -12753,6 +22571,33 @@ string frame_id"####;
             0xb9, 0xa7, 0x4d, 0xcc,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::ADSBVehicle_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for ADSBVehicle {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl ADSBVehicle {
@@ -12789,6 +22634,111 @@ string frame_id"####;
         pub const r#FLAG_BARO_VALID: u16 = 256u16;
         pub const r#FLAG_SOURCE_UAT: u16 = 32768u16;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ADSBVehicle {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#ICAO_address);
+            writer.put_str(&self.r#callsign);
+            writer.put(self.r#latitude);
+            writer.put(self.r#longitude);
+            writer.put(self.r#altitude);
+            writer.put(self.r#heading);
+            writer.put(self.r#hor_velocity);
+            writer.put(self.r#ver_velocity);
+            writer.put(self.r#altitude_type);
+            writer.put(self.r#emitter_type);
+            ::roslibrust::codec::WireCodec::encode(&self.r#tslc, writer);
+            writer.put(self.r#flags);
+            writer.put(self.r#squawk);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ICAO_address: reader.get()?,
+                r#callsign: reader.get_string()?,
+                r#latitude: reader.get()?,
+                r#longitude: reader.get()?,
+                r#altitude: reader.get()?,
+                r#heading: reader.get()?,
+                r#hor_velocity: reader.get()?,
+                r#ver_velocity: reader.get()?,
+                r#altitude_type: reader.get()?,
+                r#emitter_type: reader.get()?,
+                r#tslc: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#flags: reader.get()?,
+                r#squawk: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ADSBVehicle], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ADSBVehicleRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#ICAO_address: u32,
+        pub r#callsign: &'a str,
+        pub r#latitude: f64,
+        pub r#longitude: f64,
+        pub r#altitude: f32,
+        pub r#heading: f32,
+        pub r#hor_velocity: f32,
+        pub r#ver_velocity: f32,
+        pub r#altitude_type: u8,
+        pub r#emitter_type: u8,
+        pub r#tslc: ::roslibrust::codegen::integral_types::Duration,
+        pub r#flags: u16,
+        pub r#squawk: u16,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ADSBVehicleRef<'a> {
+        type Owned = ADSBVehicle;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#ICAO_address: reader.get()?,
+                r#callsign: reader.get_str()?,
+                r#latitude: reader.get()?,
+                r#longitude: reader.get()?,
+                r#altitude: reader.get()?,
+                r#heading: reader.get()?,
+                r#hor_velocity: reader.get()?,
+                r#ver_velocity: reader.get()?,
+                r#altitude_type: reader.get()?,
+                r#emitter_type: reader.get()?,
+                r#tslc: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#flags: reader.get()?,
+                r#squawk: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> ADSBVehicle {
+            ADSBVehicle {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#ICAO_address: self.r#ICAO_address,
+                r#callsign: self.r#callsign.to_owned(),
+                r#latitude: self.r#latitude,
+                r#longitude: self.r#longitude,
+                r#altitude: self.r#altitude,
+                r#heading: self.r#heading,
+                r#hor_velocity: self.r#hor_velocity,
+                r#ver_velocity: self.r#ver_velocity,
+                r#altitude_type: self.r#altitude_type,
+                r#emitter_type: self.r#emitter_type,
+                r#tslc: self.r#tslc.clone(),
+                r#flags: self.r#flags,
+                r#squawk: self.r#squawk,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ADSBVehicle {
+        type Borrowed<'a> = ADSBVehicleRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -12816,6 +22766,8 @@ float32 TEST_FLOAT=0 # testing"####;
             0x8f, 0x61, 0xed, 0xcf,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::Constants_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl Constants {
@@ -12825,6 +22777,33 @@ float32 TEST_FLOAT=0 # testing"####;
             "/bond_disable_heartbeat_timeout";
         pub const r#TEST_FLOAT: f32 = 0f32;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Constants {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type ConstantsRef<'a> = Constants;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Constants {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Constants {
+        type Borrowed<'a> = ConstantsRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -12866,6 +22845,78 @@ string frame_id"####;
             0x48, 0x1e, 0x65, 0x0f,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::Float64Stamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Float64Stamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float64Stamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#value);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#value: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Float64Stamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Float64StampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#value: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float64StampedRef<'a> {
+        type Owned = Float64Stamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#value: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Float64Stamped {
+            Float64Stamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#value: self.r#value,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float64Stamped {
+        type Borrowed<'a> = Float64StampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12891,6 +22942,48 @@ string frame_id"####;
             0xcd, 0xf1, 0x32, 0x6b,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::LoggerLevel_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LoggerLevel {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#level);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#level: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LoggerLevel], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LoggerLevelRef<'a> {
+        pub r#level: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LoggerLevelRef<'a> {
+        type Owned = LoggerLevel;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#level: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> LoggerLevel {
+            LoggerLevel {
+                r#level: self.r#level.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LoggerLevel {
+        type Borrowed<'a> = LoggerLevelRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12929,6 +23022,67 @@ float64 value"####;
             0x7c, 0xf0, 0x23, 0x3f,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::Metric_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Metric {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put(self.r#time);
+            writer.put_seq_with(&self.r#data, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#time: reader.get()?,
+                r#data: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Metric], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MetricRef<'a> {
+        pub r#name: &'a str,
+        pub r#time: f64,
+        pub r#data: ::std::vec::Vec<self::MetricPairRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MetricRef<'a> {
+        type Owned = Metric;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#time: reader.get()?,
+                r#data: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> Metric {
+            Metric {
+                r#name: self.r#name.to_owned(),
+                r#time: self.r#time,
+                r#data: self
+                    .r#data
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Metric {
+        type Borrowed<'a> = MetricRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -12958,6 +23112,53 @@ float64 value"####;
             0xae, 0xaf, 0xa8, 0xf8,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::MetricPair_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MetricPair {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#key);
+            writer.put(self.r#value);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#key: reader.get_string()?,
+                r#value: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MetricPair], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MetricPairRef<'a> {
+        pub r#key: &'a str,
+        pub r#value: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MetricPairRef<'a> {
+        type Owned = MetricPair;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#key: reader.get_str()?,
+                r#value: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MetricPair {
+            MetricPair {
+                r#key: self.r#key.to_owned(),
+                r#value: self.r#value,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MetricPair {
+        type Borrowed<'a> = MetricPairRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13000,6 +23201,8 @@ uint8 status"####;
             0x43, 0xa3, 0x63, 0x06,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::NodeInfo_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl NodeInfo {
@@ -13010,6 +23213,56 @@ uint8 status"####;
         pub const r#STATUS_SHUTTING_DOWN: u8 = 4u8;
         pub const r#STATUS_SHUTDOWN: u8 = 5u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NodeInfo {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#node_name);
+            writer.put(self.r#pid);
+            writer.put(self.r#status);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#node_name: reader.get_string()?,
+                r#pid: reader.get()?,
+                r#status: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [NodeInfo], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NodeInfoRef<'a> {
+        pub r#node_name: &'a str,
+        pub r#pid: i64,
+        pub r#status: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NodeInfoRef<'a> {
+        type Owned = NodeInfo;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#node_name: reader.get_str()?,
+                r#pid: reader.get()?,
+                r#status: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> NodeInfo {
+            NodeInfo {
+                r#node_name: self.r#node_name.to_owned(),
+                r#pid: self.r#pid,
+                r#status: self.r#status,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NodeInfo {
+        type Borrowed<'a> = NodeInfoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -13039,6 +23292,41 @@ int64 b"####;
             0xf0, 0x8c, 0x5d, 0x86,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::AddTwoIntsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddTwoIntsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#a);
+            writer.put(self.r#b);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#a: reader.get()?,
+                r#b: reader.get()?,
+            })
+        }
+    }
+    pub type AddTwoIntsRequestRef<'a> = AddTwoIntsRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddTwoIntsRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddTwoIntsRequest {
+        type Borrowed<'a> = AddTwoIntsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13065,6 +23353,39 @@ int64 sum"####;
             0xcd, 0xdb, 0xbe, 0x8b,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::AddTwoIntsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddTwoIntsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sum);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sum: reader.get()?,
+            })
+        }
+    }
+    pub type AddTwoIntsResponseRef<'a> = AddTwoIntsResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddTwoIntsResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddTwoIntsResponse {
+        type Borrowed<'a> = AddTwoIntsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct AddTwoInts {}
@@ -13106,6 +23427,48 @@ uint8[] bytes"####;
             0xa1, 0x34, 0xf9, 0xbf,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::RoundTripArrayRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for RoundTripArrayRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq(&self.r#bytes);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#bytes: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [RoundTripArrayRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RoundTripArrayRequestRef<'a> {
+        pub r#bytes: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RoundTripArrayRequestRef<'a> {
+        type Owned = RoundTripArrayRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#bytes: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> RoundTripArrayRequest {
+            RoundTripArrayRequest {
+                r#bytes: self.r#bytes.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for RoundTripArrayRequest {
+        type Borrowed<'a> = RoundTripArrayRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13132,6 +23495,48 @@ uint8[] bytes"####;
             0x87, 0xe0, 0xab, 0xc3,
         ];
         const ROS2_TYPE_NAME: &'static str = "test_msgs::msg::dds_::RoundTripArrayResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for RoundTripArrayResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq(&self.r#bytes);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#bytes: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [RoundTripArrayResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RoundTripArrayResponseRef<'a> {
+        pub r#bytes: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RoundTripArrayResponseRef<'a> {
+        type Owned = RoundTripArrayResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#bytes: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> RoundTripArrayResponse {
+            RoundTripArrayResponse {
+                r#bytes: self.r#bytes.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for RoundTripArrayResponse {
+        type Borrowed<'a> = RoundTripArrayResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct RoundTripArray {}
@@ -13219,6 +23624,96 @@ duration time_from_start"####;
             0x1f, 0xe1, 0xd4, 0x12,
         ];
         const ROS2_TYPE_NAME: &'static str = "trajectory_msgs::msg::dds_::JointTrajectory_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for JointTrajectory {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JointTrajectory {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#joint_names, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_string())?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [JointTrajectory], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JointTrajectoryRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#joint_names: ::std::vec::Vec<&'a str>,
+        pub r#points: ::std::vec::Vec<self::JointTrajectoryPointRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JointTrajectoryRef<'a> {
+        type Owned = JointTrajectory;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_str())?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> JointTrajectory {
+            JointTrajectory {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#joint_names: self
+                    .r#joint_names
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JointTrajectory {
+        type Borrowed<'a> = JointTrajectoryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13256,6 +23751,47 @@ duration time_from_start"####;
             0xce, 0x37, 0x5c, 0xbd,
         ];
         const ROS2_TYPE_NAME: &'static str = "trajectory_msgs::msg::dds_::JointTrajectoryPoint_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JointTrajectoryPoint {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq(&self.r#positions);
+            writer.put_seq(&self.r#velocities);
+            writer.put_seq(&self.r#accelerations);
+            writer.put_seq(&self.r#effort);
+            ::roslibrust::codec::WireCodec::encode(&self.r#time_from_start, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#positions: reader.get_seq()?,
+                r#velocities: reader.get_seq()?,
+                r#accelerations: reader.get_seq()?,
+                r#effort: reader.get_seq()?,
+                r#time_from_start: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type JointTrajectoryPointRef<'a> = JointTrajectoryPoint;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JointTrajectoryPoint {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JointTrajectoryPoint {
+        type Borrowed<'a> = JointTrajectoryPointRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13445,6 +23981,96 @@ float64 z"####;
             0xf2, 0x64, 0x73, 0xc3,
         ];
         const ROS2_TYPE_NAME: &'static str = "trajectory_msgs::msg::dds_::MultiDOFJointTrajectory_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MultiDOFJointTrajectory {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiDOFJointTrajectory {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#joint_names, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_string())?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiDOFJointTrajectory], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiDOFJointTrajectoryRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#joint_names: ::std::vec::Vec<&'a str>,
+        pub r#points: ::std::vec::Vec<self::MultiDOFJointTrajectoryPointRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiDOFJointTrajectoryRef<'a> {
+        type Owned = MultiDOFJointTrajectory;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_str())?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiDOFJointTrajectory {
+            MultiDOFJointTrajectory {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#joint_names: self
+                    .r#joint_names
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiDOFJointTrajectory {
+        type Borrowed<'a> = MultiDOFJointTrajectoryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13546,6 +24172,54 @@ float64 z"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "trajectory_msgs::msg::dds_::MultiDOFJointTrajectoryPoint_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiDOFJointTrajectoryPoint {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#transforms, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#velocities, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#accelerations, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            ::roslibrust::codec::WireCodec::encode(&self.r#time_from_start, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#transforms: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#velocities: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#accelerations: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#time_from_start: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type MultiDOFJointTrajectoryPointRef<'a> = MultiDOFJointTrajectoryPoint;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiDOFJointTrajectoryPoint {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiDOFJointTrajectoryPoint {
+        type Borrowed<'a> = MultiDOFJointTrajectoryPointRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -13651,6 +24325,33 @@ string frame_id"####;
             0x28, 0x50, 0x6e, 0x1e,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::ImageMarker_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for ImageMarker {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl ImageMarker {
@@ -13662,6 +24363,130 @@ string frame_id"####;
         pub const r#ADD: u8 = 0u8;
         pub const r#REMOVE: u8 = 1u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ImageMarker {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#ns);
+            writer.put(self.r#id);
+            writer.put(self.r#type);
+            writer.put(self.r#action);
+            ::roslibrust::codec::WireCodec::encode(&self.r#position, writer);
+            writer.put(self.r#scale);
+            ::roslibrust::codec::WireCodec::encode(&self.r#outline_color, writer);
+            writer.put(self.r#filled);
+            ::roslibrust::codec::WireCodec::encode(&self.r#fill_color, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#lifetime, writer);
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#outline_colors, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ns: reader.get_string()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#position: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#scale: reader.get()?,
+                r#outline_color: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#filled: reader.get()?,
+                r#fill_color: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#lifetime: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#outline_colors: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ImageMarker], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImageMarkerRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#ns: &'a str,
+        pub r#id: i32,
+        pub r#type: i32,
+        pub r#action: i32,
+        pub r#position: geometry_msgs::PointRef<'a>,
+        pub r#scale: f32,
+        pub r#outline_color: std_msgs::ColorRGBARef<'a>,
+        pub r#filled: u8,
+        pub r#fill_color: std_msgs::ColorRGBARef<'a>,
+        pub r#lifetime: ::roslibrust::codegen::integral_types::Duration,
+        pub r#points: ::std::vec::Vec<geometry_msgs::PointRef<'a>>,
+        pub r#outline_colors: ::std::vec::Vec<std_msgs::ColorRGBARef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ImageMarkerRef<'a> {
+        type Owned = ImageMarker;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#ns: reader.get_str()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#position: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#scale: reader.get()?,
+                r#outline_color: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#filled: reader.get()?,
+                r#fill_color: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#lifetime: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#outline_colors: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> ImageMarker {
+            ImageMarker {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#ns: self.r#ns.to_owned(),
+                r#id: self.r#id,
+                r#type: self.r#type,
+                r#action: self.r#action,
+                r#position: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#position,
+                ),
+                r#scale: self.r#scale,
+                r#outline_color: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#outline_color,
+                ),
+                r#filled: self.r#filled,
+                r#fill_color: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#fill_color,
+                ),
+                r#lifetime: self.r#lifetime.clone(),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#outline_colors: self
+                    .r#outline_colors
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ImageMarker {
+        type Borrowed<'a> = ImageMarkerRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -14208,6 +25033,121 @@ uint8 command_type"####;
             0x76, 0xd8, 0x2f, 0x69,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::InteractiveMarker_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InteractiveMarker {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarker {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#description);
+            writer.put(self.r#scale);
+            writer.put_seq_with(&self.r#menu_entries, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#controls, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#name: reader.get_string()?,
+                r#description: reader.get_string()?,
+                r#scale: reader.get()?,
+                r#menu_entries: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#controls: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarker], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#name: &'a str,
+        pub r#description: &'a str,
+        pub r#scale: f32,
+        pub r#menu_entries: ::std::vec::Vec<self::MenuEntryRef<'a>>,
+        pub r#controls: ::std::vec::Vec<self::InteractiveMarkerControlRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerRef<'a> {
+        type Owned = InteractiveMarker;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#name: reader.get_str()?,
+                r#description: reader.get_str()?,
+                r#scale: reader.get()?,
+                r#menu_entries: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#controls: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarker {
+            InteractiveMarker {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#name: self.r#name.to_owned(),
+                r#description: self.r#description.to_owned(),
+                r#scale: self.r#scale,
+                r#menu_entries: self
+                    .r#menu_entries
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#controls: self
+                    .r#controls
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarker {
+        type Borrowed<'a> = InteractiveMarkerRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -14495,6 +25435,8 @@ string frame_id"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerControl_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl InteractiveMarkerControl {
@@ -14512,6 +25454,92 @@ string frame_id"####;
         pub const r#ROTATE_3D: u8 = 8u8;
         pub const r#MOVE_ROTATE_3D: u8 = 9u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerControl {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            ::roslibrust::codec::WireCodec::encode(&self.r#orientation, writer);
+            writer.put(self.r#orientation_mode);
+            writer.put(self.r#interaction_mode);
+            writer.put(self.r#always_visible);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put(self.r#independent_marker_orientation);
+            writer.put_str(&self.r#description);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#orientation: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation_mode: reader.get()?,
+                r#interaction_mode: reader.get()?,
+                r#always_visible: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#independent_marker_orientation: reader.get()?,
+                r#description: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerControl], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerControlRef<'a> {
+        pub r#name: &'a str,
+        pub r#orientation: geometry_msgs::QuaternionRef<'a>,
+        pub r#orientation_mode: u8,
+        pub r#interaction_mode: u8,
+        pub r#always_visible: bool,
+        pub r#markers: ::std::vec::Vec<self::MarkerRef<'a>>,
+        pub r#independent_marker_orientation: bool,
+        pub r#description: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerControlRef<'a> {
+        type Owned = InteractiveMarkerControl;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#orientation_mode: reader.get()?,
+                r#interaction_mode: reader.get()?,
+                r#always_visible: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#independent_marker_orientation: reader.get()?,
+                r#description: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerControl {
+            InteractiveMarkerControl {
+                r#name: self.r#name.to_owned(),
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#orientation,
+                ),
+                r#orientation_mode: self.r#orientation_mode,
+                r#interaction_mode: self.r#interaction_mode,
+                r#always_visible: self.r#always_visible,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#independent_marker_orientation: self.r#independent_marker_orientation,
+                r#description: self.r#description.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerControl {
+        type Borrowed<'a> = InteractiveMarkerControlRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -14634,6 +25662,33 @@ string frame_id"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerFeedback_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InteractiveMarkerFeedback {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl InteractiveMarkerFeedback {
@@ -14644,6 +25699,88 @@ string frame_id"####;
         pub const r#MOUSE_DOWN: u8 = 4u8;
         pub const r#MOUSE_UP: u8 = 5u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerFeedback {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#client_id);
+            writer.put_str(&self.r#marker_name);
+            writer.put_str(&self.r#control_name);
+            writer.put(self.r#event_type);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put(self.r#menu_entry_id);
+            ::roslibrust::codec::WireCodec::encode(&self.r#mouse_point, writer);
+            writer.put(self.r#mouse_point_valid);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#client_id: reader.get_string()?,
+                r#marker_name: reader.get_string()?,
+                r#control_name: reader.get_string()?,
+                r#event_type: reader.get()?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#menu_entry_id: reader.get()?,
+                r#mouse_point: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#mouse_point_valid: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerFeedback], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerFeedbackRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#client_id: &'a str,
+        pub r#marker_name: &'a str,
+        pub r#control_name: &'a str,
+        pub r#event_type: u8,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#menu_entry_id: u32,
+        pub r#mouse_point: geometry_msgs::PointRef<'a>,
+        pub r#mouse_point_valid: bool,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerFeedbackRef<'a> {
+        type Owned = InteractiveMarkerFeedback;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#client_id: reader.get_str()?,
+                r#marker_name: reader.get_str()?,
+                r#control_name: reader.get_str()?,
+                r#event_type: reader.get()?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#menu_entry_id: reader.get()?,
+                r#mouse_point: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#mouse_point_valid: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerFeedback {
+            InteractiveMarkerFeedback {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#client_id: self.r#client_id.to_owned(),
+                r#marker_name: self.r#marker_name.to_owned(),
+                r#control_name: self.r#control_name.to_owned(),
+                r#event_type: self.r#event_type,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#menu_entry_id: self.r#menu_entry_id,
+                r#mouse_point: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#mouse_point,
+                ),
+                r#mouse_point_valid: self.r#mouse_point_valid,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerFeedback {
+        type Borrowed<'a> = InteractiveMarkerFeedbackRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -15694,6 +26831,67 @@ uint8 command_type"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerInit_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerInit {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#server_id);
+            writer.put(self.r#seq_num);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_string()?,
+                r#seq_num: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerInit], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerInitRef<'a> {
+        pub r#server_id: &'a str,
+        pub r#seq_num: u64,
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarkerRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerInitRef<'a> {
+        type Owned = InteractiveMarkerInit;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_str()?,
+                r#seq_num: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerInit {
+            InteractiveMarkerInit {
+                r#server_id: self.r#server_id.to_owned(),
+                r#seq_num: self.r#seq_num,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerInit {
+        type Borrowed<'a> = InteractiveMarkerInitRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -15778,6 +26976,83 @@ string frame_id"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerPose_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InteractiveMarkerPose {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerPose {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put_str(&self.r#name);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#name: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerPose], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerPoseRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#name: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerPoseRef<'a> {
+        type Owned = InteractiveMarkerPose;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#name: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerPose {
+            InteractiveMarkerPose {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#name: self.r#name.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerPose {
+        type Borrowed<'a> = InteractiveMarkerPoseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -16908,12 +28183,101 @@ uint8 command_type"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerUpdate_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl InteractiveMarkerUpdate {
         pub const r#KEEP_ALIVE: u8 = 0u8;
         pub const r#UPDATE: u8 = 1u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerUpdate {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#server_id);
+            writer.put(self.r#seq_num);
+            writer.put(self.r#type);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#poses, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#erases, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_string()?,
+                r#seq_num: reader.get()?,
+                r#type: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#poses: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#erases: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerUpdate], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerUpdateRef<'a> {
+        pub r#server_id: &'a str,
+        pub r#seq_num: u64,
+        pub r#type: u8,
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarkerRef<'a>>,
+        pub r#poses: ::std::vec::Vec<self::InteractiveMarkerPoseRef<'a>>,
+        pub r#erases: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerUpdateRef<'a> {
+        type Owned = InteractiveMarkerUpdate;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_str()?,
+                r#seq_num: reader.get()?,
+                r#type: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#poses: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#erases: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerUpdate {
+            InteractiveMarkerUpdate {
+                r#server_id: self.r#server_id.to_owned(),
+                r#seq_num: self.r#seq_num,
+                r#type: self.r#type,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#poses: self
+                    .r#poses
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#erases: self
+                    .r#erases
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerUpdate {
+        type Borrowed<'a> = InteractiveMarkerUpdateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -17061,6 +28425,33 @@ string frame_id"####;
             0xbf, 0x53, 0x57, 0xa9,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::Marker_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Marker {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.secs = sec;
+            self.r#header.stamp.nsecs = nanosec as i32;
+        }
+        fn seq(&self) -> ::std::option::Option<u32> {
+            ::std::option::Option::Some(self.r#header.seq)
+        }
+        fn set_seq(&mut self, seq: u32) {
+            self.r#header.seq = seq;
+        }
     }
     #[allow(unused)]
     impl Marker {
@@ -17081,6 +28472,134 @@ string frame_id"####;
         pub const r#DELETE: u8 = 2u8;
         pub const r#DELETEALL: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Marker {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#ns);
+            writer.put(self.r#id);
+            writer.put(self.r#type);
+            writer.put(self.r#action);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#scale, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#color, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#lifetime, writer);
+            writer.put(self.r#frame_locked);
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#colors, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_str(&self.r#text);
+            writer.put_str(&self.r#mesh_resource);
+            writer.put(self.r#mesh_use_embedded_materials);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ns: reader.get_string()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#scale: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#color: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#lifetime: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#frame_locked: reader.get()?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#colors: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#text: reader.get_string()?,
+                r#mesh_resource: reader.get_string()?,
+                r#mesh_use_embedded_materials: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Marker], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MarkerRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#ns: &'a str,
+        pub r#id: i32,
+        pub r#type: i32,
+        pub r#action: i32,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#scale: geometry_msgs::Vector3Ref<'a>,
+        pub r#color: std_msgs::ColorRGBARef<'a>,
+        pub r#lifetime: ::roslibrust::codegen::integral_types::Duration,
+        pub r#frame_locked: bool,
+        pub r#points: ::std::vec::Vec<geometry_msgs::PointRef<'a>>,
+        pub r#colors: ::std::vec::Vec<std_msgs::ColorRGBARef<'a>>,
+        pub r#text: &'a str,
+        pub r#mesh_resource: &'a str,
+        pub r#mesh_use_embedded_materials: bool,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MarkerRef<'a> {
+        type Owned = Marker;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#ns: reader.get_str()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#scale: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#color: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#lifetime: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#frame_locked: reader.get()?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#colors: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#text: reader.get_str()?,
+                r#mesh_resource: reader.get_str()?,
+                r#mesh_use_embedded_materials: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Marker {
+            Marker {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#ns: self.r#ns.to_owned(),
+                r#id: self.r#id,
+                r#type: self.r#type,
+                r#action: self.r#action,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#scale: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#scale),
+                r#color: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#color),
+                r#lifetime: self.r#lifetime.clone(),
+                r#frame_locked: self.r#frame_locked,
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#colors: self
+                    .r#colors
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#text: self.r#text.to_owned(),
+                r#mesh_resource: self.r#mesh_resource.to_owned(),
+                r#mesh_use_embedded_materials: self.r#mesh_use_embedded_materials,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Marker {
+        type Borrowed<'a> = MarkerRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -17283,6 +28802,57 @@ string frame_id"####;
             0x51, 0x75, 0x09, 0xf3,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::MarkerArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MarkerArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MarkerArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MarkerArrayRef<'a> {
+        pub r#markers: ::std::vec::Vec<self::MarkerRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MarkerArrayRef<'a> {
+        type Owned = MarkerArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MarkerArray {
+            MarkerArray {
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MarkerArray {
+        type Borrowed<'a> = MarkerArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -17365,6 +28935,8 @@ uint8 command_type"####;
             0xc6, 0x4c, 0x51, 0xd6,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::MenuEntry_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl MenuEntry {
@@ -17372,4 +28944,64 @@ uint8 command_type"####;
         pub const r#ROSRUN: u8 = 1u8;
         pub const r#ROSLAUNCH: u8 = 2u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MenuEntry {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#id);
+            writer.put(self.r#parent_id);
+            writer.put_str(&self.r#title);
+            writer.put_str(&self.r#command);
+            writer.put(self.r#command_type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get()?,
+                r#parent_id: reader.get()?,
+                r#title: reader.get_string()?,
+                r#command: reader.get_string()?,
+                r#command_type: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MenuEntry], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MenuEntryRef<'a> {
+        pub r#id: u32,
+        pub r#parent_id: u32,
+        pub r#title: &'a str,
+        pub r#command: &'a str,
+        pub r#command_type: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MenuEntryRef<'a> {
+        type Owned = MenuEntry;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get()?,
+                r#parent_id: reader.get()?,
+                r#title: reader.get_str()?,
+                r#command: reader.get_str()?,
+                r#command_type: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MenuEntry {
+            MenuEntry {
+                r#id: self.r#id,
+                r#parent_id: self.r#parent_id,
+                r#title: self.r#title.to_owned(),
+                r#command: self.r#command.to_owned(),
+                r#command_type: self.r#command_type,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MenuEntry {
+        type Borrowed<'a> = MenuEntryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
 }
@@ -59,6 +59,53 @@ uint32 nanosec"####;
             0x53, 0x98, 0xbe, 0x24,
         ];
         const ROS2_TYPE_NAME: &'static str = "actionlib_msgs::msg::dds_::GoalID_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GoalID {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            writer.put_str(&self.r#id);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#id: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GoalID], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalIDRef<'a> {
+        pub r#stamp: builtin_interfaces::TimeRef<'a>,
+        pub r#id: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GoalIDRef<'a> {
+        type Owned = GoalID;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#id: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> GoalID {
+            GoalID {
+                r#stamp: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#stamp),
+                r#id: self.r#id.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GoalID {
+        type Borrowed<'a> = GoalIDRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -145,6 +192,8 @@ uint32 nanosec"####;
             0x2c, 0x6a, 0xe9, 0xff,
         ];
         const ROS2_TYPE_NAME: &'static str = "actionlib_msgs::msg::dds_::GoalStatus_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl GoalStatus {
@@ -159,6 +208,58 @@ uint32 nanosec"####;
         pub const r#RECALLED: u8 = 8u8;
         pub const r#LOST: u8 = 9u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GoalStatus {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#goal_id, writer);
+            writer.put(self.r#status);
+            writer.put_str(&self.r#text);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#goal_id: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: reader.get()?,
+                r#text: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GoalStatus], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalStatusRef<'a> {
+        pub r#goal_id: self::GoalIDRef<'a>,
+        pub r#status: u8,
+        pub r#text: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GoalStatusRef<'a> {
+        type Owned = GoalStatus;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#goal_id: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: reader.get()?,
+                r#text: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> GoalStatus {
+            GoalStatus {
+                r#goal_id: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#goal_id,
+                ),
+                r#status: self.r#status,
+                r#text: self.r#text.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GoalStatus {
+        type Borrowed<'a> = GoalStatusRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -310,6 +411,81 @@ uint32 nanosec"####;
             0xf8, 0x55, 0xa1, 0xbc,
         ];
         const ROS2_TYPE_NAME: &'static str = "actionlib_msgs::msg::dds_::GoalStatusArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GoalStatusArray {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GoalStatusArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#status_list, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status_list: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GoalStatusArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GoalStatusArrayRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status_list: ::std::vec::Vec<self::GoalStatusRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GoalStatusArrayRef<'a> {
+        type Owned = GoalStatusArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status_list: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> GoalStatusArray {
+            GoalStatusArray {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status_list: self
+                    .r#status_list
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GoalStatusArray {
+        type Borrowed<'a> = GoalStatusArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -363,6 +539,41 @@ uint32 nanosec"####;
             0x95, 0x99, 0xd9, 0x3b,
         ];
         const ROS2_TYPE_NAME: &'static str = "builtin_interfaces::msg::dds_::Duration_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Duration {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sec);
+            writer.put(self.r#nanosec);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sec: reader.get()?,
+                r#nanosec: reader.get()?,
+            })
+        }
+    }
+    pub type DurationRef<'a> = Duration;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Duration {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Duration {
+        type Borrowed<'a> = DurationRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -399,6 +610,41 @@ uint32 nanosec"####;
             0xac, 0xf9, 0xc1, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "builtin_interfaces::msg::dds_::Time_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Time {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sec);
+            writer.put(self.r#nanosec);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sec: reader.get()?,
+                r#nanosec: reader.get()?,
+            })
+        }
+    }
+    pub type TimeRef<'a> = Time;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Time {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Time {
+        type Borrowed<'a> = TimeRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -512,6 +758,81 @@ uint32 nanosec"####;
             0x4b, 0xfe, 0xe9, 0x2c,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::DiagnosticArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for DiagnosticArray {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DiagnosticArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#status, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DiagnosticArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DiagnosticArrayRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status: ::std::vec::Vec<self::DiagnosticStatusRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DiagnosticArrayRef<'a> {
+        type Owned = DiagnosticArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> DiagnosticArray {
+            DiagnosticArray {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status: self
+                    .r#status
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DiagnosticArray {
+        type Borrowed<'a> = DiagnosticArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -564,6 +885,8 @@ string value"####;
             0x2f, 0x03, 0x80, 0x2b,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::DiagnosticStatus_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl DiagnosticStatus {
@@ -572,6 +895,75 @@ string value"####;
         pub const r#ERROR: u8 = 2u8;
         pub const r#STALE: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DiagnosticStatus {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#level);
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#message);
+            writer.put_str(&self.r#hardware_id);
+            writer.put_seq_with(&self.r#values, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#level: reader.get()?,
+                r#name: reader.get_string()?,
+                r#message: reader.get_string()?,
+                r#hardware_id: reader.get_string()?,
+                r#values: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DiagnosticStatus], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DiagnosticStatusRef<'a> {
+        pub r#level: u8,
+        pub r#name: &'a str,
+        pub r#message: &'a str,
+        pub r#hardware_id: &'a str,
+        pub r#values: ::std::vec::Vec<self::KeyValueRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DiagnosticStatusRef<'a> {
+        type Owned = DiagnosticStatus;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#level: reader.get()?,
+                r#name: reader.get_str()?,
+                r#message: reader.get_str()?,
+                r#hardware_id: reader.get_str()?,
+                r#values: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> DiagnosticStatus {
+            DiagnosticStatus {
+                r#level: self.r#level,
+                r#name: self.r#name.to_owned(),
+                r#message: self.r#message.to_owned(),
+                r#hardware_id: self.r#hardware_id.to_owned(),
+                r#values: self
+                    .r#values
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DiagnosticStatus {
+        type Borrowed<'a> = DiagnosticStatusRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -600,6 +992,53 @@ string value"####;
             0x04, 0x13, 0xbb, 0x42,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::KeyValue_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for KeyValue {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#key);
+            writer.put_str(&self.r#value);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#key: reader.get_string()?,
+                r#value: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [KeyValue], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct KeyValueRef<'a> {
+        pub r#key: &'a str,
+        pub r#value: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for KeyValueRef<'a> {
+        type Owned = KeyValue;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#key: reader.get_str()?,
+                r#value: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> KeyValue {
+            KeyValue {
+                r#key: self.r#key.to_owned(),
+                r#value: self.r#value.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for KeyValue {
+        type Borrowed<'a> = KeyValueRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -641,6 +1080,48 @@ string load_namespace"####;
             0x3f, 0x8c, 0x82, 0xd4,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::AddDiagnosticsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddDiagnosticsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#load_namespace);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#load_namespace: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AddDiagnosticsRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AddDiagnosticsRequestRef<'a> {
+        pub r#load_namespace: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddDiagnosticsRequestRef<'a> {
+        type Owned = AddDiagnosticsRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#load_namespace: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> AddDiagnosticsRequest {
+            AddDiagnosticsRequest {
+                r#load_namespace: self.r#load_namespace.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddDiagnosticsRequest {
+        type Borrowed<'a> = AddDiagnosticsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -674,6 +1155,53 @@ string message"####;
             0x9d, 0xca, 0x5e, 0xac,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::AddDiagnosticsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddDiagnosticsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AddDiagnosticsResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AddDiagnosticsResponseRef<'a> {
+        pub r#success: bool,
+        pub r#message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddDiagnosticsResponseRef<'a> {
+        type Owned = AddDiagnosticsResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> AddDiagnosticsResponse {
+            AddDiagnosticsResponse {
+                r#success: self.r#success,
+                r#message: self.r#message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddDiagnosticsResponse {
+        type Borrowed<'a> = AddDiagnosticsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct AddDiagnostics {}
@@ -711,6 +1239,35 @@ string message"####;
             0x3d, 0x77, 0xb2, 0x29,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::SelfTestRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SelfTestRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type SelfTestRequestRef<'a> = SelfTestRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SelfTestRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SelfTestRequest {
+        type Borrowed<'a> = SelfTestRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -772,6 +1329,67 @@ string value"####;
             0x6b, 0xc4, 0xce, 0x46,
         ];
         const ROS2_TYPE_NAME: &'static str = "diagnostic_msgs::msg::dds_::SelfTestResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SelfTestResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#id);
+            writer.put(self.r#passed);
+            writer.put_seq_with(&self.r#status, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get_string()?,
+                r#passed: reader.get()?,
+                r#status: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SelfTestResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SelfTestResponseRef<'a> {
+        pub r#id: &'a str,
+        pub r#passed: u8,
+        pub r#status: ::std::vec::Vec<self::DiagnosticStatusRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SelfTestResponseRef<'a> {
+        type Owned = SelfTestResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get_str()?,
+                r#passed: reader.get()?,
+                r#status: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> SelfTestResponse {
+            SelfTestResponse {
+                r#id: self.r#id.to_owned(),
+                r#passed: self.r#passed,
+                r#status: self
+                    .r#status
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SelfTestResponse {
+        type Borrowed<'a> = SelfTestResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SelfTest {}
@@ -841,6 +1459,41 @@ float64 z"####;
             0xa3, 0xf5, 0x7c, 0xa0,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Accel_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Accel {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#linear, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#angular, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#linear: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angular: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type AccelRef<'a> = Accel;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Accel {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Accel {
+        type Borrowed<'a> = AccelRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -933,6 +1586,72 @@ uint32 nanosec"####;
             0x96, 0x98, 0xb1, 0x65,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::AccelStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for AccelStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AccelStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#accel, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#accel: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AccelStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AccelStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#accel: self::AccelRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AccelStampedRef<'a> {
+        type Owned = AccelStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> AccelStamped {
+            AccelStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#accel),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AccelStamped {
+        type Borrowed<'a> = AccelStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -996,6 +1715,41 @@ float64 z"####;
             0xc5, 0xdb, 0x59, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::AccelWithCovariance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AccelWithCovariance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#accel, writer);
+            writer.put_slice(&self.r#covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#accel: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#covariance: reader.get_array()?,
+            })
+        }
+    }
+    pub type AccelWithCovarianceRef<'a> = AccelWithCovariance;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AccelWithCovariance {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AccelWithCovariance {
+        type Borrowed<'a> = AccelWithCovarianceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1127,6 +1881,72 @@ uint32 nanosec"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "geometry_msgs::msg::dds_::AccelWithCovarianceStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for AccelWithCovarianceStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AccelWithCovarianceStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#accel, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#accel: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [AccelWithCovarianceStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AccelWithCovarianceStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#accel: self::AccelWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AccelWithCovarianceStampedRef<'a> {
+        type Owned = AccelWithCovarianceStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> AccelWithCovarianceStamped {
+            AccelWithCovarianceStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#accel: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#accel),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AccelWithCovarianceStamped {
+        type Borrowed<'a> = AccelWithCovarianceStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1185,6 +2005,53 @@ float64 z"####;
             0x93, 0x0b, 0x4b, 0xd7,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Inertia_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Inertia {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#m);
+            ::roslibrust::codec::WireCodec::encode(&self.r#com, writer);
+            writer.put(self.r#ixx);
+            writer.put(self.r#ixy);
+            writer.put(self.r#ixz);
+            writer.put(self.r#iyy);
+            writer.put(self.r#iyz);
+            writer.put(self.r#izz);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#m: reader.get()?,
+                r#com: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ixx: reader.get()?,
+                r#ixy: reader.get()?,
+                r#ixz: reader.get()?,
+                r#iyy: reader.get()?,
+                r#iyz: reader.get()?,
+                r#izz: reader.get()?,
+            })
+        }
+    }
+    pub type InertiaRef<'a> = Inertia;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Inertia {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Inertia {
+        type Borrowed<'a> = InertiaRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1291,6 +2158,74 @@ uint32 nanosec"####;
             0xb6, 0x95, 0xaa, 0x09,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::InertiaStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InertiaStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InertiaStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#inertia, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#inertia: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InertiaStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InertiaStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#inertia: self::InertiaRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InertiaStampedRef<'a> {
+        type Owned = InertiaStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#inertia: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> InertiaStamped {
+            InertiaStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#inertia: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#inertia,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InertiaStamped {
+        type Borrowed<'a> = InertiaStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1321,6 +2256,43 @@ float64 z"####;
             0x2a, 0x7f, 0x68, 0x81,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Point_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Point {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+            })
+        }
+    }
+    pub type PointRef<'a> = Point;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Point {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Point {
+        type Borrowed<'a> = PointRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1358,6 +2330,43 @@ float32 z"####;
             0x2a, 0x4b, 0xfd, 0xbc,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Point32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Point32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+            })
+        }
+    }
+    pub type Point32Ref<'a> = Point32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Point32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Point32 {
+        type Borrowed<'a> = Point32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1430,6 +2439,72 @@ uint32 nanosec"####;
             0xc1, 0x04, 0x58, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PointStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PointStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#point, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#point: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#point: self::PointRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointStampedRef<'a> {
+        type Owned = PointStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#point: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PointStamped {
+            PointStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#point: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#point),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointStamped {
+        type Borrowed<'a> = PointStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1470,6 +2545,42 @@ float32 z"####;
             0xda, 0x0f, 0x78, 0x4a,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Polygon_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Polygon {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    pub type PolygonRef<'a> = Polygon;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Polygon {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Polygon {
+        type Borrowed<'a> = PolygonRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1567,6 +2678,74 @@ uint32 nanosec"####;
             0xe9, 0x13, 0x8b, 0x46,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PolygonStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PolygonStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PolygonStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#polygon, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#polygon: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PolygonStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PolygonStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#polygon: self::PolygonRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PolygonStampedRef<'a> {
+        type Owned = PolygonStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#polygon: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PolygonStamped {
+            PolygonStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#polygon: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#polygon,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PolygonStamped {
+        type Borrowed<'a> = PolygonStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1610,6 +2789,41 @@ float64 w 1"####;
             0x35, 0xcc, 0x90, 0xfa,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Pose_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Pose {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#position, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#orientation, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#position: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type PoseRef<'a> = Pose;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Pose {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Pose {
+        type Borrowed<'a> = PoseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1646,6 +2860,43 @@ float64 theta"####;
             0xa4, 0x72, 0x4c, 0xcc,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Pose2D_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Pose2D {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#theta);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#theta: reader.get()?,
+            })
+        }
+    }
+    pub type Pose2DRef<'a> = Pose2D;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Pose2D {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Pose2D {
+        type Borrowed<'a> = Pose2DRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1747,6 +2998,81 @@ uint32 nanosec"####;
             0xd5, 0x00, 0x4a, 0xa0,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PoseArray {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#poses, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#poses: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PoseArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PoseArrayRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#poses: ::std::vec::Vec<self::PoseRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseArrayRef<'a> {
+        type Owned = PoseArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#poses: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> PoseArray {
+            PoseArray {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#poses: self
+                    .r#poses
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseArray {
+        type Borrowed<'a> = PoseArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1847,6 +3173,72 @@ uint32 nanosec"####;
             0xd7, 0x3b, 0x4c, 0xd8,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PoseStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PoseStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PoseStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: self::PoseRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseStampedRef<'a> {
+        type Owned = PoseStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PoseStamped {
+            PoseStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseStamped {
+        type Borrowed<'a> = PoseStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -1917,6 +3309,41 @@ float64 w 1"####;
             0x1c, 0x02, 0xe1, 0xbb,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseWithCovariance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseWithCovariance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put_slice(&self.r#covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#covariance: reader.get_array()?,
+            })
+        }
+    }
+    pub type PoseWithCovarianceRef<'a> = PoseWithCovariance;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseWithCovariance {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseWithCovariance {
+        type Borrowed<'a> = PoseWithCovarianceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2062,6 +3489,72 @@ uint32 nanosec"####;
             0xbf, 0xea, 0x3b, 0x6e,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseWithCovarianceStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PoseWithCovarianceStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PoseWithCovarianceStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PoseWithCovarianceStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PoseWithCovarianceStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: self::PoseWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PoseWithCovarianceStampedRef<'a> {
+        type Owned = PoseWithCovarianceStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> PoseWithCovarianceStamped {
+            PoseWithCovarianceStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PoseWithCovarianceStamped {
+        type Borrowed<'a> = PoseWithCovarianceStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2099,6 +3592,45 @@ float64 w 1"####;
             0x6f, 0x45, 0x86, 0x84,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Quaternion_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Quaternion {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+            writer.put(self.r#w);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+                r#w: reader.get()?,
+            })
+        }
+    }
+    pub type QuaternionRef<'a> = Quaternion;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Quaternion {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Quaternion {
+        type Borrowed<'a> = QuaternionRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2173,6 +3705,74 @@ uint32 nanosec"####;
             0x22, 0xdb, 0xac, 0x6e,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::QuaternionStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for QuaternionStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for QuaternionStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#quaternion, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#quaternion: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [QuaternionStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct QuaternionStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#quaternion: self::QuaternionRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for QuaternionStampedRef<'a> {
+        type Owned = QuaternionStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#quaternion: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> QuaternionStamped {
+            QuaternionStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#quaternion: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#quaternion,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for QuaternionStamped {
+        type Borrowed<'a> = QuaternionStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2221,6 +3821,41 @@ float64 z"####;
             0x25, 0x81, 0xfd, 0xdc,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Transform_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Transform {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#translation, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#rotation, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#translation: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#rotation: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type TransformRef<'a> = Transform;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Transform {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Transform {
+        type Borrowed<'a> = TransformRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2347,6 +3982,79 @@ uint32 nanosec"####;
             0xbd, 0x27, 0x52, 0x06,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TransformStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TransformStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TransformStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#child_frame_id);
+            ::roslibrust::codec::WireCodec::encode(&self.r#transform, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#child_frame_id: reader.get_string()?,
+                r#transform: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TransformStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TransformStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#child_frame_id: &'a str,
+        pub r#transform: self::TransformRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TransformStampedRef<'a> {
+        type Owned = TransformStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#child_frame_id: reader.get_str()?,
+                r#transform: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TransformStamped {
+            TransformStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#child_frame_id: self.r#child_frame_id.to_owned(),
+                r#transform: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#transform,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TransformStamped {
+        type Borrowed<'a> = TransformStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2387,6 +4095,41 @@ float64 z"####;
             0xe6, 0xfb, 0x8d, 0x2a,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Twist_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Twist {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#linear, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#angular, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#linear: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angular: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type TwistRef<'a> = Twist;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Twist {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Twist {
+        type Borrowed<'a> = TwistRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2481,6 +4224,72 @@ uint32 nanosec"####;
             0x21, 0xdb, 0x6f, 0x64,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TwistStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TwistStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TwistStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TwistStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TwistStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#twist: self::TwistRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TwistStampedRef<'a> {
+        type Owned = TwistStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TwistStamped {
+            TwistStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#twist),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TwistStamped {
+        type Borrowed<'a> = TwistStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2545,6 +4354,41 @@ float64 z"####;
             0x05, 0x9c, 0x8c, 0x18,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TwistWithCovariance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TwistWithCovariance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+            writer.put_slice(&self.r#covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#covariance: reader.get_array()?,
+            })
+        }
+    }
+    pub type TwistWithCovarianceRef<'a> = TwistWithCovariance;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TwistWithCovariance {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TwistWithCovariance {
+        type Borrowed<'a> = TwistWithCovarianceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2679,6 +4523,72 @@ uint32 nanosec"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "geometry_msgs::msg::dds_::TwistWithCovarianceStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TwistWithCovarianceStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TwistWithCovarianceStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TwistWithCovarianceStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TwistWithCovarianceStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#twist: self::TwistWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TwistWithCovarianceStampedRef<'a> {
+        type Owned = TwistWithCovarianceStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> TwistWithCovarianceStamped {
+            TwistWithCovarianceStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#twist),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TwistWithCovarianceStamped {
+        type Borrowed<'a> = TwistWithCovarianceStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2714,6 +4624,43 @@ float64 z"####;
             0xdb, 0xb0, 0x76, 0x5d,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Vector3_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Vector3 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put(self.r#z);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#z: reader.get()?,
+            })
+        }
+    }
+    pub type Vector3Ref<'a> = Vector3;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Vector3 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Vector3 {
+        type Borrowed<'a> = Vector3Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2794,6 +4741,72 @@ uint32 nanosec"####;
             0xf7, 0xd7, 0x88, 0x7a,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Vector3Stamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Vector3Stamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Vector3Stamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#vector, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#vector: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Vector3Stamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Vector3StampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#vector: self::Vector3Ref<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Vector3StampedRef<'a> {
+        type Owned = Vector3Stamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#vector: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> Vector3Stamped {
+            Vector3Stamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#vector: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#vector),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Vector3Stamped {
+        type Borrowed<'a> = Vector3StampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2834,6 +4847,41 @@ float64 z"####;
             0x56, 0x77, 0xce, 0x52,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Wrench_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Wrench {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#force, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#torque, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#force: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#torque: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type WrenchRef<'a> = Wrench;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Wrench {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Wrench {
+        type Borrowed<'a> = WrenchRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -2928,6 +4976,72 @@ uint32 nanosec"####;
             0x3f, 0xa7, 0x8f, 0x77,
         ];
         const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::WrenchStamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for WrenchStamped {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for WrenchStamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#wrench, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#wrench: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [WrenchStamped], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct WrenchStampedRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#wrench: self::WrenchRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for WrenchStampedRef<'a> {
+        type Owned = WrenchStamped;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#wrench: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> WrenchStamped {
+            WrenchStamped {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#wrench: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#wrench),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for WrenchStamped {
+        type Borrowed<'a> = WrenchStampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -3026,6 +5140,91 @@ uint32 nanosec"####;
             0x35, 0x4a, 0x69, 0x33,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GridCells_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for GridCells {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GridCells {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#cell_width);
+            writer.put(self.r#cell_height);
+            writer.put_seq_with(&self.r#cells, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#cell_width: reader.get()?,
+                r#cell_height: reader.get()?,
+                r#cells: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GridCells], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GridCellsRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#cell_width: f32,
+        pub r#cell_height: f32,
+        pub r#cells: ::std::vec::Vec<geometry_msgs::PointRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GridCellsRef<'a> {
+        type Owned = GridCells;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#cell_width: reader.get()?,
+                r#cell_height: reader.get()?,
+                r#cells: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> GridCells {
+            GridCells {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#cell_width: self.r#cell_width,
+                r#cell_height: self.r#cell_height,
+                r#cells: self
+                    .r#cells
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GridCells {
+        type Borrowed<'a> = GridCellsRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3118,6 +5317,47 @@ float64 w 1"####;
             0x17, 0xd7, 0x63, 0xc8,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::MapMetaData_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MapMetaData {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map_load_time, writer);
+            writer.put(self.r#resolution);
+            writer.put(self.r#width);
+            writer.put(self.r#height);
+            ::roslibrust::codec::WireCodec::encode(&self.r#origin, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map_load_time: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#resolution: reader.get()?,
+                r#width: reader.get()?,
+                r#height: reader.get()?,
+                r#origin: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type MapMetaDataRef<'a> = MapMetaData;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MapMetaData {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MapMetaData {
+        type Borrowed<'a> = MapMetaDataRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3294,6 +5534,77 @@ uint32 nanosec"####;
             0x0b, 0xc0, 0xcf, 0xc7,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::OccupancyGrid_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for OccupancyGrid {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for OccupancyGrid {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#info, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#info: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [OccupancyGrid], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct OccupancyGridRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#info: self::MapMetaDataRef<'a>,
+        pub r#data: ::std::vec::Vec<i8>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for OccupancyGridRef<'a> {
+        type Owned = OccupancyGrid;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#info: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> OccupancyGrid {
+            OccupancyGrid {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#info: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#info),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for OccupancyGrid {
+        type Borrowed<'a> = OccupancyGridRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3519,6 +5830,82 @@ uint32 nanosec"####;
             0x6d, 0xfe, 0xc6, 0xe0,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::Odometry_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Odometry {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Odometry {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#child_frame_id);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#twist, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#child_frame_id: reader.get_string()?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#twist: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Odometry], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct OdometryRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#child_frame_id: &'a str,
+        pub r#pose: geometry_msgs::PoseWithCovarianceRef<'a>,
+        pub r#twist: geometry_msgs::TwistWithCovarianceRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for OdometryRef<'a> {
+        type Owned = Odometry;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#child_frame_id: reader.get_str()?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> Odometry {
+            Odometry {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#child_frame_id: self.r#child_frame_id.to_owned(),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#twist: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#twist),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Odometry {
+        type Borrowed<'a> = OdometryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3699,6 +6086,81 @@ uint32 nanosec"####;
             0x66, 0xca, 0x33, 0x4b,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::Path_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Path {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Path {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#poses, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#poses: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Path], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PathRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#poses: ::std::vec::Vec<geometry_msgs::PoseStampedRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PathRef<'a> {
+        type Owned = Path;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#poses: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> Path {
+            Path {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#poses: self
+                    .r#poses
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Path {
+        type Borrowed<'a> = PathRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -3722,6 +6184,35 @@ uint32 nanosec"####;
             0xdf, 0x8e, 0x02, 0xde,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetMapRequestRef<'a> = GetMapRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapRequest {
+        type Borrowed<'a> = GetMapRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4037,6 +6528,48 @@ uint32 nanosec"####;
             0x45, 0x5f, 0x09, 0x2a,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetMapResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetMapResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetMapResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetMapResponseRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetMapResponseRef<'a> {
+        type Owned = GetMapResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetMapResponse {
+            GetMapResponse {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetMapResponse {
+        type Borrowed<'a> = GetMapResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetMap {}
@@ -4236,6 +6769,58 @@ uint32 nanosec"####;
             0x13, 0xa0, 0x79, 0xe2,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetPlanRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetPlanRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#start, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#goal, writer);
+            writer.put(self.r#tolerance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#start: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#goal: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#tolerance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetPlanRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetPlanRequestRef<'a> {
+        pub r#start: geometry_msgs::PoseStampedRef<'a>,
+        pub r#goal: geometry_msgs::PoseStampedRef<'a>,
+        pub r#tolerance: f32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetPlanRequestRef<'a> {
+        type Owned = GetPlanRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#start: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#goal: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#tolerance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> GetPlanRequest {
+            GetPlanRequest {
+                r#start: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#start),
+                r#goal: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#goal),
+                r#tolerance: self.r#tolerance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetPlanRequest {
+        type Borrowed<'a> = GetPlanRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4567,6 +7152,48 @@ uint32 nanosec"####;
             0x77, 0x91, 0x59, 0x12,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::GetPlanResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetPlanResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#plan, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#plan: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetPlanResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetPlanResponseRef<'a> {
+        pub r#plan: self::PathRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetPlanResponseRef<'a> {
+        type Owned = GetPlanResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#plan: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> GetPlanResponse {
+            GetPlanResponse {
+                r#plan: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#plan),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetPlanResponse {
+        type Borrowed<'a> = GetPlanResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetPlan {}
@@ -4609,6 +7236,48 @@ string map_url"####;
             0xf2, 0x6d, 0x19, 0xbf,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::LoadMapRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LoadMapRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#map_url);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map_url: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LoadMapRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LoadMapRequestRef<'a> {
+        pub r#map_url: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LoadMapRequestRef<'a> {
+        type Owned = LoadMapRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map_url: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> LoadMapRequest {
+            LoadMapRequest {
+                r#map_url: self.r#map_url.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LoadMapRequest {
+        type Borrowed<'a> = LoadMapRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -4933,6 +7602,8 @@ uint32 nanosec"####;
             0xcd, 0xd5, 0x7c, 0x50,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::LoadMapResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl LoadMapResponse {
@@ -4942,6 +7613,51 @@ uint32 nanosec"####;
         pub const r#RESULT_INVALID_MAP_METADATA: u8 = 3u8;
         pub const r#RESULT_UNDEFINED_FAILURE: u8 = 255u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LoadMapResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+            writer.put(self.r#result);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#result: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LoadMapResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LoadMapResponseRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+        pub r#result: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LoadMapResponseRef<'a> {
+        type Owned = LoadMapResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#result: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> LoadMapResponse {
+            LoadMapResponse {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+                r#result: self.r#result,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LoadMapResponse {
+        type Borrowed<'a> = LoadMapResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(dead_code)]
     pub struct LoadMap {}
     impl ::roslibrust::RosServiceType for LoadMap {
@@ -5443,6 +8159,55 @@ uint32 nanosec"####;
             0xa0, 0x68, 0x11, 0xea,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::SetMapRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetMapRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#map, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#initial_pose, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#initial_pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetMapRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetMapRequestRef<'a> {
+        pub r#map: self::OccupancyGridRef<'a>,
+        pub r#initial_pose: geometry_msgs::PoseWithCovarianceStampedRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetMapRequestRef<'a> {
+        type Owned = SetMapRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#initial_pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> SetMapRequest {
+            SetMapRequest {
+                r#map: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#map),
+                r#initial_pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#initial_pose,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetMapRequest {
+        type Borrowed<'a> = SetMapRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5469,6 +8234,39 @@ bool success"####;
             0xc3, 0x68, 0xaf, 0x26,
         ];
         const ROS2_TYPE_NAME: &'static str = "nav_msgs::msg::dds_::SetMapResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetMapResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+            })
+        }
+    }
+    pub type SetMapResponseRef<'a> = SetMapResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetMapResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetMapResponse {
+        type Borrowed<'a> = SetMapResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetMap {}
@@ -5524,6 +8322,39 @@ pub mod ros2_test_msgs {
             0x02, 0x64, 0xac, 0xd7,
         ];
         const ROS2_TYPE_NAME: &'static str = "ros2_test_msgs::msg::dds_::Bool_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Bool {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type BoolRef<'a> = Bool;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Bool {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Bool {
+        type Borrowed<'a> = BoolRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5537,8 +8368,8 @@ pub mod ros2_test_msgs {
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct BoundedInt {
-        #[serde(with = "::roslibrust::codegen::serde_rosmsg_bytes")]
-        pub r#data: ::std::vec::Vec<u8>,
+        #[serde(with = "::roslibrust::codegen::serde_rosmsg_bytes::bounded")]
+        pub r#data: ::roslibrust::codegen::BoundedVec<u8, 5>,
     }
     impl ::roslibrust::RosMessageType for BoundedInt {
         const ROS_TYPE_NAME: &'static str = "ros2_test_msgs/BoundedInt";
@@ -5564,7 +8395,7 @@ uint8[<=5] data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct BoundedReferenced {
-        pub r#data: ::std::vec::Vec<self::BoundedInt>,
+        pub r#data: ::roslibrust::codegen::BoundedVec<self::BoundedInt, 3>,
     }
     impl ::roslibrust::RosMessageType for BoundedReferenced {
         const ROS_TYPE_NAME: &'static str = "ros2_test_msgs/BoundedReferenced";
@@ -5594,9 +8425,10 @@ uint8[<=5] data"####;
     )]
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct BoundedString {
-        pub r#data: ::std::string::String,
-        pub r#data_list: ::std::vec::Vec<::std::string::String>,
-        pub r#data_matrix: ::std::vec::Vec<::std::string::String>,
+        pub r#data: ::roslibrust::codegen::BoundedString<5>,
+        pub r#data_list: ::std::vec::Vec<::roslibrust::codegen::BoundedString<5>>,
+        pub r#data_matrix:
+            ::roslibrust::codegen::BoundedVec<::roslibrust::codegen::BoundedString<5>, 3>,
     }
     impl ::roslibrust::RosMessageType for BoundedString {
         const ROS_TYPE_NAME: &'static str = "ros2_test_msgs/BoundedString";
@@ -5626,7 +8458,7 @@ string<=5[<=3] data_matrix"####;
     pub struct Char {
         pub r#data: u8,
         pub r#data_array: ::std::vec::Vec<u8>,
-        pub r#data_bounded_array: ::std::vec::Vec<u8>,
+        pub r#data_bounded_array: ::roslibrust::codegen::BoundedVec<u8, 11>,
     }
     impl ::roslibrust::RosMessageType for Char {
         const ROS_TYPE_NAME: &'static str = "ros2_test_msgs/Char";
@@ -5692,6 +8524,73 @@ string[] s_vec ["hello", "world"]
             0x35, 0x54, 0x74, 0xbe,
         ];
         const ROS2_TYPE_NAME: &'static str = "ros2_test_msgs::msg::dds_::Defaults_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Defaults {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x);
+            writer.put(self.r#y);
+            writer.put_str(&self.r#full_name);
+            writer.put_seq(&self.r#samples);
+            writer.put_seq(&self.r#f_samples);
+            writer.put_seq_with(&self.r#s_vec, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#full_name: reader.get_string()?,
+                r#samples: reader.get_seq()?,
+                r#f_samples: reader.get_seq()?,
+                r#s_vec: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Defaults], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DefaultsRef<'a> {
+        pub r#x: u8,
+        pub r#y: i16,
+        pub r#full_name: &'a str,
+        pub r#samples: ::std::vec::Vec<i32>,
+        pub r#f_samples: ::std::vec::Vec<f32>,
+        pub r#s_vec: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DefaultsRef<'a> {
+        type Owned = Defaults;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x: reader.get()?,
+                r#y: reader.get()?,
+                r#full_name: reader.get_str()?,
+                r#samples: reader.get_seq()?,
+                r#f_samples: reader.get_seq()?,
+                r#s_vec: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> Defaults {
+            Defaults {
+                r#x: self.r#x,
+                r#y: self.r#y,
+                r#full_name: self.r#full_name.to_owned(),
+                r#samples: self.r#samples.clone(),
+                r#f_samples: self.r#f_samples.clone(),
+                r#s_vec: self.r#s_vec.iter().map(|item| (*item).to_owned()).collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Defaults {
+        type Borrowed<'a> = DefaultsRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5747,6 +8646,41 @@ uint32 nanosec"####;
             0xbd, 0x2f, 0x50, 0xcc,
         ];
         const ROS2_TYPE_NAME: &'static str = "ros2_test_msgs::msg::dds_::Stamped_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Stamped {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#duration, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#duration: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type StampedRef<'a> = Stamped;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Stamped {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Stamped {
+        type Borrowed<'a> = StampedRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5774,6 +8708,41 @@ int64 b"####;
             0x3b, 0x85, 0x23, 0xfd,
         ];
         const ROS2_TYPE_NAME: &'static str = "ros2_test_msgs::msg::dds_::AddTwoIntsRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddTwoIntsRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#a);
+            writer.put(self.r#b);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#a: reader.get()?,
+                r#b: reader.get()?,
+            })
+        }
+    }
+    pub type AddTwoIntsRequestRef<'a> = AddTwoIntsRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddTwoIntsRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddTwoIntsRequest {
+        type Borrowed<'a> = AddTwoIntsRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -5799,6 +8768,39 @@ int64 b"####;
             0x39, 0x6a, 0x45, 0x11,
         ];
         const ROS2_TYPE_NAME: &'static str = "ros2_test_msgs::msg::dds_::AddTwoIntsResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for AddTwoIntsResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sum);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sum: reader.get()?,
+            })
+        }
+    }
+    pub type AddTwoIntsResponseRef<'a> = AddTwoIntsResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for AddTwoIntsResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for AddTwoIntsResponse {
+        type Borrowed<'a> = AddTwoIntsResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct AddTwoInts {}
@@ -5956,6 +8958,27 @@ uint32 nanosec"####;
             0x40, 0xe6, 0x97, 0x41,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::BatteryState_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for BatteryState {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
     }
     #[allow(unused)]
     impl BatteryState {
@@ -5981,6 +9004,121 @@ uint32 nanosec"####;
         pub const r#POWER_SUPPLY_TECHNOLOGY_NICD: u8 = 5u8;
         pub const r#POWER_SUPPLY_TECHNOLOGY_LIMN: u8 = 6u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for BatteryState {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#voltage);
+            writer.put(self.r#temperature);
+            writer.put(self.r#current);
+            writer.put(self.r#charge);
+            writer.put(self.r#capacity);
+            writer.put(self.r#design_capacity);
+            writer.put(self.r#percentage);
+            writer.put(self.r#power_supply_status);
+            writer.put(self.r#power_supply_health);
+            writer.put(self.r#power_supply_technology);
+            writer.put(self.r#present);
+            writer.put_seq(&self.r#cell_voltage);
+            writer.put_seq(&self.r#cell_temperature);
+            writer.put_str(&self.r#location);
+            writer.put_str(&self.r#serial_number);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#voltage: reader.get()?,
+                r#temperature: reader.get()?,
+                r#current: reader.get()?,
+                r#charge: reader.get()?,
+                r#capacity: reader.get()?,
+                r#design_capacity: reader.get()?,
+                r#percentage: reader.get()?,
+                r#power_supply_status: reader.get()?,
+                r#power_supply_health: reader.get()?,
+                r#power_supply_technology: reader.get()?,
+                r#present: reader.get()?,
+                r#cell_voltage: reader.get_seq()?,
+                r#cell_temperature: reader.get_seq()?,
+                r#location: reader.get_string()?,
+                r#serial_number: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [BatteryState], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BatteryStateRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#voltage: f32,
+        pub r#temperature: f32,
+        pub r#current: f32,
+        pub r#charge: f32,
+        pub r#capacity: f32,
+        pub r#design_capacity: f32,
+        pub r#percentage: f32,
+        pub r#power_supply_status: u8,
+        pub r#power_supply_health: u8,
+        pub r#power_supply_technology: u8,
+        pub r#present: bool,
+        pub r#cell_voltage: ::std::vec::Vec<f32>,
+        pub r#cell_temperature: ::std::vec::Vec<f32>,
+        pub r#location: &'a str,
+        pub r#serial_number: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for BatteryStateRef<'a> {
+        type Owned = BatteryState;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#voltage: reader.get()?,
+                r#temperature: reader.get()?,
+                r#current: reader.get()?,
+                r#charge: reader.get()?,
+                r#capacity: reader.get()?,
+                r#design_capacity: reader.get()?,
+                r#percentage: reader.get()?,
+                r#power_supply_status: reader.get()?,
+                r#power_supply_health: reader.get()?,
+                r#power_supply_technology: reader.get()?,
+                r#present: reader.get()?,
+                r#cell_voltage: reader.get_seq()?,
+                r#cell_temperature: reader.get_seq()?,
+                r#location: reader.get_str()?,
+                r#serial_number: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> BatteryState {
+            BatteryState {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#voltage: self.r#voltage,
+                r#temperature: self.r#temperature,
+                r#current: self.r#current,
+                r#charge: self.r#charge,
+                r#capacity: self.r#capacity,
+                r#design_capacity: self.r#design_capacity,
+                r#percentage: self.r#percentage,
+                r#power_supply_status: self.r#power_supply_status,
+                r#power_supply_health: self.r#power_supply_health,
+                r#power_supply_technology: self.r#power_supply_technology,
+                r#present: self.r#present,
+                r#cell_voltage: self.r#cell_voltage.clone(),
+                r#cell_temperature: self.r#cell_temperature.clone(),
+                r#location: self.r#location.to_owned(),
+                r#serial_number: self.r#serial_number.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for BatteryState {
+        type Borrowed<'a> = BatteryStateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -6203,6 +9341,117 @@ uint32 nanosec"####;
             0x18, 0xe7, 0xe2, 0x75,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::CameraInfo_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for CameraInfo {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for CameraInfo {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put_str(&self.r#distortion_model);
+            writer.put_seq(&self.r#d);
+            writer.put_slice(&self.r#k);
+            writer.put_slice(&self.r#r);
+            writer.put_slice(&self.r#p);
+            writer.put(self.r#binning_x);
+            writer.put(self.r#binning_y);
+            ::roslibrust::codec::WireCodec::encode(&self.r#roi, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#distortion_model: reader.get_string()?,
+                r#d: reader.get_seq()?,
+                r#k: reader.get_array()?,
+                r#r: reader.get_array()?,
+                r#p: reader.get_array()?,
+                r#binning_x: reader.get()?,
+                r#binning_y: reader.get()?,
+                r#roi: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [CameraInfo], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CameraInfoRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#height: u32,
+        pub r#width: u32,
+        pub r#distortion_model: &'a str,
+        pub r#d: ::std::vec::Vec<f64>,
+        pub r#k: [f64; 9],
+        pub r#r: [f64; 9],
+        pub r#p: [f64; 12],
+        pub r#binning_x: u32,
+        pub r#binning_y: u32,
+        pub r#roi: self::RegionOfInterestRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for CameraInfoRef<'a> {
+        type Owned = CameraInfo;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#distortion_model: reader.get_str()?,
+                r#d: reader.get_seq()?,
+                r#k: reader.get_array()?,
+                r#r: reader.get_array()?,
+                r#p: reader.get_array()?,
+                r#binning_x: reader.get()?,
+                r#binning_y: reader.get()?,
+                r#roi: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> CameraInfo {
+            CameraInfo {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#height: self.r#height,
+                r#width: self.r#width,
+                r#distortion_model: self.r#distortion_model.to_owned(),
+                r#d: self.r#d.clone(),
+                r#k: self.r#k,
+                r#r: self.r#r,
+                r#p: self.r#p,
+                r#binning_x: self.r#binning_x,
+                r#binning_y: self.r#binning_y,
+                r#roi: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#roi),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for CameraInfo {
+        type Borrowed<'a> = CameraInfoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6252,6 +9501,53 @@ float32[] values"####;
             0x58, 0xaf, 0x6c, 0xd4,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::ChannelFloat32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ChannelFloat32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put_seq(&self.r#values);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#values: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ChannelFloat32], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ChannelFloat32Ref<'a> {
+        pub r#name: &'a str,
+        pub r#values: ::std::vec::Vec<f32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ChannelFloat32Ref<'a> {
+        type Owned = ChannelFloat32;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#values: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> ChannelFloat32 {
+            ChannelFloat32 {
+                r#name: self.r#name.to_owned(),
+                r#values: self.r#values.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ChannelFloat32 {
+        type Borrowed<'a> = ChannelFloat32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6330,6 +9626,77 @@ uint32 nanosec"####;
             0x1e, 0x8e, 0x37, 0x1a,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::CompressedImage_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for CompressedImage {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for CompressedImage {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#format);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#format: reader.get_string()?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [CompressedImage], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CompressedImageRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#format: &'a str,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for CompressedImageRef<'a> {
+        type Owned = CompressedImage;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#format: reader.get_str()?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> CompressedImage {
+            CompressedImage {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#format: self.r#format.to_owned(),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for CompressedImage {
+        type Borrowed<'a> = CompressedImageRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6405,6 +9772,77 @@ uint32 nanosec"####;
             0x35, 0x95, 0xbc, 0x32,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::FluidPressure_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for FluidPressure {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for FluidPressure {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#fluid_pressure);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#fluid_pressure: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [FluidPressure], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FluidPressureRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#fluid_pressure: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for FluidPressureRef<'a> {
+        type Owned = FluidPressure;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#fluid_pressure: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> FluidPressure {
+            FluidPressure {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#fluid_pressure: self.r#fluid_pressure,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for FluidPressure {
+        type Borrowed<'a> = FluidPressureRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6488,6 +9926,77 @@ uint32 nanosec"####;
             0xa7, 0x32, 0xa2, 0x2d,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Illuminance_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Illuminance {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Illuminance {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#illuminance);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#illuminance: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Illuminance], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct IlluminanceRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#illuminance: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for IlluminanceRef<'a> {
+        type Owned = Illuminance;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#illuminance: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Illuminance {
+            Illuminance {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#illuminance: self.r#illuminance,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Illuminance {
+        type Borrowed<'a> = IlluminanceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6582,6 +10091,97 @@ uint32 nanosec"####;
             0x82, 0x52, 0x7d, 0x47,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Image_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Image {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Image {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put_str(&self.r#encoding);
+            writer.put(self.r#is_bigendian);
+            writer.put(self.r#step);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#encoding: reader.get_string()?,
+                r#is_bigendian: reader.get()?,
+                r#step: reader.get()?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Image], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImageRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#height: u32,
+        pub r#width: u32,
+        pub r#encoding: &'a str,
+        pub r#is_bigendian: u8,
+        pub r#step: u32,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ImageRef<'a> {
+        type Owned = Image;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#encoding: reader.get_str()?,
+                r#is_bigendian: reader.get()?,
+                r#step: reader.get()?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Image {
+            Image {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#height: self.r#height,
+                r#width: self.r#width,
+                r#encoding: self.r#encoding.to_owned(),
+                r#is_bigendian: self.r#is_bigendian,
+                r#step: self.r#step,
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Image {
+        type Borrowed<'a> = ImageRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6692,6 +10292,107 @@ uint32 nanosec"####;
             0x74, 0x00, 0x0b, 0x5b,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Imu_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Imu {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Imu {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#orientation, writer);
+            writer.put_slice(&self.r#orientation_covariance);
+            ::roslibrust::codec::WireCodec::encode(&self.r#angular_velocity, writer);
+            writer.put_slice(&self.r#angular_velocity_covariance);
+            ::roslibrust::codec::WireCodec::encode(&self.r#linear_acceleration, writer);
+            writer.put_slice(&self.r#linear_acceleration_covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation_covariance: reader.get_array()?,
+                r#angular_velocity: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angular_velocity_covariance: reader.get_array()?,
+                r#linear_acceleration: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#linear_acceleration_covariance: reader.get_array()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Imu], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImuRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#orientation: geometry_msgs::QuaternionRef<'a>,
+        pub r#orientation_covariance: [f64; 9],
+        pub r#angular_velocity: geometry_msgs::Vector3Ref<'a>,
+        pub r#angular_velocity_covariance: [f64; 9],
+        pub r#linear_acceleration: geometry_msgs::Vector3Ref<'a>,
+        pub r#linear_acceleration_covariance: [f64; 9],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ImuRef<'a> {
+        type Owned = Imu;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#orientation_covariance: reader.get_array()?,
+                r#angular_velocity: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(
+                    reader,
+                )?,
+                r#angular_velocity_covariance: reader.get_array()?,
+                r#linear_acceleration: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(
+                    reader,
+                )?,
+                r#linear_acceleration_covariance: reader.get_array()?,
+            })
+        }
+        fn to_owned_message(&self) -> Imu {
+            Imu {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#orientation,
+                ),
+                r#orientation_covariance: self.r#orientation_covariance,
+                r#angular_velocity: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#angular_velocity,
+                ),
+                r#angular_velocity_covariance: self.r#angular_velocity_covariance,
+                r#linear_acceleration: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#linear_acceleration,
+                ),
+                r#linear_acceleration_covariance: self.r#linear_acceleration_covariance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Imu {
+        type Borrowed<'a> = ImuRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6782,6 +10483,87 @@ uint32 nanosec"####;
             0x91, 0x79, 0x26, 0x0e,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::JointState_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for JointState {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JointState {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#name, |writer, item| writer.put_str(item));
+            writer.put_seq(&self.r#position);
+            writer.put_seq(&self.r#velocity);
+            writer.put_seq(&self.r#effort);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#name: reader.get_seq_with(|reader| reader.get_string())?,
+                r#position: reader.get_seq()?,
+                r#velocity: reader.get_seq()?,
+                r#effort: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [JointState], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JointStateRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#name: ::std::vec::Vec<&'a str>,
+        pub r#position: ::std::vec::Vec<f64>,
+        pub r#velocity: ::std::vec::Vec<f64>,
+        pub r#effort: ::std::vec::Vec<f64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JointStateRef<'a> {
+        type Owned = JointState;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#name: reader.get_seq_with(|reader| reader.get_str())?,
+                r#position: reader.get_seq()?,
+                r#velocity: reader.get_seq()?,
+                r#effort: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> JointState {
+            JointState {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#name: self.r#name.iter().map(|item| (*item).to_owned()).collect(),
+                r#position: self.r#position.clone(),
+                r#velocity: self.r#velocity.clone(),
+                r#effort: self.r#effort.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JointState {
+        type Borrowed<'a> = JointStateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6855,6 +10637,77 @@ uint32 nanosec"####;
             0x98, 0x93, 0x72, 0xc5,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Joy_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Joy {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Joy {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq(&self.r#axes);
+            writer.put_seq(&self.r#buttons);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#axes: reader.get_seq()?,
+                r#buttons: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Joy], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JoyRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#axes: ::std::vec::Vec<f32>,
+        pub r#buttons: ::std::vec::Vec<i32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JoyRef<'a> {
+        type Owned = Joy;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#axes: reader.get_seq()?,
+                r#buttons: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Joy {
+            Joy {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#axes: self.r#axes.clone(),
+                r#buttons: self.r#buttons.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Joy {
+        type Borrowed<'a> = JoyRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6895,6 +10748,8 @@ float32 intensity"####;
             0x83, 0x42, 0x90, 0xcd,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::JoyFeedback_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl JoyFeedback {
@@ -6902,6 +10757,41 @@ float32 intensity"####;
         pub const r#TYPE_RUMBLE: u8 = 1u8;
         pub const r#TYPE_BUZZER: u8 = 2u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JoyFeedback {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#type);
+            writer.put(self.r#id);
+            writer.put(self.r#intensity);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#type: reader.get()?,
+                r#id: reader.get()?,
+                r#intensity: reader.get()?,
+            })
+        }
+    }
+    pub type JoyFeedbackRef<'a> = JoyFeedback;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JoyFeedback {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JoyFeedback {
+        type Borrowed<'a> = JoyFeedbackRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -6943,6 +10833,42 @@ float32 intensity"####;
             0x50, 0x37, 0xbc, 0xaa,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::JoyFeedbackArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JoyFeedbackArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#array, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#array: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    pub type JoyFeedbackArrayRef<'a> = JoyFeedbackArray;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JoyFeedbackArray {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JoyFeedbackArray {
+        type Borrowed<'a> = JoyFeedbackArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -6972,6 +10898,39 @@ float32[] echoes  # Multiple values of ranges or intensities.
             0x58, 0x2b, 0xc9, 0x52,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::LaserEcho_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LaserEcho {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq(&self.r#echoes);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#echoes: reader.get_seq()?,
+            })
+        }
+    }
+    pub type LaserEchoRef<'a> = LaserEcho;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LaserEcho {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LaserEcho {
+        type Borrowed<'a> = LaserEchoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7072,6 +11031,112 @@ uint32 nanosec"####;
             0x92, 0x4b, 0xd8, 0x28,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::LaserScan_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for LaserScan {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for LaserScan {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#angle_min);
+            writer.put(self.r#angle_max);
+            writer.put(self.r#angle_increment);
+            writer.put(self.r#time_increment);
+            writer.put(self.r#scan_time);
+            writer.put(self.r#range_min);
+            writer.put(self.r#range_max);
+            writer.put_seq(&self.r#ranges);
+            writer.put_seq(&self.r#intensities);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader.get_seq()?,
+                r#intensities: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [LaserScan], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LaserScanRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#angle_min: f32,
+        pub r#angle_max: f32,
+        pub r#angle_increment: f32,
+        pub r#time_increment: f32,
+        pub r#scan_time: f32,
+        pub r#range_min: f32,
+        pub r#range_max: f32,
+        pub r#ranges: ::std::vec::Vec<f32>,
+        pub r#intensities: ::std::vec::Vec<f32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for LaserScanRef<'a> {
+        type Owned = LaserScan;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader.get_seq()?,
+                r#intensities: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> LaserScan {
+            LaserScan {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#angle_min: self.r#angle_min,
+                r#angle_max: self.r#angle_max,
+                r#angle_increment: self.r#angle_increment,
+                r#time_increment: self.r#time_increment,
+                r#scan_time: self.r#scan_time,
+                r#range_min: self.r#range_min,
+                r#range_max: self.r#range_max,
+                r#ranges: self.r#ranges.clone(),
+                r#intensities: self.r#intensities.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for LaserScan {
+        type Borrowed<'a> = LaserScanRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7167,6 +11232,79 @@ uint32 nanosec"####;
             0xe0, 0x0e, 0x4d, 0xff,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::MagneticField_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MagneticField {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MagneticField {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#magnetic_field, writer);
+            writer.put_slice(&self.r#magnetic_field_covariance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#magnetic_field: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#magnetic_field_covariance: reader.get_array()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MagneticField], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MagneticFieldRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#magnetic_field: geometry_msgs::Vector3Ref<'a>,
+        pub r#magnetic_field_covariance: [f64; 9],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MagneticFieldRef<'a> {
+        type Owned = MagneticField;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#magnetic_field: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#magnetic_field_covariance: reader.get_array()?,
+            })
+        }
+        fn to_owned_message(&self) -> MagneticField {
+            MagneticField {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#magnetic_field: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#magnetic_field,
+                ),
+                r#magnetic_field_covariance: self.r#magnetic_field_covariance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MagneticField {
+        type Borrowed<'a> = MagneticFieldRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7336,6 +11474,118 @@ uint32 nanosec"####;
             0xb3, 0xbb, 0xc4, 0x0b,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::MultiDOFJointState_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MultiDOFJointState {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiDOFJointState {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#joint_names, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#transforms, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#twist, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#wrench, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_string())?,
+                r#transforms: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#twist: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#wrench: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiDOFJointState], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiDOFJointStateRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#joint_names: ::std::vec::Vec<&'a str>,
+        pub r#transforms: ::std::vec::Vec<geometry_msgs::TransformRef<'a>>,
+        pub r#twist: ::std::vec::Vec<geometry_msgs::TwistRef<'a>>,
+        pub r#wrench: ::std::vec::Vec<geometry_msgs::WrenchRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiDOFJointStateRef<'a> {
+        type Owned = MultiDOFJointState;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_str())?,
+                r#transforms: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#twist: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#wrench: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiDOFJointState {
+            MultiDOFJointState {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#joint_names: self
+                    .r#joint_names
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#transforms: self
+                    .r#transforms
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#twist: self
+                    .r#twist
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#wrench: self
+                    .r#wrench
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiDOFJointState {
+        type Borrowed<'a> = MultiDOFJointStateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7445,6 +11695,130 @@ uint32 nanosec"####;
             0xd0, 0x87, 0xee, 0x48,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::MultiEchoLaserScan_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MultiEchoLaserScan {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiEchoLaserScan {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#angle_min);
+            writer.put(self.r#angle_max);
+            writer.put(self.r#angle_increment);
+            writer.put(self.r#time_increment);
+            writer.put(self.r#scan_time);
+            writer.put(self.r#range_min);
+            writer.put(self.r#range_max);
+            writer.put_seq_with(&self.r#ranges, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#intensities, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#intensities: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiEchoLaserScan], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiEchoLaserScanRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#angle_min: f32,
+        pub r#angle_max: f32,
+        pub r#angle_increment: f32,
+        pub r#time_increment: f32,
+        pub r#scan_time: f32,
+        pub r#range_min: f32,
+        pub r#range_max: f32,
+        pub r#ranges: ::std::vec::Vec<self::LaserEchoRef<'a>>,
+        pub r#intensities: ::std::vec::Vec<self::LaserEchoRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiEchoLaserScanRef<'a> {
+        type Owned = MultiEchoLaserScan;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#angle_min: reader.get()?,
+                r#angle_max: reader.get()?,
+                r#angle_increment: reader.get()?,
+                r#time_increment: reader.get()?,
+                r#scan_time: reader.get()?,
+                r#range_min: reader.get()?,
+                r#range_max: reader.get()?,
+                r#ranges: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#intensities: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiEchoLaserScan {
+            MultiEchoLaserScan {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#angle_min: self.r#angle_min,
+                r#angle_max: self.r#angle_max,
+                r#angle_increment: self.r#angle_increment,
+                r#time_increment: self.r#time_increment,
+                r#scan_time: self.r#scan_time,
+                r#range_min: self.r#range_min,
+                r#range_max: self.r#range_max,
+                r#ranges: self
+                    .r#ranges
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#intensities: self
+                    .r#intensities
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiEchoLaserScan {
+        type Borrowed<'a> = MultiEchoLaserScanRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7581,6 +11955,27 @@ uint32 nanosec"####;
             0x98, 0xa6, 0x54, 0x04,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::NavSatFix_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for NavSatFix {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
     }
     #[allow(unused)]
     impl NavSatFix {
@@ -7589,6 +11984,76 @@ uint32 nanosec"####;
         pub const r#COVARIANCE_TYPE_DIAGONAL_KNOWN: u8 = 2u8;
         pub const r#COVARIANCE_TYPE_KNOWN: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NavSatFix {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#status, writer);
+            writer.put(self.r#latitude);
+            writer.put(self.r#longitude);
+            writer.put(self.r#altitude);
+            writer.put_slice(&self.r#position_covariance);
+            writer.put(self.r#position_covariance_type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#status: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#latitude: reader.get()?,
+                r#longitude: reader.get()?,
+                r#altitude: reader.get()?,
+                r#position_covariance: reader.get_array()?,
+                r#position_covariance_type: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [NavSatFix], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NavSatFixRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#status: self::NavSatStatusRef<'a>,
+        pub r#latitude: f64,
+        pub r#longitude: f64,
+        pub r#altitude: f64,
+        pub r#position_covariance: [f64; 9],
+        pub r#position_covariance_type: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NavSatFixRef<'a> {
+        type Owned = NavSatFix;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#latitude: reader.get()?,
+                r#longitude: reader.get()?,
+                r#altitude: reader.get()?,
+                r#position_covariance: reader.get_array()?,
+                r#position_covariance_type: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> NavSatFix {
+            NavSatFix {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#status: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#status),
+                r#latitude: self.r#latitude,
+                r#longitude: self.r#longitude,
+                r#altitude: self.r#altitude,
+                r#position_covariance: self.r#position_covariance,
+                r#position_covariance_type: self.r#position_covariance_type,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NavSatFix {
+        type Borrowed<'a> = NavSatFixRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -7635,6 +12100,8 @@ uint16 service"####;
             0x09, 0x5a, 0x32, 0x04,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::NavSatStatus_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl NavSatStatus {
@@ -7647,6 +12114,39 @@ uint16 service"####;
         pub const r#SERVICE_COMPASS: u16 = 4u16;
         pub const r#SERVICE_GALILEO: u16 = 8u16;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for NavSatStatus {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#status);
+            writer.put(self.r#service);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#status: reader.get()?,
+                r#service: reader.get()?,
+            })
+        }
+    }
+    pub type NavSatStatusRef<'a> = NavSatStatus;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for NavSatStatus {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for NavSatStatus {
+        type Borrowed<'a> = NavSatStatusRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -7765,6 +12265,95 @@ uint32 nanosec"####;
             0x55, 0xa6, 0x5b, 0x3b,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::PointCloud_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PointCloud {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointCloud {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#channels, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#channels: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointCloud], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointCloudRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#points: ::std::vec::Vec<geometry_msgs::Point32Ref<'a>>,
+        pub r#channels: ::std::vec::Vec<self::ChannelFloat32Ref<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointCloudRef<'a> {
+        type Owned = PointCloud;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#channels: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> PointCloud {
+            PointCloud {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#channels: self
+                    .r#channels
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointCloud {
+        type Borrowed<'a> = PointCloudRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7879,6 +12468,116 @@ uint32 nanosec"####;
             0x24, 0xba, 0xe2, 0xb5,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::PointCloud2_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for PointCloud2 {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointCloud2 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put_seq_with(&self.r#fields, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put(self.r#is_bigendian);
+            writer.put(self.r#point_step);
+            writer.put(self.r#row_step);
+            writer.put_seq(&self.r#data);
+            writer.put(self.r#is_dense);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#fields: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#is_bigendian: reader.get()?,
+                r#point_step: reader.get()?,
+                r#row_step: reader.get()?,
+                r#data: reader.get_seq()?,
+                r#is_dense: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointCloud2], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointCloud2Ref<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#height: u32,
+        pub r#width: u32,
+        pub r#fields: ::std::vec::Vec<self::PointFieldRef<'a>>,
+        pub r#is_bigendian: bool,
+        pub r#point_step: u32,
+        pub r#row_step: u32,
+        pub r#data: &'a [u8],
+        pub r#is_dense: bool,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointCloud2Ref<'a> {
+        type Owned = PointCloud2;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#fields: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#is_bigendian: reader.get()?,
+                r#point_step: reader.get()?,
+                r#row_step: reader.get()?,
+                r#data: reader.get_byte_seq()?,
+                r#is_dense: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> PointCloud2 {
+            PointCloud2 {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#height: self.r#height,
+                r#width: self.r#width,
+                r#fields: self
+                    .r#fields
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#is_bigendian: self.r#is_bigendian,
+                r#point_step: self.r#point_step,
+                r#row_step: self.r#row_step,
+                r#data: self.r#data.to_vec(),
+                r#is_dense: self.r#is_dense,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointCloud2 {
+        type Borrowed<'a> = PointCloud2Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -7922,6 +12621,8 @@ uint32 count     # How many elements in the field"####;
             0x66, 0x37, 0xef, 0x01,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::PointField_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl PointField {
@@ -7934,6 +12635,61 @@ uint32 count     # How many elements in the field"####;
         pub const r#FLOAT32: u8 = 7u8;
         pub const r#FLOAT64: u8 = 8u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for PointField {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            writer.put(self.r#offset);
+            writer.put(self.r#datatype);
+            writer.put(self.r#count);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#offset: reader.get()?,
+                r#datatype: reader.get()?,
+                r#count: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [PointField], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointFieldRef<'a> {
+        pub r#name: &'a str,
+        pub r#offset: u32,
+        pub r#datatype: u8,
+        pub r#count: u32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for PointFieldRef<'a> {
+        type Owned = PointField;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#offset: reader.get()?,
+                r#datatype: reader.get()?,
+                r#count: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> PointField {
+            PointField {
+                r#name: self.r#name.to_owned(),
+                r#offset: self.r#offset,
+                r#datatype: self.r#datatype,
+                r#count: self.r#count,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for PointField {
+        type Borrowed<'a> = PointFieldRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -8038,12 +12794,98 @@ uint32 nanosec"####;
             0x92, 0x89, 0x1e, 0xbd,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Range_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Range {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
     }
     #[allow(unused)]
     impl Range {
         pub const r#ULTRASOUND: u8 = 0u8;
         pub const r#INFRARED: u8 = 1u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Range {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#radiation_type);
+            writer.put(self.r#field_of_view);
+            writer.put(self.r#min_range);
+            writer.put(self.r#max_range);
+            writer.put(self.r#range);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#radiation_type: reader.get()?,
+                r#field_of_view: reader.get()?,
+                r#min_range: reader.get()?,
+                r#max_range: reader.get()?,
+                r#range: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Range], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RangeRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#radiation_type: u8,
+        pub r#field_of_view: f32,
+        pub r#min_range: f32,
+        pub r#max_range: f32,
+        pub r#range: f32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RangeRef<'a> {
+        type Owned = Range;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#radiation_type: reader.get()?,
+                r#field_of_view: reader.get()?,
+                r#min_range: reader.get()?,
+                r#max_range: reader.get()?,
+                r#range: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Range {
+            Range {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#radiation_type: self.r#radiation_type,
+                r#field_of_view: self.r#field_of_view,
+                r#min_range: self.r#min_range,
+                r#max_range: self.r#max_range,
+                r#range: self.r#range,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Range {
+        type Borrowed<'a> = RangeRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -8090,6 +12932,47 @@ bool do_rectify"####;
             0xee, 0xd8, 0xab, 0xb1,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::RegionOfInterest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for RegionOfInterest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#x_offset);
+            writer.put(self.r#y_offset);
+            writer.put(self.r#height);
+            writer.put(self.r#width);
+            writer.put(self.r#do_rectify);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#x_offset: reader.get()?,
+                r#y_offset: reader.get()?,
+                r#height: reader.get()?,
+                r#width: reader.get()?,
+                r#do_rectify: reader.get()?,
+            })
+        }
+    }
+    pub type RegionOfInterestRef<'a> = RegionOfInterest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RegionOfInterest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for RegionOfInterest {
+        type Borrowed<'a> = RegionOfInterestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8166,6 +13049,77 @@ uint32 nanosec"####;
             0x40, 0x13, 0x88, 0x39,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::RelativeHumidity_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for RelativeHumidity {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for RelativeHumidity {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#relative_humidity);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#relative_humidity: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [RelativeHumidity], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RelativeHumidityRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#relative_humidity: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for RelativeHumidityRef<'a> {
+        type Owned = RelativeHumidity;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#relative_humidity: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> RelativeHumidity {
+            RelativeHumidity {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#relative_humidity: self.r#relative_humidity,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for RelativeHumidity {
+        type Borrowed<'a> = RelativeHumidityRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8237,6 +13191,77 @@ uint32 nanosec"####;
             0xd5, 0xba, 0xd4, 0xb8,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::Temperature_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Temperature {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Temperature {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put(self.r#temperature);
+            writer.put(self.r#variance);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#temperature: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Temperature], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TemperatureRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#temperature: f64,
+        pub r#variance: f64,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TemperatureRef<'a> {
+        type Owned = Temperature;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#temperature: reader.get()?,
+                r#variance: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Temperature {
+            Temperature {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#temperature: self.r#temperature,
+                r#variance: self.r#variance,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Temperature {
+        type Borrowed<'a> = TemperatureRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8307,6 +13332,79 @@ uint32 nanosec"####;
             0x1a, 0x8e, 0xfc, 0x99,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::TimeReference_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for TimeReference {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TimeReference {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#time_ref, writer);
+            writer.put_str(&self.r#source);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#time_ref: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#source: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TimeReference], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TimeReferenceRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#time_ref: builtin_interfaces::TimeRef<'a>,
+        pub r#source: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TimeReferenceRef<'a> {
+        type Owned = TimeReference;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#time_ref: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#source: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TimeReference {
+            TimeReference {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#time_ref: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#time_ref,
+                ),
+                r#source: self.r#source.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TimeReference {
+        type Borrowed<'a> = TimeReferenceRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8589,6 +13687,50 @@ uint32 nanosec"####;
             0x7b, 0xae, 0x2a, 0x5d,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::SetCameraInfoRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetCameraInfoRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#camera_info, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#camera_info: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetCameraInfoRequest], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetCameraInfoRequestRef<'a> {
+        pub r#camera_info: self::CameraInfoRef<'a>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetCameraInfoRequestRef<'a> {
+        type Owned = SetCameraInfoRequest;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#camera_info: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+            })
+        }
+        fn to_owned_message(&self) -> SetCameraInfoRequest {
+            SetCameraInfoRequest {
+                r#camera_info: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#camera_info,
+                ),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetCameraInfoRequest {
+        type Borrowed<'a> = SetCameraInfoRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8616,6 +13758,53 @@ string status_message                    # Used to give details about success"##
             0x29, 0x2d, 0x3d, 0x58,
         ];
         const ROS2_TYPE_NAME: &'static str = "sensor_msgs::msg::dds_::SetCameraInfoResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetCameraInfoResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#status_message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#status_message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetCameraInfoResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetCameraInfoResponseRef<'a> {
+        pub r#success: bool,
+        pub r#status_message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetCameraInfoResponseRef<'a> {
+        type Owned = SetCameraInfoResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#status_message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SetCameraInfoResponse {
+            SetCameraInfoResponse {
+                r#success: self.r#success,
+                r#status_message: self.r#status_message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetCameraInfoResponse {
+        type Borrowed<'a> = SetCameraInfoResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetCameraInfo {}
@@ -8707,6 +13896,8 @@ uint32 nanosec"####;
             0x34, 0x5a, 0x6f, 0x45,
         ];
         const ROS2_TYPE_NAME: &'static str = "service_msgs::msg::dds_::ServiceEventInfo_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl ServiceEventInfo {
@@ -8715,6 +13906,43 @@ uint32 nanosec"####;
         pub const r#RESPONSE_SENT: u8 = 2u8;
         pub const r#RESPONSE_RECEIVED: u8 = 3u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ServiceEventInfo {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#event_type);
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            writer.put_slice(&self.r#client_gid);
+            writer.put(self.r#sequence_number);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#event_type: reader.get()?,
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#client_gid: reader.get_array()?,
+                r#sequence_number: reader.get()?,
+            })
+        }
+    }
+    pub type ServiceEventInfoRef<'a> = ServiceEventInfo;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ServiceEventInfo {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ServiceEventInfo {
+        type Borrowed<'a> = ServiceEventInfoRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
 }
 #[allow(unused_imports)]
 pub mod shape_msgs {
@@ -8773,6 +14001,47 @@ uint32[3] vertex_indices"####;
             0xeb, 0xb5, 0x52, 0x89,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::Mesh_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Mesh {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#triangles, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#vertices, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#triangles: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#vertices: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    pub type MeshRef<'a> = Mesh;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Mesh {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Mesh {
+        type Borrowed<'a> = MeshRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8800,6 +14069,39 @@ uint32[3] vertex_indices"####;
             0xa5, 0x02, 0x0b, 0xd0,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::MeshTriangle_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MeshTriangle {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_slice(&self.r#vertex_indices);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#vertex_indices: reader.get_array()?,
+            })
+        }
+    }
+    pub type MeshTriangleRef<'a> = MeshTriangle;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MeshTriangle {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MeshTriangle {
+        type Borrowed<'a> = MeshTriangleRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8831,6 +14133,39 @@ float64[4] coef"####;
             0xf1, 0x6c, 0x58, 0xc8,
         ];
         const ROS2_TYPE_NAME: &'static str = "shape_msgs::msg::dds_::Plane_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Plane {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_slice(&self.r#coef);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#coef: reader.get_array()?,
+            })
+        }
+    }
+    pub type PlaneRef<'a> = Plane;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Plane {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Plane {
+        type Borrowed<'a> = PlaneRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -8845,7 +14180,7 @@ float64[4] coef"####;
     #[serde(crate = "::roslibrust::codegen::serde")]
     pub struct SolidPrimitive {
         pub r#type: u8,
-        pub r#dimensions: ::std::vec::Vec<f64>,
+        pub r#dimensions: ::roslibrust::codegen::BoundedVec<f64, 3>,
         pub r#polygon: geometry_msgs::Polygon,
     }
     impl ::roslibrust::RosMessageType for SolidPrimitive {
@@ -9001,6 +14336,39 @@ bool data"####;
             0x45, 0x28, 0xf6, 0xc9,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Bool_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Bool {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type BoolRef<'a> = Bool;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Bool {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Bool {
+        type Borrowed<'a> = BoolRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9031,6 +14399,39 @@ byte data"####;
             0x80, 0x92, 0xf3, 0x27,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Byte_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Byte {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type ByteRef<'a> = Byte;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Byte {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Byte {
+        type Borrowed<'a> = ByteRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9120,6 +14521,53 @@ uint32 stride  # stride of given dimension"####;
             0xa7, 0x91, 0xdb, 0x7d,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::ByteMultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ByteMultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ByteMultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ByteMultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ByteMultiArrayRef<'a> {
+        type Owned = ByteMultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> ByteMultiArray {
+            ByteMultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ByteMultiArray {
+        type Borrowed<'a> = ByteMultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9150,6 +14598,39 @@ char data"####;
             0xa6, 0xa5, 0x86, 0xa9,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Char_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Char {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type CharRef<'a> = Char;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Char {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Char {
+        type Borrowed<'a> = CharRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9181,6 +14662,45 @@ float32 a"####;
             0xc7, 0x81, 0x3f, 0xe8,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::ColorRGBA_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ColorRGBA {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#r);
+            writer.put(self.r#g);
+            writer.put(self.r#b);
+            writer.put(self.r#a);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#r: reader.get()?,
+                r#g: reader.get()?,
+                r#b: reader.get()?,
+                r#a: reader.get()?,
+            })
+        }
+    }
+    pub type ColorRGBARef<'a> = ColorRGBA;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ColorRGBA {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ColorRGBA {
+        type Borrowed<'a> = ColorRGBARef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9204,6 +14724,35 @@ float32 a"####;
             0x26, 0xa9, 0x63, 0x12,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Empty_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Empty {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type EmptyRef<'a> = Empty;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Empty {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Empty {
+        type Borrowed<'a> = EmptyRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9234,6 +14783,39 @@ float32 data"####;
             0x4d, 0x83, 0xd6, 0xe2,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Float32Ref<'a> = Float32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float32 {
+        type Borrowed<'a> = Float32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9322,6 +14904,53 @@ uint32 stride  # stride of given dimension"####;
             0x1f, 0xa8, 0xbf, 0xe0,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float32MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float32MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Float32MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Float32MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<f32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float32MultiArrayRef<'a> {
+        type Owned = Float32MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Float32MultiArray {
+            Float32MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float32MultiArray {
+        type Borrowed<'a> = Float32MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9352,6 +14981,39 @@ float64 data"####;
             0xe7, 0x90, 0x18, 0x2a,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float64_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float64 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Float64Ref<'a> = Float64;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float64 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float64 {
+        type Borrowed<'a> = Float64Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9440,6 +15102,53 @@ uint32 stride  # stride of given dimension"####;
             0x8c, 0x55, 0x39, 0x7f,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Float64MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Float64MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Float64MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Float64MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<f64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Float64MultiArrayRef<'a> {
+        type Owned = Float64MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Float64MultiArray {
+            Float64MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Float64MultiArray {
+        type Borrowed<'a> = Float64MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9487,6 +15196,53 @@ uint32 nanosec"####;
             0x97, 0xce, 0x6a, 0x01,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Header_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Header {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#stamp, writer);
+            writer.put_str(&self.r#frame_id);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#frame_id: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Header], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct HeaderRef<'a> {
+        pub r#stamp: builtin_interfaces::TimeRef<'a>,
+        pub r#frame_id: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for HeaderRef<'a> {
+        type Owned = Header;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#stamp: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#frame_id: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> Header {
+            Header {
+                r#stamp: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#stamp),
+                r#frame_id: self.r#frame_id.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Header {
+        type Borrowed<'a> = HeaderRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9517,6 +15273,39 @@ int16 data"####;
             0x8e, 0xee, 0x17, 0xe5,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int16_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int16 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int16Ref<'a> = Int16;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int16 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int16 {
+        type Borrowed<'a> = Int16Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9605,6 +15394,53 @@ uint32 stride  # stride of given dimension"####;
             0x52, 0xa0, 0x76, 0xce,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int16MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int16MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int16MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int16MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i16>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int16MultiArrayRef<'a> {
+        type Owned = Int16MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int16MultiArray {
+            Int16MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int16MultiArray {
+        type Borrowed<'a> = Int16MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9635,6 +15471,39 @@ int32 data"####;
             0x1c, 0xef, 0x0d, 0xeb,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int32Ref<'a> = Int32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int32 {
+        type Borrowed<'a> = Int32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9723,6 +15592,53 @@ uint32 stride  # stride of given dimension"####;
             0xde, 0xfd, 0x17, 0x01,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int32MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int32MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int32MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int32MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int32MultiArrayRef<'a> {
+        type Owned = Int32MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int32MultiArray {
+            Int32MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int32MultiArray {
+        type Borrowed<'a> = Int32MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9753,6 +15669,39 @@ int64 data"####;
             0x77, 0x4f, 0x4b, 0x49,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int64_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int64 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int64Ref<'a> = Int64;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int64 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int64 {
+        type Borrowed<'a> = Int64Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9841,6 +15790,53 @@ uint32 stride  # stride of given dimension"####;
             0x1c, 0x89, 0xb4, 0x22,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int64MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int64MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int64MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int64MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int64MultiArrayRef<'a> {
+        type Owned = Int64MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int64MultiArray {
+            Int64MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int64MultiArray {
+        type Borrowed<'a> = Int64MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9871,6 +15867,39 @@ int8 data"####;
             0x3b, 0xd0, 0xf4, 0x40,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int8_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int8 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type Int8Ref<'a> = Int8;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int8 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int8 {
+        type Borrowed<'a> = Int8Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9959,6 +15988,53 @@ uint32 stride  # stride of given dimension"####;
             0x4d, 0x21, 0x88, 0xdb,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::Int8MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Int8MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Int8MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Int8MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<i8>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for Int8MultiArrayRef<'a> {
+        type Owned = Int8MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> Int8MultiArray {
+            Int8MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Int8MultiArray {
+        type Borrowed<'a> = Int8MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -9993,6 +16069,58 @@ uint32 stride  # stride of given dimension"####;
             0x28, 0x2c, 0x9c, 0xbe,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::MultiArrayDimension_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiArrayDimension {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#label);
+            writer.put(self.r#size);
+            writer.put(self.r#stride);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#label: reader.get_string()?,
+                r#size: reader.get()?,
+                r#stride: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiArrayDimension], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiArrayDimensionRef<'a> {
+        pub r#label: &'a str,
+        pub r#size: u32,
+        pub r#stride: u32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiArrayDimensionRef<'a> {
+        type Owned = MultiArrayDimension;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#label: reader.get_str()?,
+                r#size: reader.get()?,
+                r#stride: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiArrayDimension {
+            MultiArrayDimension {
+                r#label: self.r#label.to_owned(),
+                r#size: self.r#size,
+                r#stride: self.r#stride,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiArrayDimension {
+        type Borrowed<'a> = MultiArrayDimensionRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10059,6 +16187,62 @@ uint32 stride  # stride of given dimension"####;
             0xcb, 0x50, 0xda, 0xc6,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::MultiArrayLayout_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiArrayLayout {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#dim, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put(self.r#data_offset);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#dim: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#data_offset: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiArrayLayout], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiArrayLayoutRef<'a> {
+        pub r#dim: ::std::vec::Vec<self::MultiArrayDimensionRef<'a>>,
+        pub r#data_offset: u32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiArrayLayoutRef<'a> {
+        type Owned = MultiArrayLayout;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#dim: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#data_offset: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiArrayLayout {
+            MultiArrayLayout {
+                r#dim: self
+                    .r#dim
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#data_offset: self.r#data_offset,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiArrayLayout {
+        type Borrowed<'a> = MultiArrayLayoutRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10089,6 +16273,48 @@ string data"####;
             0x8f, 0x6b, 0x1a, 0x18,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::String_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for String {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [String], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct StringRef<'a> {
+        pub r#data: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for StringRef<'a> {
+        type Owned = String;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> String {
+            String {
+                r#data: self.r#data.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for String {
+        type Borrowed<'a> = StringRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10119,6 +16345,39 @@ uint16 data"####;
             0x3e, 0x77, 0x57, 0xb1,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt16_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt16 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt16Ref<'a> = UInt16;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt16 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt16 {
+        type Borrowed<'a> = UInt16Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10207,6 +16466,53 @@ uint32 stride  # stride of given dimension"####;
             0x51, 0x29, 0x49, 0x2b,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt16MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt16MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt16MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt16MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<u16>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt16MultiArrayRef<'a> {
+        type Owned = UInt16MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt16MultiArray {
+            UInt16MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt16MultiArray {
+        type Borrowed<'a> = UInt16MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10237,6 +16543,39 @@ uint32 data"####;
             0x35, 0x16, 0xfb, 0x4a,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt32_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt32 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt32Ref<'a> = UInt32;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt32 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt32 {
+        type Borrowed<'a> = UInt32Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10325,6 +16664,53 @@ uint32 stride  # stride of given dimension"####;
             0xe1, 0x6b, 0xb5, 0xb0,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt32MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt32MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt32MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt32MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<u32>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt32MultiArrayRef<'a> {
+        type Owned = UInt32MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt32MultiArray {
+            UInt32MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt32MultiArray {
+        type Borrowed<'a> = UInt32MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10355,6 +16741,39 @@ uint64 data"####;
             0x98, 0xe8, 0xc9, 0x43,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt64_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt64 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt64Ref<'a> = UInt64;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt64 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt64 {
+        type Borrowed<'a> = UInt64Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10443,6 +16862,53 @@ uint32 stride  # stride of given dimension"####;
             0xf9, 0x81, 0xb3, 0x66,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt64MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt64MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt64MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt64MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: ::std::vec::Vec<u64>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt64MultiArrayRef<'a> {
+        type Owned = UInt64MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt64MultiArray {
+            UInt64MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.clone(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt64MultiArray {
+        type Borrowed<'a> = UInt64MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10473,6 +16939,39 @@ uint8 data"####;
             0x2e, 0x72, 0xf0, 0x10,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt8_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt8 {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type UInt8Ref<'a> = UInt8;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt8 {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt8 {
+        type Borrowed<'a> = UInt8Ref<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10562,6 +17061,53 @@ uint32 stride  # stride of given dimension"####;
             0xad, 0xe9, 0xf3, 0x85,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_msgs::msg::dds_::UInt8MultiArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UInt8MultiArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#layout, writer);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [UInt8MultiArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UInt8MultiArrayRef<'a> {
+        pub r#layout: self::MultiArrayLayoutRef<'a>,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UInt8MultiArrayRef<'a> {
+        type Owned = UInt8MultiArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> UInt8MultiArray {
+            UInt8MultiArray {
+                r#layout: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#layout),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UInt8MultiArray {
+        type Borrowed<'a> = UInt8MultiArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -10601,6 +17147,35 @@ pub mod std_srvs {
             0x4d, 0x30, 0x15, 0x32,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::EmptyRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for EmptyRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type EmptyRequestRef<'a> = EmptyRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for EmptyRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for EmptyRequest {
+        type Borrowed<'a> = EmptyRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10624,6 +17199,35 @@ pub mod std_srvs {
             0x7c, 0xbc, 0xc5, 0xf8,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::EmptyResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for EmptyResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type EmptyResponseRef<'a> = EmptyResponse;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for EmptyResponse {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for EmptyResponse {
+        type Borrowed<'a> = EmptyResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Empty {}
@@ -10664,6 +17268,39 @@ pub mod std_srvs {
             0x0a, 0x04, 0x85, 0x97,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::SetBoolRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetBoolRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#data: reader.get()?,
+            })
+        }
+    }
+    pub type SetBoolRequestRef<'a> = SetBoolRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetBoolRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetBoolRequest {
+        type Borrowed<'a> = SetBoolRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10691,6 +17328,53 @@ string message # informational, e.g. for error messages"####;
             0x8a, 0xc5, 0x70, 0x71,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::SetBoolResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for SetBoolResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [SetBoolResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SetBoolResponseRef<'a> {
+        pub r#success: bool,
+        pub r#message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for SetBoolResponseRef<'a> {
+        type Owned = SetBoolResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> SetBoolResponse {
+            SetBoolResponse {
+                r#success: self.r#success,
+                r#message: self.r#message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for SetBoolResponse {
+        type Borrowed<'a> = SetBoolResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct SetBool {}
@@ -10728,6 +17412,35 @@ string message # informational, e.g. for error messages"####;
             0x8f, 0x51, 0xf0, 0xaf,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::TriggerRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TriggerRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type TriggerRequestRef<'a> = TriggerRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TriggerRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TriggerRequest {
+        type Borrowed<'a> = TriggerRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -10755,6 +17468,53 @@ string message # informational, e.g. for error messages"####;
             0xa9, 0xbb, 0xdf, 0xd9,
         ];
         const ROS2_TYPE_NAME: &'static str = "std_srvs::msg::dds_::TriggerResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for TriggerResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#success);
+            writer.put_str(&self.r#message);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [TriggerResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TriggerResponseRef<'a> {
+        pub r#success: bool,
+        pub r#message: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for TriggerResponseRef<'a> {
+        type Owned = TriggerResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#success: reader.get()?,
+                r#message: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> TriggerResponse {
+            TriggerResponse {
+                r#success: self.r#success,
+                r#message: self.r#message.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for TriggerResponse {
+        type Borrowed<'a> = TriggerResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct Trigger {}
@@ -10968,6 +17728,104 @@ uint32 nanosec"####;
             0x5e, 0xcf, 0x27, 0x11,
         ];
         const ROS2_TYPE_NAME: &'static str = "stereo_msgs::msg::dds_::DisparityImage_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for DisparityImage {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for DisparityImage {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#image, writer);
+            writer.put(self.r#f);
+            writer.put(self.r#t);
+            ::roslibrust::codec::WireCodec::encode(&self.r#valid_window, writer);
+            writer.put(self.r#min_disparity);
+            writer.put(self.r#max_disparity);
+            writer.put(self.r#delta_d);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#image: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#f: reader.get()?,
+                r#t: reader.get()?,
+                r#valid_window: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#min_disparity: reader.get()?,
+                r#max_disparity: reader.get()?,
+                r#delta_d: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [DisparityImage], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DisparityImageRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#image: sensor_msgs::ImageRef<'a>,
+        pub r#f: f32,
+        pub r#t: f32,
+        pub r#valid_window: sensor_msgs::RegionOfInterestRef<'a>,
+        pub r#min_disparity: f32,
+        pub r#max_disparity: f32,
+        pub r#delta_d: f32,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for DisparityImageRef<'a> {
+        type Owned = DisparityImage;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#image: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#f: reader.get()?,
+                r#t: reader.get()?,
+                r#valid_window: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#min_disparity: reader.get()?,
+                r#max_disparity: reader.get()?,
+                r#delta_d: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> DisparityImage {
+            DisparityImage {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#image: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#image),
+                r#f: self.r#f,
+                r#t: self.r#t,
+                r#valid_window: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#valid_window,
+                ),
+                r#min_disparity: self.r#min_disparity,
+                r#max_disparity: self.r#max_disparity,
+                r#delta_d: self.r#delta_d,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for DisparityImage {
+        type Borrowed<'a> = DisparityImageRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -11114,6 +17972,90 @@ uint32 nanosec"####;
             0xe2, 0x13, 0x79, 0x6e,
         ];
         const ROS2_TYPE_NAME: &'static str = "trajectory_msgs::msg::dds_::JointTrajectory_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for JointTrajectory {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JointTrajectory {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#joint_names, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_string())?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [JointTrajectory], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JointTrajectoryRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#joint_names: ::std::vec::Vec<&'a str>,
+        pub r#points: ::std::vec::Vec<self::JointTrajectoryPointRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JointTrajectoryRef<'a> {
+        type Owned = JointTrajectory;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_str())?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> JointTrajectory {
+            JointTrajectory {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#joint_names: self
+                    .r#joint_names
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JointTrajectory {
+        type Borrowed<'a> = JointTrajectoryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11182,6 +18124,47 @@ uint32 nanosec"####;
             0xcf, 0xa5, 0x32, 0xd7,
         ];
         const ROS2_TYPE_NAME: &'static str = "trajectory_msgs::msg::dds_::JointTrajectoryPoint_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for JointTrajectoryPoint {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq(&self.r#positions);
+            writer.put_seq(&self.r#velocities);
+            writer.put_seq(&self.r#accelerations);
+            writer.put_seq(&self.r#effort);
+            ::roslibrust::codec::WireCodec::encode(&self.r#time_from_start, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#positions: reader.get_seq()?,
+                r#velocities: reader.get_seq()?,
+                r#accelerations: reader.get_seq()?,
+                r#effort: reader.get_seq()?,
+                r#time_from_start: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type JointTrajectoryPointRef<'a> = JointTrajectoryPoint;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for JointTrajectoryPoint {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for JointTrajectoryPoint {
+        type Borrowed<'a> = JointTrajectoryPointRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11418,6 +18401,90 @@ float64 z"####;
             0x2b, 0x44, 0x3a, 0x4a,
         ];
         const ROS2_TYPE_NAME: &'static str = "trajectory_msgs::msg::dds_::MultiDOFJointTrajectory_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for MultiDOFJointTrajectory {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiDOFJointTrajectory {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_seq_with(&self.r#joint_names, |writer, item| writer.put_str(item));
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_string())?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MultiDOFJointTrajectory], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MultiDOFJointTrajectoryRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#joint_names: ::std::vec::Vec<&'a str>,
+        pub r#points: ::std::vec::Vec<self::MultiDOFJointTrajectoryPointRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiDOFJointTrajectoryRef<'a> {
+        type Owned = MultiDOFJointTrajectory;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#joint_names: reader.get_seq_with(|reader| reader.get_str())?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MultiDOFJointTrajectory {
+            MultiDOFJointTrajectory {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#joint_names: self
+                    .r#joint_names
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiDOFJointTrajectory {
+        type Borrowed<'a> = MultiDOFJointTrajectoryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -11532,6 +18599,54 @@ float64 z"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "trajectory_msgs::msg::dds_::MultiDOFJointTrajectoryPoint_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MultiDOFJointTrajectoryPoint {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#transforms, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#velocities, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#accelerations, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            ::roslibrust::codec::WireCodec::encode(&self.r#time_from_start, writer);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#transforms: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#velocities: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#accelerations: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#time_from_start: ::roslibrust::codec::WireCodec::decode(reader)?,
+            })
+        }
+    }
+    pub type MultiDOFJointTrajectoryPointRef<'a> = MultiDOFJointTrajectoryPoint;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MultiDOFJointTrajectoryPoint {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MultiDOFJointTrajectoryPoint {
+        type Borrowed<'a> = MultiDOFJointTrajectoryPointRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
 }
 #[allow(unused_imports)]
@@ -11683,6 +18798,27 @@ uint32 nanosec"####;
             0x15, 0xd1, 0x18, 0x36,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::ImageMarker_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for ImageMarker {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
     }
     #[allow(unused)]
     impl ImageMarker {
@@ -11694,6 +18830,132 @@ uint32 nanosec"####;
         pub const r#ADD: i32 = 0i32;
         pub const r#REMOVE: i32 = 1i32;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for ImageMarker {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#ns);
+            writer.put(self.r#id);
+            writer.put(self.r#type);
+            writer.put(self.r#action);
+            ::roslibrust::codec::WireCodec::encode(&self.r#position, writer);
+            writer.put(self.r#scale);
+            ::roslibrust::codec::WireCodec::encode(&self.r#outline_color, writer);
+            writer.put(self.r#filled);
+            ::roslibrust::codec::WireCodec::encode(&self.r#fill_color, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#lifetime, writer);
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#outline_colors, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ns: reader.get_string()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#position: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#scale: reader.get()?,
+                r#outline_color: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#filled: reader.get()?,
+                r#fill_color: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#lifetime: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#outline_colors: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [ImageMarker], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ImageMarkerRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#ns: &'a str,
+        pub r#id: i32,
+        pub r#type: i32,
+        pub r#action: i32,
+        pub r#position: geometry_msgs::PointRef<'a>,
+        pub r#scale: f32,
+        pub r#outline_color: std_msgs::ColorRGBARef<'a>,
+        pub r#filled: u8,
+        pub r#fill_color: std_msgs::ColorRGBARef<'a>,
+        pub r#lifetime: builtin_interfaces::DurationRef<'a>,
+        pub r#points: ::std::vec::Vec<geometry_msgs::PointRef<'a>>,
+        pub r#outline_colors: ::std::vec::Vec<std_msgs::ColorRGBARef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for ImageMarkerRef<'a> {
+        type Owned = ImageMarker;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#ns: reader.get_str()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#position: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#scale: reader.get()?,
+                r#outline_color: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#filled: reader.get()?,
+                r#fill_color: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#lifetime: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#outline_colors: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> ImageMarker {
+            ImageMarker {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#ns: self.r#ns.to_owned(),
+                r#id: self.r#id,
+                r#type: self.r#type,
+                r#action: self.r#action,
+                r#position: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#position,
+                ),
+                r#scale: self.r#scale,
+                r#outline_color: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#outline_color,
+                ),
+                r#filled: self.r#filled,
+                r#fill_color: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#fill_color,
+                ),
+                r#lifetime: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#lifetime,
+                ),
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#outline_colors: self
+                    .r#outline_colors
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for ImageMarker {
+        type Borrowed<'a> = ImageMarkerRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -12732,6 +19994,115 @@ float32 v"####;
             0xb7, 0x2b, 0xe3, 0x46,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::InteractiveMarker_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InteractiveMarker {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarker {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put_str(&self.r#name);
+            writer.put_str(&self.r#description);
+            writer.put(self.r#scale);
+            writer.put_seq_with(&self.r#menu_entries, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#controls, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#name: reader.get_string()?,
+                r#description: reader.get_string()?,
+                r#scale: reader.get()?,
+                r#menu_entries: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#controls: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarker], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#name: &'a str,
+        pub r#description: &'a str,
+        pub r#scale: f32,
+        pub r#menu_entries: ::std::vec::Vec<self::MenuEntryRef<'a>>,
+        pub r#controls: ::std::vec::Vec<self::InteractiveMarkerControlRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerRef<'a> {
+        type Owned = InteractiveMarker;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#name: reader.get_str()?,
+                r#description: reader.get_str()?,
+                r#scale: reader.get()?,
+                r#menu_entries: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#controls: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarker {
+            InteractiveMarker {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#name: self.r#name.to_owned(),
+                r#description: self.r#description.to_owned(),
+                r#scale: self.r#scale,
+                r#menu_entries: self
+                    .r#menu_entries
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#controls: self
+                    .r#controls
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarker {
+        type Borrowed<'a> = InteractiveMarkerRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -13265,6 +20636,8 @@ float32 v"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerControl_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl InteractiveMarkerControl {
@@ -13282,6 +20655,92 @@ float32 v"####;
         pub const r#ROTATE_3D: u8 = 8u8;
         pub const r#MOVE_ROTATE_3D: u8 = 9u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerControl {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#name);
+            ::roslibrust::codec::WireCodec::encode(&self.r#orientation, writer);
+            writer.put(self.r#orientation_mode);
+            writer.put(self.r#interaction_mode);
+            writer.put(self.r#always_visible);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put(self.r#independent_marker_orientation);
+            writer.put_str(&self.r#description);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_string()?,
+                r#orientation: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#orientation_mode: reader.get()?,
+                r#interaction_mode: reader.get()?,
+                r#always_visible: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#independent_marker_orientation: reader.get()?,
+                r#description: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerControl], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerControlRef<'a> {
+        pub r#name: &'a str,
+        pub r#orientation: geometry_msgs::QuaternionRef<'a>,
+        pub r#orientation_mode: u8,
+        pub r#interaction_mode: u8,
+        pub r#always_visible: bool,
+        pub r#markers: ::std::vec::Vec<self::MarkerRef<'a>>,
+        pub r#independent_marker_orientation: bool,
+        pub r#description: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerControlRef<'a> {
+        type Owned = InteractiveMarkerControl;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#name: reader.get_str()?,
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#orientation_mode: reader.get()?,
+                r#interaction_mode: reader.get()?,
+                r#always_visible: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#independent_marker_orientation: reader.get()?,
+                r#description: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerControl {
+            InteractiveMarkerControl {
+                r#name: self.r#name.to_owned(),
+                r#orientation: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#orientation,
+                ),
+                r#orientation_mode: self.r#orientation_mode,
+                r#interaction_mode: self.r#interaction_mode,
+                r#always_visible: self.r#always_visible,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#independent_marker_orientation: self.r#independent_marker_orientation,
+                r#description: self.r#description.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerControl {
+        type Borrowed<'a> = InteractiveMarkerControlRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -13427,6 +20886,27 @@ uint32 nanosec"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerFeedback_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InteractiveMarkerFeedback {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
     }
     #[allow(unused)]
     impl InteractiveMarkerFeedback {
@@ -13437,6 +20917,88 @@ uint32 nanosec"####;
         pub const r#MOUSE_DOWN: u8 = 4u8;
         pub const r#MOUSE_UP: u8 = 5u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerFeedback {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#client_id);
+            writer.put_str(&self.r#marker_name);
+            writer.put_str(&self.r#control_name);
+            writer.put(self.r#event_type);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put(self.r#menu_entry_id);
+            ::roslibrust::codec::WireCodec::encode(&self.r#mouse_point, writer);
+            writer.put(self.r#mouse_point_valid);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#client_id: reader.get_string()?,
+                r#marker_name: reader.get_string()?,
+                r#control_name: reader.get_string()?,
+                r#event_type: reader.get()?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#menu_entry_id: reader.get()?,
+                r#mouse_point: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#mouse_point_valid: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerFeedback], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerFeedbackRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#client_id: &'a str,
+        pub r#marker_name: &'a str,
+        pub r#control_name: &'a str,
+        pub r#event_type: u8,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#menu_entry_id: u32,
+        pub r#mouse_point: geometry_msgs::PointRef<'a>,
+        pub r#mouse_point_valid: bool,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerFeedbackRef<'a> {
+        type Owned = InteractiveMarkerFeedback;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#client_id: reader.get_str()?,
+                r#marker_name: reader.get_str()?,
+                r#control_name: reader.get_str()?,
+                r#event_type: reader.get()?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#menu_entry_id: reader.get()?,
+                r#mouse_point: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#mouse_point_valid: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerFeedback {
+            InteractiveMarkerFeedback {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#client_id: self.r#client_id.to_owned(),
+                r#marker_name: self.r#marker_name.to_owned(),
+                r#control_name: self.r#control_name.to_owned(),
+                r#event_type: self.r#event_type,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#menu_entry_id: self.r#menu_entry_id,
+                r#mouse_point: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#mouse_point,
+                ),
+                r#mouse_point_valid: self.r#mouse_point_valid,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerFeedback {
+        type Borrowed<'a> = InteractiveMarkerFeedbackRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -15471,6 +23033,67 @@ float32 v"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerInit_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerInit {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#server_id);
+            writer.put(self.r#seq_num);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_string()?,
+                r#seq_num: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerInit], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerInitRef<'a> {
+        pub r#server_id: &'a str,
+        pub r#seq_num: u64,
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarkerRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerInitRef<'a> {
+        type Owned = InteractiveMarkerInit;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_str()?,
+                r#seq_num: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerInit {
+            InteractiveMarkerInit {
+                r#server_id: self.r#server_id.to_owned(),
+                r#seq_num: self.r#seq_num,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerInit {
+        type Borrowed<'a> = InteractiveMarkerInitRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -15578,6 +23201,77 @@ uint32 nanosec"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerPose_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for InteractiveMarkerPose {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerPose {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            writer.put_str(&self.r#name);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#name: reader.get_string()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerPose], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerPoseRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#name: &'a str,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerPoseRef<'a> {
+        type Owned = InteractiveMarkerPose;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#name: reader.get_str()?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerPose {
+            InteractiveMarkerPose {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#name: self.r#name.to_owned(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerPose {
+        type Borrowed<'a> = InteractiveMarkerPoseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -17715,12 +25409,101 @@ float32 v"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::InteractiveMarkerUpdate_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl InteractiveMarkerUpdate {
         pub const r#KEEP_ALIVE: u8 = 0u8;
         pub const r#UPDATE: u8 = 1u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for InteractiveMarkerUpdate {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#server_id);
+            writer.put(self.r#seq_num);
+            writer.put(self.r#type);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#poses, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#erases, |writer, item| writer.put_str(item));
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_string()?,
+                r#seq_num: reader.get()?,
+                r#type: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#poses: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#erases: reader.get_seq_with(|reader| reader.get_string())?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [InteractiveMarkerUpdate], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InteractiveMarkerUpdateRef<'a> {
+        pub r#server_id: &'a str,
+        pub r#seq_num: u64,
+        pub r#type: u8,
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarkerRef<'a>>,
+        pub r#poses: ::std::vec::Vec<self::InteractiveMarkerPoseRef<'a>>,
+        pub r#erases: ::std::vec::Vec<&'a str>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for InteractiveMarkerUpdateRef<'a> {
+        type Owned = InteractiveMarkerUpdate;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#server_id: reader.get_str()?,
+                r#seq_num: reader.get()?,
+                r#type: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#poses: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#erases: reader.get_seq_with(|reader| reader.get_str())?,
+            })
+        }
+        fn to_owned_message(&self) -> InteractiveMarkerUpdate {
+            InteractiveMarkerUpdate {
+                r#server_id: self.r#server_id.to_owned(),
+                r#seq_num: self.r#seq_num,
+                r#type: self.r#type,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#poses: self
+                    .r#poses
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#erases: self
+                    .r#erases
+                    .iter()
+                    .map(|item| (*item).to_owned())
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for InteractiveMarkerUpdate {
+        type Borrowed<'a> = InteractiveMarkerUpdateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -18013,6 +25796,27 @@ float32 v"####;
             0x2b, 0x4f, 0x3c, 0xe0,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::Marker_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    impl ::roslibrust::HasHeader for Marker {
+        type Header = std_msgs::Header;
+        fn header(&self) -> &Self::Header {
+            &self.r#header
+        }
+        fn header_mut(&mut self) -> &mut Self::Header {
+            &mut self.r#header
+        }
+        fn frame_id(&self) -> &str {
+            &self.r#header.frame_id
+        }
+        fn stamp(&self) -> (i32, u32) {
+            (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+        }
+        fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+            self.r#header.stamp.sec = sec;
+            self.r#header.stamp.nanosec = nanosec;
+        }
     }
     #[allow(unused)]
     impl Marker {
@@ -18033,6 +25837,169 @@ float32 v"####;
         pub const r#DELETE: i32 = 2i32;
         pub const r#DELETEALL: i32 = 3i32;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for Marker {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            ::roslibrust::codec::WireCodec::encode(&self.r#header, writer);
+            writer.put_str(&self.r#ns);
+            writer.put(self.r#id);
+            writer.put(self.r#type);
+            writer.put(self.r#action);
+            ::roslibrust::codec::WireCodec::encode(&self.r#pose, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#scale, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#color, writer);
+            ::roslibrust::codec::WireCodec::encode(&self.r#lifetime, writer);
+            writer.put(self.r#frame_locked);
+            writer.put_seq_with(&self.r#points, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_seq_with(&self.r#colors, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_str(&self.r#texture_resource);
+            ::roslibrust::codec::WireCodec::encode(&self.r#texture, writer);
+            writer.put_seq_with(&self.r#uv_coordinates, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+            writer.put_str(&self.r#text);
+            writer.put_str(&self.r#mesh_resource);
+            ::roslibrust::codec::WireCodec::encode(&self.r#mesh_file, writer);
+            writer.put(self.r#mesh_use_embedded_materials);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#ns: reader.get_string()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#pose: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#scale: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#color: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#lifetime: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#frame_locked: reader.get()?,
+                r#points: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#colors: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#texture_resource: reader.get_string()?,
+                r#texture: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#uv_coordinates: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+                r#text: reader.get_string()?,
+                r#mesh_resource: reader.get_string()?,
+                r#mesh_file: ::roslibrust::codec::WireCodec::decode(reader)?,
+                r#mesh_use_embedded_materials: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [Marker], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MarkerRef<'a> {
+        pub r#header: std_msgs::HeaderRef<'a>,
+        pub r#ns: &'a str,
+        pub r#id: i32,
+        pub r#type: i32,
+        pub r#action: i32,
+        pub r#pose: geometry_msgs::PoseRef<'a>,
+        pub r#scale: geometry_msgs::Vector3Ref<'a>,
+        pub r#color: std_msgs::ColorRGBARef<'a>,
+        pub r#lifetime: builtin_interfaces::DurationRef<'a>,
+        pub r#frame_locked: bool,
+        pub r#points: ::std::vec::Vec<geometry_msgs::PointRef<'a>>,
+        pub r#colors: ::std::vec::Vec<std_msgs::ColorRGBARef<'a>>,
+        pub r#texture_resource: &'a str,
+        pub r#texture: sensor_msgs::CompressedImageRef<'a>,
+        pub r#uv_coordinates: ::std::vec::Vec<self::UVCoordinateRef<'a>>,
+        pub r#text: &'a str,
+        pub r#mesh_resource: &'a str,
+        pub r#mesh_file: self::MeshFileRef<'a>,
+        pub r#mesh_use_embedded_materials: bool,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MarkerRef<'a> {
+        type Owned = Marker;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#ns: reader.get_str()?,
+                r#id: reader.get()?,
+                r#type: reader.get()?,
+                r#action: reader.get()?,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#scale: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#color: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#lifetime: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#frame_locked: reader.get()?,
+                r#points: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#colors: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#texture_resource: reader.get_str()?,
+                r#texture: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#uv_coordinates: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+                r#text: reader.get_str()?,
+                r#mesh_resource: reader.get_str()?,
+                r#mesh_file: ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)?,
+                r#mesh_use_embedded_materials: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> Marker {
+            Marker {
+                r#header: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#header),
+                r#ns: self.r#ns.to_owned(),
+                r#id: self.r#id,
+                r#type: self.r#type,
+                r#action: self.r#action,
+                r#pose: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#pose),
+                r#scale: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#scale),
+                r#color: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(&self.r#color),
+                r#lifetime: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#lifetime,
+                ),
+                r#frame_locked: self.r#frame_locked,
+                r#points: self
+                    .r#points
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#colors: self
+                    .r#colors
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#texture_resource: self.r#texture_resource.to_owned(),
+                r#texture: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#texture,
+                ),
+                r#uv_coordinates: self
+                    .r#uv_coordinates
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+                r#text: self.r#text.to_owned(),
+                r#mesh_resource: self.r#mesh_resource.to_owned(),
+                r#mesh_file: ::roslibrust::codec::WireDecodeBorrowed::to_owned_message(
+                    &self.r#mesh_file,
+                ),
+                r#mesh_use_embedded_materials: self.r#mesh_use_embedded_materials,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for Marker {
+        type Borrowed<'a> = MarkerRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -18481,6 +26448,57 @@ float32 v"####;
             0x1d, 0x06, 0x7d, 0xcf,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::MarkerArray_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MarkerArray {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MarkerArray], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MarkerArrayRef<'a> {
+        pub r#markers: ::std::vec::Vec<self::MarkerRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MarkerArrayRef<'a> {
+        type Owned = MarkerArray;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> MarkerArray {
+            MarkerArray {
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MarkerArray {
+        type Borrowed<'a> = MarkerArrayRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -18563,6 +26581,8 @@ uint8 command_type"####;
             0xc6, 0x4c, 0x51, 0xd6,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::MenuEntry_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
     }
     #[allow(unused)]
     impl MenuEntry {
@@ -18570,6 +26590,66 @@ uint8 command_type"####;
         pub const r#ROSRUN: u8 = 1u8;
         pub const r#ROSLAUNCH: u8 = 2u8;
     }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MenuEntry {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#id);
+            writer.put(self.r#parent_id);
+            writer.put_str(&self.r#title);
+            writer.put_str(&self.r#command);
+            writer.put(self.r#command_type);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get()?,
+                r#parent_id: reader.get()?,
+                r#title: reader.get_string()?,
+                r#command: reader.get_string()?,
+                r#command_type: reader.get()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MenuEntry], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MenuEntryRef<'a> {
+        pub r#id: u32,
+        pub r#parent_id: u32,
+        pub r#title: &'a str,
+        pub r#command: &'a str,
+        pub r#command_type: u8,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MenuEntryRef<'a> {
+        type Owned = MenuEntry;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#id: reader.get()?,
+                r#parent_id: reader.get()?,
+                r#title: reader.get_str()?,
+                r#command: reader.get_str()?,
+                r#command_type: reader.get()?,
+            })
+        }
+        fn to_owned_message(&self) -> MenuEntry {
+            MenuEntry {
+                r#id: self.r#id,
+                r#parent_id: self.r#parent_id,
+                r#title: self.r#title.to_owned(),
+                r#command: self.r#command.to_owned(),
+                r#command_type: self.r#command_type,
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MenuEntry {
+        type Borrowed<'a> = MenuEntryRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
+    }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
     #[derive(
@@ -18603,6 +26683,53 @@ uint8[] data"####;
             0x35, 0x08, 0xf8, 0xc4,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::MeshFile_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for MeshFile {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put_str(&self.r#filename);
+            writer.put_seq(&self.r#data);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#filename: reader.get_string()?,
+                r#data: reader.get_seq()?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [MeshFile], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct MeshFileRef<'a> {
+        pub r#filename: &'a str,
+        pub r#data: &'a [u8],
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for MeshFileRef<'a> {
+        type Owned = MeshFile;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#filename: reader.get_str()?,
+                r#data: reader.get_byte_seq()?,
+            })
+        }
+        fn to_owned_message(&self) -> MeshFile {
+            MeshFile {
+                r#filename: self.r#filename.to_owned(),
+                r#data: self.r#data.to_vec(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for MeshFile {
+        type Borrowed<'a> = MeshFileRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -18632,6 +26759,41 @@ float32 v"####;
             0x0b, 0x19, 0x6a, 0x26,
         ];
         const ROS2_TYPE_NAME: &'static str = "visualization_msgs::msg::dds_::UVCoordinate_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for UVCoordinate {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#u);
+            writer.put(self.r#v);
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#u: reader.get()?,
+                r#v: reader.get()?,
+            })
+        }
+    }
+    pub type UVCoordinateRef<'a> = UVCoordinate;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for UVCoordinate {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for UVCoordinate {
+        type Borrowed<'a> = UVCoordinateRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -18656,6 +26818,35 @@ float32 v"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::GetInteractiveMarkersRequest_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetInteractiveMarkersRequest {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {}
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {})
+        }
+    }
+    pub type GetInteractiveMarkersRequestRef<'a> = GetInteractiveMarkersRequest;
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetInteractiveMarkersRequest {
+        type Owned = Self;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::roslibrust::codec::WireCodec::decode(reader)
+        }
+        fn to_owned_message(&self) -> Self {
+            self.clone()
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetInteractiveMarkersRequest {
+        type Borrowed<'a> = GetInteractiveMarkersRequestRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(non_snake_case)]
     #[allow(dead_code)]
@@ -20684,6 +28875,62 @@ float32 v"####;
         ];
         const ROS2_TYPE_NAME: &'static str =
             "visualization_msgs::msg::dds_::GetInteractiveMarkersResponse_";
+        const WIRE_CODEC: ::std::option::Option<::roslibrust::codec::WireCodecFns<Self>> =
+            ::std::option::Option::Some(::roslibrust::codec::WireCodecFns::of());
+    }
+    #[allow(unused_variables)]
+    impl ::roslibrust::codec::WireCodec for GetInteractiveMarkersResponse {
+        fn encode(&self, writer: &mut ::roslibrust::codec::WireWriter<'_>) {
+            writer.put(self.r#sequence_number);
+            writer.put_seq_with(&self.r#markers, |writer, item| {
+                ::roslibrust::codec::WireCodec::encode(item, writer)
+            });
+        }
+        fn decode(
+            reader: &mut ::roslibrust::codec::WireReader<'_>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sequence_number: reader.get()?,
+                r#markers: reader
+                    .get_seq_with(|reader| ::roslibrust::codec::WireCodec::decode(reader))?,
+            })
+        }
+    }
+    #[doc = "Borrowed variant of [GetInteractiveMarkersResponse], strings and byte sequences point into the buffer it was decoded from"]
+    #[allow(non_snake_case)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GetInteractiveMarkersResponseRef<'a> {
+        pub r#sequence_number: u64,
+        pub r#markers: ::std::vec::Vec<self::InteractiveMarkerRef<'a>>,
+    }
+    impl<'a> ::roslibrust::codec::WireDecodeBorrowed<'a> for GetInteractiveMarkersResponseRef<'a> {
+        type Owned = GetInteractiveMarkersResponse;
+        fn decode_borrowed(
+            reader: &mut ::roslibrust::codec::WireReader<'a>,
+        ) -> ::std::result::Result<Self, ::roslibrust::codec::WireError> {
+            ::std::result::Result::Ok(Self {
+                r#sequence_number: reader.get()?,
+                r#markers: reader.get_seq_with(|reader| {
+                    ::roslibrust::codec::WireDecodeBorrowed::decode_borrowed(reader)
+                })?,
+            })
+        }
+        fn to_owned_message(&self) -> GetInteractiveMarkersResponse {
+            GetInteractiveMarkersResponse {
+                r#sequence_number: self.r#sequence_number,
+                r#markers: self
+                    .r#markers
+                    .iter()
+                    .map(::roslibrust::codec::WireDecodeBorrowed::to_owned_message)
+                    .collect(),
+            }
+        }
+    }
+    impl ::roslibrust::codec::HasBorrowed for GetInteractiveMarkersResponse {
+        type Borrowed<'a> = GetInteractiveMarkersResponseRef<'a>;
+        fn shorten_borrowed<'b, 'a>(msg: &'b Self::Borrowed<'a>) -> &'b Self::Borrowed<'b> {
+            msg
+        }
     }
     #[allow(dead_code)]
     pub struct GetInteractiveMarkers {}
@@ -51,7 +51,7 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Duration;
 
-use roslibrust_common::{Publish, RosMessageType, Subscribe, TopicProvider};
+use roslibrust_common::{HasHeader, Publish, RosMessageType, Subscribe, TopicProvider};
 use tokio::sync::{broadcast, RwLock};
 use tokio_util::sync::CancellationToken;
 use transforms::time::TimePoint;
@@ -580,6 +580,26 @@ where
     }
 }
 
+impl<M, P, T> TransformManager<M, P, T>
+where
+    M: TFMessageType<T>,
+    P: Publish<M> + Send + Sync,
+    T: RosTimestamp + Send + Sync + 'static,
+{
+    /// Get the transform from the frame of a stamped message into `target_frame`, at the message's stamp.
+    ///
+    /// Shorthand for calling [get_transform](Self::get_transform) with the message's `frame_id` and `stamp`.
+    pub async fn get_transform_for<S: HasHeader>(
+        &self,
+        target_frame: &str,
+        msg: &S,
+    ) -> Result<transforms::Transform<T>, TransformManagerError> {
+        let (sec, nsec) = msg.stamp();
+        self.get_transform(target_frame, msg.frame_id(), T::from_ros_time(sec, nsec))
+            .await
+    }
+}
+
 impl<M, P, T> Drop for TransformManager<M, P, T>
 where
     M: TFMessageType<T>,
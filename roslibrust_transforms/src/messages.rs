@@ -206,6 +206,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::AccelStamped_";
         }
+        impl ::roslibrust::HasHeader for AccelStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -384,6 +409,31 @@ string frame_id"####;
             const ROS2_TYPE_NAME: &'static str =
                 "geometry_msgs::msg::dds_::AccelWithCovarianceStamped_";
         }
+        impl ::roslibrust::HasHeader for AccelWithCovarianceStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -527,6 +577,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::InertiaStamped_";
         }
+        impl ::roslibrust::HasHeader for InertiaStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -643,6 +718,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PointStamped_";
         }
+        impl ::roslibrust::HasHeader for PointStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -755,6 +855,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PolygonStamped_";
         }
+        impl ::roslibrust::HasHeader for PolygonStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -914,6 +1039,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseArray_";
         }
+        impl ::roslibrust::HasHeader for PoseArray {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -990,6 +1140,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseStamped_";
         }
+        impl ::roslibrust::HasHeader for PoseStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1181,6 +1356,31 @@ string frame_id"####;
             const ROS2_TYPE_NAME: &'static str =
                 "geometry_msgs::msg::dds_::PoseWithCovarianceStamped_";
         }
+        impl ::roslibrust::HasHeader for PoseWithCovarianceStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1266,6 +1466,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::QuaternionStamped_";
         }
+        impl ::roslibrust::HasHeader for QuaternionStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1412,6 +1637,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TransformStamped_";
         }
+        impl ::roslibrust::HasHeader for TransformStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1524,6 +1774,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TwistStamped_";
         }
+        impl ::roslibrust::HasHeader for TwistStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1702,6 +1977,31 @@ string frame_id"####;
             const ROS2_TYPE_NAME: &'static str =
                 "geometry_msgs::msg::dds_::TwistWithCovarianceStamped_";
         }
+        impl ::roslibrust::HasHeader for TwistWithCovarianceStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1793,6 +2093,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Vector3Stamped_";
         }
+        impl ::roslibrust::HasHeader for Vector3Stamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -1907,6 +2232,31 @@ string frame_id"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::WrenchStamped_";
         }
+        impl ::roslibrust::HasHeader for WrenchStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.secs, self.r#header.stamp.nsecs as u32)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.secs = sec;
+                self.r#header.stamp.nsecs = nanosec as i32;
+            }
+            fn seq(&self) -> ::std::option::Option<u32> {
+                ::std::option::Option::Some(self.r#header.seq)
+            }
+            fn set_seq(&mut self, seq: u32) {
+                self.r#header.seq = seq;
+            }
+        }
     }
     #[allow(unused_imports)]
     pub mod service_msgs {
@@ -3563,6 +3913,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::AccelStamped_";
         }
+        impl ::roslibrust::HasHeader for AccelStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -3757,6 +4126,25 @@ uint32 nanosec"####;
             const ROS2_TYPE_NAME: &'static str =
                 "geometry_msgs::msg::dds_::AccelWithCovarianceStamped_";
         }
+        impl ::roslibrust::HasHeader for AccelWithCovarianceStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -3921,6 +4309,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::InertiaStamped_";
         }
+        impl ::roslibrust::HasHeader for InertiaStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4060,6 +4467,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PointStamped_";
         }
+        impl ::roslibrust::HasHeader for PointStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4197,6 +4623,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PolygonStamped_";
         }
+        impl ::roslibrust::HasHeader for PolygonStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4377,6 +4822,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseArray_";
         }
+        impl ::roslibrust::HasHeader for PoseArray {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4477,6 +4941,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::PoseStamped_";
         }
+        impl ::roslibrust::HasHeader for PoseStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4693,6 +5176,25 @@ uint32 nanosec"####;
             const ROS2_TYPE_NAME: &'static str =
                 "geometry_msgs::msg::dds_::PoseWithCovarianceStamped_";
         }
+        impl ::roslibrust::HasHeader for PoseWithCovarianceStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4804,6 +5306,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::QuaternionStamped_";
         }
+        impl ::roslibrust::HasHeader for QuaternionStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -4978,6 +5499,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TransformStamped_";
         }
+        impl ::roslibrust::HasHeader for TransformStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -5112,6 +5652,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::TwistStamped_";
         }
+        impl ::roslibrust::HasHeader for TwistStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -5310,6 +5869,25 @@ uint32 nanosec"####;
             const ROS2_TYPE_NAME: &'static str =
                 "geometry_msgs::msg::dds_::TwistWithCovarianceStamped_";
         }
+        impl ::roslibrust::HasHeader for TwistWithCovarianceStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -5425,6 +6003,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::Vector3Stamped_";
         }
+        impl ::roslibrust::HasHeader for Vector3Stamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
         #[allow(non_snake_case)]
         #[allow(dead_code)]
         #[derive(
@@ -5559,6 +6156,25 @@ uint32 nanosec"####;
             ];
             const ROS2_TYPE_NAME: &'static str = "geometry_msgs::msg::dds_::WrenchStamped_";
         }
+        impl ::roslibrust::HasHeader for WrenchStamped {
+            type Header = std_msgs::Header;
+            fn header(&self) -> &Self::Header {
+                &self.r#header
+            }
+            fn header_mut(&mut self) -> &mut Self::Header {
+                &mut self.r#header
+            }
+            fn frame_id(&self) -> &str {
+                &self.r#header.frame_id
+            }
+            fn stamp(&self) -> (i32, u32) {
+                (self.r#header.stamp.sec, self.r#header.stamp.nanosec)
+            }
+            fn set_stamp(&mut self, sec: i32, nanosec: u32) {
+                self.r#header.stamp.sec = sec;
+                self.r#header.stamp.nanosec = nanosec;
+            }
+        }
     }
     #[allow(unused_imports)]
     pub mod service_msgs {
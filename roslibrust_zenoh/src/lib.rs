@@ -1,6 +1,8 @@
 //! A crate for interfacing to ROS1 via the [zenoh-ros1-plugin / zenoh-ros1-bridge](https://github.com/eclipse-zenoh/zenoh-plugin-ros1).
 //!
 //! It is not recommended to depend on this crate directly, but instead access it via [roslibrust](https://docs.rs/roslibrust/latest/roslibrust/) with the `zenoh` feature enabled.
+use roslibrust_common::codec::Bytes;
+use roslibrust_common::graph::{poll_graph_events, Graph, GraphEvent};
 use roslibrust_common::topic_name::{GlobalTopicName, ToGlobalTopicName};
 use roslibrust_common::*;
 
@@ -21,25 +23,42 @@ impl ZenohClient {
     }
 }
 
+/// Converts an error returned by a zenoh operation on `session` into our error type.
+///
+/// Zenoh's errors are untyped, so the only thing we can reliably tell apart is the session having been closed.
+fn map_zenoh_error(session: &zenoh::Session, context: &str, e: zenoh::Error) -> Error {
+    if session.is_closed() {
+        Error::Disconnected
+    } else {
+        Error::Unexpected(anyhow::anyhow!("{context}: {e}"))
+    }
+}
+
 /// The publisher type returned by [TopicProvider::advertise] on [ZenohClient]
 /// This type is self de-registering, and dropping the publisher will automatically un-advertise the topic.
 pub struct ZenohPublisher<T> {
+    session: zenoh::Session,
     publisher: zenoh::pubsub::Publisher<'static>,
     _marker: std::marker::PhantomData<T>,
 }
 
 impl<T: RosMessageType> Publish<T> for ZenohPublisher<T> {
     async fn publish(&self, data: &T) -> Result<()> {
-        let bytes = roslibrust_serde_rosmsg::to_vec_skip_length(data).map_err(|e| {
-            Error::SerializationError(format!("Failed to serialize message: {e:?}"))
-        })?;
+        let bytes = match T::WIRE_CODEC {
+            Some(codec) => {
+                let mut buf = roslibrust_common::codec::BytesMut::new();
+                codec.encode_ros1_into(data, &mut buf);
+                Vec::from(buf)
+            }
+            None => roslibrust_serde_rosmsg::to_vec_skip_length(data).map_err(|e| {
+                Error::SerializationError(format!("Failed to serialize message: {e:?}"))
+            })?,
+        };
 
-        match self.publisher.put(&bytes).await {
-            Ok(()) => Ok(()),
-            Err(e) => Err(Error::Unexpected(anyhow::anyhow!(
-                "Failed to publish message to zenoh: {e:?}"
-            ))),
-        }
+        self.publisher
+            .put(&bytes)
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to publish message", e))
     }
 }
 
@@ -58,19 +77,19 @@ pub struct ZenohSubscriber<T> {
 
 impl<T: RosMessageType> Subscribe<T> for ZenohSubscriber<T> {
     async fn next(&mut self) -> Result<T> {
-        let next = self.subscriber.recv_async().await;
-
-        let sample = match next {
-            Ok(sample) => sample,
-            Err(e) => {
-                // TODO errors still suck with this API
-                return Err(Error::Unexpected(anyhow::anyhow!(
-                    "Failed to receive next sample: {e:?}"
-                )));
-            }
-        };
+        // We hold the subscriber, so its channel only closes when the session does
+        let sample = self
+            .subscriber
+            .recv_async()
+            .await
+            .map_err(|_| Error::Disconnected)?;
 
         let bytes = sample.payload().to_bytes();
+        if let Some(codec) = T::WIRE_CODEC {
+            return codec.decode_ros1(&bytes).map_err(|e| {
+                Error::SerializationError(format!("Failed to deserialize sample: {e:?}"))
+            });
+        }
         // Note: Zenoh decided to not make the 4 byte length header part of the payload
         // So we use the known length version of the deserialization
         let msg = roslibrust_serde_rosmsg::from_slice_known_length(&bytes, bytes.len() as u32)
@@ -81,6 +100,93 @@ impl<T: RosMessageType> Subscribe<T> for ZenohSubscriber<T> {
     }
 }
 
+/// A message received by [ZenohSubscriberAny::next_sample]
+#[derive(Debug, Clone)]
+pub struct ZenohSampleAny {
+    /// The topic the message was received on
+    pub topic: String,
+    /// ROS1 type of the message e.g. `std_msgs/String`
+    pub type_name: String,
+    pub md5sum: String,
+    /// The ROS1 serialized message body, without the 4 byte length header
+    pub data: Bytes,
+}
+
+/// The subscriber type returned by [ZenohClient::subscribe_any].
+/// This type is self de-registering, and dropping the subscriber will automatically unsubscribe from the topic.
+pub struct ZenohSubscriberAny {
+    subscriber: ZenohSubInner,
+}
+
+impl ZenohSubscriberAny {
+    /// Returns the raw bytes of the next message, or None once the session is closed.
+    ///
+    /// Matching the ros1 backend's `SubscriberAny`, this is the message body without the 4 byte length header.
+    pub async fn next(&mut self) -> Option<Bytes> {
+        self.next_sample().await.map(|sample| sample.data)
+    }
+
+    /// Same as [ZenohSubscriberAny::next], but also returns the type and md5sum the message was published with
+    pub async fn next_sample(&mut self) -> Option<ZenohSampleAny> {
+        loop {
+            let sample = self.subscriber.recv_async().await.ok()?;
+            let Some((topic, type_name, md5sum)) = demangle_topic(sample.key_expr().as_str())
+            else {
+                warn!(
+                    "Ignoring sample with unrecognized key {}",
+                    sample.key_expr()
+                );
+                continue;
+            };
+            return Some(ZenohSampleAny {
+                topic,
+                type_name,
+                md5sum,
+                data: Bytes::copy_from_slice(&sample.payload().to_bytes()),
+            });
+        }
+    }
+}
+
+/// The publisher type returned by [ZenohClient::advertise_any].
+/// This type is self de-registering, and dropping the publisher will automatically un-advertise the topic.
+pub struct ZenohPublisherAny {
+    session: zenoh::Session,
+    publisher: zenoh::pubsub::Publisher<'static>,
+}
+
+impl ZenohPublisherAny {
+    /// Publishes a ROS1 serialized message.
+    ///
+    /// Matching the ros1 backend's `PublisherAny`, `data` is expected to start with the 4 byte length header
+    /// as it would appear on the wire. Zenoh doesn't carry the header so it is removed before publishing.
+    pub async fn publish(&self, data: impl AsRef<[u8]>) -> Result<()> {
+        let body = strip_length_header(data.as_ref())?;
+        self.publisher
+            .put(body)
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to publish message", e))
+    }
+}
+
+/// Returns the message body following the 4 byte length header, checking the header matches the body
+fn strip_length_header(data: &[u8]) -> Result<&[u8]> {
+    let Some((header, body)) = data.split_first_chunk::<4>() else {
+        return Err(Error::SerializationError(format!(
+            "Message of {} bytes is too short to contain a length header",
+            data.len()
+        )));
+    };
+    let length = u32::from_le_bytes(*header) as usize;
+    if length != body.len() {
+        return Err(Error::SerializationError(format!(
+            "Length header of {length} doesn't match message body of {} bytes",
+            body.len()
+        )));
+    }
+    Ok(body)
+}
+
 impl TopicProvider for ZenohClient {
     type Publisher<T: RosMessageType> = ZenohPublisher<T>;
 
@@ -92,17 +198,14 @@ impl TopicProvider for ZenohClient {
     ) -> Result<Self::Publisher<MsgType>> {
         let topic: GlobalTopicName = topic.to_global_name()?;
         let mangled_topic = mangle_topic(topic.as_ref(), MsgType::ROS_TYPE_NAME, MsgType::MD5SUM);
-        let publisher = match self.session.declare_publisher(mangled_topic).await {
-            Ok(publisher) => publisher,
-            Err(e) => {
-                // TODO errors still suck with this API...
-                return Err(Error::Unexpected(anyhow::anyhow!(
-                    "Failed to declare publisher: {e:?}"
-                )));
-            }
-        };
+        let publisher = self
+            .session
+            .declare_publisher(mangled_topic)
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to declare publisher", e))?;
 
         Ok(ZenohPublisher {
+            session: self.session.clone(),
             publisher,
             _marker: std::marker::PhantomData,
         })
@@ -114,15 +217,11 @@ impl TopicProvider for ZenohClient {
     ) -> Result<Self::Subscriber<MsgType>> {
         let topic: GlobalTopicName = topic.to_global_name()?;
         let mangled_topic = mangle_topic(topic.as_ref(), MsgType::ROS_TYPE_NAME, MsgType::MD5SUM);
-        let sub = match self.session.declare_subscriber(mangled_topic).await {
-            Ok(sub) => sub,
-            Err(e) => {
-                // TODO errors still suck with this API...
-                return Err(Error::Unexpected(anyhow::anyhow!(
-                    "Failed to declare subscriber: {e:?}"
-                )));
-            }
-        };
+        let sub = self
+            .session
+            .declare_subscriber(mangled_topic)
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to declare subscriber", e))?;
         Ok(ZenohSubscriber {
             subscriber: sub,
             _marker: std::marker::PhantomData,
@@ -152,14 +251,212 @@ fn mangle_service(service: &str, type_str: &str, md5sum: &str) -> (String, Strin
     (format!("{type_str}/{md5sum}"), service.to_string())
 }
 
+/// Reverses [mangle_topic], returning the topic, type and md5sum encoded in a key of the form `{hex type}/{md5sum}/{topic}`
+fn demangle_topic(key: &str) -> Option<(String, String, String)> {
+    let mut parts = key.splitn(3, '/');
+    let type_name = String::from_utf8(hex::decode(parts.next()?).ok()?).ok()?;
+    let md5sum = parts.next()?.to_string();
+    let topic = format!("/{}", parts.next()?);
+    Some((topic, type_name, md5sum))
+}
+
+/// What a zenoh-ros1-bridge is bridging for a [DiscoveryEntry]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveryKind {
+    /// A ROS1 node publishes the topic
+    Publisher,
+    /// A ROS1 node subscribes to the topic
+    Subscriber,
+    /// A ROS1 node provides the service
+    Service,
+    /// A ROS1 node calls the service
+    ServiceClient,
+}
+
+/// A topic or service announced by a zenoh-ros1-bridge under `ros1_discovery_info`, returned by [ZenohClient::discover]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveryEntry {
+    pub kind: DiscoveryKind,
+    /// Name of the topic or service e.g. `/chatter`
+    pub topic: String,
+    /// ROS1 type of the topic or service e.g. `std_msgs/String`
+    pub type_name: String,
+    pub md5sum: String,
+    /// Namespace of the bridge which announced the entry
+    pub bridge_namespace: String,
+}
+
+/// Parses a key of the form `ros1_discovery_info/{discovery_ns}/{class}/{hex type}/{md5sum}/{bridge_ns}/{topic}`
+fn parse_discovery_key(key: &str) -> Option<DiscoveryEntry> {
+    let mut parts = key.splitn(7, '/');
+    if parts.next()? != "ros1_discovery_info" {
+        return None;
+    }
+    let _discovery_namespace = parts.next()?;
+    let kind = match parts.next()? {
+        "pub" => DiscoveryKind::Publisher,
+        "sub" => DiscoveryKind::Subscriber,
+        "srv" => DiscoveryKind::Service,
+        "cl" => DiscoveryKind::ServiceClient,
+        _ => return None,
+    };
+    let type_name = String::from_utf8(hex::decode(parts.next()?).ok()?).ok()?;
+    let md5sum = parts.next()?.to_string();
+    let bridge_namespace = parts.next()?.to_string();
+    let topic = format!("/{}", parts.next()?);
+    Some(DiscoveryEntry {
+        kind,
+        topic,
+        type_name,
+        md5sum,
+        bridge_namespace,
+    })
+}
+
+impl ZenohClient {
+    /// Returns the topics and services currently bridged by the zenoh-ros1-bridges reachable from this session.
+    ///
+    /// This queries the liveliness tokens the bridges declare under `ros1_discovery_info`, and decodes the
+    /// type names they carry. Resources which are only used from zenoh (e.g. by another [ZenohClient]) aren't announced
+    /// by any bridge and won't be returned.
+    pub async fn discover(&self) -> Result<Vec<DiscoveryEntry>> {
+        let replies = self
+            .session
+            .liveliness()
+            .get("ros1_discovery_info/**")
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to query discovery info", e))?;
+        let mut entries = vec![];
+        while let Ok(reply) = replies.recv_async().await {
+            let Ok(sample) = reply.result() else {
+                continue;
+            };
+            match parse_discovery_key(sample.key_expr().as_str()) {
+                Some(entry) => entries.push(entry),
+                None => debug!("Ignoring unrecognized discovery key {}", sample.key_expr()),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Subscribes to a topic without knowing its type, receiving the raw ROS1 serialized messages.
+    ///
+    /// The subscription matches the topic with any type and md5sum, see [ZenohSubscriberAny::next_sample] to find out
+    /// which type each message has. Like the ros1 backend's `subscribe_any` the returned bytes are the message body
+    /// without the 4 byte length header.
+    pub async fn subscribe_any(&self, topic: impl ToGlobalTopicName) -> Result<ZenohSubscriberAny> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        // Same as mangle_topic, but matching any type and md5sum
+        let key = format!("*/*/{}", topic.as_ref().trim_matches('/'));
+        let subscriber = self
+            .session
+            .declare_subscriber(key)
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to declare subscriber", e))?;
+        Ok(ZenohSubscriberAny { subscriber })
+    }
+
+    /// Advertises a topic whose type isn't known at compile time, publishing raw ROS1 serialized messages.
+    ///
+    /// `topic_type` and `md5sum` are what the zenoh-ros1-bridge will advertise the topic as in ROS1, e.g. as found via
+    /// [ZenohClient::discover] or read from a bag file.
+    pub async fn advertise_any(
+        &self,
+        topic: impl ToGlobalTopicName,
+        topic_type: &str,
+        md5sum: &str,
+    ) -> Result<ZenohPublisherAny> {
+        let topic: GlobalTopicName = topic.to_global_name()?;
+        let mangled_topic = mangle_topic(topic.as_ref(), topic_type, md5sum);
+        let publisher = self
+            .session
+            .declare_publisher(mangled_topic)
+            .await
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to declare publisher", e))?;
+        Ok(ZenohPublisherAny {
+            session: self.session.clone(),
+            publisher,
+        })
+    }
+}
+
+/// How often [GraphProvider::graph_events] re-queries the bridges' discovery info
+const GRAPH_POLL_PERIOD: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Built from the liveliness tokens zenoh-ros1-bridge declares for each ROS1 resource it bridges.
+///
+/// The bridges don't share which ROS1 nodes are behind a topic, so each bridge is reported as a node named
+/// after its bridge namespace. Service clients announced by bridges aren't part of the [Graph].
+impl GraphProvider for ZenohClient {
+    async fn graph(&self) -> Result<Graph> {
+        let mut graph = Graph::default();
+        for entry in self.discover().await? {
+            let node = &entry.bridge_namespace;
+            match entry.kind {
+                DiscoveryKind::Publisher => {
+                    graph.add_publisher(&entry.topic, &entry.type_name, node)
+                }
+                DiscoveryKind::Subscriber => {
+                    graph.add_subscriber(&entry.topic, &entry.type_name, node)
+                }
+                DiscoveryKind::Service => {
+                    graph.add_service_provider(&entry.topic, &entry.type_name, node)
+                }
+                DiscoveryKind::ServiceClient => {}
+            }
+        }
+        Ok(graph)
+    }
+
+    fn graph_events(
+        &self,
+    ) -> impl futures_core::Stream<Item = Result<GraphEvent>> + Send + 'static {
+        // The session is owned by the caller, the stream ends once it is closed
+        let client = self.clone();
+        poll_graph_events(
+            move || {
+                if client.session.is_closed() {
+                    return None;
+                }
+                let client = client.clone();
+                Some(async move { client.graph().await })
+            },
+            || tokio::time::sleep(GRAPH_POLL_PERIOD),
+        )
+    }
+}
+
+/// How long [ZenohServiceClient] waits for a response by default, matching zenoh's default query timeout
+const DEFAULT_SERVICE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 /// The client type returned by [ServiceProvider::service_client] on [ZenohClient]
 /// This type allows calling a service multiple times without re-negotiating the connection each time.
+///
+/// Calls fail with [Error::Timeout] when no response arrives within the timeout (10 seconds unless changed with
+/// [ZenohServiceClient::with_timeout]), and with [Error::ServerError] when the service replies with an error.
 pub struct ZenohServiceClient<T: RosServiceType> {
     session: zenoh::Session,
     zenoh_query: String,
+    timeout: std::time::Duration,
+    consolidation: Option<zenoh::query::ConsolidationMode>,
     _marker: std::marker::PhantomData<T>,
 }
 
+impl<T: RosServiceType> ZenohServiceClient<T> {
+    /// Sets how long each call waits for a response before failing with [Error::Timeout]
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the consolidation mode of the queries sent for each call, which controls how replies from
+    /// multiple service servers are combined. Zenoh's default is used if this isn't called.
+    pub fn with_consolidation(mut self, consolidation: zenoh::query::ConsolidationMode) -> Self {
+        self.consolidation = Some(consolidation);
+        self
+    }
+}
+
 impl<T: RosServiceType> Service<T> for ZenohServiceClient<T> {
     async fn call(&self, request: &T::Request) -> Result<T::Response> {
         // Note: Zenoh decided the 4 byte length header is not part of the payload
@@ -168,48 +465,79 @@ impl<T: RosServiceType> Service<T> for ZenohServiceClient<T> {
         })?;
         debug!("request bytes: {request_bytes:?}");
 
-        let query = match self
+        let started = std::time::Instant::now();
+        let mut query = self
             .session
             .get(&self.zenoh_query)
             .payload(&request_bytes)
+            .timeout(self.timeout);
+        if let Some(consolidation) = self.consolidation {
+            query = query.consolidation(consolidation);
+        }
+        let replies = query
             .await
-        {
-            Ok(query) => query,
-            Err(e) => {
-                // TODO errors still suck with this API...
-                return Err(Error::Unexpected(anyhow::anyhow!(
-                    "Failed to create query for service: {e:?}"
-                )));
-            }
-        };
-
-        let response = match query.recv_async().await {
-            Ok(data) => data,
-            Err(e) => {
-                return Err(Error::Unexpected(anyhow::anyhow!(
-                    "Failed to receive response from service: {e:?}"
-                )));
-            }
-        };
-
-        // TODO unclear why this is double failable in the API
-        let sample = match response.into_result() {
-            Ok(bytes) => bytes,
-            Err(e) => {
-                return Err(Error::Unexpected(anyhow::anyhow!(
-                    "Failed to receive sample from service: {e:?}"
-                )));
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to query service", e))?;
+
+        // Each server that matched the query sends us a reply, which is either a response or an error.
+        // Zenoh also reports hitting the timeout as an error reply, see [server_error_from_reply].
+        let mut error = None;
+        let mut timed_out = false;
+        let mut received_reply = false;
+        while let Ok(reply) = replies.recv_async().await {
+            received_reply = true;
+            let replier_known = reply.replier_id().is_some();
+            match reply.into_result() {
+                Ok(sample) => {
+                    let bytes = sample.payload().to_bytes();
+                    // Note: Zenoh decided to not make the 4 byte length header part of the payload
+                    return roslibrust_serde_rosmsg::from_slice_known_length(
+                        &bytes,
+                        bytes.len() as u32,
+                    )
+                    .map_err(|e| {
+                        Error::SerializationError(format!("Failed to deserialize response: {e:?}"))
+                    });
+                }
+                Err(e) => {
+                    let payload = e.payload().try_to_string().unwrap_or_default().to_string();
+                    match server_error_from_reply(payload, replier_known) {
+                        Some(server_error) => error = Some(server_error),
+                        None => timed_out = true,
+                    }
+                }
             }
-        };
+        }
 
-        let bytes = sample.payload().to_bytes();
+        if let Some(error) = error {
+            Err(error)
+        } else if self.session.is_closed() {
+            Err(Error::Disconnected)
+        } else if timed_out || (!received_reply && started.elapsed() >= self.timeout) {
+            Err(Error::Timeout(format!(
+                "No response from service {} within {:?}",
+                self.zenoh_query, self.timeout
+            )))
+        } else {
+            Err(Error::ServerError(format!(
+                "No service server replied on {}",
+                self.zenoh_query
+            )))
+        }
+    }
+}
 
-        // Note: Zenoh decided to not make the 4 byte length header part of the payload
-        let msg = roslibrust_serde_rosmsg::from_slice_known_length(&bytes, bytes.len() as u32)
-            .map_err(|e| {
-                Error::SerializationError(format!("Failed to deserialize sample: {e:?}"))
-            })?;
-        Ok(msg)
+/// The payload of the error reply zenoh makes up locally when a query runs out of time
+const ZENOH_TIMEOUT_PAYLOAD: &str = "Timeout";
+
+/// Converts an error reply to a service query into the error the caller gets.
+///
+/// Returns `None` for the reply zenoh sends when the query times out, which unlike replies from
+/// service servers has no replier and carries [ZENOH_TIMEOUT_PAYLOAD].
+fn server_error_from_reply(payload: String, replier_known: bool) -> Option<Error> {
+    if !replier_known && payload == ZENOH_TIMEOUT_PAYLOAD {
+        None
+    } else {
+        Some(Error::ServerError(payload))
     }
 }
 
@@ -222,6 +550,32 @@ pub struct ZenohServiceServer {
     _shutdown_channel: tokio::sync::oneshot::Sender<()>,
 }
 
+/// Runs `server` on the payload of a service query, returning the serialized response or a description of what failed
+async fn handle_service_query<SrvType: RosServiceType, F: ServiceFn<SrvType>>(
+    server: &std::sync::Arc<F>,
+    payload: Option<&ZBytes>,
+) -> std::result::Result<Vec<u8>, String> {
+    let Some(payload) = payload else {
+        return Err("Received a query with no payload for a ros1 service".to_string());
+    };
+    let bytes = payload.to_bytes();
+    debug!("Got bytes: {bytes:?}");
+
+    // Note: Zenoh decided the 4 byte length header is not part of the payload
+    let request = roslibrust_serde_rosmsg::from_slice_known_length(&bytes, bytes.len() as u32)
+        .map_err(|e| format!("Failed to deserialize request: {e:?}"))?;
+
+    // Evaluate the server function inside a spawn_blocking to uphold trait expectations from roslibrust_common
+    let server = server.clone();
+    let response = tokio::task::spawn_blocking(move || server(request))
+        .await
+        .map_err(|e| format!("Failed to join task: {e:?}"))?
+        .map_err(|e| format!("Failed to handle request: {e:?}"))?;
+
+    roslibrust_serde_rosmsg::to_vec_skip_length(&response)
+        .map_err(|e| format!("Failed to serialize response: {e:?}"))
+}
+
 impl ServiceProvider for ZenohClient {
     type ServiceClient<T: RosServiceType> = ZenohServiceClient<T>;
     type ServiceServer = ZenohServiceServer;
@@ -248,6 +602,8 @@ impl ServiceProvider for ZenohClient {
         Ok(ZenohServiceClient {
             session: self.session.clone(),
             zenoh_query: mangled_topic,
+            timeout: DEFAULT_SERVICE_TIMEOUT,
+            consolidation: None,
             _marker: std::marker::PhantomData,
         })
     }
@@ -273,9 +629,7 @@ impl ServiceProvider for ZenohClient {
                 });
             })
             .await
-            .map_err(|e| {
-                Error::Unexpected(anyhow::anyhow!("Failed to declare queryable: {e:?}"))
-            })?;
+            .map_err(|e| map_zenoh_error(&self.session, "Failed to declare queryable", e))?;
 
         // Move the server into an Arc so we can ensure lifetime of it remains valid across spawn_blocking:
         let server = std::sync::Arc::new(server);
@@ -285,52 +639,18 @@ impl ServiceProvider for ZenohClient {
         tokio::spawn(async move {
             while let Some(query) = rx.recv().await {
                 debug!("Got query: {query:?}");
-                let Some(payload) = query.payload() else {
-                    error!("Received a query with no payload for a ros0 service {query:?}");
-                    continue;
-                };
-                let bytes = payload.to_bytes();
-                debug!("Got bytes: {bytes:?}");
-
-                // Note: Zenoh decided the 4 byte length header is not part of the payload
-                let Ok(request) =
-                    roslibrust_serde_rosmsg::from_slice_known_length(&bytes, bytes.len() as u32)
-                        .map_err(|e| {
-                            error!("Failed to deserialize request: {e:?}");
-                        })
-                else {
-                    continue;
-                };
-
-                // Evaluate the server function inside a spawn_blocking to uphold trait expectations from roslibrust_common
-                let server_copy = server.clone();
-                let join_response = tokio::task::spawn_blocking(move || server_copy(request)).await;
-                let response = match join_response {
-                    Ok(Ok(response)) => response,
-                    Ok(Err(e)) => {
-                        error!("Failed to handle request: {e:?}");
-                        continue;
-                    }
-                    Err(e) => {
-                        error!("Failed to join task: {e:?}");
-                        continue;
+                // Failures are sent back as error replies so the caller gets an error instead of waiting for a timeout
+                let reply = match handle_service_query::<SrvType, F>(&server, query.payload()).await
+                {
+                    Ok(response_bytes) => query.reply(query.key_expr(), response_bytes).await,
+                    Err(message) => {
+                        error!("{message}");
+                        query.reply_err(message).await
                     }
                 };
-
-                let Ok(response_bytes) = roslibrust_serde_rosmsg::to_vec_skip_length(&response)
-                    .map_err(|e| {
-                        error!("Failed to serialize response: {e:?}");
-                    })
-                else {
-                    continue;
-                };
-
-                let _ = query
-                    .reply(query.key_expr(), response_bytes)
-                    .await
-                    .map_err(|e| {
-                        error!("Failed to reply to query: {e:?}");
-                    });
+                let _ = reply.map_err(|e| {
+                    error!("Failed to reply to query: {e:?}");
+                });
             }
         });
         // zenoh-ros1-bridge won't serve our service without us publishing info on 'ros1_discovery_info'
@@ -350,9 +670,11 @@ impl ServiceProvider for ZenohClient {
             .declare_publisher(zenoh_info_topic)
             .await
             .map_err(|e| {
-                Error::Unexpected(anyhow::anyhow!(
-                    "Failed to declare queryable for service discovery: {e:?}"
-                ))
+                map_zenoh_error(
+                    &self.session,
+                    "Failed to declare publisher for service discovery",
+                    e,
+                )
             })?;
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
@@ -412,6 +734,154 @@ mod tests {
             "7374645f737276732f536574426f6f6c/09fb03525b03e7ea1fd3992bafd87e16/service_server_rs/my_set_bool");
     }
 
+    #[test]
+    fn test_parse_discovery_key() {
+        let (type_mangle, svc_name) = mangle_service(
+            "/service_server_rs/my_set_bool",
+            "std_srvs/SetBool",
+            "09fb03525b03e7ea1fd3992bafd87e16",
+        );
+        let key = format!("ros1_discovery_info/*/srv/{type_mangle}/bridge/{svc_name}");
+        assert_eq!(
+            parse_discovery_key(&key),
+            Some(DiscoveryEntry {
+                kind: DiscoveryKind::Service,
+                topic: "/service_server_rs/my_set_bool".to_string(),
+                type_name: "std_srvs/SetBool".to_string(),
+                md5sum: "09fb03525b03e7ea1fd3992bafd87e16".to_string(),
+                bridge_namespace: "bridge".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_discovery_key(
+                "7374645f6d7367732f537472696e67/992ce8a1687cec8c8bd883ec73ca41d1/chatter"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_demangle_topic() {
+        let key = mangle_topic(
+            "/ns/chatter",
+            "std_msgs/String",
+            "992ce8a1687cec8c8bd883ec73ca41d1",
+        );
+        assert_eq!(
+            demangle_topic(&key),
+            Some((
+                "/ns/chatter".to_string(),
+                "std_msgs/String".to_string(),
+                "992ce8a1687cec8c8bd883ec73ca41d1".to_string()
+            ))
+        );
+        assert_eq!(demangle_topic("not_hex/abc/chatter"), None);
+    }
+
+    #[test]
+    fn test_strip_length_header() {
+        let data = [5, 0, 0, 0, 1, 0, 0, 0, b'a'];
+        assert_eq!(strip_length_header(&data).unwrap(), &data[4..]);
+        assert!(strip_length_header(&data[..8]).is_err());
+        assert!(strip_length_header(&[1, 0]).is_err());
+    }
+
+    #[test]
+    fn test_server_error_from_reply() {
+        assert!(server_error_from_reply("Timeout".to_string(), false).is_none());
+        // A server can reply with the same text, but it is known who sent it
+        assert!(matches!(
+            server_error_from_reply("Timeout".to_string(), true),
+            Some(Error::ServerError(message)) if message == "Timeout"
+        ));
+        assert!(matches!(
+            server_error_from_reply("Failed to handle request".to_string(), true),
+            Some(Error::ServerError(message)) if message == "Failed to handle request"
+        ));
+        assert!(matches!(
+            server_error_from_reply(String::new(), false),
+            Some(Error::ServerError(_))
+        ));
+    }
+
+    #[derive(
+        roslibrust_codegen::Serialize, roslibrust_codegen::Deserialize, Debug, Clone, PartialEq,
+    )]
+    #[serde(crate = "roslibrust_codegen::serde")]
+    struct AddTwoIntsRequest {
+        a: i64,
+        b: i64,
+    }
+
+    impl RosMessageType for AddTwoIntsRequest {
+        const ROS_TYPE_NAME: &'static str = "test_srvs/AddTwoIntsRequest";
+    }
+
+    #[derive(
+        roslibrust_codegen::Serialize, roslibrust_codegen::Deserialize, Debug, Clone, PartialEq,
+    )]
+    #[serde(crate = "roslibrust_codegen::serde")]
+    struct AddTwoIntsResponse {
+        sum: i64,
+    }
+
+    impl RosMessageType for AddTwoIntsResponse {
+        const ROS_TYPE_NAME: &'static str = "test_srvs/AddTwoIntsResponse";
+    }
+
+    struct AddTwoInts;
+
+    impl RosServiceType for AddTwoInts {
+        const ROS_SERVICE_NAME: &'static str = "test_srvs/AddTwoInts";
+        type Request = AddTwoIntsRequest;
+        type Response = AddTwoIntsResponse;
+    }
+
+    #[tokio::test]
+    async fn test_handle_service_query() {
+        let server = std::sync::Arc::new(
+            |request: AddTwoIntsRequest| -> std::result::Result<AddTwoIntsResponse, ServiceError> {
+                if request.a < 0 {
+                    return Err(anyhow::anyhow!("a must not be negative"));
+                }
+                Ok(AddTwoIntsResponse {
+                    sum: request.a + request.b,
+                })
+            },
+        );
+        let payload = |request: &AddTwoIntsRequest| {
+            ZBytes::from(roslibrust_serde_rosmsg::to_vec_skip_length(request).unwrap())
+        };
+
+        let response = handle_service_query::<AddTwoInts, _>(
+            &server,
+            Some(&payload(&AddTwoIntsRequest { a: 1, b: 2 })),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response, 3i64.to_le_bytes());
+
+        let error = handle_service_query::<AddTwoInts, _>(
+            &server,
+            Some(&payload(&AddTwoIntsRequest { a: -1, b: 2 })),
+        )
+        .await
+        .unwrap_err();
+        assert!(error.starts_with("Failed to handle request"), "{error}");
+
+        let error = handle_service_query::<AddTwoInts, _>(&server, Some(&ZBytes::from(vec![1u8])))
+            .await
+            .unwrap_err();
+        assert!(
+            error.starts_with("Failed to deserialize request"),
+            "{error}"
+        );
+
+        assert!(handle_service_query::<AddTwoInts, _>(&server, None)
+            .await
+            .is_err());
+    }
+
     #[test]
     #[should_panic]
     #[allow(clippy::unnecessary_literal_unwrap)]